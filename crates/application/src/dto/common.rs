@@ -12,11 +12,22 @@ pub struct PaginationQuery {
     pub page_size: i32,
 }
 
-fn default_page_index() -> i32 {
+pub(crate) fn default_page_index() -> i32 {
     1
 }
 
-fn default_page_size() -> i32 {
+pub(crate) fn default_page_size() -> i32 {
     10
 }
 
+
+/// 反序列化可清除的字段：不传为 `None`（保持不变），传 `null` 为 `Some(None)`（清除）
+///
+/// 需要配合 `#[serde(default)]` 使用
+pub(crate) fn deserialize_optional_field<'de, T, D>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    T: Deserialize<'de>,
+    D: serde::Deserializer<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
+}
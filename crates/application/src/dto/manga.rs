@@ -29,6 +29,10 @@ pub struct MangaInfo {
     pub media_library_id: i32,
     pub cover: Option<String>,
     pub has_chapters: bool,
+    /// 所属系列 ID
+    pub series_id: Option<i32>,
+    /// 在系列中的卷号
+    pub volume_number: Option<f32>,
     pub create_time: String,
     pub update_time: String,
 }
@@ -80,6 +84,8 @@ impl From<domain::entity::manga::Model> for MangaInfo {
             media_library_id: manga.media_library_id,
             cover: manga.cover,
             has_chapters: manga.has_chapters,
+            series_id: manga.series_id,
+            volume_number: manga.volume_number,
             create_time: manga.create_time,
             update_time: manga.update_time,
         }
@@ -126,3 +132,47 @@ pub struct ThumbnailQuery {
     pub quality: Option<u8>,
 }
 
+
+/// 设置漫画封面页请求 DTO
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UpdateMangaCoverRequest {
+    /// 封面所在章节 ID，单文件夹漫画不传
+    #[schema(example = 5)]
+    pub chapter_id: Option<i32>,
+    /// 封面页索引（从 0 开始），不传则恢复自动选择封面
+    #[schema(example = 2)]
+    pub page_index: Option<i32>,
+}
+
+/// 漫画健康报告查询参数
+#[derive(Debug, Clone, Deserialize)]
+pub struct MangaHealthQuery {
+    /// 只查询指定媒体库，不传则查询全部
+    pub media_library_id: Option<i32>,
+}
+
+/// 漫画健康报告 DTO（列出无法读取的页面）
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MangaHealthReport {
+    pub manga_id: i32,
+    pub title: String,
+    pub media_library_id: i32,
+    /// 单文件夹漫画中无法读取的页面文件名
+    #[schema(example = json!(["012.jpg"]))]
+    pub bad_pages: Vec<String>,
+    /// 存在损坏页面的章节（章节漫画）
+    pub chapters: Vec<ChapterHealthReport>,
+    /// 损坏页面总数
+    #[schema(example = 1)]
+    pub bad_page_count: usize,
+}
+
+/// 章节健康报告 DTO
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ChapterHealthReport {
+    pub chapter_id: i32,
+    pub title: String,
+    /// 无法读取的页面文件名
+    #[schema(example = json!(["007.png"]))]
+    pub bad_pages: Vec<String>,
+}
@@ -46,3 +46,11 @@ pub struct OptimizedChapterImageListResponse {
     pub url_template: String,
 }
 
+
+/// 设置章节封面页请求 DTO
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UpdateChapterCoverRequest {
+    /// 封面页索引（从 0 开始），不传则恢复自动选择封面
+    #[schema(example = 2)]
+    pub page_index: Option<i32>,
+}
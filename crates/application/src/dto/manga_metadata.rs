@@ -0,0 +1,85 @@
+use infrastructure::file_scanner::manga_provider::{MangaDetails, MangaSearchResult};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// 漫画元数据搜索参数
+#[derive(Debug, Clone, Deserialize)]
+pub struct MangaMetadataQuery {
+    /// 搜索关键词，不传则使用漫画标题
+    pub title: Option<String>,
+}
+
+/// 漫画元数据搜索结果 DTO
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MangaMetadataSearchInfo {
+    /// 数据源名称（如 AniList）
+    #[schema(example = "AniList")]
+    pub provider: String,
+    /// 数据源中的漫画 ID，用于获取详情
+    #[schema(example = 30013)]
+    pub provider_id: u64,
+    pub title: String,
+    /// 原始标题（通常为日文）
+    pub original_title: Option<String>,
+    pub start_year: Option<i32>,
+    pub cover_url: Option<String>,
+}
+
+impl MangaMetadataSearchInfo {
+    pub fn new(provider: &str, result: MangaSearchResult) -> Self {
+        MangaMetadataSearchInfo {
+            provider: provider.to_string(),
+            provider_id: result.provider_id,
+            title: result.title,
+            original_title: result.original_title,
+            start_year: result.start_year,
+            cover_url: result.cover_url,
+        }
+    }
+}
+
+/// 漫画元数据详情 DTO
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MangaMetadataInfo {
+    /// 数据源名称（如 AniList）
+    #[schema(example = "AniList")]
+    pub provider: String,
+    #[schema(example = 30013)]
+    pub provider_id: u64,
+    pub title: String,
+    pub original_title: Option<String>,
+    /// 简介（纯文本）
+    pub synopsis: String,
+    /// 作者列表（原作和作画）
+    pub authors: Vec<String>,
+    pub genres: Vec<String>,
+    /// 连载状态：ongoing / completed / hiatus / cancelled / not_yet_released / unknown
+    #[schema(example = "ongoing")]
+    pub status: String,
+    pub start_year: Option<i32>,
+    pub volumes: Option<i32>,
+    pub chapters: Option<i32>,
+    pub cover_url: Option<String>,
+    /// 数据源中的页面 URL
+    pub site_url: Option<String>,
+}
+
+impl MangaMetadataInfo {
+    pub fn new(provider: &str, details: MangaDetails) -> Self {
+        MangaMetadataInfo {
+            provider: provider.to_string(),
+            provider_id: details.provider_id,
+            title: details.title,
+            original_title: details.original_title,
+            synopsis: details.synopsis,
+            authors: details.authors,
+            genres: details.genres,
+            status: details.status.as_str().to_string(),
+            start_year: details.start_year,
+            volumes: details.volumes,
+            chapters: details.chapters,
+            cover_url: details.cover_url,
+            site_url: details.site_url,
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::dto::MangaInfo;
+use crate::dto::common::deserialize_optional_field;
+
+/// 漫画系列信息 DTO
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MangaSeriesInfo {
+    pub id: i32,
+    pub title: String,
+    pub description: Option<String>,
+    /// 作为系列封面的漫画 ID（为空时使用第一卷的封面）
+    pub cover_manga_id: Option<i32>,
+    pub media_library_id: i32,
+    pub create_time: String,
+    pub update_time: String,
+}
+
+/// 从 Domain 层的 MangaSeries Model 转换为 MangaSeriesInfo DTO
+impl From<domain::entity::manga_series::Model> for MangaSeriesInfo {
+    fn from(series: domain::entity::manga_series::Model) -> Self {
+        MangaSeriesInfo {
+            id: series.id,
+            title: series.title,
+            description: series.description,
+            cover_manga_id: series.cover_manga_id,
+            media_library_id: series.media_library_id,
+            create_time: series.create_time,
+            update_time: series.update_time,
+        }
+    }
+}
+
+/// 漫画系列详情 DTO（包含按卷号排序的所有漫画）
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MangaSeriesDetail {
+    pub series: MangaSeriesInfo,
+    /// 系列中的所有漫画，按卷号排序（无卷号的排在最后）
+    pub volumes: Vec<MangaInfo>,
+}
+
+/// 更新漫画系列请求 DTO
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct UpdateMangaSeriesRequest {
+    /// 系列标题，不传则保持不变
+    #[schema(example = "One Piece")]
+    pub title: Option<String>,
+    /// 系列简介，不传则保持不变，传 null 则清除
+    #[serde(default, deserialize_with = "deserialize_optional_field")]
+    #[schema(value_type = Option<String>)]
+    pub description: Option<Option<String>>,
+    /// 作为系列封面的漫画 ID，不传则保持不变，传 null 则恢复使用第一卷的封面
+    #[serde(default, deserialize_with = "deserialize_optional_field")]
+    #[schema(value_type = Option<i32>, example = 12)]
+    pub cover_manga_id: Option<Option<i32>>,
+}
+
+/// 合并漫画系列请求 DTO
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MergeMangaSeriesRequest {
+    /// 要合并到当前系列的系列 ID 列表，合并后这些系列会被删除
+    #[schema(example = json!([3, 4]))]
+    pub source_series_ids: Vec<i32>,
+}
+
+/// 拆分漫画系列请求 DTO
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct SplitMangaSeriesRequest {
+    /// 要移出到新系列的漫画 ID 列表
+    #[schema(example = json!([12, 13]))]
+    pub manga_ids: Vec<i32>,
+    /// 新系列的标题，不传则使用第一本漫画的标题
+    pub title: Option<String>,
+}
@@ -7,8 +7,12 @@ pub mod user;
 pub mod media_library;
 pub mod manga;
 pub mod manga_chapter;
+pub mod manga_series;
+pub mod manga_metadata;
 pub mod game;
 pub mod movie;
+pub mod person;
+pub mod tv_series;
 pub mod photo;
 pub mod common;
 pub mod config;
@@ -18,10 +22,19 @@ pub mod scan_task;
 pub use auth::{LoginRequest, LoginResponse};
 pub use user::{RegisterRequest, UserInfo, FixPasswordsResponse};
 pub use media_library::{CreateMediaLibraryRequest, MediaLibraryInfo};
-pub use manga::{CreateMangaRequest, MangaInfo, PagedResponse, ImageInfo, OptimizedImageListResponse, ThumbnailQuery};
-pub use manga_chapter::{MangaChapterInfo, OptimizedChapterImageListResponse};
+pub use manga::{CreateMangaRequest, MangaInfo, PagedResponse, ImageInfo, OptimizedImageListResponse, ThumbnailQuery, UpdateMangaCoverRequest, MangaHealthQuery, MangaHealthReport, ChapterHealthReport};
+pub use manga_chapter::{MangaChapterInfo, OptimizedChapterImageListResponse, UpdateChapterCoverRequest};
+pub use manga_series::{MangaSeriesInfo, MangaSeriesDetail, UpdateMangaSeriesRequest, MergeMangaSeriesRequest, SplitMangaSeriesRequest};
+pub use manga_metadata::{MangaMetadataQuery, MangaMetadataSearchInfo, MangaMetadataInfo};
 pub use game::{CreateGameRequest, GameInfo, ScanGamesRequest, LaunchGameRequest, UpdateDefaultStartPathRequest};
-pub use movie::MovieInfo;
+pub use movie::{
+    IdentifyMovieRequest, MovieCollectionDetail, MovieCollectionInfo, MovieCollectionPartInfo, MovieCreditInfo, MovieDetailInfo,
+    MovieExtraInfo, MovieFacetsInfo, MovieIdentifyCandidate, MovieIdentifyQuery, MovieInfo, MovieListQuery, MovieListResponse, MoviePartInfo, MoviePlayItem,
+    MoviePlayListQuery, MoviePlayStateInfo, MovieSubtitleInfo, MovieTrackInfo,
+    MovieVersionInfo, MovieVideoQuery, NfoExportQuery, NfoExportResult, ReportMovieProgressRequest,
+};
+pub use person::{PersonDetail, PersonMovieInfo};
+pub use tv_series::{TvSeriesInfo, TvSeriesDetailInfo, TvSeasonInfo, TvSeasonDetailInfo, TvEpisodeInfo};
 pub use photo::{PhotoInfo, PhotoDetailInfo, PhotoExifInfo, PhotoAlbumInfo, PhotoScanOptions, PhotoScanResult};
 pub use common::PaginationQuery;
 pub use config::{
@@ -1,3 +1,6 @@
+use super::common::{default_page_index, default_page_size};
+use super::manga::PagedResponse;
+use domain::value_object::{MovieFacets, MovieFilter, MovieSortKey, ResolutionClass};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -107,6 +110,38 @@ pub struct MovieInfo {
     /// 封面图片路径
     #[schema(example = "/api/movies/1/cover")]
     pub cover: Option<String>,
+
+    /// 背景图 URL
+    #[schema(example = "https://image.tmdb.org/t/p/original/backdrop.jpg")]
+    pub backdrop: Option<String>,
+
+    /// TMDB 电影 ID
+    #[schema(example = 949)]
+    pub tmdb_id: Option<i64>,
+
+    /// IMDb 电影 ID
+    #[schema(example = "tt0113277")]
+    pub imdb_id: Option<String>,
+
+    /// 来源（从文件名解析）
+    #[schema(example = "BluRay")]
+    pub source: Option<String>,
+
+    /// 视频编码（从文件名解析）
+    #[schema(example = "x264")]
+    pub video_codec: Option<String>,
+
+    /// 版本（从文件名解析）
+    #[schema(example = "Director's Cut")]
+    pub edition: Option<String>,
+
+    /// 发布组（从文件名解析）
+    #[schema(example = "SPARKS")]
+    pub release_group: Option<String>,
+
+    /// 所属电影系列 ID
+    #[schema(example = 3)]
+    pub collection_id: Option<i32>,
     
     /// 所属媒体库 ID
     #[schema(example = 1)]
@@ -158,13 +193,686 @@ impl From<domain::entity::movie::Model> for MovieInfo {
             tags,
             poster_urls,
             cover: model.cover,
+            backdrop: model.backdrop,
+            tmdb_id: model.tmdb_id,
+            imdb_id: model.imdb_id,
+            source: model.source,
+            video_codec: model.video_codec,
+            edition: model.edition,
+            release_group: model.release_group,
+            collection_id: model.collection_id,
             media_library_id: model.media_library_id,
         }
     }
 }
 
+/// 电影详细信息 DTO（包含音视频和字幕轨道）
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MovieDetailInfo {
+    /// 基本电影信息
+    #[serde(flatten)]
+    pub movie: MovieInfo,
+
+    /// 视频编码（第一条视频轨道）
+    #[schema(example = "hevc")]
+    pub video_codec: Option<String>,
+
+    /// 是否为 HDR
+    #[schema(example = true)]
+    pub is_hdr: bool,
+
+    /// 帧率
+    #[schema(example = 23.976)]
+    pub frame_rate: Option<f64>,
+
+    /// 视频码率（bit/s）
+    #[schema(example = 24000000i64)]
+    pub bit_rate: Option<i64>,
+
+    /// 浏览器能否直接播放（无需转码）
+    #[schema(example = false)]
+    pub direct_play: bool,
+
+    /// 视频轨道列表
+    pub video_tracks: Vec<MovieTrackInfo>,
+
+    /// 音频轨道列表
+    pub audio_tracks: Vec<MovieTrackInfo>,
+
+    /// 内嵌字幕轨道列表
+    pub subtitle_tracks: Vec<MovieTrackInfo>,
+
+    /// 可播放的版本列表（第一个为默认版本）
+    pub versions: Vec<MovieVersionInfo>,
+
+    /// 演职人员（演员按出场顺序，之后是导演、编剧、制片人）
+    pub credits: Vec<MovieCreditInfo>,
+}
+
+impl MovieDetailInfo {
+    /// 根据电影、轨道、媒体源和演职人员构建详细信息
+    ///
+    /// 没有媒体源记录的电影（旧版本扫描入库）只有一个由电影文件构成的版本
+    pub fn new(
+        movie: domain::entity::movie::Model,
+        tracks: Vec<domain::entity::movie_track::Model>,
+        sources: Vec<domain::entity::movie_source::Model>,
+        credits: Vec<MovieCreditInfo>,
+    ) -> Self {
+        let direct_play = domain::MovieDomainService::can_direct_play(movie.extension.as_deref(), &tracks);
+        let video = tracks.iter().find(|t| t.is_video()).cloned();
+
+        let mut video_tracks: Vec<MovieTrackInfo> = Vec::new();
+        let mut audio_tracks: Vec<MovieTrackInfo> = Vec::new();
+        let mut subtitle_tracks: Vec<MovieTrackInfo> = Vec::new();
+        for track in tracks {
+            if track.is_video() {
+                video_tracks.push(track.into());
+            } else if track.is_audio() {
+                audio_tracks.push(track.into());
+            } else if track.is_subtitle() {
+                subtitle_tracks.push(track.into());
+            }
+        }
+
+        let versions = MovieVersionInfo::from_sources(&movie, sources);
+
+        MovieDetailInfo {
+            movie: movie.into(),
+            video_codec: video.as_ref().map(|v| v.codec.clone()),
+            is_hdr: video.as_ref().is_some_and(|v| v.is_hdr),
+            frame_rate: video.as_ref().and_then(|v| v.frame_rate),
+            bit_rate: video.as_ref().and_then(|v| v.bit_rate),
+            direct_play,
+            video_tracks,
+            audio_tracks,
+            subtitle_tracks,
+            versions,
+            credits,
+        }
+    }
+}
+
+/// 电影演职人员信息 DTO
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MovieCreditInfo {
+    /// 人物 ID（用于 `/api/people/{id}`）
+    #[schema(example = 1)]
+    pub person_id: i32,
+
+    /// 姓名
+    #[schema(example = "Al Pacino")]
+    pub name: String,
+
+    /// 职责（actor / director / writer / producer）
+    #[schema(example = "actor")]
+    pub role: String,
+
+    /// 饰演的角色（仅演员）
+    #[schema(example = "Vincent Hanna")]
+    pub character: Option<String>,
+
+    /// 头像 URL
+    #[schema(example = "https://image.tmdb.org/t/p/w185/fMDFeVf0pjopTJbyRSLFwNDm8Wr.jpg")]
+    pub profile_url: Option<String>,
+}
+
+impl MovieCreditInfo {
+    /// 按人物 ID 关联演职人员和人物（找不到人物的记录被忽略）
+    pub fn from_credits(
+        credits: Vec<domain::entity::movie_credit::Model>,
+        persons: &[domain::entity::person::Model],
+    ) -> Vec<Self> {
+        credits
+            .into_iter()
+            .filter_map(|credit| {
+                let person = persons.iter().find(|p| p.id == credit.person_id)?;
+                Some(MovieCreditInfo {
+                    person_id: person.id,
+                    name: person.name.clone(),
+                    role: credit.role,
+                    character: credit.character_name,
+                    profile_url: person.profile_url.clone(),
+                })
+            })
+            .collect()
+    }
+}
+
+/// 电影版本信息 DTO（同一电影的不同文件，如 1080p / 2160p）
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MovieVersionInfo {
+    /// 版本序号，播放时作为 `source` 参数
+    #[schema(example = 0)]
+    pub index: i32,
+
+    /// 版本名称
+    #[schema(example = "2160p BluRay")]
+    pub label: String,
+
+    /// 分辨率
+    #[schema(example = "3840x2160")]
+    pub resolution: Option<String>,
+
+    /// 所有分段的总大小（字节）
+    #[schema(example = 42949672960i64)]
+    pub byte_size: i64,
+
+    /// 格式化的总大小
+    #[schema(example = "40.0 GB")]
+    pub formatted_size: String,
+
+    /// 所有分段的总时长（秒）
+    #[schema(example = 9000)]
+    pub duration: i32,
+
+    /// 格式化的总时长
+    #[schema(example = "2h 30m")]
+    pub formatted_duration: String,
+
+    /// 分段文件，按播放顺序排列；未分段时只有一项
+    pub parts: Vec<MoviePartInfo>,
+}
+
+/// 电影分段文件信息 DTO
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MoviePartInfo {
+    /// 文件名中的分段序号（`CD2` 为 2），未分段时为空
+    #[schema(example = 1)]
+    pub part_index: Option<i32>,
+
+    /// 视频文件路径
+    #[schema(example = "/movies/Heat (1995) - CD1.avi")]
+    pub path: String,
+
+    /// 文件扩展名
+    #[schema(example = "avi")]
+    pub extension: Option<String>,
+
+    /// 文件大小（字节）
+    #[schema(example = 734003200i64)]
+    pub byte_size: i64,
+
+    /// 时长（秒）
+    #[schema(example = 5100)]
+    pub duration: i32,
+}
+
+impl MovieVersionInfo {
+    /// 按版本序号把媒体源分组（媒体源已按版本和分段排序）
+    fn from_sources(movie: &domain::entity::movie::Model, sources: Vec<domain::entity::movie_source::Model>) -> Vec<Self> {
+        if sources.is_empty() {
+            let part = MoviePartInfo {
+                part_index: None,
+                path: movie.path.clone(),
+                extension: movie.extension.clone(),
+                byte_size: movie.byte_size,
+                duration: movie.duration,
+            };
+            let label = movie.edition.clone().unwrap_or_else(|| movie.get_resolution());
+            return vec![Self::new(0, label, movie.resolution.clone(), vec![part])];
+        }
+
+        let mut versions: Vec<Self> = Vec::new();
+        for source in sources {
+            let part = MoviePartInfo {
+                part_index: source.part_index,
+                path: source.path,
+                extension: source.extension,
+                byte_size: source.byte_size,
+                duration: source.duration,
+            };
+            match versions.last_mut() {
+                Some(version) if version.index == source.version_index => version.push_part(part),
+                _ => versions.push(Self::new(source.version_index, source.label, source.resolution, vec![part])),
+            }
+        }
+        versions
+    }
+
+    fn new(index: i32, label: String, resolution: Option<String>, parts: Vec<MoviePartInfo>) -> Self {
+        let mut version = Self {
+            index,
+            label,
+            resolution,
+            byte_size: 0,
+            formatted_size: String::new(),
+            duration: 0,
+            formatted_duration: String::new(),
+            parts: Vec::new(),
+        };
+        for part in parts {
+            version.push_part(part);
+        }
+        version
+    }
+
+    fn push_part(&mut self, part: MoviePartInfo) {
+        self.byte_size += part.byte_size;
+        self.duration += part.duration;
+        self.formatted_size = format_byte_size(self.byte_size);
+        self.formatted_duration = format_duration(self.duration);
+        self.parts.push(part);
+    }
+}
+
+/// 电影列表查询参数（分页、筛选和排序）
+#[derive(Debug, Clone, Deserialize)]
+pub struct MovieListQuery {
+    /// 页码（从 1 开始），默认 1
+    #[serde(default = "default_page_index")]
+    pub page_index: i32,
+    /// 每页数量，默认 10
+    #[serde(default = "default_page_size")]
+    pub page_size: i32,
+    /// 类型
+    pub genre: Option<String>,
+    /// 上映年份下限（包含）
+    pub year_from: Option<i32>,
+    /// 上映年份上限（包含）
+    pub year_to: Option<i32>,
+    /// 最低评分
+    pub min_rating: Option<f32>,
+    /// 分辨率档位：sd / hd / 4k
+    pub resolution: Option<String>,
+    /// 演员或导演姓名
+    pub person: Option<String>,
+    /// 媒体库 ID
+    pub media_library_id: Option<i32>,
+    /// 排序字段：title / release_date / rating / date_added / file_size / duration，默认 title
+    pub sort: Option<String>,
+    /// 排序方向：asc / desc，默认标题升序、其余降序
+    pub order: Option<String>,
+}
+
+impl MovieListQuery {
+    /// 转换为仓储层的筛选条件，空字符串视为未设置
+    pub fn to_filter(&self) -> anyhow::Result<MovieFilter> {
+        let non_empty = |value: &Option<String>| value.as_deref().map(str::trim).filter(|v| !v.is_empty()).map(String::from);
+
+        let resolution = match non_empty(&self.resolution) {
+            Some(value) => Some(
+                ResolutionClass::parse(&value).ok_or_else(|| anyhow::anyhow!("Invalid resolution: {}", value))?,
+            ),
+            None => None,
+        };
+        let sort = match non_empty(&self.sort) {
+            Some(value) => MovieSortKey::parse(&value).ok_or_else(|| anyhow::anyhow!("Invalid sort key: {}", value))?,
+            None => MovieSortKey::default(),
+        };
+        let descending = match non_empty(&self.order).as_deref() {
+            Some("asc") => false,
+            Some("desc") => true,
+            Some(value) => anyhow::bail!("Invalid sort order: {}", value),
+            None => sort.default_descending(),
+        };
+
+        Ok(MovieFilter {
+            genre: non_empty(&self.genre),
+            year_from: self.year_from,
+            year_to: self.year_to,
+            min_rating: self.min_rating,
+            resolution,
+            person: non_empty(&self.person),
+            media_library_id: self.media_library_id,
+            sort,
+            descending,
+        })
+    }
+}
+
+/// 电影列表的可选筛选值 DTO
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MovieFacetsInfo {
+    /// 所有出现过的类型（按名称排序）
+    #[schema(example = json!(["剧情", "动作", "犯罪"]))]
+    pub genres: Vec<String>,
+
+    /// 所有出现过的上映年份（从新到旧）
+    #[schema(example = json!([2024, 2023, 1995]))]
+    pub years: Vec<i32>,
+}
+
+impl From<MovieFacets> for MovieFacetsInfo {
+    fn from(facets: MovieFacets) -> Self {
+        MovieFacetsInfo {
+            genres: facets.genres,
+            years: facets.years,
+        }
+    }
+}
+
+/// 电影分页列表 DTO（附带可选的筛选值）
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MovieListResponse {
+    /// 分页数据
+    #[serde(flatten)]
+    pub page: PagedResponse<MovieInfo>,
+
+    /// 可选的筛选值（只受媒体库条件影响，不随其他筛选条件变化）
+    pub facets: MovieFacetsInfo,
+}
+
+/// 电影视频流查询参数
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct MovieVideoQuery {
+    /// 版本序号（见详情中的 `versions[].index`），默认 0
+    pub source: Option<i32>,
+    /// 分段在版本中的位置（从 0 开始），默认 0
+    pub part: Option<usize>,
+}
+
+/// 电影轨道信息 DTO
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MovieTrackInfo {
+    /// 容器内的轨道编号
+    #[schema(example = 2)]
+    pub track_index: i32,
+
+    /// 编码
+    #[schema(example = "eac3")]
+    pub codec: String,
+
+    /// 语言
+    #[schema(example = "jpn")]
+    pub language: Option<String>,
+
+    /// 轨道名称
+    #[schema(example = "日语 5.1")]
+    pub title: Option<String>,
+
+    /// 是否为默认轨道
+    #[schema(example = true)]
+    pub is_default: bool,
+
+    /// 是否为强制字幕
+    #[schema(example = false)]
+    pub is_forced: bool,
+
+    /// 视频宽度（像素，仅视频轨道）
+    #[schema(example = 3840)]
+    pub width: Option<i32>,
+
+    /// 视频高度（像素，仅视频轨道）
+    #[schema(example = 2160)]
+    pub height: Option<i32>,
+
+    /// 帧率（仅视频轨道）
+    #[schema(example = 23.976)]
+    pub frame_rate: Option<f64>,
+
+    /// 是否为 HDR（仅视频轨道）
+    #[schema(example = false)]
+    pub is_hdr: bool,
+
+    /// 码率（bit/s）
+    #[schema(example = 640000i64)]
+    pub bit_rate: Option<i64>,
+
+    /// 声道数（仅音频轨道）
+    #[schema(example = 6)]
+    pub channels: Option<i32>,
+
+    /// 采样率（Hz，仅音频轨道）
+    #[schema(example = 48000)]
+    pub sample_rate: Option<i32>,
+}
+
+impl From<domain::entity::movie_track::Model> for MovieTrackInfo {
+    fn from(model: domain::entity::movie_track::Model) -> Self {
+        MovieTrackInfo {
+            track_index: model.track_index,
+            codec: model.codec,
+            language: model.language,
+            title: model.title,
+            is_default: model.is_default,
+            is_forced: model.is_forced,
+            width: model.width,
+            height: model.height,
+            frame_rate: model.frame_rate,
+            is_hdr: model.is_hdr,
+            bit_rate: model.bit_rate,
+            channels: model.channels,
+            sample_rate: model.sample_rate,
+        }
+    }
+}
+
+/// 电影字幕信息 DTO
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MovieSubtitleInfo {
+    /// 字幕 ID（外挂字幕为字幕 ID，内嵌字幕为轨道 ID）
+    #[schema(example = 1)]
+    pub id: i32,
+
+    /// 来源（external：外挂字幕文件，embedded：视频内嵌的字幕轨道）
+    #[schema(example = "external")]
+    pub source: String,
+
+    /// 原始格式（srt / ass / ssa / vtt）
+    #[schema(example = "ass")]
+    pub format: String,
+
+    /// 语言（BCP 47）
+    #[schema(example = "zh-Hans")]
+    pub language: Option<String>,
+
+    /// 标题
+    #[schema(example = "chs&eng")]
+    pub title: Option<String>,
+
+    /// 是否为默认字幕
+    #[schema(example = false)]
+    pub is_default: bool,
+
+    /// 是否为强制字幕
+    #[schema(example = false)]
+    pub is_forced: bool,
+
+    /// 是否为听障字幕（SDH / CC）
+    #[schema(example = false)]
+    pub is_sdh: bool,
+
+    /// WebVTT 地址（用于 `<track>` 元素的 src）
+    #[schema(example = "/api/movies/1/subtitles/1")]
+    pub url: String,
+}
+
+impl MovieSubtitleInfo {
+    /// 从内嵌的文本字幕轨道创建
+    pub fn from_embedded_track(track: domain::entity::movie_track::Model) -> Self {
+        let format = match track.codec.as_str() {
+            "subrip" => "srt",
+            "webvtt" => "vtt",
+            codec => codec,
+        };
+        // 轨道名称中的 SDH / CC 标记（如 `English SDH`）
+        let is_sdh = track.title.as_deref().is_some_and(|title| {
+            title
+                .split(|c: char| !c.is_ascii_alphanumeric())
+                .any(|word| word.eq_ignore_ascii_case("sdh") || word.eq_ignore_ascii_case("cc"))
+        });
+
+        MovieSubtitleInfo {
+            url: format!("/api/movies/{}/subtitles/embedded/{}", track.movie_id, track.id),
+            id: track.id,
+            source: "embedded".to_string(),
+            format: format.to_string(),
+            language: track.language,
+            title: track.title,
+            is_default: track.is_default,
+            is_forced: track.is_forced,
+            is_sdh,
+        }
+    }
+}
+
+impl From<domain::entity::movie_subtitle::Model> for MovieSubtitleInfo {
+    fn from(model: domain::entity::movie_subtitle::Model) -> Self {
+        MovieSubtitleInfo {
+            url: format!("/api/movies/{}/subtitles/{}", model.movie_id, model.id),
+            id: model.id,
+            source: "external".to_string(),
+            format: model.format,
+            language: model.language,
+            title: model.title,
+            is_default: model.is_default,
+            is_forced: model.is_forced,
+            is_sdh: model.is_sdh,
+        }
+    }
+}
+
+/// 电影附加视频信息 DTO（预告片、花絮等）
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MovieExtraInfo {
+    /// 附加视频 ID
+    #[schema(example = 1)]
+    pub id: i32,
+
+    /// 类型（trailer / sample / behind_the_scenes / interview / deleted_scene / featurette / extra）
+    #[schema(example = "trailer")]
+    pub extra_type: String,
+
+    /// 标题
+    #[schema(example = "Heat (1995)-trailer")]
+    pub title: String,
+
+    /// 文件扩展名
+    #[schema(example = "mkv")]
+    pub extension: Option<String>,
+
+    /// 文件大小（字节）
+    #[schema(example = 104857600i64)]
+    pub byte_size: i64,
+
+    /// 格式化的文件大小
+    #[schema(example = "100.0 MB")]
+    pub formatted_size: String,
+
+    /// 时长（秒）
+    #[schema(example = 150)]
+    pub duration: i32,
+
+    /// 格式化的时长
+    #[schema(example = "2m")]
+    pub formatted_duration: String,
+
+    /// 视频流地址
+    #[schema(example = "/api/movies/1/extras/1/video")]
+    pub url: String,
+}
+
+impl From<domain::entity::movie_extra::Model> for MovieExtraInfo {
+    fn from(model: domain::entity::movie_extra::Model) -> Self {
+        MovieExtraInfo {
+            url: format!("/api/movies/{}/extras/{}/video", model.movie_id, model.id),
+            id: model.id,
+            extra_type: model.extra_type,
+            title: model.title,
+            extension: model.extension,
+            formatted_size: format_byte_size(model.byte_size),
+            byte_size: model.byte_size,
+            formatted_duration: format_duration(model.duration),
+            duration: model.duration,
+        }
+    }
+}
+
+/// 手动识别电影的搜索参数
+#[derive(Debug, Clone, Deserialize)]
+pub struct MovieIdentifyQuery {
+    /// 搜索关键词，默认使用电影当前标题
+    pub query: Option<String>,
+    /// 上映年份
+    pub year: Option<u32>,
+}
+
+/// 手动识别电影的候选结果 DTO
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MovieIdentifyCandidate {
+    /// 元数据提供者
+    #[schema(example = "TMDB")]
+    pub provider: String,
+
+    /// 提供者中的电影 ID
+    #[schema(example = 949)]
+    pub provider_id: u64,
+
+    /// 标题
+    #[schema(example = "盗火线")]
+    pub title: String,
+
+    /// 原始标题
+    #[schema(example = "Heat")]
+    pub original_title: Option<String>,
+
+    /// 上映日期（YYYY-MM-DD）
+    #[schema(example = "1995-12-15")]
+    pub release_date: Option<String>,
+
+    /// 简介
+    pub overview: Option<String>,
+
+    /// 海报 URL
+    #[schema(example = "https://image.tmdb.org/t/p/w342/poster.jpg")]
+    pub poster_url: Option<String>,
+
+    /// 评分（0-10）
+    #[schema(example = 7.9)]
+    pub rating: f32,
+}
+
+/// 手动识别电影请求 DTO
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct IdentifyMovieRequest {
+    /// 元数据提供者，默认使用优先级最高的提供者
+    #[schema(example = "TMDB")]
+    pub provider: Option<String>,
+
+    /// 提供者中的电影 ID
+    #[schema(example = 949)]
+    pub provider_id: u64,
+}
+
+/// NFO 导出查询参数
+#[derive(Debug, Clone, Deserialize)]
+pub struct NfoExportQuery {
+    /// 是否覆盖用户编写的 NFO 和已存在的图片，默认 false
+    #[serde(default)]
+    pub force: bool,
+}
+
+/// NFO 导出结果 DTO
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct NfoExportResult {
+    /// 处理的电影数量
+    #[schema(example = 120)]
+    pub total: i32,
+
+    /// 写入的 NFO 数量
+    #[schema(example = 100)]
+    pub nfo_written: i32,
+
+    /// 写入的海报数量
+    #[schema(example = 98)]
+    pub poster_written: i32,
+
+    /// 写入的背景图数量
+    #[schema(example = 95)]
+    pub fanart_written: i32,
+
+    /// 保留了用户编写的 NFO 而跳过的数量
+    #[schema(example = 18)]
+    pub skipped: i32,
+
+    /// 写入失败的数量
+    #[schema(example = 2)]
+    pub failed: i32,
+}
+
 /// 格式化字节大小为人类可读格式
-fn format_byte_size(bytes: i64) -> String {
+pub(crate) fn format_byte_size(bytes: i64) -> String {
     const KB: i64 = 1024;
     const MB: i64 = KB * 1024;
     const GB: i64 = MB * 1024;
@@ -184,7 +892,7 @@ fn format_byte_size(bytes: i64) -> String {
 }
 
 /// 格式化时长为人类可读格式
-fn format_duration(seconds: i32) -> String {
+pub(crate) fn format_duration(seconds: i32) -> String {
     let hours = seconds / 3600;
     let minutes = (seconds % 3600) / 60;
     
@@ -195,3 +903,190 @@ fn format_duration(seconds: i32) -> String {
     }
 }
 
+
+/// 电影系列信息 DTO
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MovieCollectionInfo {
+    /// 系列 ID
+    #[schema(example = 3)]
+    pub id: i32,
+
+    /// TMDB 系列 ID
+    #[schema(example = 10)]
+    pub tmdb_id: i64,
+
+    /// 系列名称
+    #[schema(example = "星球大战（系列）")]
+    pub name: String,
+
+    /// 简介
+    pub overview: Option<String>,
+
+    /// 海报 URL
+    #[schema(example = "https://image.tmdb.org/t/p/w500/poster.jpg")]
+    pub poster_url: Option<String>,
+
+    /// 背景图 URL
+    #[schema(example = "https://image.tmdb.org/t/p/original/backdrop.jpg")]
+    pub backdrop_url: Option<String>,
+
+    /// 媒体库中已有的电影数量
+    #[schema(example = 6)]
+    pub owned_count: i32,
+
+    /// 系列中的电影总数
+    #[schema(example = 9)]
+    pub total_count: i32,
+}
+
+impl MovieCollectionInfo {
+    /// 从系列实体和已有电影数量创建
+    pub fn new(model: domain::entity::movie_collection::Model, owned_count: i32) -> Self {
+        let total_count = model.get_parts().len() as i32;
+        Self {
+            id: model.id,
+            tmdb_id: model.tmdb_id,
+            name: model.name,
+            overview: model.overview,
+            poster_url: model.poster_url,
+            backdrop_url: model.backdrop_url,
+            owned_count,
+            total_count: total_count.max(owned_count),
+        }
+    }
+}
+
+/// 电影系列中缺少的电影 DTO
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MovieCollectionPartInfo {
+    /// TMDB 电影 ID
+    #[schema(example = 1891)]
+    pub tmdb_id: i64,
+
+    /// 标题
+    #[schema(example = "星球大战5：帝国反击战")]
+    pub title: String,
+
+    /// 原始标题
+    #[schema(example = "The Empire Strikes Back")]
+    pub original_title: Option<String>,
+
+    /// 上映日期（YYYY-MM-DD）
+    #[schema(example = "1980-05-20")]
+    pub release_date: Option<String>,
+
+    /// 海报 URL
+    #[schema(example = "https://image.tmdb.org/t/p/w500/poster.jpg")]
+    pub poster_url: Option<String>,
+}
+
+impl From<domain::entity::movie_collection::CollectionPart> for MovieCollectionPartInfo {
+    fn from(part: domain::entity::movie_collection::CollectionPart) -> Self {
+        Self {
+            tmdb_id: part.tmdb_id,
+            title: part.title,
+            original_title: part.original_title,
+            release_date: part.release_date,
+            poster_url: part.poster_url,
+        }
+    }
+}
+
+/// 电影系列详情 DTO
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MovieCollectionDetail {
+    pub collection: MovieCollectionInfo,
+    /// 媒体库中已有的电影，按上映日期排序
+    pub movies: Vec<MovieInfo>,
+    /// 系列中缺少的电影，按上映日期排序
+    pub missing: Vec<MovieCollectionPartInfo>,
+}
+
+/// 电影播放状态 DTO（当前用户）
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MoviePlayStateInfo {
+    /// 电影 ID
+    #[schema(example = 1)]
+    pub movie_id: i32,
+
+    /// 上次播放到的位置（秒）
+    #[schema(example = 3120)]
+    pub position_seconds: i32,
+
+    /// 总时长（秒）
+    #[schema(example = 10200)]
+    pub duration_seconds: i32,
+
+    /// 续播位置（秒），看完或刚开始时为 0
+    #[schema(example = 3120)]
+    pub resume_position_seconds: i32,
+
+    /// 看完的次数
+    #[schema(example = 1)]
+    pub play_count: i32,
+
+    /// 是否已看
+    #[schema(example = false)]
+    pub watched: bool,
+
+    /// 最后播放时间，没有播放过时为空
+    #[schema(example = "2026-10-18 21:30:00")]
+    pub last_played: Option<String>,
+}
+
+impl MoviePlayStateInfo {
+    /// 没有播放记录时的状态
+    pub fn empty(movie_id: i32) -> Self {
+        MoviePlayStateInfo {
+            movie_id,
+            position_seconds: 0,
+            duration_seconds: 0,
+            resume_position_seconds: 0,
+            play_count: 0,
+            watched: false,
+            last_played: None,
+        }
+    }
+}
+
+impl From<domain::entity::movie_play_state::Model> for MoviePlayStateInfo {
+    fn from(state: domain::entity::movie_play_state::Model) -> Self {
+        MoviePlayStateInfo {
+            movie_id: state.movie_id,
+            position_seconds: state.position,
+            duration_seconds: state.duration,
+            resume_position_seconds: state.resume_position(),
+            play_count: state.play_count,
+            watched: state.watched,
+            last_played: Some(state.last_played_time),
+        }
+    }
+}
+
+/// 上报播放进度请求 DTO
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReportMovieProgressRequest {
+    /// 当前播放位置（秒）
+    #[schema(example = 3120.5)]
+    pub position_seconds: f64,
+
+    /// 播放器探测到的总时长（秒），不传时使用电影记录的时长
+    #[schema(example = 10200.0)]
+    pub duration_seconds: Option<f64>,
+}
+
+/// 继续观看 / 最近看过列表的查询参数
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct MoviePlayListQuery {
+    /// 返回数量，默认 20，最多 100
+    pub limit: Option<usize>,
+}
+
+/// 带播放状态的电影 DTO
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MoviePlayItem {
+    /// 电影信息
+    pub movie: MovieInfo,
+    /// 当前用户的播放状态
+    pub play_state: MoviePlayStateInfo,
+}
@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::movie::MovieInfo;
+
+/// 人物详细信息 DTO（包含在媒体库中参与的所有电影）
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PersonDetail {
+    /// 人物 ID
+    #[schema(example = 1)]
+    pub id: i32,
+
+    /// 姓名
+    #[schema(example = "Michael Mann")]
+    pub name: String,
+
+    /// TMDB 人物 ID
+    #[schema(example = 638)]
+    pub tmdb_id: Option<i64>,
+
+    /// 头像 URL
+    #[schema(example = "https://image.tmdb.org/t/p/w185/njEvx8AgHQIiRx2zVKmOMrnE1wP.jpg")]
+    pub profile_url: Option<String>,
+
+    /// 参与的电影（按上映日期从新到旧）
+    pub filmography: Vec<PersonMovieInfo>,
+}
+
+/// 人物参与的一部电影
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PersonMovieInfo {
+    /// 在这部电影中的职责（actor / director / writer / producer），可能有多个
+    #[schema(example = json!(["director", "writer"]))]
+    pub roles: Vec<String>,
+
+    /// 饰演的角色（仅演员）
+    #[schema(example = "Vincent Hanna")]
+    pub character: Option<String>,
+
+    /// 电影信息
+    pub movie: MovieInfo,
+}
+
+impl PersonDetail {
+    /// 根据人物、演职记录和电影构建详细信息
+    pub fn new(
+        person: domain::entity::person::Model,
+        credits: Vec<domain::entity::movie_credit::Model>,
+        movies: Vec<domain::entity::movie::Model>,
+    ) -> Self {
+        let mut filmography: Vec<PersonMovieInfo> = movies
+            .into_iter()
+            .map(|movie| {
+                let movie_credits: Vec<&domain::entity::movie_credit::Model> =
+                    credits.iter().filter(|c| c.movie_id == movie.id).collect();
+                let mut roles: Vec<String> = Vec::new();
+                for credit in &movie_credits {
+                    if !roles.contains(&credit.role) {
+                        roles.push(credit.role.clone());
+                    }
+                }
+                PersonMovieInfo {
+                    roles,
+                    character: movie_credits.iter().find_map(|c| c.character_name.clone()),
+                    movie: movie.into(),
+                }
+            })
+            .collect();
+        filmography.sort_by(|a, b| b.movie.release_date.cmp(&a.movie.release_date));
+
+        PersonDetail {
+            id: person.id,
+            name: person.name,
+            tmdb_id: person.tmdb_id,
+            profile_url: person.profile_url,
+            filmography,
+        }
+    }
+}
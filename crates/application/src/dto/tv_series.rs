@@ -0,0 +1,293 @@
+use super::movie::{format_byte_size, format_duration};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// 电视剧信息 DTO
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TvSeriesInfo {
+    /// 电视剧 ID
+    #[schema(example = 1)]
+    pub id: i32,
+
+    /// 创建时间
+    #[schema(example = "2025-11-11 17:50:54")]
+    pub create_time: String,
+
+    /// 更新时间
+    #[schema(example = "2025-11-11 17:50:54")]
+    pub update_time: String,
+
+    /// 剧名
+    #[schema(example = "绝命毒师")]
+    pub title: String,
+
+    /// 原始剧名
+    #[schema(example = "Breaking Bad")]
+    pub original_title: Option<String>,
+
+    /// 简介
+    #[schema(example = "一位高中化学老师走上制毒之路")]
+    pub description: Option<String>,
+
+    /// 剧集文件夹路径
+    #[schema(example = "G:\\TV\\Breaking Bad")]
+    pub path: String,
+
+    /// 首播日期（YYYY-MM-DD）
+    #[schema(example = "2008-01-20")]
+    pub first_air_date: Option<String>,
+
+    /// 评分（0-10）
+    #[schema(example = 8.9)]
+    pub rating: f32,
+
+    /// 评价人数
+    #[schema(example = 12345)]
+    pub votes: i32,
+
+    /// 类型/流派列表
+    #[schema(example = json!(["剧情", "犯罪"]))]
+    pub genres: Option<Vec<String>>,
+
+    /// 主演列表
+    #[schema(example = json!(["布莱恩·科兰斯顿", "亚伦·保尔"]))]
+    pub actors: Option<Vec<String>>,
+
+    /// 海报 URL 列表
+    #[schema(example = json!(["https://image.tmdb.org/t/p/w500/poster.jpg"]))]
+    pub poster_urls: Option<Vec<String>>,
+
+    /// 封面图片路径
+    #[schema(example = "https://image.tmdb.org/t/p/w500/poster.jpg")]
+    pub cover: Option<String>,
+
+    /// TMDB 剧集 ID
+    #[schema(example = 1396)]
+    pub tmdb_id: Option<i64>,
+
+    /// 本地季数
+    #[schema(example = 5)]
+    pub season_count: i32,
+
+    /// 本地集数
+    #[schema(example = 62)]
+    pub episode_count: i32,
+
+    /// 所属媒体库 ID
+    #[schema(example = 1)]
+    pub media_library_id: i32,
+}
+
+impl From<domain::entity::tv_series::Model> for TvSeriesInfo {
+    fn from(model: domain::entity::tv_series::Model) -> Self {
+        let genres = model.genres.and_then(|g| serde_json::from_str(&g).ok());
+        let actors = model.actors.and_then(|a| serde_json::from_str(&a).ok());
+        let poster_urls = model.poster_urls.and_then(|p| serde_json::from_str(&p).ok());
+
+        TvSeriesInfo {
+            id: model.id,
+            create_time: model.create_time,
+            update_time: model.update_time,
+            title: model.title,
+            original_title: model.original_title,
+            description: model.description,
+            path: model.path,
+            first_air_date: model.first_air_date,
+            rating: model.rating,
+            votes: model.votes,
+            genres,
+            actors,
+            poster_urls,
+            cover: model.cover,
+            tmdb_id: model.tmdb_id,
+            season_count: model.season_count,
+            episode_count: model.episode_count,
+            media_library_id: model.media_library_id,
+        }
+    }
+}
+
+/// 电视剧季信息 DTO
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TvSeasonInfo {
+    /// 季 ID
+    #[schema(example = 1)]
+    pub id: i32,
+
+    /// 所属电视剧 ID
+    #[schema(example = 1)]
+    pub series_id: i32,
+
+    /// 季号（0 为特别篇）
+    #[schema(example = 1)]
+    pub season_number: i32,
+
+    /// 季标题
+    #[schema(example = "第 1 季")]
+    pub title: Option<String>,
+
+    /// 简介
+    pub description: Option<String>,
+
+    /// 首播日期（YYYY-MM-DD）
+    #[schema(example = "2008-01-20")]
+    pub air_date: Option<String>,
+
+    /// 封面图片路径
+    pub cover: Option<String>,
+
+    /// 本地集数
+    #[schema(example = 7)]
+    pub episode_count: i32,
+}
+
+impl From<domain::entity::tv_season::Model> for TvSeasonInfo {
+    fn from(model: domain::entity::tv_season::Model) -> Self {
+        TvSeasonInfo {
+            id: model.id,
+            series_id: model.series_id,
+            season_number: model.season_number,
+            title: model.title,
+            description: model.description,
+            air_date: model.air_date,
+            cover: model.cover,
+            episode_count: model.episode_count,
+        }
+    }
+}
+
+/// 电视剧单集信息 DTO
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TvEpisodeInfo {
+    /// 单集 ID
+    #[schema(example = 1)]
+    pub id: i32,
+
+    /// 所属电视剧 ID
+    #[schema(example = 1)]
+    pub series_id: i32,
+
+    /// 所属季 ID
+    #[schema(example = 1)]
+    pub season_id: i32,
+
+    /// 季号
+    #[schema(example = 1)]
+    pub season_number: i32,
+
+    /// 集号
+    #[schema(example = 2)]
+    pub episode_number: i32,
+
+    /// 结束集号（一个文件包含多集时）
+    #[schema(example = json!(null))]
+    pub episode_number_end: Option<i32>,
+
+    /// 绝对集号（动漫）
+    #[schema(example = json!(null))]
+    pub absolute_number: Option<i32>,
+
+    /// 发布组（字幕组）
+    #[schema(example = json!(null))]
+    pub release_group: Option<String>,
+
+    /// 集号标签
+    #[schema(example = "S01E02")]
+    pub label: String,
+
+    /// 单集标题
+    #[schema(example = "猫在袋子里")]
+    pub title: Option<String>,
+
+    /// 简介
+    pub description: Option<String>,
+
+    /// 播出日期（YYYY-MM-DD）
+    #[schema(example = "2008-01-27")]
+    pub air_date: Option<String>,
+
+    /// 文件大小（字节）
+    #[schema(example = 1500000000i64)]
+    pub byte_size: i64,
+
+    /// 格式化后的文件大小（如 "1.4 GB"）
+    #[schema(example = "1.4 GB")]
+    pub formatted_size: String,
+
+    /// 文件扩展名
+    #[schema(example = "mkv")]
+    pub extension: Option<String>,
+
+    /// 时长（秒）
+    #[schema(example = 2880)]
+    pub duration: i32,
+
+    /// 格式化后的时长（如 "48m"）
+    #[schema(example = "48m")]
+    pub formatted_duration: String,
+
+    /// 分辨率字符串（如 "1920x1080"）
+    #[schema(example = "1920x1080")]
+    pub resolution: Option<String>,
+
+    /// 剧照 URL
+    pub still: Option<String>,
+
+    /// 评分（0-10）
+    #[schema(example = 8.3)]
+    pub rating: f32,
+
+    /// 视频流地址
+    #[schema(example = "/api/tv-episodes/1/video")]
+    pub video_url: String,
+}
+
+impl From<domain::entity::tv_episode::Model> for TvEpisodeInfo {
+    fn from(model: domain::entity::tv_episode::Model) -> Self {
+        TvEpisodeInfo {
+            label: model.episode_label(),
+            formatted_size: format_byte_size(model.byte_size),
+            formatted_duration: format_duration(model.duration),
+            video_url: format!("/api/tv-episodes/{}/video", model.id),
+            id: model.id,
+            series_id: model.series_id,
+            season_id: model.season_id,
+            season_number: model.season_number,
+            episode_number: model.episode_number,
+            episode_number_end: model.episode_number_end,
+            absolute_number: model.absolute_number,
+            release_group: model.release_group,
+            title: model.title,
+            description: model.description,
+            air_date: model.air_date,
+            byte_size: model.byte_size,
+            extension: model.extension,
+            duration: model.duration,
+            resolution: model.resolution,
+            still: model.still,
+            rating: model.rating,
+        }
+    }
+}
+
+/// 电视剧详细信息 DTO（包含季列表）
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TvSeriesDetailInfo {
+    /// 基本电视剧信息
+    #[serde(flatten)]
+    pub series: TvSeriesInfo,
+
+    /// 季列表（按季号排序）
+    pub seasons: Vec<TvSeasonInfo>,
+}
+
+/// 电视剧季详细信息 DTO（包含单集列表）
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TvSeasonDetailInfo {
+    /// 基本季信息
+    #[serde(flatten)]
+    pub season: TvSeasonInfo,
+
+    /// 单集列表（按集号排序）
+    pub episodes: Vec<TvEpisodeInfo>,
+}
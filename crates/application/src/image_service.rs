@@ -1,5 +1,11 @@
 use domain::repository::{MangaRepository, MangaChapterRepository};
+use domain::service::MangaDomainService;
+use crate::dto::{ChapterHealthReport, MangaHealthReport};
+use infrastructure::cbz::{check_archive_limits, CbzWriter, ComicInfo};
+use infrastructure::file_scanner::find_unreadable_pages;
 use moka::future::Cache;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -19,8 +25,73 @@ pub struct ImageService {
     /// 章节实体缓存：Key = chapter_id, Value = MangaChapter
     /// TTL: 5 分钟，最大容量: 1000 个章节
     chapter_cache: Cache<i32, Arc<domain::entity::manga_chapter::Model>>,
+    /// 自动选择的漫画封面缓存：Key = manga_id, Value = 封面图片路径
+    /// TTL: 1 小时，最大容量: 1000 个漫画
+    auto_cover_cache: Cache<i32, Arc<String>>,
+    /// 自动选择的章节封面缓存：Key = chapter_id, Value = 封面图片路径
+    /// TTL: 1 小时，最大容量: 1000 个章节
+    chapter_auto_cover_cache: Cache<i32, Arc<String>>,
 }
 
+/// CBZ 导出任务
+///
+/// 在开始传输前准备好文件名和全部条目，这样"漫画不存在"、压缩包超出大小限制等错误可以在返回响应前暴露，
+/// 之后再通过 [`CbzExport::write_to`] 以流的方式写出压缩包
+pub struct CbzExport {
+    /// 下载文件名（含 `.cbz` 扩展名）
+    pub file_name: String,
+    /// ComicInfo.xml 元数据
+    comic_info: ComicInfo,
+    /// 压缩包条目：(条目名, 图片文件路径)
+    entries: Vec<(String, String)>,
+}
+
+/// ComicInfo.xml 在压缩包中的条目名
+const COMIC_INFO_ENTRY: &str = "ComicInfo.xml";
+
+impl CbzExport {
+    /// 创建导出任务，并检查压缩包是否超出 4GB / 65535 个条目的限制
+    async fn new(file_name: String, comic_info: ComicInfo, entries: Vec<(String, String)>) -> anyhow::Result<Self> {
+        let comic_info_len = comic_info.to_xml().len() as u64;
+        let mut sizes = Vec::with_capacity(entries.len() + 1);
+        sizes.push((COMIC_INFO_ENTRY, comic_info_len));
+        for (entry_name, image_path) in &entries {
+            let metadata = tokio::fs::metadata(image_path).await
+                .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", image_path, e))?;
+            sizes.push((entry_name.as_str(), metadata.len()));
+        }
+        check_archive_limits(&sizes)?;
+
+        Ok(Self {
+            file_name,
+            comic_info,
+            entries,
+        })
+    }
+
+    /// 将 CBZ 写入到指定的异步写入器
+    ///
+    /// ComicInfo.xml 作为第一个条目写入，随后逐个以流的方式写入图片
+    pub async fn write_to<W: tokio::io::AsyncWrite + Unpin>(self, writer: W) -> anyhow::Result<()> {
+        let mut cbz = CbzWriter::new(writer);
+        cbz.add_bytes(COMIC_INFO_ENTRY, self.comic_info.to_xml().as_bytes()).await?;
+
+        for (entry_name, image_path) in &self.entries {
+            cbz.add_file(entry_name, image_path).await?;
+        }
+
+        cbz.finish().await?;
+        Ok(())
+    }
+}
+
+/// 自动选择封面时最多检查的图片数量
+const COVER_CANDIDATE_LIMIT: usize = 8;
+/// 封面图片短边的最小像素数，小于该值的图片视为图标/水印
+const COVER_MIN_SIDE: u32 = 200;
+/// 亮度标准差阈值，低于该值的图片视为空白页/纯色页
+const COVER_MIN_LUMA_STDDEV: f64 = 12.0;
+
 impl ImageService {
     /// 创建新的图片服务实例
     pub fn new(
@@ -51,6 +122,15 @@ impl ImageService {
                 .max_capacity(1000)
                 .time_to_live(Duration::from_secs(3600)) // 1 小时
                 .build(),
+            // 自动封面缓存：挑选封面需要解码多张图片，结果缓存 1 小时
+            auto_cover_cache: Cache::builder()
+                .max_capacity(1000)
+                .time_to_live(Duration::from_secs(3600)) // 1 小时
+                .build(),
+            chapter_auto_cover_cache: Cache::builder()
+                .max_capacity(1000)
+                .time_to_live(Duration::from_secs(3600)) // 1 小时
+                .build(),
         }
     }
 
@@ -84,6 +164,9 @@ impl ImageService {
             self.scan_images_in_folder(&manga.path).await?
         };
 
+        // 跳过扫描时发现的损坏页面，阅读器不会再请求到无法显示的图片
+        let images = skip_bad_pages(images, &manga.get_bad_pages());
+
         // 存入缓存
         self.image_list_cache.insert(manga_id, Arc::new(images.clone())).await;
 
@@ -135,17 +218,29 @@ impl ImageService {
 
     /// 获取漫画的封面路径（用于流式传输，带缓存）
     ///
-    /// 如果 cover 字段是 API 路径格式（如 `/manga/1/cover`），则获取漫画文件夹中的第一张图片
+    /// 如果 cover 字段是手动封面标记（如 `/manga/1/images/3`），则使用指定的页
+    /// 如果 cover 字段是 API 路径格式（如 `/manga/1/cover`），则自动从前几页中挑选封面
     /// 否则直接使用 cover 字段作为文件系统路径
     ///
     /// **章节漫画支持：**
-    /// - 如果 `has_chapters = true`，自动获取第一章的封面
+    /// - 如果 `has_chapters = true` 且未手动指定封面，自动获取第一章的封面
     pub async fn get_manga_cover_path(&self, manga_id: i32) -> anyhow::Result<String> {
         // 从缓存或数据库获取 manga 信息
         let manga = self.get_manga_from_cache_or_db(manga_id).await?;
 
         tracing::debug!("Getting cover path for manga {}, has_chapters: {}", manga_id, manga.has_chapters);
 
+        // ✅ 手动指定的封面页优先
+        if let Some((chapter_id, page_index)) = manga.cover.as_deref()
+            .and_then(MangaDomainService::parse_cover_page_marker)
+        {
+            tracing::debug!("Using manual cover page {} (chapter: {:?}) for manga {}", page_index, chapter_id, manga_id);
+            return match chapter_id {
+                Some(chapter_id) => self.get_chapter_image_path(chapter_id, page_index).await,
+                None => self.get_manga_image_path(manga_id, page_index).await,
+            };
+        }
+
         // ✅ 如果是章节漫画，获取第一章的封面
         if manga.has_chapters {
             tracing::debug!("Manga {} has chapters, getting first chapter cover", manga_id);
@@ -170,22 +265,90 @@ impl ImageService {
         // 如果 cover 是 API 路径格式（以 / 开头），则获取第一张图片
         if let Some(cover) = &manga.cover {
             if cover.starts_with("/manga/") || cover.starts_with("/api/manga/") {
-                // 这是 API 路径格式，使用缓存的图片列表获取第一张图片
-                let images = self.get_manga_images(manga_id).await?;
-                return images.first()
-                    .cloned()
-                    .ok_or_else(|| anyhow::anyhow!("No images found in manga folder"));
+                // 这是 API 路径格式，自动挑选封面
+                return self.select_manga_cover(manga_id).await;
             } else {
                 // 这是真实的文件系统路径
                 return Ok(cover.clone());
             }
         }
 
-        // 如果没有 cover 字段，使用缓存的图片列表获取第一张图片
+        // 如果没有 cover 字段，自动挑选封面
+        self.select_manga_cover(manga_id).await
+    }
+
+    /// 自动挑选单文件夹漫画的封面（带缓存）
+    async fn select_manga_cover(&self, manga_id: i32) -> anyhow::Result<String> {
+        if let Some(cached_cover) = self.auto_cover_cache.get(&manga_id).await {
+            return Ok((*cached_cover).clone());
+        }
+
         let images = self.get_manga_images(manga_id).await?;
-        images.first()
-            .cloned()
-            .ok_or_else(|| anyhow::anyhow!("No images found in manga folder"))
+        let cover = select_cover_image(images).await?
+            .ok_or_else(|| anyhow::anyhow!("No images found in manga folder"))?;
+
+        self.auto_cover_cache.insert(manga_id, Arc::new(cover.clone())).await;
+
+        Ok(cover)
+    }
+
+    /// 手动设置漫画的封面页
+    ///
+    /// # 参数
+    /// - `manga_id`: 漫画 ID
+    /// - `chapter_id`: 封面所在章节 ID（单文件夹漫画传 None）
+    /// - `page_index`: 封面页索引（从 0 开始），传 None 恢复自动选择
+    ///
+    /// # 业务规则
+    /// - 章节漫画必须指定 `chapter_id`，且章节必须属于该漫画
+    /// - 页索引必须在图片列表范围内
+    /// - 修改后清除该漫画的封面缓存和缩略图缓存
+    pub async fn set_manga_cover_page(
+        &self,
+        manga_id: i32,
+        chapter_id: Option<i32>,
+        page_index: Option<i32>,
+    ) -> anyhow::Result<domain::entity::manga::Model> {
+        let mut manga = self.manga_repo.find_by_id(manga_id).await?
+            .ok_or_else(|| anyhow::anyhow!("Manga not found"))?;
+
+        match page_index {
+            Some(page_index) => {
+                match chapter_id {
+                    Some(chapter_id) => {
+                        let chapter = self.get_chapter_from_cache_or_db(chapter_id).await?;
+                        if chapter.manga_id != manga_id {
+                            return Err(anyhow::anyhow!("Chapter {} does not belong to manga {}", chapter_id, manga_id));
+                        }
+                        self.get_chapter_image_path(chapter_id, page_index).await?;
+                    }
+                    None => {
+                        if manga.has_chapters {
+                            return Err(anyhow::anyhow!("chapter_id is required for manga with chapters"));
+                        }
+                        self.get_manga_image_path(manga_id, page_index).await?;
+                    }
+                }
+                manga.set_cover(MangaDomainService::cover_page_marker(manga_id, chapter_id, page_index));
+            }
+            None => {
+                // 恢复为扫描时写入的默认封面路径
+                let default_cover = if manga.has_chapters {
+                    format!("/manga_chapter/{}/cover", manga_id)
+                } else {
+                    format!("/manga/{}/cover", manga_id)
+                };
+                manga.set_cover(default_cover);
+            }
+        }
+
+        let updated_manga = self.manga_repo.update(manga).await?;
+
+        self.manga_cache.invalidate(&manga_id).await;
+        self.auto_cover_cache.invalidate(&manga_id).await;
+        remove_cached_thumbnails(manga_id).await;
+
+        Ok(updated_manga)
     }
 
     /// 获取漫画的封面（完整数据）
@@ -210,6 +373,7 @@ impl ImageService {
     /// - 缩略图保存在 `./cache/thumbnails/` 目录
     /// - 文件名格式：`{manga_id}_{width}x{height}_q{quality}.jpg`
     /// - 如果缓存文件存在且原图未修改，直接返回缓存
+    /// - 手动设置封面页后，该漫画的缩略图缓存会被清除
     pub async fn get_manga_cover_thumbnail(
         &self,
         manga_id: i32,
@@ -324,6 +488,48 @@ impl ImageService {
         Ok(images)
     }
 
+    /// 准备漫画的 CBZ 导出
+    ///
+    /// - 单文件夹漫画：图片按阅读顺序命名为 `0001.jpg`、`0002.png` ...
+    /// - 章节漫画：每个章节一个子目录（`001 第1话/0001.jpg`），章节按章节号排序
+    pub async fn prepare_manga_cbz(&self, manga_id: i32) -> anyhow::Result<CbzExport> {
+        let manga = self.get_manga_from_cache_or_db(manga_id).await?;
+
+        let entries = if manga.has_chapters {
+            let mut chapters = self.manga_chapter_repo.find_by_manga_id(manga_id).await?;
+            chapters.sort_by(|a, b| a.chapter_number.partial_cmp(&b.chapter_number).unwrap_or(std::cmp::Ordering::Equal));
+
+            let mut entries = Vec::new();
+            for (chapter_index, chapter) in chapters.iter().enumerate() {
+                let folder = format!("{:03} {}", chapter_index + 1, sanitize_archive_name(&chapter.title));
+                let images = self.get_chapter_images(chapter.id).await?;
+                entries.extend(
+                    cbz_page_entries(images)
+                        .into_iter()
+                        .map(|(name, path)| (format!("{}/{}", folder, name), path)),
+                );
+            }
+            entries
+        } else {
+            cbz_page_entries(self.get_manga_images(manga_id).await?)
+        };
+
+        if entries.is_empty() {
+            return Err(anyhow::anyhow!("No images found in manga {}", manga_id));
+        }
+
+        let comic_info = ComicInfo {
+            title: manga.title.clone(),
+            series: Some(manga.title.clone()),
+            summary: manga.description.clone(),
+            genre: Some(manga.manga_type_string.clone()).filter(|genre| !genre.is_empty()),
+            page_count: entries.len(),
+            ..Default::default()
+        };
+
+        CbzExport::new(format!("{}.cbz", sanitize_archive_name(&manga.title)), comic_info, entries).await
+    }
+
     // ==================== 章节图片相关方法 ====================
 
     /// 从缓存或数据库获取章节信息
@@ -380,6 +586,9 @@ impl ImageService {
             self.scan_images_in_folder(&chapter.path).await?
         };
 
+        // 跳过扫描时发现的损坏页面
+        let images = skip_bad_pages(images, &chapter.get_bad_pages());
+
         // 存入缓存
         self.chapter_image_list_cache.insert(chapter_id, Arc::new(images.clone())).await;
 
@@ -407,33 +616,126 @@ impl ImageService {
             .map_err(|e| anyhow::anyhow!("Failed to read image: {}", e))
     }
 
+    /// 准备章节的 CBZ 导出
+    ///
+    /// ComicInfo.xml 中 Series 为漫画标题，Number 为章节号
+    pub async fn prepare_chapter_cbz(&self, manga_id: i32, chapter_id: i32) -> anyhow::Result<CbzExport> {
+        let chapter = self.get_chapter_from_cache_or_db(chapter_id).await?;
+        if chapter.manga_id != manga_id {
+            return Err(anyhow::anyhow!("Chapter {} does not belong to manga {}", chapter_id, manga_id));
+        }
+        let manga = self.get_manga_from_cache_or_db(manga_id).await?;
+
+        let entries = cbz_page_entries(self.get_chapter_images(chapter_id).await?);
+        if entries.is_empty() {
+            return Err(anyhow::anyhow!("No images found in chapter {}", chapter_id));
+        }
+
+        let comic_info = ComicInfo {
+            title: chapter.title.clone(),
+            series: Some(manga.title.clone()),
+            number: Some(chapter.chapter_number.to_string()),
+            summary: manga.description.clone(),
+            genre: Some(manga.manga_type_string.clone()).filter(|genre| !genre.is_empty()),
+            page_count: entries.len(),
+            ..Default::default()
+        };
+
+        let file_name = format!(
+            "{} - {}.cbz",
+            sanitize_archive_name(&manga.title),
+            sanitize_archive_name(&chapter.title)
+        );
+        CbzExport::new(file_name, comic_info, entries).await
+    }
+
     /// 获取章节的封面路径（用于流式传输，带缓存）
     ///
-    /// 如果 cover 字段是 API 路径格式（如 `/manga/1/chapter/1/cover`），则获取章节文件夹中的第一张图片
+    /// 如果 cover 字段是手动封面标记（如 `/manga_chapter/1/2/images/3`），则使用指定的页
+    /// 如果 cover 字段是 API 路径格式（如 `/manga/1/chapter/1/cover`），则自动从前几页中挑选封面
     /// 否则直接使用 cover 字段作为文件系统路径
     pub async fn get_chapter_cover_path(&self, chapter_id: i32) -> anyhow::Result<String> {
         // 从缓存或数据库获取章节信息
         let chapter = self.get_chapter_from_cache_or_db(chapter_id).await?;
 
-        // 如果 cover 是 API 路径格式（以 / 开头），则获取第一张图片
+        // ✅ 手动指定的封面页优先
+        if let Some((_, page_index)) = chapter.cover.as_deref()
+            .and_then(MangaDomainService::parse_cover_page_marker)
+        {
+            return self.get_chapter_image_path(chapter_id, page_index).await;
+        }
+
+        // 如果 cover 是 API 路径格式（以 / 开头），则自动挑选封面
         if let Some(cover) = &chapter.cover {
             if cover.starts_with("/manga/") || cover.starts_with("/api/manga/") {
-                // 这是 API 路径格式，使用缓存的图片列表获取第一张图片
-                let images = self.get_chapter_images(chapter_id).await?;
-                return images.first()
-                    .cloned()
-                    .ok_or_else(|| anyhow::anyhow!("No images found in chapter folder"));
+                return self.select_chapter_cover(chapter_id).await;
             } else {
                 // 这是真实的文件系统路径
                 return Ok(cover.clone());
             }
         }
 
-        // 如果没有 cover 字段，使用缓存的图片列表获取第一张图片
+        // 如果没有 cover 字段，自动挑选封面
+        self.select_chapter_cover(chapter_id).await
+    }
+
+    /// 自动挑选章节的封面（带缓存）
+    async fn select_chapter_cover(&self, chapter_id: i32) -> anyhow::Result<String> {
+        if let Some(cached_cover) = self.chapter_auto_cover_cache.get(&chapter_id).await {
+            return Ok((*cached_cover).clone());
+        }
+
         let images = self.get_chapter_images(chapter_id).await?;
-        images.first()
-            .cloned()
-            .ok_or_else(|| anyhow::anyhow!("No images found in chapter folder"))
+        let cover = select_cover_image(images).await?
+            .ok_or_else(|| anyhow::anyhow!("No images found in chapter folder"))?;
+
+        self.chapter_auto_cover_cache.insert(chapter_id, Arc::new(cover.clone())).await;
+
+        Ok(cover)
+    }
+
+    /// 手动设置章节的封面页
+    ///
+    /// # 参数
+    /// - `manga_id`: 漫画 ID
+    /// - `chapter_id`: 章节 ID
+    /// - `page_index`: 封面页索引（从 0 开始），传 None 恢复自动选择
+    ///
+    /// # 业务规则
+    /// - 章节必须属于该漫画
+    /// - 页索引必须在章节图片列表范围内
+    /// - 章节漫画的封面默认取自第一章，因此同时清除漫画的封面缓存和缩略图缓存
+    pub async fn set_chapter_cover_page(
+        &self,
+        manga_id: i32,
+        chapter_id: i32,
+        page_index: Option<i32>,
+    ) -> anyhow::Result<domain::entity::manga_chapter::Model> {
+        let mut chapter = self.manga_chapter_repo.find_by_id(chapter_id).await?
+            .ok_or_else(|| anyhow::anyhow!("Chapter not found"))?;
+
+        if chapter.manga_id != manga_id {
+            return Err(anyhow::anyhow!("Chapter {} does not belong to manga {}", chapter_id, manga_id));
+        }
+
+        let cover = match page_index {
+            Some(page_index) => {
+                self.get_chapter_image_path(chapter_id, page_index).await?;
+                MangaDomainService::cover_page_marker(manga_id, Some(chapter_id), page_index)
+            }
+            // 恢复为扫描时写入的默认封面路径
+            None => format!("/manga/{}/chapter/{}/cover", manga_id, chapter_id),
+        };
+        chapter.update_cover(Some(cover));
+
+        let updated_chapter = self.manga_chapter_repo.update(chapter).await?;
+
+        self.chapter_cache.invalidate(&chapter_id).await;
+        self.chapter_auto_cover_cache.invalidate(&chapter_id).await;
+        self.auto_cover_cache.invalidate(&manga_id).await;
+        remove_cached_thumbnails(manga_id).await;
+
+        Ok(updated_chapter)
     }
 
     /// 获取章节的封面（完整数据）
@@ -477,6 +779,308 @@ impl ImageService {
         // 压缩为缩略图
         compress_image(&image_data, width, height, quality)
     }
+
+    /// 获取漫画的健康报告（列出扫描时发现的损坏页面）
+    pub async fn get_manga_health(&self, manga_id: i32) -> anyhow::Result<MangaHealthReport> {
+        let manga = self.manga_repo.find_by_id(manga_id).await?
+            .ok_or_else(|| anyhow::anyhow!("Manga not found"))?;
+
+        let chapters = if manga.has_chapters {
+            self.manga_chapter_repo.find_by_manga_id(manga_id).await?
+        } else {
+            Vec::new()
+        };
+
+        Ok(build_health_report(&manga, &chapters))
+    }
+
+    /// 获取存在损坏页面的所有漫画的健康报告
+    ///
+    /// # 参数
+    /// - `media_library_id`: 只查询指定媒体库，None 表示全部
+    pub async fn get_health_report(&self, media_library_id: Option<i32>) -> anyhow::Result<Vec<MangaHealthReport>> {
+        let mut mangas: HashMap<i32, domain::entity::manga::Model> = self.manga_repo
+            .find_with_bad_pages()
+            .await?
+            .into_iter()
+            .map(|manga| (manga.id, manga))
+            .collect();
+
+        let mut chapters_by_manga: HashMap<i32, Vec<domain::entity::manga_chapter::Model>> = HashMap::new();
+        for chapter in self.manga_chapter_repo.find_with_bad_pages().await? {
+            chapters_by_manga.entry(chapter.manga_id).or_default().push(chapter);
+        }
+
+        // 只有章节存在损坏页面的漫画需要单独查询
+        for &manga_id in chapters_by_manga.keys() {
+            if let Entry::Vacant(entry) = mangas.entry(manga_id)
+                && let Some(manga) = self.manga_repo.find_by_id(manga_id).await?
+            {
+                entry.insert(manga);
+            }
+        }
+
+        let mut reports: Vec<MangaHealthReport> = mangas
+            .values()
+            .filter(|manga| media_library_id.is_none_or(|id| manga.media_library_id == id))
+            .map(|manga| {
+                let chapters = chapters_by_manga.get(&manga.id).map(Vec::as_slice).unwrap_or_default();
+                build_health_report(manga, chapters)
+            })
+            .collect();
+
+        reports.sort_by(|a, b| natord::compare(&a.title, &b.title));
+        Ok(reports)
+    }
+
+    /// 重新检查漫画的所有页面
+    ///
+    /// 用于修复/替换图片后刷新损坏页面列表，也可以为升级前扫描的漫画补充检查
+    ///
+    /// # 业务规则
+    /// - 章节漫画逐个检查所有章节
+    /// - 检查完成后清除图片列表缓存，阅读器立即生效
+    pub async fn recheck_manga_pages(&self, manga_id: i32) -> anyhow::Result<MangaHealthReport> {
+        let mut manga = self.manga_repo.find_by_id(manga_id).await?
+            .ok_or_else(|| anyhow::anyhow!("Manga not found"))?;
+
+        let mut chapters = Vec::new();
+        if manga.has_chapters {
+            for mut chapter in self.manga_chapter_repo.find_by_manga_id(manga_id).await? {
+                let bad_pages = self.check_folder_pages(&chapter.path, chapter.get_image_paths()).await?;
+                chapter.set_bad_pages(bad_pages);
+                self.chapter_image_list_cache.invalidate(&chapter.id).await;
+                self.chapter_cache.invalidate(&chapter.id).await;
+                chapters.push(chapter);
+            }
+            chapters = self.manga_chapter_repo.update_batch(chapters).await?;
+        } else {
+            let bad_pages = self.check_folder_pages(&manga.path, manga.get_image_paths()).await?;
+            manga.set_bad_pages(bad_pages);
+            manga = self.manga_repo.update(manga).await?;
+        }
+
+        self.image_list_cache.invalidate(&manga_id).await;
+        self.manga_cache.invalidate(&manga_id).await;
+
+        let report = build_health_report(&manga, &chapters);
+        tracing::info!("Rechecked pages of manga {}: {} unreadable", manga_id, report.bad_page_count);
+        Ok(report)
+    }
+
+    /// 在线程池中检查文件夹中的页面，返回损坏页面的文件名
+    ///
+    /// 数据库中没有图片列表时重新扫描文件夹
+    async fn check_folder_pages(&self, folder: &str, image_names: Option<Vec<String>>) -> anyhow::Result<Vec<String>> {
+        let image_names = match image_names {
+            Some(names) => names,
+            None => self.scan_images_in_folder(folder).await?
+                .into_iter()
+                .filter_map(|path| {
+                    std::path::Path::new(&path)
+                        .file_name()
+                        .map(|name| name.to_string_lossy().to_string())
+                })
+                .collect(),
+        };
+
+        let folder = folder.to_string();
+        tokio::task::spawn_blocking(move || find_unreadable_pages(&folder, &image_names))
+            .await
+            .map_err(|e| anyhow::anyhow!("Task join error: {}", e))
+    }
+}
+
+/// 从图片列表中移除损坏页面（按文件名匹配）
+fn skip_bad_pages(images: Vec<String>, bad_pages: &[String]) -> Vec<String> {
+    if bad_pages.is_empty() {
+        return images;
+    }
+
+    images
+        .into_iter()
+        .filter(|path| {
+            let file_name = std::path::Path::new(path)
+                .file_name()
+                .map(|name| name.to_string_lossy());
+            !file_name.is_some_and(|name| bad_pages.iter().any(|bad| *bad == name))
+        })
+        .collect()
+}
+
+/// 根据漫画和章节的损坏页面列表生成健康报告
+fn build_health_report(
+    manga: &domain::entity::manga::Model,
+    chapters: &[domain::entity::manga_chapter::Model],
+) -> MangaHealthReport {
+    let bad_pages = manga.get_bad_pages();
+
+    let mut chapter_reports: Vec<ChapterHealthReport> = chapters
+        .iter()
+        .map(|chapter| ChapterHealthReport {
+            chapter_id: chapter.id,
+            title: chapter.title.clone(),
+            bad_pages: chapter.get_bad_pages(),
+        })
+        .filter(|report| !report.bad_pages.is_empty())
+        .collect();
+    chapter_reports.sort_by(|a, b| natord::compare(&a.title, &b.title));
+
+    let bad_page_count = bad_pages.len() + chapter_reports.iter().map(|c| c.bad_pages.len()).sum::<usize>();
+
+    MangaHealthReport {
+        manga_id: manga.id,
+        title: manga.title.clone(),
+        media_library_id: manga.media_library_id,
+        bad_pages,
+        chapters: chapter_reports,
+        bad_page_count,
+    }
+}
+
+/// 从图片列表中自动挑选封面
+///
+/// 在线程池中检查前 [`COVER_CANDIDATE_LIMIT`] 张图片，挑选规则：
+/// 1. 文件名明确标记为封面的图片
+/// 2. 跳过汉化组声明/招募/广告页、过小的图片和近乎纯色的空白页
+/// 3. 剩余图片中优先选择竖版图片，否则选择第一张可用图片
+/// 4. 都不满足时回退到第一张图片
+async fn select_cover_image(images: Vec<String>) -> anyhow::Result<Option<String>> {
+    if images.len() <= 1 {
+        return Ok(images.into_iter().next());
+    }
+
+    tokio::task::spawn_blocking(move || pick_cover_image(&images))
+        .await
+        .map_err(|e| anyhow::anyhow!("Task join error: {}", e))
+}
+
+/// 封面挑选的同步实现，见 [`select_cover_image`]
+fn pick_cover_image(images: &[String]) -> Option<String> {
+    let file_name = |path: &str| {
+        std::path::Path::new(path)
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string())
+            .unwrap_or_default()
+    };
+
+    let candidates = &images[..images.len().min(COVER_CANDIDATE_LIMIT)];
+
+    if let Some(named_cover) = candidates.iter().find(|path| {
+        let name = file_name(path);
+        MangaDomainService::is_cover_page_name(&name) && !MangaDomainService::is_credit_page_name(&name)
+    }) {
+        return Some(named_cover.clone());
+    }
+
+    let mut first_usable: Option<&String> = None;
+
+    for path in candidates {
+        if MangaDomainService::is_credit_page_name(&file_name(path)) {
+            tracing::debug!("Skipping credit page as cover: {}", path);
+            continue;
+        }
+
+        let Ok((width, height)) = image::image_dimensions(path) else {
+            continue;
+        };
+
+        if width.min(height) < COVER_MIN_SIDE {
+            tracing::debug!("Skipping tiny image as cover: {} ({}x{})", path, width, height);
+            continue;
+        }
+
+        if is_nearly_uniform(path) {
+            tracing::debug!("Skipping blank image as cover: {}", path);
+            continue;
+        }
+
+        // 竖版图片最像封面，直接选用
+        if height > width {
+            return Some(path.clone());
+        }
+
+        if first_usable.is_none() {
+            first_usable = Some(path);
+        }
+    }
+
+    first_usable.or_else(|| images.first()).cloned()
+}
+
+/// 判断图片是否近乎纯色（空白页、纯黑页等）
+///
+/// 将图片缩小为 32x32 灰度图后计算亮度标准差，无法解码的图片视为不可用
+fn is_nearly_uniform(path: &str) -> bool {
+    let Ok(img) = image::open(path) else {
+        return true;
+    };
+
+    let luma = img.thumbnail(32, 32).to_luma8();
+    let pixel_count = (luma.width() * luma.height()) as f64;
+    if pixel_count == 0.0 {
+        return true;
+    }
+
+    let mean = luma.pixels().map(|p| p.0[0] as f64).sum::<f64>() / pixel_count;
+    let variance = luma.pixels()
+        .map(|p| (p.0[0] as f64 - mean).powi(2))
+        .sum::<f64>() / pixel_count;
+
+    variance.sqrt() < COVER_MIN_LUMA_STDDEV
+}
+
+/// 为图片列表生成 CBZ 条目名：按顺序编号并保留原扩展名，如 `0001.jpg`
+fn cbz_page_entries(images: Vec<String>) -> Vec<(String, String)> {
+    images
+        .into_iter()
+        .enumerate()
+        .map(|(index, path)| {
+            let extension = std::path::Path::new(&path)
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .unwrap_or("jpg")
+                .to_lowercase();
+            (format!("{:04}.{}", index + 1, extension), path)
+        })
+        .collect()
+}
+
+/// 替换文件名中不允许出现的字符，用于压缩包内目录名和下载文件名
+fn sanitize_archive_name(name: &str) -> String {
+    let sanitized: String = name
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | ':' | '*' | '?' | '"' | '<' | '>' | '|' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect();
+
+    let sanitized = sanitized.trim().trim_end_matches('.').to_string();
+    if sanitized.is_empty() {
+        "untitled".to_string()
+    } else {
+        sanitized
+    }
+}
+
+/// 删除漫画的所有磁盘缩略图缓存（`./data/cache/thumbnails/{manga_id}_*.jpg`）
+async fn remove_cached_thumbnails(manga_id: i32) {
+    let cache_dir = std::path::Path::new("./data/cache/thumbnails");
+    let prefix = format!("{}_", manga_id);
+
+    let Ok(mut entries) = tokio::fs::read_dir(cache_dir).await else {
+        return;
+    };
+
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        if entry.file_name().to_string_lossy().starts_with(&prefix)
+            && let Err(e) = tokio::fs::remove_file(entry.path()).await
+        {
+            tracing::warn!("Failed to remove thumbnail cache {}: {}", entry.path().display(), e);
+        }
+    }
 }
 
 /// 压缩图片为缩略图
@@ -486,7 +1090,7 @@ impl ImageService {
 /// - `width`: 缩略图宽度（像素）
 /// - `height`: 缩略图高度（像素）
 /// - `quality`: 图片质量（0-100）
-fn compress_image(image_data: &[u8], width: u32, height: u32, _quality: u8) -> anyhow::Result<Vec<u8>> {
+pub(crate) fn compress_image(image_data: &[u8], width: u32, height: u32, _quality: u8) -> anyhow::Result<Vec<u8>> {
     use std::io::Cursor;
 
     // 读取图片
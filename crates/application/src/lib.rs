@@ -3,9 +3,16 @@ pub mod user_service;
 pub mod media_library_service;
 pub mod manga_service;
 pub mod manga_chapter_service;
+pub mod manga_series_service;
+pub mod manga_metadata_service;
 pub mod game_service;
 pub mod movie_service;
+pub mod person_service;
+pub mod tv_series_service;
 pub mod photo_service;
 pub mod image_service;
 pub mod scan_task;
 pub mod dto;
+
+#[cfg(test)]
+mod test_support;
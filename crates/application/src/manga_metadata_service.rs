@@ -0,0 +1,78 @@
+use domain::repository::MangaRepository;
+use domain::service::MangaSeriesDomainService;
+use infrastructure::file_scanner::manga_provider::{AniListProvider, MangaMetadataProvider};
+use std::sync::Arc;
+use crate::dto::{MangaMetadataInfo, MangaMetadataSearchInfo};
+
+/// 漫画在线元数据服务
+///
+/// 通过可插拔的 `MangaMetadataProvider` 查询作者、类型、连载状态和简介，
+/// 默认使用 AniList，可在配置文件的 `manga.anilist` 中关闭
+pub struct MangaMetadataService {
+    manga_repo: Arc<dyn MangaRepository>,
+    provider: Option<Arc<dyn MangaMetadataProvider>>,
+}
+
+impl MangaMetadataService {
+    /// 根据配置创建漫画元数据服务实例
+    pub fn new(manga_repo: Arc<dyn MangaRepository>) -> Self {
+        let config = shared::config::get().manga().anilist();
+        let provider: Option<Arc<dyn MangaMetadataProvider>> = if config.enabled() {
+            Some(Arc::new(AniListProvider::new(config.api_url().to_string())))
+        } else {
+            None
+        };
+
+        Self::with_provider(manga_repo, provider)
+    }
+
+    /// 使用指定的元数据提供者创建服务实例
+    pub fn with_provider(
+        manga_repo: Arc<dyn MangaRepository>,
+        provider: Option<Arc<dyn MangaMetadataProvider>>,
+    ) -> Self {
+        Self { manga_repo, provider }
+    }
+
+    /// 获取当前启用的元数据提供者
+    fn provider(&self) -> anyhow::Result<&Arc<dyn MangaMetadataProvider>> {
+        self.provider
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Manga metadata provider is disabled"))
+    }
+
+    /// 按标题搜索漫画
+    pub async fn search(&self, title: &str) -> anyhow::Result<Vec<MangaMetadataSearchInfo>> {
+        let title = title.trim();
+        if title.is_empty() {
+            return Err(anyhow::anyhow!("title must not be empty"));
+        }
+
+        let provider = self.provider()?;
+        let results = provider.search(title).await.map_err(|e| anyhow::anyhow!(e))?;
+
+        Ok(results
+            .into_iter()
+            .map(|result| MangaMetadataSearchInfo::new(provider.name(), result))
+            .collect())
+    }
+
+    /// 为库中的漫画搜索候选元数据
+    ///
+    /// 使用去掉卷号后的系列名搜索（`One Piece v01` → `One Piece`）
+    pub async fn search_for_manga(&self, manga_id: i32) -> anyhow::Result<Vec<MangaMetadataSearchInfo>> {
+        let manga = self.manga_repo.find_by_id(manga_id).await?
+            .ok_or_else(|| anyhow::anyhow!("Manga not found"))?;
+
+        let (series_title, _) = MangaSeriesDomainService::parse_series_title(&manga.title);
+        self.search(&series_title).await
+    }
+
+    /// 获取漫画的详细元数据
+    pub async fn get_details(&self, provider_id: u64) -> anyhow::Result<MangaMetadataInfo> {
+        let provider = self.provider()?;
+        let details = provider.get_details(provider_id).await.map_err(|e| anyhow::anyhow!(e))?;
+
+        Ok(MangaMetadataInfo::new(provider.name(), details))
+    }
+}
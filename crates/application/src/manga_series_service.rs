@@ -0,0 +1,320 @@
+use domain::entity::manga_series::Model as MangaSeriesModel;
+use domain::repository::{MangaRepository, MangaSeriesRepository};
+use domain::service::MangaSeriesDomainService;
+use std::collections::HashMap;
+use std::sync::Arc;
+use crate::dto::{MangaInfo, MangaSeriesDetail, UpdateMangaSeriesRequest};
+
+/// 漫画系列服务
+///
+/// 同一系列的不同卷通常分布在不同文件夹中（`Title v01`、`Title v02`），
+/// 扫描后按规范化标题自动归入同一个系列，也支持手动合并和拆分
+pub struct MangaSeriesService {
+    series_repo: Arc<dyn MangaSeriesRepository>,
+    manga_repo: Arc<dyn MangaRepository>,
+}
+
+impl MangaSeriesService {
+    /// 创建新的漫画系列服务实例
+    pub fn new(
+        series_repo: Arc<dyn MangaSeriesRepository>,
+        manga_repo: Arc<dyn MangaRepository>,
+    ) -> Self {
+        Self { series_repo, manga_repo }
+    }
+
+    /// 根据 ID 查询系列
+    pub async fn find_by_id(&self, id: i32) -> anyhow::Result<Option<MangaSeriesModel>> {
+        self.series_repo.find_by_id(id).await
+    }
+
+    /// 分页查询所有系列（按标题排序）
+    pub async fn find_paged(&self, page_size: i32, page_index: i32) -> anyhow::Result<Option<Vec<MangaSeriesModel>>> {
+        self.series_repo.find_by_paged(page_size, page_index).await
+    }
+
+    /// 获取所有系列的总数
+    pub async fn count_all(&self) -> anyhow::Result<i32> {
+        self.series_repo.count_all().await
+    }
+
+    /// 获取系列详情（包含按卷号排序的所有漫画）
+    pub async fn get_detail(&self, id: i32) -> anyhow::Result<Option<MangaSeriesDetail>> {
+        let Some(series) = self.series_repo.find_by_id(id).await? else {
+            return Ok(None);
+        };
+
+        let volumes = self.find_volumes(id).await?;
+
+        Ok(Some(MangaSeriesDetail {
+            series: series.into(),
+            volumes: volumes.into_iter().map(MangaInfo::from).collect(),
+        }))
+    }
+
+    /// 查询系列中的所有漫画
+    ///
+    /// 按卷号升序排列，没有卷号的漫画排在最后并按标题自然排序
+    pub async fn find_volumes(&self, series_id: i32) -> anyhow::Result<Vec<domain::entity::manga::Model>> {
+        let mut volumes = self.manga_repo.find_by_series_id(series_id).await?;
+
+        volumes.sort_by(|a, b| match (a.volume_number, b.volume_number) {
+            (Some(x), Some(y)) => x.partial_cmp(&y).unwrap_or(std::cmp::Ordering::Equal),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => natord::compare(&a.title, &b.title),
+        });
+
+        Ok(volumes)
+    }
+
+    /// 将媒体库中尚未归入系列的漫画按规范化标题自动分组
+    ///
+    /// # 业务规则
+    /// - 已经归入系列的漫画（包括手动合并/拆分过的）不会被重新分组
+    /// - 规范化标题相同的漫画归入同一系列，不存在时自动创建
+    /// - 每本漫画都会归入一个系列（单卷漫画也会有自己的系列）
+    ///
+    /// # 返回
+    /// - `usize` - 本次归入系列的漫画数量
+    pub async fn group_media_library(&self, media_library_id: i32) -> anyhow::Result<usize> {
+        let ungrouped: Vec<_> = self.manga_repo
+            .find_by_media_library_id(media_library_id)
+            .await?
+            .into_iter()
+            .filter(|manga| manga.series_id.is_none())
+            .collect();
+
+        if ungrouped.is_empty() {
+            return Ok(0);
+        }
+
+        let mut series_by_key: HashMap<String, i32> = self.series_repo
+            .find_by_media_library_id(media_library_id)
+            .await?
+            .into_iter()
+            .map(|series| (series.normalized_title, series.id))
+            .collect();
+
+        let mut updated_mangas = Vec::with_capacity(ungrouped.len());
+        for mut manga in ungrouped {
+            let (series_title, volume_number) = MangaSeriesDomainService::parse_series_title(&manga.title);
+            let key = MangaSeriesDomainService::normalize_title(&series_title);
+
+            let series_id = match series_by_key.get(&key) {
+                Some(&series_id) => series_id,
+                None => {
+                    let series = self.series_repo
+                        .create(MangaSeriesModel::new(series_title, media_library_id)?)
+                        .await?;
+                    series_by_key.insert(key, series.id);
+                    series.id
+                }
+            };
+
+            manga.assign_series(series_id, volume_number);
+            updated_mangas.push(manga);
+        }
+
+        let updated_mangas = self.manga_repo.update_batch(updated_mangas).await?;
+        tracing::info!("Grouped {} mangas into series for media library {}", updated_mangas.len(), media_library_id);
+
+        Ok(updated_mangas.len())
+    }
+
+    /// 更新系列的标题、简介和封面
+    ///
+    /// # 业务规则
+    /// - 只更新请求中传入的字段，不传的字段保持不变
+    /// - 简介和封面漫画传 null 时清除
+    /// - 封面漫画必须属于该系列
+    pub async fn update(&self, id: i32, req: UpdateMangaSeriesRequest) -> anyhow::Result<MangaSeriesModel> {
+        let mut series = self.series_repo.find_by_id(id).await?
+            .ok_or_else(|| anyhow::anyhow!("Manga series not found"))?;
+
+        if let Some(title) = req.title {
+            series.update_title(title)?;
+        }
+
+        if let Some(Some(cover_manga_id)) = req.cover_manga_id {
+            let manga = self.manga_repo.find_by_id(cover_manga_id).await?
+                .ok_or_else(|| anyhow::anyhow!("Manga not found"))?;
+            if manga.series_id != Some(id) {
+                return Err(anyhow::anyhow!("Manga {} does not belong to series {}", cover_manga_id, id));
+            }
+        }
+
+        if let Some(description) = req.description {
+            series.set_description(description);
+        }
+        if let Some(cover_manga_id) = req.cover_manga_id {
+            series.set_cover_manga(cover_manga_id);
+        }
+
+        self.series_repo.update(series).await
+    }
+
+    /// 将其他系列合并到目标系列
+    ///
+    /// # 业务规则
+    /// - 源系列和目标系列必须属于同一个媒体库
+    /// - 源系列的漫画保留原有卷号，合并后源系列被删除
+    /// - 目标系列没有简介时，沿用第一个有简介的源系列的简介
+    pub async fn merge(&self, target_id: i32, source_series_ids: Vec<i32>) -> anyhow::Result<MangaSeriesModel> {
+        let mut target = self.series_repo.find_by_id(target_id).await?
+            .ok_or_else(|| anyhow::anyhow!("Manga series not found"))?;
+
+        if source_series_ids.is_empty() {
+            return Err(anyhow::anyhow!("source_series_ids must not be empty"));
+        }
+
+        let mut sources = Vec::with_capacity(source_series_ids.len());
+        for source_id in source_series_ids {
+            if source_id == target_id {
+                return Err(anyhow::anyhow!("Cannot merge a series into itself"));
+            }
+            let source = self.series_repo.find_by_id(source_id).await?
+                .ok_or_else(|| anyhow::anyhow!("Manga series {} not found", source_id))?;
+            if source.media_library_id != target.media_library_id {
+                return Err(anyhow::anyhow!("Manga series {} belongs to a different media library", source_id));
+            }
+            sources.push(source);
+        }
+
+        let mut moved_mangas = Vec::new();
+        for source in &sources {
+            for mut manga in self.manga_repo.find_by_series_id(source.id).await? {
+                let volume_number = manga.volume_number;
+                manga.assign_series(target_id, volume_number);
+                moved_mangas.push(manga);
+            }
+        }
+        self.manga_repo.update_batch(moved_mangas).await?;
+
+        if target.description.is_none()
+            && let Some(description) = sources.iter().find_map(|source| source.description.clone())
+        {
+            target.set_description(Some(description));
+            target = self.series_repo.update(target).await?;
+        }
+
+        for source in sources {
+            self.series_repo.delete(source.id).await?;
+        }
+
+        Ok(target)
+    }
+
+    /// 将系列中的部分漫画拆分到一个新系列
+    ///
+    /// # 业务规则
+    /// - 被拆分的漫画必须属于该系列
+    /// - 新系列标题默认使用第一本漫画的标题
+    /// - 原系列的漫画全部移出后，原系列被删除
+    pub async fn split(&self, series_id: i32, manga_ids: Vec<i32>, title: Option<String>) -> anyhow::Result<MangaSeriesModel> {
+        let mut series = self.series_repo.find_by_id(series_id).await?
+            .ok_or_else(|| anyhow::anyhow!("Manga series not found"))?;
+
+        if manga_ids.is_empty() {
+            return Err(anyhow::anyhow!("manga_ids must not be empty"));
+        }
+
+        let volumes = self.manga_repo.find_by_series_id(series_id).await?;
+        let (mut moved, remaining): (Vec<_>, Vec<_>) = volumes
+            .into_iter()
+            .partition(|manga| manga_ids.contains(&manga.id));
+
+        if moved.len() != manga_ids.len() {
+            return Err(anyhow::anyhow!("Some mangas do not belong to series {}", series_id));
+        }
+
+        let title = title.unwrap_or_else(|| moved[0].title.clone());
+        let new_series = self.series_repo
+            .create(MangaSeriesModel::new(title, series.media_library_id)?)
+            .await?;
+
+        for manga in &mut moved {
+            let volume_number = manga.volume_number;
+            manga.assign_series(new_series.id, volume_number);
+        }
+        self.manga_repo.update_batch(moved).await?;
+
+        if remaining.is_empty() {
+            self.series_repo.delete(series_id).await?;
+        } else if series.cover_manga_id.is_some_and(|cover_id| manga_ids.contains(&cover_id)) {
+            // 封面漫画已被移出，恢复使用第一卷的封面
+            series.set_cover_manga(None);
+            self.series_repo.update(series).await?;
+        }
+
+        Ok(new_series)
+    }
+
+    /// 获取系列封面所使用的漫画 ID（手动指定的封面漫画，否则为第一卷）
+    pub async fn resolve_cover_manga_id(&self, series_id: i32) -> anyhow::Result<i32> {
+        let series = self.series_repo.find_by_id(series_id).await?
+            .ok_or_else(|| anyhow::anyhow!("Manga series not found"))?;
+
+        if let Some(cover_manga_id) = series.cover_manga_id {
+            return Ok(cover_manga_id);
+        }
+
+        self.find_volumes(series_id).await?
+            .first()
+            .map(|manga| manga.id)
+            .ok_or_else(|| anyhow::anyhow!("No mangas found in series {}", series_id))
+    }
+
+    /// 删除媒体库下的所有系列
+    pub async fn delete_by_media_library_id(&self, media_library_id: i32) -> anyhow::Result<()> {
+        self.series_repo.delete_by_media_library_id(media_library_id).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{create_table, memory_db};
+    use infrastructure::repository::{MangaRepositoryImpl, MangaSeriesRepositoryImpl};
+
+    async fn setup() -> MangaSeriesService {
+        let db = memory_db().await;
+        create_table(&db, domain::entity::manga_series::Entity).await;
+        create_table(&db, domain::entity::manga::Entity).await;
+        MangaSeriesService::new(
+            Arc::new(MangaSeriesRepositoryImpl::new(db.clone())),
+            Arc::new(MangaRepositoryImpl::new(db)),
+        )
+    }
+
+    async fn create_series(service: &MangaSeriesService, description: Option<&str>) -> MangaSeriesModel {
+        let mut series = MangaSeriesModel::new("One Piece".to_string(), 1).unwrap();
+        series.set_description(description.map(str::to_string));
+        service.series_repo.create(series).await.unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_partial_update_keeps_description() {
+        let service = setup().await;
+        let series = create_series(&service, Some("海贼王")).await;
+
+        let req: UpdateMangaSeriesRequest = serde_json::from_str(r#"{"title": "ONE PIECE"}"#).unwrap();
+        let updated = service.update(series.id, req).await.unwrap();
+
+        assert_eq!(updated.title, "ONE PIECE");
+        assert_eq!(updated.description.as_deref(), Some("海贼王"));
+        assert_eq!(updated.cover_manga_id, None);
+    }
+
+    #[tokio::test]
+    async fn test_update_with_null_clears_description() {
+        let service = setup().await;
+        let series = create_series(&service, Some("海贼王")).await;
+
+        let req: UpdateMangaSeriesRequest = serde_json::from_str(r#"{"description": null}"#).unwrap();
+        let updated = service.update(series.id, req).await.unwrap();
+
+        assert_eq!(updated.title, "One Piece");
+        assert_eq!(updated.description, None);
+    }
+}
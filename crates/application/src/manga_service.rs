@@ -59,6 +59,9 @@ impl MangaService {
             cover: req.cover,
             has_chapters: false, // 默认为单文件夹漫画
             image_paths: None, // 初始为空，后续可以通过扫描填充
+            bad_pages: None,
+            series_id: None,
+            volume_number: None,
             create_time: now.clone(),
             update_time: now,
         };
@@ -86,6 +89,9 @@ impl MangaService {
                     cover: req.cover,
                     has_chapters: false, // 默认为单文件夹漫画
                     image_paths: None, // 初始为空，后续可以通过扫描填充
+                    bad_pages: None,
+                    series_id: None,
+                    volume_number: None,
                     create_time: now.clone(),
                     update_time: now,
                 }
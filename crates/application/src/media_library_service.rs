@@ -1,8 +1,10 @@
-use domain::repository::{MediaLibraryRepository, MangaRepository, MangaChapterRepository, GameRepository, MovieRepository, PhotoRepository, PhotoExifRepository};
+use domain::repository::{MediaLibraryRepository, MangaRepository, MangaChapterRepository, GameRepository, PhotoRepository, PhotoExifRepository, TvEpisodeRepository, TvSeasonRepository, TvSeriesRepository};
 use infrastructure::file_scanner;
 use std::sync::Arc;
 use crate::dto::CreateMediaLibraryRequest;
 use crate::image_service::ImageService;
+use crate::manga_series_service::MangaSeriesService;
+use crate::movie_service::MovieRepositories;
 use crate::scan_task::ScanTaskManager;
 
 /// 媒体库服务
@@ -11,10 +13,14 @@ pub struct MediaLibraryService {
     manga_repo: Arc<dyn MangaRepository>,
     manga_chapter_repo: Arc<dyn MangaChapterRepository>,
     game_repo: Arc<dyn GameRepository>,
-    movie_repo: Arc<dyn MovieRepository>,
+    movie_repos: MovieRepositories,
+    tv_series_repo: Arc<dyn TvSeriesRepository>,
+    tv_season_repo: Arc<dyn TvSeasonRepository>,
+    tv_episode_repo: Arc<dyn TvEpisodeRepository>,
     photo_repo: Arc<dyn PhotoRepository>,
     photo_exif_repo: Arc<dyn PhotoExifRepository>,
     image_service: Arc<ImageService>,
+    manga_series_service: Arc<MangaSeriesService>,
     scan_task_manager: Arc<ScanTaskManager>,
 }
 
@@ -25,10 +31,14 @@ impl MediaLibraryService {
         manga_repo: Arc<dyn MangaRepository>,
         manga_chapter_repo: Arc<dyn MangaChapterRepository>,
         game_repo: Arc<dyn GameRepository>,
-        movie_repo: Arc<dyn MovieRepository>,
+        movie_repos: MovieRepositories,
+        tv_series_repo: Arc<dyn TvSeriesRepository>,
+        tv_season_repo: Arc<dyn TvSeasonRepository>,
+        tv_episode_repo: Arc<dyn TvEpisodeRepository>,
         photo_repo: Arc<dyn PhotoRepository>,
         photo_exif_repo: Arc<dyn PhotoExifRepository>,
         image_service: Arc<ImageService>,
+        manga_series_service: Arc<MangaSeriesService>,
         scan_task_manager: Arc<ScanTaskManager>,
     ) -> Self {
         Self {
@@ -36,10 +46,14 @@ impl MediaLibraryService {
             manga_repo,
             manga_chapter_repo,
             game_repo,
-            movie_repo,
+            movie_repos,
+            tv_series_repo,
+            tv_season_repo,
+            tv_episode_repo,
             photo_repo,
             photo_exif_repo,
             image_service,
+            manga_series_service,
             scan_task_manager,
         }
     }
@@ -57,7 +71,7 @@ impl MediaLibraryService {
     /// 删除媒体库
     ///
     /// # 业务规则
-    /// - 删除媒体库时，必须先删除所有关联的游戏、漫画和电影
+    /// - 删除媒体库时，必须先删除所有关联的游戏、漫画、电影和电视剧
     /// - 使用事务确保数据一致性
     ///
     /// # 参数
@@ -89,17 +103,37 @@ impl MediaLibraryService {
                 self.manga_repo.delete(manga.id).await?;
             }
         }
+        self.manga_series_service.delete_by_media_library_id(id).await?;
 
         // 4. 删除所有关联的电影
-        let movies = self.movie_repo.find_by_media_library_id(id).await?;
+        let movies = self.movie_repos.movie.find_by_media_library_id(id).await?;
         if !movies.is_empty() {
             tracing::info!("Deleting {} movies associated with media library {}", movies.len(), id);
             for movie in movies {
-                self.movie_repo.delete(movie.id).await?;
+                self.movie_repos.track.delete_by_movie_id(movie.id).await?;
+                self.movie_repos.subtitle.delete_by_movie_id(movie.id).await?;
+                self.movie_repos.source.delete_by_movie_id(movie.id).await?;
+                self.movie_repos.extra.delete_by_movie_id(movie.id).await?;
+                self.movie_repos.credit.delete_by_movie_id(movie.id).await?;
+                self.movie_repos.play_state.delete_by_movie_id(movie.id).await?;
+                self.movie_repos.movie.delete(movie.id).await?;
+                crate::movie_service::remove_cached_subtitles(movie.id).await;
+                crate::movie_service::remove_cached_artwork(movie.id).await;
             }
         }
 
-        // 5. 删除媒体库本身
+        // 5. 删除所有关联的电视剧（包括季和单集）
+        let tv_series = self.tv_series_repo.find_by_media_library_id(id).await?;
+        if !tv_series.is_empty() {
+            tracing::info!("Deleting {} tv series associated with media library {}", tv_series.len(), id);
+            for series in tv_series {
+                self.tv_episode_repo.delete_by_series_id(series.id).await?;
+                self.tv_season_repo.delete_by_series_id(series.id).await?;
+                self.tv_series_repo.delete(series.id).await?;
+            }
+        }
+
+        // 6. 删除媒体库本身
         self.media_library_repo.delete(id).await?;
 
         tracing::info!("Successfully deleted media library {} and all associated resources", id);
@@ -134,6 +168,19 @@ impl MediaLibraryService {
         // 保存扫描结果中的图片路径列表，用于后续存储到数据库
         let mut manga_image_paths_map: std::collections::HashMap<String, Vec<String>> = std::collections::HashMap::new();
         let mut chapter_image_paths_map: std::collections::HashMap<String, Vec<String>> = std::collections::HashMap::new();
+        // 保存扫描时发现的损坏页面
+        let mut manga_bad_pages_map: std::collections::HashMap<String, Vec<String>> = std::collections::HashMap::new();
+        let mut chapter_bad_pages_map: std::collections::HashMap<String, Vec<String>> = std::collections::HashMap::new();
+        // 保存扫描时探测到的电影轨道（按视频路径）
+        let mut movie_tracks_map: std::collections::HashMap<String, Vec<domain::entity::movie_track::Model>> = std::collections::HashMap::new();
+        let mut movie_subtitles_map: std::collections::HashMap<String, Vec<domain::entity::movie_subtitle::Model>> = std::collections::HashMap::new();
+        let mut movie_sources_map: std::collections::HashMap<String, Vec<domain::entity::movie_source::Model>> = std::collections::HashMap::new();
+        let mut movie_extras_map: std::collections::HashMap<String, Vec<domain::entity::movie_extra::Model>> = std::collections::HashMap::new();
+        let mut movie_credits_map: std::collections::HashMap<String, Vec<infrastructure::file_scanner::movie_scaner::provider::MovieCredit>> = std::collections::HashMap::new();
+        // 保存扫描时获取到的电影系列（按视频路径）
+        let mut movie_collections_map: std::collections::HashMap<String, infrastructure::file_scanner::movie_scaner::provider::CollectionDetails> = std::collections::HashMap::new();
+        // 保存扫描到的季和单集（按电视剧文件夹路径）
+        let mut tv_seasons_map: std::collections::HashMap<String, Vec<infrastructure::file_scanner::tv_scanner::SeasonScanResult>> = std::collections::HashMap::new();
 
         // 如果是可扫描类型，扫描并添加相应的媒体
         if aggregate.is_scannable() {
@@ -145,12 +192,13 @@ impl MediaLibraryService {
                     let mut total_added = 0;
                     for result in scan_results {
                         match result {
-                            infrastructure::file_scanner::MangaScanResult::SingleFolder { path, page_count, image_paths } => {
+                            infrastructure::file_scanner::MangaScanResult::SingleFolder { path, page_count, image_paths, bad_pages } => {
                                 // 单文件夹漫画
                                 let byte_size = domain::service::MangaDomainService::calculate_folder_byte_size(&path);
 
                                 // 保存图片路径列表（用于后续存储到数据库）
                                 manga_image_paths_map.insert(path.clone(), image_paths);
+                                manga_bad_pages_map.insert(path.clone(), bad_pages);
 
                                 aggregate.add_mangas_batch(vec![(path, page_count, byte_size)])?;
                                 total_added += 1;
@@ -165,6 +213,7 @@ impl MediaLibraryService {
                                 // 保存章节图片路径列表（用于后续存储到数据库）
                                 for ch in &chapters {
                                     chapter_image_paths_map.insert(ch.path.clone(), ch.image_paths.clone());
+                                    chapter_bad_pages_map.insert(ch.path.clone(), ch.bad_pages.clone());
                                 }
 
                                 aggregate.add_manga_with_chapters(root_path, chapter_data)?;
@@ -196,11 +245,40 @@ impl MediaLibraryService {
 
                     tracing::info!("Scanned {} movies from folders", video_scan_results.len());
 
+                    for video in &video_scan_results {
+                        movie_tracks_map.insert(video.path.clone(), Self::convert_video_tracks(&video.tracks));
+                        movie_subtitles_map.insert(video.path.clone(), Self::convert_external_subtitles(&video.subtitles));
+                        movie_sources_map.insert(video.path.clone(), Self::convert_media_sources(&video.sources));
+                        movie_extras_map.insert(video.path.clone(), Self::convert_movie_extras(&video.extras));
+                        movie_credits_map.insert(video.path.clone(), video.credits.clone());
+                        if let Some(collection) = &video.collection {
+                            movie_collections_map.insert(video.path.clone(), collection.clone());
+                        }
+                    }
+
                     // 转换为 Movie 实体并添加到聚合根
                     let movies = self.convert_video_scan_to_movies(video_scan_results, aggregate.media_library.id)?;
                     let added_count = aggregate.add_movies_batch(movies)?;
                     tracing::info!("Added {} movies to media library with full metadata", added_count);
                 }
+                "电视剧" | "动漫" => {
+                    // 电视剧和动漫元数据语言沿用电影配置，动漫按字幕组命名解析并换算绝对集号
+                    let (language, _) = self.extract_movie_config(&aggregate.media_library.config_json)?;
+
+                    let series_scan_results = self.scan_tv_folders(&req.paths_json, language, req.media_type == "动漫").await?;
+
+                    tracing::info!("Scanned {} tv series from folders", series_scan_results.len());
+
+                    let mut series_models = Vec::new();
+                    for series in series_scan_results {
+                        let (model, seasons) = Self::convert_series_scan_to_model(series, aggregate.media_library.id)?;
+                        tv_seasons_map.insert(model.path.clone(), seasons);
+                        series_models.push(model);
+                    }
+
+                    let added_count = aggregate.add_tv_series_batch(series_models)?;
+                    tracing::info!("Added {} tv series to media library", added_count);
+                }
                 "照片" => {
                     // 扫描照片文件夹（传递配置）
                     let config_json = config_clone.as_ref()
@@ -242,6 +320,9 @@ impl MediaLibraryService {
                 if let Some(image_paths) = manga_image_paths_map.get(&manga.path) {
                     manga.set_image_paths(image_paths.clone());
                 }
+                if let Some(bad_pages) = manga_bad_pages_map.get(&manga.path) {
+                    manga.set_bad_pages(bad_pages.clone());
+                }
             }
 
             // 批量插入漫画
@@ -284,6 +365,9 @@ impl MediaLibraryService {
                     if let Some(image_paths) = chapter_image_paths_map.get(&chapter.path) {
                         chapter.set_image_paths(image_paths.clone());
                     }
+                    if let Some(bad_pages) = chapter_bad_pages_map.get(&chapter.path) {
+                        chapter.set_bad_pages(bad_pages.clone());
+                    }
                 }
 
                 // 批量插入章节
@@ -301,6 +385,11 @@ impl MediaLibraryService {
                 }
                 tracing::info!("Chapter image cache preheated successfully");
             }
+
+            // ✅ 按规范化标题将漫画归入系列（分组失败不影响扫描结果）
+            if let Err(e) = self.manga_series_service.group_media_library(media_library.id).await {
+                tracing::warn!("Failed to group mangas into series for media library {}: {}", media_library.id, e);
+            }
         }
 
         // 批量创建游戏
@@ -325,10 +414,159 @@ impl MediaLibraryService {
                 movie.media_library_id = media_library.id;
             }
 
+            // 保存电影系列，同一系列只保存一次
+            let mut collection_ids: std::collections::HashMap<u64, i32> = std::collections::HashMap::new();
+            for collection in movie_collections_map.values() {
+                if collection_ids.contains_key(&collection.tmdb_id) {
+                    continue;
+                }
+                match crate::movie_service::save_movie_collection(self.movie_repos.collection.as_ref(), collection).await {
+                    Ok(saved) => {
+                        collection_ids.insert(collection.tmdb_id, saved.id);
+                    }
+                    Err(e) => tracing::warn!("Failed to save movie collection {}: {}", collection.name, e),
+                }
+            }
+            for movie in &mut movies {
+                movie.collection_id = movie_collections_map
+                    .get(&movie.path)
+                    .and_then(|collection| collection_ids.get(&collection.tmdb_id).copied());
+            }
+
             tracing::info!("Creating {} movies for media library {}", movies.len(), media_library.id);
 
             // 批量插入电影
-            self.movie_repo.create_batch(movies).await?;
+            let created_movies = self.movie_repos.movie.create_batch(movies).await?;
+
+            // 批量创建轨道（按路径对应到新生成的电影 ID）
+            let mut tracks = Vec::new();
+            for movie in &created_movies {
+                if let Some(movie_tracks) = movie_tracks_map.remove(&movie.path) {
+                    tracks.extend(movie_tracks.into_iter().map(|mut track| {
+                        track.movie_id = movie.id;
+                        track
+                    }));
+                }
+            }
+            if !tracks.is_empty() {
+                tracing::info!("Creating {} movie tracks for media library {}", tracks.len(), media_library.id);
+                self.movie_repos.track.create_batch(tracks).await?;
+            }
+
+            // 批量创建外挂字幕
+            let mut subtitles = Vec::new();
+            for movie in &created_movies {
+                if let Some(movie_subtitles) = movie_subtitles_map.remove(&movie.path) {
+                    subtitles.extend(movie_subtitles.into_iter().map(|mut subtitle| {
+                        subtitle.movie_id = movie.id;
+                        subtitle
+                    }));
+                }
+            }
+            if !subtitles.is_empty() {
+                tracing::info!("Creating {} movie subtitles for media library {}", subtitles.len(), media_library.id);
+                self.movie_repos.subtitle.create_batch(subtitles).await?;
+            }
+
+            // 批量创建媒体源（多版本和分段文件）
+            let mut sources = Vec::new();
+            for movie in &created_movies {
+                if let Some(movie_sources) = movie_sources_map.remove(&movie.path) {
+                    sources.extend(movie_sources.into_iter().map(|mut source| {
+                        source.movie_id = movie.id;
+                        source
+                    }));
+                }
+            }
+            if !sources.is_empty() {
+                tracing::info!("Creating {} movie sources for media library {}", sources.len(), media_library.id);
+                self.movie_repos.source.create_batch(sources).await?;
+            }
+
+            // 批量创建预告片和花絮
+            let mut extras = Vec::new();
+            for movie in &created_movies {
+                if let Some(movie_extras) = movie_extras_map.remove(&movie.path) {
+                    extras.extend(movie_extras.into_iter().map(|mut extra| {
+                        extra.movie_id = movie.id;
+                        extra
+                    }));
+                }
+            }
+            if !extras.is_empty() {
+                tracing::info!("Creating {} movie extras for media library {}", extras.len(), media_library.id);
+                self.movie_repos.extra.create_batch(extras).await?;
+            }
+
+            // 创建演职人员（同一人物在各部电影间共用）
+            for movie in &created_movies {
+                let known = movie_credits_map.remove(&movie.path).unwrap_or_default();
+                if let Err(e) = crate::movie_service::save_movie_credits(
+                    self.movie_repos.person.as_ref(),
+                    self.movie_repos.credit.as_ref(),
+                    movie,
+                    &known,
+                )
+                .await
+                {
+                    tracing::warn!("Failed to save credits for movie {}: {}", movie.path, e);
+                }
+            }
+
+            // 下载海报和背景图到本地缓存，离线时也能显示
+            crate::movie_service::cache_movie_artwork(&created_movies).await;
+
+            // 按配置在视频旁写入 NFO 和图片（失败只记录日志，不影响媒体库创建）
+            if Self::extract_movie_write_nfo(&media_library.config_json) {
+                for movie in &created_movies {
+                    if let Err(e) = crate::movie_service::write_movie_sidecars(movie, false).await {
+                        tracing::warn!("Failed to write sidecars for movie {}: {}", movie.path, e);
+                    }
+                }
+            }
+        }
+
+        // 批量创建电视剧、季和单集
+        if !aggregate.tv_series.is_empty() {
+            let mut tv_series = aggregate.tv_series;
+            for series in &mut tv_series {
+                series.media_library_id = media_library.id;
+            }
+
+            tracing::info!("Creating {} tv series for media library {}", tv_series.len(), media_library.id);
+            let created_series = self.tv_series_repo.create_batch(tv_series).await?;
+
+            // 先创建季（按路径对应到新生成的电视剧 ID），再用季 ID 创建单集
+            let mut season_results = Vec::new();
+            let mut seasons = Vec::new();
+            for series in &created_series {
+                for season in tv_seasons_map.remove(&series.path).unwrap_or_default() {
+                    seasons.push(domain::entity::tv_season::Model {
+                        id: 0, // 数据库会自动生成
+                        series_id: series.id,
+                        season_number: season.season_number as i32,
+                        title: season.title.clone(),
+                        description: season.description.clone(),
+                        air_date: season.air_date.clone(),
+                        cover: season.poster_url.clone(),
+                        episode_count: season.episodes.len() as i32,
+                    });
+                    season_results.push((series.id, season));
+                }
+            }
+            let created_seasons = self.tv_season_repo.create_batch(seasons).await?;
+
+            let now = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+            let mut episodes = Vec::new();
+            for (season, (series_id, season_result)) in created_seasons.iter().zip(season_results) {
+                for episode in season_result.episodes {
+                    episodes.push(Self::convert_episode_scan_to_model(episode, series_id, season.id, &now));
+                }
+            }
+            if !episodes.is_empty() {
+                tracing::info!("Creating {} tv episodes for media library {}", episodes.len(), media_library.id);
+                self.tv_episode_repo.create_batch(episodes).await?;
+            }
         }
 
         // 批量创建照片
@@ -573,23 +811,10 @@ impl MediaLibraryService {
             .unwrap_or("zh-CN");
 
         // 将语言代码转换为 Language 枚举
-        let language = match language_code {
-            "en-US" => Language::English,
-            "zh-CN" => Language::ChineseSimplified,
-            "zh-TW" => Language::ChineseTraditional,
-            "ja-JP" => Language::Japanese,
-            "ko-KR" => Language::Korean,
-            "fr-FR" => Language::French,
-            "de-DE" => Language::German,
-            "es-ES" => Language::Spanish,
-            "it-IT" => Language::Italian,
-            "pt-BR" => Language::Portuguese,
-            "ru-RU" => Language::Russian,
-            _ => {
-                tracing::warn!("Unknown language code: {}, using default: ChineseSimplified", language_code);
-                Language::ChineseSimplified
-            }
-        };
+        let language = Language::from_code(language_code).unwrap_or_else(|| {
+            tracing::warn!("Unknown language code: {}, using default: ChineseSimplified", language_code);
+            Language::ChineseSimplified
+        });
 
         // 提取最小文件大小配置（MB）
         let min_file_size_mb = config
@@ -601,6 +826,14 @@ impl MediaLibraryService {
         Ok((language, min_file_size_mb))
     }
 
+    /// 从配置中提取是否写入 NFO 和图片（`movieWriteNfo`，默认关闭）
+    pub(crate) fn extract_movie_write_nfo(config_json: &str) -> bool {
+        serde_json::from_str::<serde_json::Value>(config_json)
+            .ok()
+            .and_then(|config| config.get("movieWriteNfo").and_then(|v| v.as_bool()))
+            .unwrap_or(false)
+    }
+
     /// 扫描游戏文件夹（异步版本，支持并行扫描多个路径）
     ///
     /// # 参数
@@ -773,10 +1006,11 @@ impl MediaLibraryService {
 
         for video in video_results {
             // 计算分辨率字符串
+            // 读不到实际分辨率时使用文件名中的分辨率标记
             let resolution = if video.width > 0 && video.height > 0 {
                 Some(format!("{}x{}", video.width, video.height))
             } else {
-                None
+                video.file_info.resolution.clone()
             };
 
             // 将 Vec<String> 转换为 JSON 字符串
@@ -842,7 +1076,7 @@ impl MediaLibraryService {
                 create_time: now.clone(),
                 update_time: now.clone(),
                 title: video.title.clone(),
-                original_title: None,
+                original_title: video.original_title.clone(),
                 description,
                 path: video.path.clone(),
                 byte_size: video.byte_size as i64,
@@ -862,6 +1096,14 @@ impl MediaLibraryService {
                 tags,
                 poster_urls,
                 cover,
+                backdrop: video.backdrop_url.clone(),
+                tmdb_id: video.tmdb_id.map(|id| id as i64),
+                imdb_id: video.imdb_id.clone(),
+                source: video.file_info.source.clone(),
+                video_codec: video.file_info.video_codec.clone(),
+                edition: video.file_info.edition.clone(),
+                release_group: video.file_info.release_group.clone(),
+                collection_id: None, // 保存电影系列后设置
                 media_library_id,
             };
 
@@ -871,6 +1113,199 @@ impl MediaLibraryService {
         Ok(movies)
     }
 
+    /// 扫描电视剧或动漫文件夹（多个路径依次扫描，使用指定语言）
+    async fn scan_tv_folders(
+        &self,
+        paths_json: &str,
+        language: infrastructure::file_scanner::movie_scaner::models::language::Language,
+        anime: bool,
+    ) -> anyhow::Result<Vec<infrastructure::file_scanner::tv_scanner::SeriesScanResult>> {
+        let paths: Vec<String> = serde_json::from_str(paths_json)
+            .unwrap_or_else(|_| vec![paths_json.to_string()]);
+
+        let mut all_results = Vec::new();
+        for path in paths {
+            tracing::info!("Scanning tv series in path: {} (language: {})", path, language.display_name());
+
+            // ✅ 基础设施层：扫描剧集文件并刮削剧集、季和单集元数据
+            let results = if anime {
+                infrastructure::file_scanner::anime_scan_with_language(path.clone(), language).await
+            } else {
+                infrastructure::file_scanner::tv_scan_with_language(path.clone(), language).await
+            };
+            let mut results = results.map_err(|e| anyhow::anyhow!("Failed to scan tv series in {}: {}", path, e))?;
+
+            tracing::info!("Found {} tv series in {}", results.len(), path);
+            all_results.append(&mut results);
+        }
+
+        Ok(all_results)
+    }
+
+    /// 将电视剧扫描结果转换为 TvSeries 实体，返回实体和待入库的季列表
+    fn convert_series_scan_to_model(
+        series: infrastructure::file_scanner::tv_scanner::SeriesScanResult,
+        media_library_id: i32,
+    ) -> anyhow::Result<(domain::entity::tv_series::Model, Vec<infrastructure::file_scanner::tv_scanner::SeasonScanResult>)> {
+        let now = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        let to_json = |values: &Vec<String>| -> anyhow::Result<Option<String>> {
+            Ok(if values.is_empty() { None } else { Some(serde_json::to_string(values)?) })
+        };
+
+        let model = domain::entity::tv_series::Model {
+            id: 0, // 数据库会自动生成
+            create_time: now.clone(),
+            update_time: now,
+            title: series.title.clone(),
+            original_title: series.original_title.clone(),
+            description: series.description.clone(),
+            path: series.path.clone(),
+            first_air_date: series.first_air_date.clone(),
+            rating: series.rating,
+            votes: series.votes as i32,
+            genres: to_json(&series.genres)?,
+            actors: to_json(&series.actors)?,
+            poster_urls: to_json(&series.poster_urls)?,
+            cover: series.poster_urls.first().cloned(),
+            tmdb_id: series.tmdb_id.map(|id| id as i64),
+            season_count: series.seasons.len() as i32,
+            episode_count: series.episode_count() as i32,
+            media_library_id,
+        };
+
+        Ok((model, series.seasons))
+    }
+
+    /// 将单集扫描结果转换为 TvEpisode 实体
+    fn convert_episode_scan_to_model(
+        episode: infrastructure::file_scanner::tv_scanner::EpisodeScanResult,
+        series_id: i32,
+        season_id: i32,
+        now: &str,
+    ) -> domain::entity::tv_episode::Model {
+        let video = episode.video;
+        let resolution = if video.width > 0 && video.height > 0 {
+            Some(format!("{}x{}", video.width, video.height))
+        } else {
+            None
+        };
+
+        domain::entity::tv_episode::Model {
+            id: 0, // 数据库会自动生成
+            create_time: now.to_string(),
+            update_time: now.to_string(),
+            series_id,
+            season_id,
+            season_number: episode.season_number as i32,
+            episode_number: episode.episode_number as i32,
+            episode_number_end: episode.episode_number_end.map(|e| e as i32),
+            absolute_number: episode.absolute_number.map(|e| e as i32),
+            release_group: episode.release_group,
+            title: episode.title,
+            description: episode.description,
+            air_date: episode.air_date,
+            path: video.path,
+            byte_size: video.byte_size as i64,
+            extension: Some(video.extension),
+            duration: video.duration as i32,
+            width: video.width as i32,
+            height: video.height as i32,
+            resolution,
+            still: episode.still_url,
+            rating: episode.rating,
+        }
+    }
+
+    /// 将扫描探测到的轨道转换为 MovieTrack 实体（movie_id 在电影入库后填充）
+    fn convert_video_tracks(
+        tracks: &[infrastructure::file_scanner::movie_scaner::container::TrackInfo],
+    ) -> Vec<domain::entity::movie_track::Model> {
+        tracks
+            .iter()
+            .map(|track| domain::entity::movie_track::Model {
+                id: 0, // 数据库会自动生成
+                movie_id: 0,
+                track_type: track.kind.as_str().to_string(),
+                track_index: track.index as i32,
+                codec: track.codec.clone(),
+                language: track.language.clone(),
+                title: track.title.clone(),
+                is_default: track.is_default,
+                is_forced: track.is_forced,
+                width: track.width.map(|w| w as i32),
+                height: track.height.map(|h| h as i32),
+                frame_rate: track.frame_rate,
+                is_hdr: track.hdr,
+                bit_rate: track.bit_rate.map(|b| b as i64),
+                channels: track.channels.map(|c| c as i32),
+                sample_rate: track.sample_rate.map(|r| r as i32),
+            })
+            .collect()
+    }
+
+    /// 将扫描发现的外挂字幕转换为 MovieSubtitle 实体（movie_id 在电影入库后填充）
+    fn convert_external_subtitles(
+        subtitles: &[infrastructure::file_scanner::movie_scaner::subtitle::ExternalSubtitle],
+    ) -> Vec<domain::entity::movie_subtitle::Model> {
+        subtitles
+            .iter()
+            .map(|subtitle| domain::entity::movie_subtitle::Model {
+                id: 0, // 数据库会自动生成
+                movie_id: 0,
+                path: subtitle.path.clone(),
+                format: subtitle.format.as_str().to_string(),
+                language: subtitle.language.clone(),
+                title: subtitle.title.clone(),
+                is_default: subtitle.is_default,
+                is_forced: subtitle.is_forced,
+                is_sdh: subtitle.is_sdh,
+            })
+            .collect()
+    }
+
+    /// 将扫描合并出的版本和分段文件转换为 MovieSource 实体（movie_id 在电影入库后填充）
+    fn convert_media_sources(
+        sources: &[infrastructure::file_scanner::movie_scaner::models::video::MediaSource],
+    ) -> Vec<domain::entity::movie_source::Model> {
+        sources
+            .iter()
+            .map(|source| domain::entity::movie_source::Model {
+                id: 0, // 数据库会自动生成
+                movie_id: 0,
+                version_index: source.version as i32,
+                part_index: source.part.map(|p| p as i32),
+                label: source.label.clone(),
+                path: source.path.clone(),
+                byte_size: source.byte_size as i64,
+                extension: Some(source.extension.clone()),
+                duration: source.duration as i32,
+                width: source.width as i32,
+                height: source.height as i32,
+                resolution: (source.width > 0 && source.height > 0)
+                    .then(|| format!("{}x{}", source.width, source.height)),
+            })
+            .collect()
+    }
+
+    /// 将扫描到的预告片和花絮转换为 MovieExtra 实体（movie_id 在电影入库后填充）
+    fn convert_movie_extras(
+        extras: &[infrastructure::file_scanner::movie_scaner::models::video::MovieExtra],
+    ) -> Vec<domain::entity::movie_extra::Model> {
+        extras
+            .iter()
+            .map(|extra| domain::entity::movie_extra::Model {
+                id: 0, // 数据库会自动生成
+                movie_id: 0,
+                extra_type: extra.kind.as_str().to_string(),
+                title: extra.title.clone(),
+                path: extra.path.clone(),
+                byte_size: extra.byte_size as i64,
+                extension: Some(extra.extension.clone()),
+                duration: extra.duration as i32,
+            })
+            .collect()
+    }
+
     /// 从配置 JSON 中提取照片扫描选项
     ///
     /// # 参数
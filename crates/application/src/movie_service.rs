@@ -1,31 +1,95 @@
-use domain::repository::MovieRepository;
-use std::sync::Arc;
+use crate::dto::{
+    IdentifyMovieRequest, MovieCollectionDetail, MovieCollectionInfo, MovieCreditInfo, MovieDetailInfo,
+    MovieIdentifyCandidate, MovieExtraInfo, MovieListQuery, MovieListResponse, MoviePlayItem, MoviePlayStateInfo,
+    PagedResponse, ReportMovieProgressRequest, MovieInfo, MovieSubtitleInfo, NfoExportResult,
+};
+use crate::media_library_service::MediaLibraryService;
+use domain::repository::{
+    MediaLibraryRepository, MovieCollectionRepository, MovieCreditRepository, MovieExtraRepository, MoviePlayStateRepository, MovieRepository,
+    MovieSourceRepository, MovieSubtitleRepository, MovieTrackRepository, PersonRepository,
+};
+use infrastructure::file_scanner::movie_scaner::models::language::Language;
+use infrastructure::file_scanner::movie_scaner::provider::{CollectionDetails, MovieCredit, MovieDetails};
+use infrastructure::file_scanner::movie_scaner::artwork::{self, ArtworkCache, ArtworkKind};
+use infrastructure::file_scanner::movie_scaner::nfo::{self, MovieNfo, SidecarWriteResult};
+use infrastructure::file_scanner::movie_scaner::subtitle::{self, SubtitleFormat};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock};
+
+/// 内嵌字幕转换结果的缓存目录（统一放在 data 目录下）
+const SUBTITLE_CACHE_DIR: &str = "./data/cache/subtitles";
+
+/// 电影海报和背景图的本地缓存（统一放在 data 目录下）
+static ARTWORK_CACHE: LazyLock<ArtworkCache> = LazyLock::new(|| ArtworkCache::new("./data/cache/movies"));
+
+/// 电影图片的获取结果
+pub enum MovieArtwork {
+    /// 本地缓存的 JPEG 图片
+    Local(Vec<u8>),
+    /// 下载失败时回退到的远程地址
+    Remote(String),
+}
+
+/// 电影相关的仓储
+///
+/// 电影服务和媒体库服务（扫描入库、删除媒体库）共用
+#[derive(Clone)]
+pub struct MovieRepositories {
+    pub movie: Arc<dyn MovieRepository>,
+    pub track: Arc<dyn MovieTrackRepository>,
+    pub subtitle: Arc<dyn MovieSubtitleRepository>,
+    pub source: Arc<dyn MovieSourceRepository>,
+    pub extra: Arc<dyn MovieExtraRepository>,
+    pub credit: Arc<dyn MovieCreditRepository>,
+    pub person: Arc<dyn PersonRepository>,
+    pub play_state: Arc<dyn MoviePlayStateRepository>,
+    pub collection: Arc<dyn MovieCollectionRepository>,
+}
 
 /// 电影服务 - 处理电影相关的业务逻辑
 pub struct MovieService {
-    repo: Arc<dyn MovieRepository>,
+    repos: MovieRepositories,
+    media_library_repo: Arc<dyn MediaLibraryRepository>,
 }
 
 impl MovieService {
     /// 创建新的电影服务实例
-    pub fn new(repo: Arc<dyn MovieRepository>) -> Self {
-        Self { repo }
+    pub fn new(repos: MovieRepositories, media_library_repo: Arc<dyn MediaLibraryRepository>) -> Self {
+        Self {
+            repos,
+            media_library_repo,
+        }
     }
 
     /// 根据 ID 查询电影
     pub async fn get_by_id(&self, id: i32) -> anyhow::Result<domain::entity::movie::Model> {
-        self.repo
+        self.repos.movie
             .find_by_id(id)
             .await?
             .ok_or_else(|| anyhow::anyhow!("Movie not found with id: {}", id))
     }
 
+    /// 根据 ID 查询电影详细信息（包含音视频和字幕轨道、所有版本和分段、演职人员）
+    pub async fn get_detail_by_id(&self, id: i32) -> anyhow::Result<MovieDetailInfo> {
+        let movie = self.get_by_id(id).await?;
+        let tracks = self.repos.track.find_by_movie_id(id).await?;
+        let sources = self.repos.source.find_by_movie_id(id).await?;
+        let credits = self.repos.credit.find_by_movie_id(id).await?;
+        let persons = self
+            .repos
+            .person
+            .find_by_ids(credits.iter().map(|c| c.person_id).collect())
+            .await?;
+        let credits = MovieCreditInfo::from_credits(credits, &persons);
+        Ok(MovieDetailInfo::new(movie, tracks, sources, credits))
+    }
+
     /// 根据媒体库 ID 查询所有电影
     pub async fn get_by_media_library_id(
         &self,
         media_library_id: i32,
     ) -> anyhow::Result<Vec<domain::entity::movie::Model>> {
-        self.repo.find_by_media_library_id(media_library_id).await
+        self.repos.movie.find_by_media_library_id(media_library_id).await
     }
 
     /// 分页查询所有电影
@@ -35,19 +99,78 @@ impl MovieService {
         page_size: i32,
     ) -> anyhow::Result<(Vec<domain::entity::movie::Model>, i32)> {
         let movies = self
-            .repo
+            .repos
+            .movie
             .find_by_paged(page_size, page_index)
             .await?
             .unwrap_or_default();
 
-        let total = self.repo.count_all().await?;
+        let total = self.repos.movie.count_all().await?;
 
         Ok((movies, total))
     }
 
+    /// 按条件筛选、排序并分页查询电影，同时返回可选的筛选值
+    pub async fn get_list(&self, query: &MovieListQuery) -> anyhow::Result<MovieListResponse> {
+        let filter = query.to_filter()?;
+        let (movies, total) = self
+            .repos
+            .movie
+            .find_by_filter(&filter, query.page_size, query.page_index)
+            .await?;
+        let facets = self.repos.movie.find_facets(filter.media_library_id).await?;
+
+        let items: Vec<MovieInfo> = movies.into_iter().map(|m| m.into()).collect();
+        Ok(MovieListResponse {
+            page: PagedResponse::new(query.page_index, query.page_size, total, items),
+            facets: facets.into(),
+        })
+    }
+
     /// 获取所有电影的总数
     pub async fn count_all(&self) -> anyhow::Result<i32> {
-        self.repo.count_all().await
+        self.repos.movie.count_all().await
+    }
+
+    /// 查询所有电影系列（只包含媒体库中至少有一部电影的系列）
+    pub async fn get_collections(&self) -> anyhow::Result<Vec<MovieCollectionInfo>> {
+        let owned_counts = self.repos.movie.count_by_collection().await?;
+        let mut infos = Vec::new();
+        for collection in self.repos.collection.find_all().await? {
+            if let Some(&owned_count) = owned_counts.get(&collection.id) {
+                infos.push(MovieCollectionInfo::new(collection, owned_count));
+            }
+        }
+        Ok(infos)
+    }
+
+    /// 查询电影系列详情
+    ///
+    /// 返回媒体库中已有的电影和系列中缺少的电影，都按上映日期排序（未定档的排在最后）
+    pub async fn get_collection(&self, id: i32) -> anyhow::Result<MovieCollectionDetail> {
+        let collection = self
+            .repos
+            .collection
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Movie collection not found with id: {}", id))?;
+
+        let mut movies = self.repos.movie.find_by_collection_id(id).await?;
+        movies.sort_by(|a, b| (a.release_date.is_none(), &a.release_date).cmp(&(b.release_date.is_none(), &b.release_date)));
+
+        let owned: std::collections::HashSet<i64> = movies.iter().filter_map(|m| m.tmdb_id).collect();
+        let missing = collection
+            .get_parts()
+            .into_iter()
+            .filter(|part| !owned.contains(&part.tmdb_id))
+            .map(Into::into)
+            .collect();
+
+        Ok(MovieCollectionDetail {
+            collection: MovieCollectionInfo::new(collection, movies.len() as i32),
+            movies: movies.into_iter().map(Into::into).collect(),
+            missing,
+        })
     }
 
     /// 删除电影
@@ -55,8 +178,16 @@ impl MovieService {
         // 先检查电影是否存在
         let _movie = self.get_by_id(id).await?;
 
-        // 删除电影
-        self.repo.delete(id).await?;
+        // 删除电影及其轨道、字幕、媒体源、附加视频、演职人员和播放状态
+        self.repos.track.delete_by_movie_id(id).await?;
+        self.repos.subtitle.delete_by_movie_id(id).await?;
+        self.repos.source.delete_by_movie_id(id).await?;
+        self.repos.extra.delete_by_movie_id(id).await?;
+        self.repos.credit.delete_by_movie_id(id).await?;
+        self.repos.play_state.delete_by_movie_id(id).await?;
+        self.repos.movie.delete(id).await?;
+        remove_cached_subtitles(id).await;
+        remove_cached_artwork(id).await;
 
         tracing::info!("Deleted movie with id: {}", id);
 
@@ -64,9 +195,783 @@ impl MovieService {
     }
 
     /// 获取电影视频文件路径（用于流式传输）
-    pub async fn get_movie_video_path(&self, id: i32) -> anyhow::Result<String> {
+    ///
+    /// `source` 为版本序号，`part` 为分段在该版本中的位置（从 0 开始）。
+    /// 没有媒体源记录的电影只有版本 0 的第 0 段，即电影文件本身
+    pub async fn get_movie_video_path(&self, id: i32, source: i32, part: usize) -> anyhow::Result<String> {
         let movie = self.get_by_id(id).await?;
-        Ok(movie.path)
+        let sources = self.repos.source.find_by_movie_id(id).await?;
+        if sources.is_empty() && source == 0 && part == 0 {
+            return Ok(movie.path);
+        }
+
+        sources
+            .into_iter()
+            .filter(|s| s.version_index == source)
+            .nth(part)
+            .map(|s| s.path)
+            .ok_or_else(|| anyhow::anyhow!("Movie {} has no source {} part {}", id, source, part))
+    }
+
+    /// 获取电影的预告片和花絮
+    pub async fn get_extras(&self, movie_id: i32) -> anyhow::Result<Vec<MovieExtraInfo>> {
+        let _movie = self.get_by_id(movie_id).await?;
+        let extras = self.repos.extra.find_by_movie_id(movie_id).await?;
+        Ok(extras.into_iter().map(|e| e.into()).collect())
+    }
+
+    /// 获取附加视频文件路径（用于流式传输）
+    pub async fn get_extra_video_path(&self, movie_id: i32, extra_id: i32) -> anyhow::Result<String> {
+        self.repos.extra
+            .find_by_id(extra_id)
+            .await?
+            .filter(|e| e.movie_id == movie_id)
+            .map(|e| e.path)
+            .ok_or_else(|| anyhow::anyhow!("Extra not found with id: {}", extra_id))
+    }
+
+    /// 获取电影的字幕列表
+    ///
+    /// 包含外挂字幕和视频内嵌的文本字幕轨道（SRT / ASS / SSA / WebVTT），
+    /// PGS、VobSub 等图形字幕无法转换为 WebVTT，不会列出
+    pub async fn get_subtitles(&self, movie_id: i32) -> anyhow::Result<Vec<MovieSubtitleInfo>> {
+        let _movie = self.get_by_id(movie_id).await?;
+        let subtitles = self.repos.subtitle.find_by_movie_id(movie_id).await?;
+        let tracks = self.repos.track.find_by_movie_id(movie_id).await?;
+
+        let mut infos: Vec<MovieSubtitleInfo> = subtitles.into_iter().map(|s| s.into()).collect();
+        infos.extend(
+            tracks
+                .into_iter()
+                .filter(|t| t.is_subtitle() && subtitle::is_text_subtitle_codec(&t.codec))
+                .map(MovieSubtitleInfo::from_embedded_track),
+        );
+        Ok(infos)
+    }
+
+    /// 读取外挂字幕并转换为 WebVTT
+    ///
+    /// 字幕文件按原始编码（GBK、UTF-16 等）解码后转换，返回 UTF-8 文本
+    pub async fn get_subtitle_vtt(&self, movie_id: i32, subtitle_id: i32) -> anyhow::Result<String> {
+        let subtitle = self
+            .repos
+            .subtitle
+            .find_by_id(subtitle_id)
+            .await?
+            .filter(|s| s.movie_id == movie_id)
+            .ok_or_else(|| anyhow::anyhow!("Subtitle not found with id: {}", subtitle_id))?;
+
+        let format = SubtitleFormat::from_extension(&subtitle.format)
+            .ok_or_else(|| anyhow::anyhow!("Unsupported subtitle format: {}", subtitle.format))?;
+
+        let bytes = tokio::fs::read(&subtitle.path)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to read subtitle: {}", e))?;
+
+        Ok(subtitle::to_webvtt(format, &bytes))
+    }
+
+    /// 提取视频内嵌的文本字幕轨道并转换为 WebVTT
+    ///
+    /// 提取需要扫描整个视频文件，转换结果缓存在 `./data/cache/subtitles/` 下，
+    /// 视频文件比缓存新时（被替换）重新提取
+    pub async fn get_embedded_subtitle_vtt(&self, movie_id: i32, track_id: i32) -> anyhow::Result<String> {
+        let movie = self.get_by_id(movie_id).await?;
+        let track = self
+            .repos
+            .track
+            .find_by_movie_id(movie_id)
+            .await?
+            .into_iter()
+            .find(|t| t.id == track_id && t.is_subtitle())
+            .ok_or_else(|| anyhow::anyhow!("Subtitle track not found with id: {}", track_id))?;
+
+        if !subtitle::is_text_subtitle_codec(&track.codec) {
+            anyhow::bail!("Unsupported subtitle codec: {}", track.codec);
+        }
+
+        let cache_path = subtitle_cache_path(movie_id, track.id);
+        if is_cache_fresh(&cache_path, Path::new(&movie.path)).await {
+            match tokio::fs::read_to_string(&cache_path).await {
+                Ok(vtt) => {
+                    tracing::debug!("Subtitle cache hit for movie_id: {}, track: {}", movie_id, track.track_index);
+                    return Ok(vtt);
+                }
+                Err(e) => tracing::warn!("Failed to read subtitle cache: {}, regenerating", e),
+            }
+        }
+
+        tracing::debug!("Subtitle cache miss for movie_id: {}, track: {}", movie_id, track.track_index);
+
+        // 扫描整个视频文件，在线程池中执行
+        let video_path = PathBuf::from(&movie.path);
+        let track_number = track.track_index as u32;
+        let vtt = tokio::task::spawn_blocking(move || subtitle::extract_embedded_subtitle(&video_path, track_number))
+            .await
+            .map_err(|e| anyhow::anyhow!("Task join error: {}", e))?
+            .map_err(|e| anyhow::anyhow!("Failed to extract subtitle: {}", e))?;
+
+        // 保存到缓存（异步，不阻塞返回）
+        let vtt_clone = vtt.clone();
+        tokio::spawn(async move {
+            if let Err(e) = tokio::fs::create_dir_all(SUBTITLE_CACHE_DIR).await {
+                tracing::warn!("Failed to create subtitle cache directory: {}", e);
+                return;
+            }
+
+            if let Err(e) = tokio::fs::write(&cache_path, vtt_clone).await {
+                tracing::warn!("Failed to write subtitle cache: {}", e);
+            } else {
+                tracing::debug!("Subtitle cached: {}", cache_path.display());
+            }
+        });
+
+        Ok(vtt)
+    }
+
+    /// 获取电影海报或背景图
+    ///
+    /// 优先使用扫描时下载的本地缓存，缓存缺失时尝试重新下载，
+    /// 下载失败才回退到远程地址。指定宽高时返回按比例缩放的版本，
+    /// 缩放结果同样缓存在磁盘上
+    pub async fn get_artwork(
+        &self,
+        id: i32,
+        kind: ArtworkKind,
+        width: Option<u32>,
+        height: Option<u32>,
+        quality: Option<u8>,
+    ) -> anyhow::Result<MovieArtwork> {
+        let movie = self.get_by_id(id).await?;
+        let url = artwork_url(&movie, kind)
+            .ok_or_else(|| anyhow::anyhow!("Movie {} has no {}", id, kind.file_stem()))?;
+
+        let original = match ARTWORK_CACHE.fetch(id, kind, &url).await {
+            Ok(path) => path,
+            Err(e) => {
+                tracing::warn!("Failed to cache {} for movie {}, falling back to {}: {}", kind.file_stem(), id, url, e);
+                return Ok(MovieArtwork::Remote(url));
+            }
+        };
+
+        if width.is_none() && height.is_none() {
+            return Ok(MovieArtwork::Local(tokio::fs::read(&original).await?));
+        }
+
+        let variant = ARTWORK_CACHE.variant_path(id, kind, width, height);
+        if let Ok(data) = tokio::fs::read(&variant).await {
+            return Ok(MovieArtwork::Local(data));
+        }
+
+        let image_data = tokio::fs::read(&original).await?;
+        let quality = quality.unwrap_or_else(|| shared::config::get().server().image().thumbnail().default_quality());
+        let resized = tokio::task::spawn_blocking(move || {
+            crate::image_service::compress_image(
+                &image_data,
+                width.unwrap_or(u32::MAX),
+                height.unwrap_or(u32::MAX),
+                quality,
+            )
+        })
+        .await
+        .map_err(|e| anyhow::anyhow!("Task join error: {}", e))??;
+
+        if let Err(e) = artwork::write_atomically(&variant, &resized).await {
+            tracing::warn!("Failed to write artwork cache {}: {}", variant.display(), e);
+        }
+
+        Ok(MovieArtwork::Local(resized))
+    }
+
+    /// 搜索手动识别的候选电影
+    ///
+    /// 使用媒体库配置的元数据语言在所有提供者中搜索，`query` 为空时使用电影当前标题
+    pub async fn identify_candidates(
+        &self,
+        id: i32,
+        query: Option<String>,
+        year: Option<u32>,
+    ) -> anyhow::Result<Vec<MovieIdentifyCandidate>> {
+        let movie = self.get_by_id(id).await?;
+        let query = query.filter(|q| !q.trim().is_empty()).unwrap_or_else(|| movie.title.clone());
+        let language = self.library_language(movie.media_library_id).await;
+
+        let results = infrastructure::file_scanner::search_movie_metadata(query.trim(), year, language)
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to search metadata: {}", e))?;
+
+        Ok(results
+            .into_iter()
+            .map(|(provider, result)| MovieIdentifyCandidate {
+                provider,
+                provider_id: result.tmdb_id,
+                poster_url: result.get_poster_url("w342"),
+                original_title: Some(result.original_title).filter(|t| !t.is_empty() && *t != result.title),
+                title: result.title,
+                release_date: Some(result.release_date).filter(|d| !d.is_empty()),
+                overview: Some(result.overview).filter(|o| !o.is_empty()),
+                rating: result.vote_average as f32,
+            })
+            .collect())
+    }
+
+    /// 按选定的提供者 ID 重新匹配电影
+    ///
+    /// 重新获取详情并覆盖电影元数据，同时保存提供者 ID，之后刷新时直接按 ID 获取。
+    /// 旧的图片缓存会被清除；媒体库开启了写入 NFO 时同步更新 NFO 和图片
+    pub async fn identify(&self, id: i32, req: IdentifyMovieRequest) -> anyhow::Result<MovieInfo> {
+        let mut movie = self.get_by_id(id).await?;
+        let language = self.library_language(movie.media_library_id).await;
+
+        let details =
+            infrastructure::file_scanner::get_movie_metadata(req.provider.as_deref(), req.provider_id, language)
+                .await
+                .map_err(|e| anyhow::anyhow!("Failed to get metadata: {}", e))?;
+
+        let collection_id = details.collection_id;
+        let credits = details.credits.clone();
+        apply_movie_details(&mut movie, details)?;
+        movie.collection_id = match collection_id {
+            Some(collection_id) => {
+                match infrastructure::file_scanner::get_movie_collection(req.provider.as_deref(), collection_id, language)
+                    .await
+                {
+                    Ok(details) => Some(save_movie_collection(self.repos.collection.as_ref(), &details).await?.id),
+                    Err(e) => {
+                        tracing::warn!("Failed to get collection {} for movie {}: {}", collection_id, id, e);
+                        None
+                    }
+                }
+            }
+            None => None,
+        };
+        movie.update_time = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        let movie = self.repos.movie.update(movie).await?;
+        save_movie_credits(self.repos.person.as_ref(), self.repos.credit.as_ref(), &movie, &credits).await?;
+        tracing::info!("Identified movie {} as {:?} {}", id, req.provider, req.provider_id);
+
+        remove_cached_artwork(id).await;
+        cache_movie_artwork(std::slice::from_ref(&movie)).await;
+
+        if let Some(library) = self.media_library_repo.find_by_id(movie.media_library_id).await?
+            && MediaLibraryService::extract_movie_write_nfo(&library.config_json)
+        {
+            // 只覆盖 Modream 生成的 NFO 及其图片，用户编写的 NFO 保持不变
+            let force = nfo::sidecar_paths(Path::new(&movie.path))
+                .is_some_and(|paths| !paths.nfo.exists() || nfo::is_generated_nfo(&paths.nfo));
+            if let Err(e) = write_movie_sidecars(&movie, force).await {
+                tracing::warn!("Failed to write sidecars for movie {}: {}", id, e);
+            }
+        }
+
+        Ok(movie.into())
+    }
+
+    /// 查询用户对电影的播放状态（没有播放过时返回空状态）
+    pub async fn get_play_state(&self, user_id: i32, movie_id: i32) -> anyhow::Result<MoviePlayStateInfo> {
+        self.get_by_id(movie_id).await?;
+        let state = self.repos.play_state.find_by_user_and_movie(user_id, movie_id).await?;
+        Ok(state.map_or_else(|| MoviePlayStateInfo::empty(movie_id), Into::into))
+    }
+
+    /// 记录播放进度，播放到接近结尾时自动标记为已看
+    pub async fn report_progress(
+        &self,
+        user_id: i32,
+        movie_id: i32,
+        req: ReportMovieProgressRequest,
+    ) -> anyhow::Result<MoviePlayStateInfo> {
+        if !req.position_seconds.is_finite() || req.position_seconds < 0.0 {
+            anyhow::bail!("Invalid position: {}", req.position_seconds);
+        }
+        let movie = self.get_by_id(movie_id).await?;
+        let duration = req
+            .duration_seconds
+            .filter(|d| d.is_finite() && *d > 0.0)
+            .map_or(movie.duration, |d| d.round() as i32);
+
+        let mut state = self.find_or_new_play_state(user_id, movie_id).await?;
+        state.record_progress(req.position_seconds.round() as i32, duration);
+        Ok(self.repos.play_state.save(state).await?.into())
+    }
+
+    /// 手动标记电影为已看或未看
+    pub async fn set_watched(&self, user_id: i32, movie_id: i32, watched: bool) -> anyhow::Result<MoviePlayStateInfo> {
+        self.get_by_id(movie_id).await?;
+        let mut state = self.find_or_new_play_state(user_id, movie_id).await?;
+        if watched {
+            state.mark_watched();
+        } else {
+            state.mark_unwatched();
+        }
+        Ok(self.repos.play_state.save(state).await?.into())
+    }
+
+    /// 继续观看：看了一部分还没看完的电影（按最后播放时间从新到旧）
+    pub async fn get_continue_watching(&self, user_id: i32, limit: usize) -> anyhow::Result<Vec<MoviePlayItem>> {
+        let states = self.repos.play_state.find_by_user_id(user_id).await?;
+        let states = states.into_iter().filter(|s| s.is_resumable()).take(limit).collect();
+        self.with_movies(states).await
+    }
+
+    /// 最近看过：已看的电影（按最后播放时间从新到旧）
+    pub async fn get_recently_watched(&self, user_id: i32, limit: usize) -> anyhow::Result<Vec<MoviePlayItem>> {
+        let states = self.repos.play_state.find_by_user_id(user_id).await?;
+        let states = states.into_iter().filter(|s| s.watched).take(limit).collect();
+        self.with_movies(states).await
+    }
+
+    /// 查询用户对电影的播放状态，没有时新建
+    async fn find_or_new_play_state(
+        &self,
+        user_id: i32,
+        movie_id: i32,
+    ) -> anyhow::Result<domain::entity::movie_play_state::Model> {
+        Ok(self
+            .repos
+            .play_state
+            .find_by_user_and_movie(user_id, movie_id)
+            .await?
+            .unwrap_or_else(|| domain::entity::movie_play_state::Model::new(user_id, movie_id)))
+    }
+
+    /// 按播放状态的顺序关联电影（电影已删除的状态被忽略）
+    async fn with_movies(
+        &self,
+        states: Vec<domain::entity::movie_play_state::Model>,
+    ) -> anyhow::Result<Vec<MoviePlayItem>> {
+        let movies = self.repos.movie.find_by_ids(states.iter().map(|s| s.movie_id).collect()).await?;
+        Ok(states
+            .into_iter()
+            .filter_map(|state| {
+                let movie = movies.iter().find(|m| m.id == state.movie_id)?.clone();
+                Some(MoviePlayItem {
+                    movie: movie.into(),
+                    play_state: state.into(),
+                })
+            })
+            .collect())
+    }
+
+    /// 媒体库配置的电影元数据语言
+    async fn library_language(&self, media_library_id: i32) -> Language {
+        let config_json = match self.media_library_repo.find_by_id(media_library_id).await {
+            Ok(Some(library)) => library.config_json,
+            _ => return Language::default(),
+        };
+
+        serde_json::from_str::<serde_json::Value>(&config_json)
+            .ok()
+            .and_then(|config| config.get("movieLanguage")?.as_str().and_then(Language::from_code))
+            .unwrap_or_default()
+    }
+
+    /// 在电影视频旁写入 NFO、海报和背景图
+    ///
+    /// 不会覆盖用户编写的 NFO 和已存在的图片，除非 `force` 为 true
+    pub async fn write_sidecars(&self, id: i32, force: bool) -> anyhow::Result<NfoExportResult> {
+        let movie = self.get_by_id(id).await?;
+        let written = write_movie_sidecars(&movie, force).await?;
+
+        let mut result = NfoExportResult {
+            total: 1,
+            ..Default::default()
+        };
+        tally_sidecars(&mut result, &written);
+        Ok(result)
+    }
+
+    /// 为媒体库中的所有电影写入 NFO、海报和背景图
+    ///
+    /// 单部电影写入失败不会中断导出，失败数量记录在结果中
+    pub async fn export_library_sidecars(&self, media_library_id: i32, force: bool) -> anyhow::Result<NfoExportResult> {
+        let movies = self.repos.movie.find_by_media_library_id(media_library_id).await?;
+        let mut result = NfoExportResult {
+            total: movies.len() as i32,
+            ..Default::default()
+        };
+
+        for movie in &movies {
+            match write_movie_sidecars(movie, force).await {
+                Ok(written) => tally_sidecars(&mut result, &written),
+                Err(e) => {
+                    tracing::warn!("Failed to write sidecars for movie {}: {}", movie.id, e);
+                    result.failed += 1;
+                }
+            }
+        }
+
+        tracing::info!(
+            "Exported NFO for media library {}: {} written, {} skipped, {} failed",
+            media_library_id,
+            result.nfo_written,
+            result.skipped,
+            result.failed
+        );
+        Ok(result)
+    }
+}
+
+/// 把单部电影的写入结果累加到导出统计中
+fn tally_sidecars(result: &mut NfoExportResult, written: &SidecarWriteResult) {
+    if written.nfo_written {
+        result.nfo_written += 1;
+    } else {
+        result.skipped += 1;
+    }
+    result.poster_written += written.poster_written as i32;
+    result.fanart_written += written.fanart_written as i32;
+}
+
+/// 用提供者返回的详情覆盖电影元数据（文件相关字段保持不变）
+fn apply_movie_details(movie: &mut domain::entity::movie::Model, details: MovieDetails) -> anyhow::Result<()> {
+    let to_json = |list: &Vec<String>| -> anyhow::Result<Option<String>> {
+        Ok(if list.is_empty() { None } else { Some(serde_json::to_string(list)?) })
+    };
+
+    movie.original_title = Some(details.original_title).filter(|t| !t.is_empty() && *t != details.title);
+    movie.title = details.title;
+    movie.description = Some(details.overview).filter(|d| !d.is_empty());
+    movie.release_date = Some(details.release_date).filter(|d| !d.is_empty());
+    movie.rating = details.vote_average as f32;
+    movie.votes = details.vote_count as i32;
+    movie.genres = to_json(&details.genres)?;
+    movie.actors = to_json(&details.cast)?;
+    movie.directors = to_json(&details.directors)?;
+    movie.writers = to_json(&details.writers)?;
+    movie.producers = to_json(&details.producers)?;
+    movie.tags = to_json(&details.keywords)?;
+    movie.poster_urls = to_json(&details.poster_urls)?;
+    movie.cover = details.poster_urls.first().cloned();
+    movie.backdrop = details
+        .backdrop_path
+        .map(|path| format!("https://image.tmdb.org/t/p/original{}", path));
+    movie.tmdb_id = Some(details.tmdb_id as i64);
+    movie.imdb_id = details.imdb_id;
+    Ok(())
+}
+
+/// 保存提供者返回的电影系列（按 TMDB 系列 ID 新建或更新）
+pub(crate) async fn save_movie_collection(
+    repo: &dyn MovieCollectionRepository,
+    details: &CollectionDetails,
+) -> anyhow::Result<domain::entity::movie_collection::Model> {
+    use domain::entity::movie_collection::{CollectionPart, Model as MovieCollectionModel};
+
+    let parts: Vec<CollectionPart> = details
+        .parts
+        .iter()
+        .map(|part| CollectionPart {
+            tmdb_id: part.tmdb_id as i64,
+            title: part.title.clone(),
+            original_title: Some(part.original_title.clone()).filter(|t| !t.is_empty() && *t != part.title),
+            release_date: Some(part.release_date.clone()).filter(|d| !d.is_empty()),
+            poster_url: part.poster_url.clone(),
+        })
+        .collect();
+
+    let mut collection = MovieCollectionModel::new(details.tmdb_id as i64, details.name.clone());
+    collection.overview = Some(details.overview.clone()).filter(|o| !o.is_empty());
+    collection.poster_url = details.poster_url.clone();
+    collection.backdrop_url = details.backdrop_url.clone();
+    collection.set_parts(&parts)?;
+
+    repo.upsert(collection).await
+}
+
+/// 保存电影的演职人员（覆盖之前的记录）
+///
+/// 以电影上的演员、导演、编剧和制片人姓名列表为准，`known` 是提供者返回的演职人员，
+/// 用于补充 TMDB 人物 ID、饰演角色和头像
+pub(crate) async fn save_movie_credits(
+    person_repo: &dyn PersonRepository,
+    credit_repo: &dyn MovieCreditRepository,
+    movie: &domain::entity::movie::Model,
+    known: &[MovieCredit],
+) -> anyhow::Result<()> {
+    use domain::entity::movie_credit::Model as MovieCreditModel;
+    use domain::entity::person::Model as PersonModel;
+
+    let credits = movie_credits(movie, known);
+
+    credit_repo.delete_by_movie_id(movie.id).await?;
+    if credits.is_empty() {
+        return Ok(());
+    }
+
+    let persons: Vec<PersonModel> = credits
+        .iter()
+        .map(|credit| {
+            let mut person = PersonModel::new(credit.name.clone(), credit.tmdb_id.map(|id| id as i64));
+            person.profile_url = credit.profile_url.clone();
+            person
+        })
+        .collect();
+    let persons = person_repo.upsert_batch(persons).await?;
+
+    let models: Vec<MovieCreditModel> = credits
+        .into_iter()
+        .zip(persons)
+        .map(|(credit, person)| MovieCreditModel {
+            id: 0,
+            movie_id: movie.id,
+            person_id: person.id,
+            role: credit.role,
+            character_name: credit.character,
+            sort_order: credit.order as i32,
+        })
+        .collect();
+    credit_repo.create_batch(models).await?;
+    Ok(())
+}
+
+/// 按电影上的姓名列表生成演职人员，`known` 用于补充提供者返回的信息
+///
+/// 放在同步函数里，避免借用的中间值出现在 async fn 的 Future 中而不满足 Send
+fn movie_credits(movie: &domain::entity::movie::Model, known: &[MovieCredit]) -> Vec<MovieCredit> {
+    [
+        ("actor", movie.get_actors()),
+        ("director", movie.get_directors()),
+        ("writer", movie.get_writers()),
+        ("producer", movie.get_producers()),
+    ]
+    .iter()
+    .flat_map(|(role, names)| MovieCredit::for_names(role, names, known))
+    .collect()
+}
+
+/// 电影海报或背景图的远程地址（只接受 http/https 地址）
+fn artwork_url(movie: &domain::entity::movie::Model, kind: ArtworkKind) -> Option<String> {
+    let url = match kind {
+        ArtworkKind::Poster => movie.get_poster_urls().into_iter().next().or_else(|| movie.cover.clone()),
+        ArtworkKind::Backdrop => movie.backdrop.clone(),
+    }?;
+    (url.starts_with("http://") || url.starts_with("https://")).then_some(url)
+}
+
+/// 下载电影的海报和背景图到本地缓存
+///
+/// 扫描入库后调用，下载失败只记录日志，访问时会回退到远程地址
+pub(crate) async fn cache_movie_artwork(movies: &[domain::entity::movie::Model]) {
+    use futures::stream::{self, StreamExt};
+
+    // 先收集成自有数据，避免借用迭代器跨 await 导致 Future 不满足 Send
+    let downloads: Vec<(i32, ArtworkKind, String)> = movies
+        .iter()
+        .flat_map(|movie| {
+            [ArtworkKind::Poster, ArtworkKind::Backdrop]
+                .into_iter()
+                .filter_map(move |kind| artwork_url(movie, kind).map(|url| (movie.id, kind, url)))
+        })
+        .collect();
+
+    let failed = stream::iter(downloads)
+        .map(|(id, kind, url)| async move {
+            ARTWORK_CACHE
+                .fetch(id, kind, &url)
+                .await
+                .inspect_err(|e| tracing::warn!("Failed to cache {} for movie {}: {}", kind.file_stem(), id, e))
+                .is_err()
+        })
+        .buffer_unordered(4)
+        .filter(|failed| std::future::ready(*failed))
+        .count()
+        .await;
+
+    tracing::info!("Cached artwork for {} movies ({} downloads failed)", movies.len(), failed);
+}
+
+/// 删除电影的图片缓存
+pub(crate) async fn remove_cached_artwork(movie_id: i32) {
+    ARTWORK_CACHE.remove(movie_id).await;
+}
+
+/// 把 TMDB 图片地址换成原图尺寸（`/t/p/w500/...` → `/t/p/original/...`）
+fn original_image_url(url: &str) -> String {
+    match url.find("/t/p/") {
+        Some(start) => {
+            let size_start = start + "/t/p/".len();
+            match url[size_start..].find('/') {
+                Some(size_len) => format!("{}original{}", &url[..size_start], &url[size_start + size_len..]),
+                None => url.to_string(),
+            }
+        }
+        None => url.to_string(),
+    }
+}
+
+/// 把电影实体转换为 NFO 元数据
+pub(crate) fn to_movie_nfo(movie: &domain::entity::movie::Model) -> MovieNfo {
+    let parse_list = |json: &Option<String>| -> Vec<String> {
+        json.as_deref().and_then(|j| serde_json::from_str(j).ok()).unwrap_or_default()
+    };
+    let is_remote = |url: &String| url.starts_with("http://") || url.starts_with("https://");
+
+    let mut poster_urls = movie.get_poster_urls();
+    if poster_urls.is_empty() {
+        poster_urls.extend(movie.cover.clone());
+    }
+
+    MovieNfo {
+        title: Some(movie.title.clone()),
+        original_title: movie.original_title.clone(),
+        year: movie.release_date.as_deref().and_then(|d| d.get(..4)).and_then(|y| y.parse().ok()),
+        release_date: movie.release_date.clone().filter(|d| !d.is_empty()),
+        plot: movie.description.clone().filter(|d| !d.is_empty()),
+        genres: movie.get_genres(),
+        tags: parse_list(&movie.tags),
+        actors: movie.get_actors(),
+        directors: movie.get_directors(),
+        writers: parse_list(&movie.writers),
+        rating: Some(movie.rating).filter(|r| *r > 0.0),
+        votes: u32::try_from(movie.votes).ok().filter(|v| *v > 0),
+        tmdb_id: movie.tmdb_id.and_then(|id| u64::try_from(id).ok()),
+        imdb_id: movie.imdb_id.clone(),
+        poster_urls: poster_urls.iter().filter(|url| is_remote(url)).map(|url| original_image_url(url)).collect(),
+        backdrop_url: movie.backdrop.clone().filter(is_remote),
+    }
+}
+
+/// 在电影视频旁写入 NFO、海报和背景图
+pub(crate) async fn write_movie_sidecars(
+    movie: &domain::entity::movie::Model,
+    force: bool,
+) -> anyhow::Result<SidecarWriteResult> {
+    let result = nfo::write_movie_sidecars(Path::new(&movie.path), &to_movie_nfo(movie), force)
+        .await
+        .map_err(|e| anyhow::anyhow!(e))?;
+    if !result.nfo_written {
+        tracing::debug!("Kept user-authored NFO for movie {}", movie.id);
+    }
+    Ok(result)
+}
+
+/// 内嵌字幕的缓存路径（`./data/cache/subtitles/{movie_id}_{track_id}.vtt`）
+fn subtitle_cache_path(movie_id: i32, track_id: i32) -> PathBuf {
+    Path::new(SUBTITLE_CACHE_DIR).join(format!("{}_{}.vtt", movie_id, track_id))
+}
+
+/// 缓存文件存在且不早于视频文件的修改时间
+async fn is_cache_fresh(cache_path: &Path, video_path: &Path) -> bool {
+    let (Ok(cache), Ok(video)) = (tokio::fs::metadata(cache_path).await, tokio::fs::metadata(video_path).await) else {
+        return false;
+    };
+    match (cache.modified(), video.modified()) {
+        (Ok(cache), Ok(video)) => cache >= video,
+        _ => false,
     }
 }
 
+/// 删除电影的所有内嵌字幕缓存（`./data/cache/subtitles/{movie_id}_*.vtt`）
+pub(crate) async fn remove_cached_subtitles(movie_id: i32) {
+    let prefix = format!("{}_", movie_id);
+
+    let Ok(mut entries) = tokio::fs::read_dir(SUBTITLE_CACHE_DIR).await else {
+        return;
+    };
+
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        if entry.file_name().to_string_lossy().starts_with(&prefix)
+            && let Err(e) = tokio::fs::remove_file(entry.path()).await
+        {
+            tracing::warn!("Failed to remove subtitle cache {}: {}", entry.path().display(), e);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_support::{create_table, memory_db};
+    use domain::entity::movie_collection::CollectionPart;
+    use infrastructure::repository::{
+        MediaLibraryRepositoryImpl, MovieCollectionRepositoryImpl, MovieCreditRepositoryImpl, MovieExtraRepositoryImpl,
+        MoviePlayStateRepositoryImpl, MovieRepositoryImpl, MovieSourceRepositoryImpl, MovieSubtitleRepositoryImpl,
+        MovieTrackRepositoryImpl, PersonRepositoryImpl,
+    };
+
+    async fn setup() -> MovieService {
+        let db = memory_db().await;
+        create_table(&db, domain::entity::movie::Entity).await;
+        create_table(&db, domain::entity::movie_collection::Entity).await;
+        let repos = MovieRepositories {
+            movie: Arc::new(MovieRepositoryImpl::new(db.clone())),
+            track: Arc::new(MovieTrackRepositoryImpl::new(db.clone())),
+            subtitle: Arc::new(MovieSubtitleRepositoryImpl::new(db.clone())),
+            source: Arc::new(MovieSourceRepositoryImpl::new(db.clone())),
+            extra: Arc::new(MovieExtraRepositoryImpl::new(db.clone())),
+            credit: Arc::new(MovieCreditRepositoryImpl::new(db.clone())),
+            person: Arc::new(PersonRepositoryImpl::new(db.clone())),
+            play_state: Arc::new(MoviePlayStateRepositoryImpl::new(db.clone())),
+            collection: Arc::new(MovieCollectionRepositoryImpl::new(db.clone())),
+        };
+        MovieService::new(repos, Arc::new(MediaLibraryRepositoryImpl::new(db)))
+    }
+
+    async fn create_collection(service: &MovieService, tmdb_id: i64, part_ids: &[i64]) -> i32 {
+        let mut collection = domain::entity::movie_collection::Model::new(tmdb_id, format!("Collection {}", tmdb_id));
+        let parts: Vec<CollectionPart> = part_ids
+            .iter()
+            .map(|id| CollectionPart {
+                tmdb_id: *id,
+                title: format!("Part {}", id),
+                original_title: None,
+                release_date: None,
+                poster_url: None,
+            })
+            .collect();
+        collection.set_parts(&parts).unwrap();
+        service.repos.collection.upsert(collection).await.unwrap().id
+    }
+
+    async fn create_movie(
+        service: &MovieService,
+        title: &str,
+        tmdb_id: i64,
+        release_date: Option<&str>,
+        collection_id: Option<i32>,
+    ) {
+        let mut movie = domain::entity::movie::Model::new(title.to_string(), format!("/movies/{}.mkv", title), 1);
+        movie.tmdb_id = Some(tmdb_id);
+        movie.release_date = release_date.map(str::to_string);
+        movie.collection_id = collection_id;
+        service.repos.movie.create(movie).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_collection_owned_and_missing() {
+        let service = setup().await;
+        let trilogy = create_collection(&service, 10, &[11, 12, 13]).await;
+        // 媒体库中没有电影的系列不列出
+        create_collection(&service, 20, &[21, 22]).await;
+        create_movie(&service, "Part 13", 13, None, Some(trilogy)).await;
+        create_movie(&service, "Part 11", 11, Some("1977-05-25"), Some(trilogy)).await;
+        // 不属于该系列的同 TMDB ID 电影不算已拥有
+        create_movie(&service, "Part 12", 12, Some("1980-05-21"), None).await;
+
+        let collections = service.get_collections().await.unwrap();
+        assert_eq!(collections.len(), 1);
+        assert_eq!((collections[0].id, collections[0].owned_count, collections[0].total_count), (trilogy, 2, 3));
+
+        let detail = service.get_collection(trilogy).await.unwrap();
+        // 已有电影按上映日期排序，未定档的排在最后
+        let titles: Vec<&str> = detail.movies.iter().map(|movie| movie.title.as_str()).collect();
+        assert_eq!(titles, ["Part 11", "Part 13"]);
+        let missing: Vec<i64> = detail.missing.iter().map(|part| part.tmdb_id).collect();
+        assert_eq!(missing, [12]);
+        assert_eq!((detail.collection.owned_count, detail.collection.total_count), (2, 3));
+    }
+
+    #[test]
+    fn test_to_movie_nfo_keeps_provider_ids() {
+        let mut movie = domain::entity::movie::Model::new("Heat".to_string(), "/movies/Heat (1995)/Heat.mkv".to_string(), 1);
+        movie.tmdb_id = Some(949);
+        movie.imdb_id = Some("tt0113277".to_string());
+        movie.cover = Some("https://image.tmdb.org/t/p/w500/heat.jpg".to_string());
+
+        let nfo = to_movie_nfo(&movie);
+
+        assert_eq!((nfo.tmdb_id, nfo.imdb_id.as_deref()), (Some(949), Some("tt0113277")));
+        assert_eq!(nfo.poster_urls, vec!["https://image.tmdb.org/t/p/original/heat.jpg".to_string()]);
+    }
+}
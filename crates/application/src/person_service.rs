@@ -0,0 +1,42 @@
+use crate::dto::PersonDetail;
+use domain::repository::{MovieCreditRepository, MovieRepository, PersonRepository};
+use std::sync::Arc;
+
+/// 人物服务 - 处理演职人员相关的业务逻辑
+pub struct PersonService {
+    repo: Arc<dyn PersonRepository>,
+    credit_repo: Arc<dyn MovieCreditRepository>,
+    movie_repo: Arc<dyn MovieRepository>,
+}
+
+impl PersonService {
+    /// 创建新的人物服务实例
+    pub fn new(
+        repo: Arc<dyn PersonRepository>,
+        credit_repo: Arc<dyn MovieCreditRepository>,
+        movie_repo: Arc<dyn MovieRepository>,
+    ) -> Self {
+        Self {
+            repo,
+            credit_repo,
+            movie_repo,
+        }
+    }
+
+    /// 根据 ID 查询人物详细信息（包含在各个媒体库中参与的电影）
+    pub async fn get_detail(&self, id: i32) -> anyhow::Result<PersonDetail> {
+        let person = self
+            .repo
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("Person not found with id: {}", id))?;
+
+        let credits = self.credit_repo.find_by_person_id(id).await?;
+        let mut movie_ids: Vec<i32> = credits.iter().map(|c| c.movie_id).collect();
+        movie_ids.sort_unstable();
+        movie_ids.dedup();
+        let movies = self.movie_repo.find_by_ids(movie_ids).await?;
+
+        Ok(PersonDetail::new(person, credits, movies))
+    }
+}
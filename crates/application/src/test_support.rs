@@ -0,0 +1,16 @@
+//! 测试辅助：基于内存 SQLite 的数据库
+
+use sea_orm::{ConnectionTrait, Database, DatabaseConnection, EntityTrait, Schema};
+
+/// 创建内存 SQLite 数据库（关闭外键检查，测试只需要创建用到的表）
+pub(crate) async fn memory_db() -> DatabaseConnection {
+    let db = Database::connect("sqlite::memory:").await.expect("failed to open in-memory database");
+    db.execute_unprepared("PRAGMA foreign_keys = OFF").await.expect("failed to disable foreign keys");
+    db
+}
+
+/// 按实体定义建表
+pub(crate) async fn create_table<E: EntityTrait>(db: &DatabaseConnection, entity: E) {
+    let stmt = Schema::new(db.get_database_backend()).create_table_from_entity(entity);
+    db.execute(&stmt).await.expect("failed to create table");
+}
@@ -0,0 +1,107 @@
+use crate::dto::{TvSeasonDetailInfo, TvSeriesDetailInfo};
+use domain::repository::{TvEpisodeRepository, TvSeasonRepository, TvSeriesRepository};
+use std::sync::Arc;
+
+/// 电视剧服务 - 处理电视剧、季和单集相关的业务逻辑
+pub struct TvSeriesService {
+    repo: Arc<dyn TvSeriesRepository>,
+    season_repo: Arc<dyn TvSeasonRepository>,
+    episode_repo: Arc<dyn TvEpisodeRepository>,
+}
+
+impl TvSeriesService {
+    /// 创建新的电视剧服务实例
+    pub fn new(
+        repo: Arc<dyn TvSeriesRepository>,
+        season_repo: Arc<dyn TvSeasonRepository>,
+        episode_repo: Arc<dyn TvEpisodeRepository>,
+    ) -> Self {
+        Self {
+            repo,
+            season_repo,
+            episode_repo,
+        }
+    }
+
+    /// 根据 ID 查询电视剧
+    pub async fn get_by_id(&self, id: i32) -> anyhow::Result<domain::entity::tv_series::Model> {
+        self.repo
+            .find_by_id(id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("TV series not found with id: {}", id))
+    }
+
+    /// 根据 ID 查询电视剧详细信息（包含季列表）
+    pub async fn get_detail_by_id(&self, id: i32) -> anyhow::Result<TvSeriesDetailInfo> {
+        let series = self.get_by_id(id).await?;
+        let seasons = self.season_repo.find_by_series_id(id).await?;
+        Ok(TvSeriesDetailInfo {
+            series: series.into(),
+            seasons: seasons.into_iter().map(|s| s.into()).collect(),
+        })
+    }
+
+    /// 查询电视剧某一季的详细信息（包含单集列表）
+    pub async fn get_season_detail(&self, series_id: i32, season_number: i32) -> anyhow::Result<TvSeasonDetailInfo> {
+        let season = self
+            .season_repo
+            .find_by_series_id(series_id)
+            .await?
+            .into_iter()
+            .find(|s| s.season_number == season_number)
+            .ok_or_else(|| anyhow::anyhow!("Season {} not found for TV series {}", season_number, series_id))?;
+        let episodes = self.episode_repo.find_by_season_id(season.id).await?;
+        Ok(TvSeasonDetailInfo {
+            season: season.into(),
+            episodes: episodes.into_iter().map(|e| e.into()).collect(),
+        })
+    }
+
+    /// 根据媒体库 ID 查询所有电视剧
+    pub async fn get_by_media_library_id(
+        &self,
+        media_library_id: i32,
+    ) -> anyhow::Result<Vec<domain::entity::tv_series::Model>> {
+        self.repo.find_by_media_library_id(media_library_id).await
+    }
+
+    /// 分页查询所有电视剧
+    pub async fn get_paged(
+        &self,
+        page_index: i32,
+        page_size: i32,
+    ) -> anyhow::Result<(Vec<domain::entity::tv_series::Model>, i32)> {
+        let series = self
+            .repo
+            .find_by_paged(page_size, page_index)
+            .await?
+            .unwrap_or_default();
+
+        let total = self.repo.count_all().await?;
+
+        Ok((series, total))
+    }
+
+    /// 删除电视剧（同时删除所有季和单集）
+    pub async fn delete(&self, id: i32) -> anyhow::Result<()> {
+        let _series = self.get_by_id(id).await?;
+
+        self.episode_repo.delete_by_series_id(id).await?;
+        self.season_repo.delete_by_series_id(id).await?;
+        self.repo.delete(id).await?;
+
+        tracing::info!("Deleted TV series with id: {}", id);
+
+        Ok(())
+    }
+
+    /// 获取单集视频文件路径（用于流式传输）
+    pub async fn get_episode_video_path(&self, episode_id: i32) -> anyhow::Result<String> {
+        let episode = self
+            .episode_repo
+            .find_by_id(episode_id)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("TV episode not found with id: {}", episode_id))?;
+        Ok(episode.path)
+    }
+}
@@ -5,18 +5,19 @@ use crate::entity::media_library;
 use crate::entity::movie;
 use crate::entity::photo;
 use crate::entity::photo_exif;
+use crate::entity::tv_series;
 use crate::service::MangaDomainService;
 
 /// MediaLibrary 聚合根
 ///
 /// # DDD 设计
-/// - ✅ 聚合根：MediaLibrary 是聚合根，管理其下的所有媒体实体（Manga、Game、Movie 等）
+/// - ✅ 聚合根：MediaLibrary 是聚合根，管理其下的所有媒体实体（Manga、Game、Movie、TvSeries 等）
 /// - ✅ 一致性边界：所有对媒体实体的创建和更新都通过聚合根进行
 /// - ✅ 业务规则：聚合根保证业务规则的一致性
 ///
 /// # 职责
 /// - 管理 MediaLibrary 实体的生命周期
-/// - 管理 Manga、Game、Movie、Photo 等媒体实体的创建和更新
+/// - 管理 Manga、Game、Movie、TvSeries、Photo 等媒体实体的创建和更新
 /// - 保证 MediaLibrary 和媒体实体之间的一致性
 /// - 封装业务规则
 pub struct MediaLibraryAggregate {
@@ -30,6 +31,8 @@ pub struct MediaLibraryAggregate {
     pub games: Vec<game::Model>,
     /// 电影实体列表（聚合内的实体）
     pub movies: Vec<movie::Model>,
+    /// 电视剧实体列表（聚合内的实体，季和单集在持久化电视剧后创建）
+    pub tv_series: Vec<tv_series::Model>,
     /// 照片实体列表（聚合内的实体）
     pub photos: Vec<photo::Model>,
     /// 照片 EXIF 信息列表（聚合内的实体）
@@ -73,6 +76,7 @@ impl MediaLibraryAggregate {
             manga_chapters: Vec::new(),
             games: Vec::new(),
             movies: Vec::new(),
+            tv_series: Vec::new(),
             photos: Vec::new(),
             photo_exifs: Vec::new(),
         })
@@ -106,11 +110,24 @@ impl MediaLibraryAggregate {
             manga_chapters,
             games,
             movies,
+            tv_series: Vec::new(),
             photos,
             photo_exifs,
         }
     }
 
+    /// 设置聚合根内已有的电视剧实体
+    ///
+    /// # 参数
+    /// - `tv_series`: 电视剧实体列表
+    ///
+    /// # 返回
+    /// - `Self` - 设置后的聚合根
+    pub fn with_tv_series(mut self, tv_series: Vec<tv_series::Model>) -> Self {
+        self.tv_series = tv_series;
+        self
+    }
+
     /// 添加漫画到聚合根
     /// 
     /// # 参数
@@ -605,9 +622,59 @@ impl MediaLibraryAggregate {
         self.movies.len()
     }
 
-    /// 获取总媒体项数量（漫画 + 游戏 + 电影 + 照片）
+    // ============================================================================
+    // TvSeries 相关方法
+    // ============================================================================
+
+    /// 批量添加电视剧到聚合根
+    ///
+    /// # 参数
+    /// - `series`: 电视剧实体列表
+    ///
+    /// # 返回
+    /// - `anyhow::Result<usize>` - 成功添加的电视剧数量
+    ///
+    /// # 业务规则
+    /// - 电视剧文件夹路径不能重复
+    /// - 自动设置 media_library_id
+    /// - 自动更新媒体库的项目数量（每部电视剧计为 1 项）
+    pub fn add_tv_series_batch(
+        &mut self,
+        mut series: Vec<tv_series::Model>,
+    ) -> anyhow::Result<usize> {
+        let mut added_count = 0;
+
+        for item in &mut series {
+            let path = item.path.clone();
+
+            // 检查路径是否重复
+            if self.tv_series.iter().any(|s| s.path == path) {
+                tracing::warn!("Skipping duplicate tv series path: {}", path);
+                continue;
+            }
+
+            // 确保 media_library_id 正确
+            item.media_library_id = self.media_library.id;
+            self.tv_series.push(item.clone());
+            added_count += 1;
+        }
+
+        // 更新媒体库的项目数量
+        if added_count > 0 {
+            self.media_library.increment_item_count(added_count as i32)?;
+        }
+
+        Ok(added_count)
+    }
+
+    /// 获取电视剧数量
+    pub fn tv_series_count(&self) -> usize {
+        self.tv_series.len()
+    }
+
+    /// 获取总媒体项数量（漫画 + 游戏 + 电影 + 电视剧 + 照片）
     pub fn total_media_count(&self) -> usize {
-        self.mangas.len() + self.games.len() + self.movies.len() + self.photos.len()
+        self.mangas.len() + self.games.len() + self.movies.len() + self.tv_series.len() + self.photos.len()
     }
 
     // ============================================================================
@@ -2,7 +2,7 @@
 use sea_orm::entity::prelude::*;
 use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
 #[sea_orm(table_name = "Manga")]
 pub struct Model {
     #[sea_orm(column_name = "Id", primary_key)]
@@ -33,6 +33,12 @@ pub struct Model {
     pub has_chapters: bool,
     #[sea_orm(column_name = "ImagePaths", column_type = "Text", nullable)]
     pub image_paths: Option<String>,
+    #[sea_orm(column_name = "BadPages", column_type = "Text", nullable)]
+    pub bad_pages: Option<String>,
+    #[sea_orm(column_name = "SeriesId", nullable)]
+    pub series_id: Option<i32>,
+    #[sea_orm(column_name = "VolumeNumber", nullable)]
+    pub volume_number: Option<f32>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -46,6 +52,13 @@ pub enum Relation {
 
     #[sea_orm(has_many = "super::manga_chapter::Entity")]
     Chapters,
+
+    #[sea_orm(
+        belongs_to = "super::manga_series::Entity",
+        from = "Column::SeriesId",
+        to = "super::manga_series::Column::Id"
+    )]
+    Series,
 }
 
 impl Related<super::media_library::Entity> for Entity {
@@ -60,6 +73,12 @@ impl Related<super::manga_chapter::Entity> for Entity {
     }
 }
 
+impl Related<super::manga_series::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Series.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
 
 // ============================================================================
@@ -116,6 +135,9 @@ impl Model {
             description: None,
             cover: None,
             image_paths: None,
+            bad_pages: None,
+            series_id: None,
+            volume_number: None,
             create_time: now.clone(),
             update_time: now,
         })
@@ -254,4 +276,39 @@ impl Model {
         self.image_paths = None;
         self.update_time = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
     }
+
+    /// 设置无法读取的页面列表
+    ///
+    /// # 参数
+    /// - `pages`: 损坏或被截断的页面文件名，为空时清除
+    pub fn set_bad_pages(&mut self, pages: Vec<String>) {
+        self.bad_pages = if pages.is_empty() {
+            None
+        } else {
+            Some(serde_json::to_string(&pages).unwrap())
+        };
+        self.update_time = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    }
+
+    /// 获取无法读取的页面列表
+    ///
+    /// # 返回
+    /// - `Vec<String>` - 损坏或被截断的页面文件名
+    pub fn get_bad_pages(&self) -> Vec<String> {
+        self.bad_pages
+            .as_ref()
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_default()
+    }
+
+    /// 归入系列
+    ///
+    /// # 参数
+    /// - `series_id`: 系列 ID
+    /// - `volume_number`: 在系列中的卷号（无法识别时为 None）
+    pub fn assign_series(&mut self, series_id: i32, volume_number: Option<f32>) {
+        self.series_id = Some(series_id);
+        self.volume_number = volume_number;
+        self.update_time = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    }
 }
\ No newline at end of file
@@ -27,6 +27,8 @@ pub struct Model {
     pub cover: Option<String>,
     #[sea_orm(column_name = "ImagePaths", column_type = "Text", nullable)]
     pub image_paths: Option<String>,
+    #[sea_orm(column_name = "BadPages", column_type = "Text", nullable)]
+    pub bad_pages: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -97,6 +99,7 @@ impl Model {
             byte_size,
             cover: None,
             image_paths: None,
+            bad_pages: None,
             create_time: now.clone(),
             update_time: now,
         })
@@ -142,5 +145,29 @@ impl Model {
         self.image_paths = None;
         self.update_time = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
     }
+
+    /// 设置无法读取的页面列表
+    ///
+    /// # 参数
+    /// - `pages`: 损坏或被截断的页面文件名，为空时清除
+    pub fn set_bad_pages(&mut self, pages: Vec<String>) {
+        self.bad_pages = if pages.is_empty() {
+            None
+        } else {
+            Some(serde_json::to_string(&pages).unwrap())
+        };
+        self.update_time = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    }
+
+    /// 获取无法读取的页面列表
+    ///
+    /// # 返回
+    /// - `Vec<String>` - 损坏或被截断的页面文件名
+    pub fn get_bad_pages(&self) -> Vec<String> {
+        self.bad_pages
+            .as_ref()
+            .and_then(|json| serde_json::from_str(json).ok())
+            .unwrap_or_default()
+    }
 }
 
@@ -0,0 +1,112 @@
+//! MangaSeries Entity - 漫画系列实体
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
+#[sea_orm(table_name = "MangaSeries")]
+pub struct Model {
+    #[sea_orm(column_name = "Id", primary_key)]
+    pub id: i32,
+    #[sea_orm(column_name = "CreateTime", column_type = "custom(\"DATETIME\")")]
+    pub create_time: String,
+    #[sea_orm(column_name = "UpdateTime", column_type = "custom(\"DATETIME\")")]
+    pub update_time: String,
+    /// 系列标题
+    #[sea_orm(column_name = "Title", column_type = "Text")]
+    pub title: String,
+    /// 规范化标题（用于自动分组，见 `MangaSeriesDomainService::normalize_title`）
+    #[sea_orm(column_name = "NormalizedTitle", column_type = "Text")]
+    pub normalized_title: String,
+    /// 系列简介
+    #[sea_orm(column_name = "Description", column_type = "Text", nullable)]
+    pub description: Option<String>,
+    /// 作为系列封面的漫画 ID（为空时使用第一卷的封面）
+    #[sea_orm(column_name = "CoverMangaId", nullable)]
+    pub cover_manga_id: Option<i32>,
+    /// 所属媒体库 ID
+    #[sea_orm(column_name = "MediaLibraryId")]
+    pub media_library_id: i32,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::media_library::Entity",
+        from = "Column::MediaLibraryId",
+        to = "super::media_library::Column::Id"
+    )]
+    MediaLibrary,
+
+    #[sea_orm(has_many = "super::manga::Entity")]
+    Mangas,
+}
+
+impl Related<super::media_library::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::MediaLibrary.def()
+    }
+}
+
+impl Related<super::manga::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Mangas.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+// ============================================================================
+// 业务方法（充血模型）
+// ============================================================================
+
+impl Model {
+    /// 创建新系列（工厂方法）
+    ///
+    /// # 参数
+    /// - `title`: 系列标题
+    /// - `media_library_id`: 所属媒体库 ID
+    ///
+    /// # 业务规则
+    /// - 标题不能为空，长度不超过 200 个字符
+    /// - 规范化标题由标题自动生成
+    pub fn new(title: String, media_library_id: i32) -> anyhow::Result<Self> {
+        crate::service::MangaSeriesDomainService::validate_title(&title)?;
+
+        let now = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        Ok(Self {
+            id: 0, // 数据库会自动生成
+            normalized_title: crate::service::MangaSeriesDomainService::normalize_title(&title),
+            title,
+            description: None,
+            cover_manga_id: None,
+            media_library_id,
+            create_time: now.clone(),
+            update_time: now,
+        })
+    }
+
+    /// 更新标题
+    ///
+    /// # 业务规则
+    /// - 标题不能为空，长度不超过 200 个字符
+    /// - 同时更新规范化标题，之后扫描到的同名漫画会归入该系列
+    pub fn update_title(&mut self, new_title: String) -> anyhow::Result<()> {
+        crate::service::MangaSeriesDomainService::validate_title(&new_title)?;
+        self.normalized_title = crate::service::MangaSeriesDomainService::normalize_title(&new_title);
+        self.title = new_title;
+        self.update_time = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        Ok(())
+    }
+
+    /// 设置简介（传 None 清除）
+    pub fn set_description(&mut self, description: Option<String>) {
+        self.description = description;
+        self.update_time = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    }
+
+    /// 设置封面漫画（传 None 恢复使用第一卷的封面）
+    pub fn set_cover_manga(&mut self, manga_id: Option<i32>) {
+        self.cover_manga_id = manga_id;
+        self.update_time = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    }
+}
@@ -6,10 +6,22 @@ pub mod author;
 pub mod game;
 pub mod manga;
 pub mod manga_chapter;
+pub mod manga_series;
 pub mod media_library;
 pub mod movie;
+pub mod movie_collection;
+pub mod movie_credit;
+pub mod movie_extra;
+pub mod movie_play_state;
+pub mod movie_source;
+pub mod movie_subtitle;
+pub mod movie_track;
+pub mod person;
 pub mod photo;
 pub mod photo_album;
 pub mod photo_album_item;
 pub mod photo_exif;
+pub mod tv_episode;
+pub mod tv_season;
+pub mod tv_series;
 pub mod user;
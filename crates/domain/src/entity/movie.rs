@@ -101,6 +101,38 @@ pub struct Model {
     /// 封面图片路径
     #[sea_orm(column_name = "Cover", column_type = "Text", nullable)]
     pub cover: Option<String>,
+
+    /// 背景图 URL
+    #[sea_orm(column_name = "Backdrop", column_type = "Text", nullable)]
+    pub backdrop: Option<String>,
+
+    /// TMDB 电影 ID（手动识别或扫描匹配到的结果）
+    #[sea_orm(column_name = "TmdbId", nullable)]
+    pub tmdb_id: Option<i64>,
+
+    /// IMDb 电影 ID（如 "tt0113277"，来自 NFO、文件名或提供者）
+    #[sea_orm(column_name = "ImdbId", column_type = "Text", nullable)]
+    pub imdb_id: Option<String>,
+
+    /// 来源（从文件名解析，如 "BluRay"、"WEB-DL"）
+    #[sea_orm(column_name = "Source", column_type = "Text", nullable)]
+    pub source: Option<String>,
+
+    /// 视频编码（从文件名解析，如 "x264"、"H.265"）
+    #[sea_orm(column_name = "VideoCodec", column_type = "Text", nullable)]
+    pub video_codec: Option<String>,
+
+    /// 版本（从文件名解析，如 "Director's Cut"、"Extended"）
+    #[sea_orm(column_name = "Edition", column_type = "Text", nullable)]
+    pub edition: Option<String>,
+
+    /// 发布组（从文件名解析）
+    #[sea_orm(column_name = "ReleaseGroup", column_type = "Text", nullable)]
+    pub release_group: Option<String>,
+
+    /// 所属电影系列 ID
+    #[sea_orm(column_name = "CollectionId", nullable)]
+    pub collection_id: Option<i32>,
     
     /// 所属媒体库 ID
     #[sea_orm(column_name = "MediaLibraryId")]
@@ -115,6 +147,38 @@ pub enum Relation {
         to = "super::media_library::Column::Id"
     )]
     MediaLibrary,
+
+    /// 关联到 MovieCollection（多对一）
+    #[sea_orm(
+        belongs_to = "super::movie_collection::Entity",
+        from = "Column::CollectionId",
+        to = "super::movie_collection::Column::Id"
+    )]
+    MovieCollection,
+
+    /// 关联到 MovieTrack（一对多）
+    #[sea_orm(has_many = "super::movie_track::Entity")]
+    MovieTrack,
+
+    /// 关联到 MovieSubtitle（一对多）
+    #[sea_orm(has_many = "super::movie_subtitle::Entity")]
+    MovieSubtitle,
+
+    /// 关联到 MovieSource（一对多）
+    #[sea_orm(has_many = "super::movie_source::Entity")]
+    MovieSource,
+
+    /// 关联到 MovieExtra（一对多）
+    #[sea_orm(has_many = "super::movie_extra::Entity")]
+    MovieExtra,
+
+    /// 关联到 MovieCredit（一对多）
+    #[sea_orm(has_many = "super::movie_credit::Entity")]
+    MovieCredit,
+
+    /// 关联到 MoviePlayState（一对多）
+    #[sea_orm(has_many = "super::movie_play_state::Entity")]
+    MoviePlayState,
 }
 
 impl Related<super::media_library::Entity> for Entity {
@@ -123,6 +187,48 @@ impl Related<super::media_library::Entity> for Entity {
     }
 }
 
+impl Related<super::movie_collection::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::MovieCollection.def()
+    }
+}
+
+impl Related<super::movie_track::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::MovieTrack.def()
+    }
+}
+
+impl Related<super::movie_subtitle::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::MovieSubtitle.def()
+    }
+}
+
+impl Related<super::movie_source::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::MovieSource.def()
+    }
+}
+
+impl Related<super::movie_extra::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::MovieExtra.def()
+    }
+}
+
+impl Related<super::movie_credit::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::MovieCredit.def()
+    }
+}
+
+impl Related<super::movie_play_state::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::MoviePlayState.def()
+    }
+}
+
 impl ActiveModelBehavior for ActiveModel {}
 
 impl Model {
@@ -163,6 +269,14 @@ impl Model {
             tags: None,
             poster_urls: None,
             cover: None,
+            backdrop: None,
+            tmdb_id: None,
+            imdb_id: None,
+            source: None,
+            video_codec: None,
+            edition: None,
+            release_group: None,
+            collection_id: None,
             media_library_id,
         }
     }
@@ -202,6 +316,22 @@ impl Model {
             .unwrap_or_default()
     }
     
+    /// 获取编剧列表
+    pub fn get_writers(&self) -> Vec<String> {
+        self.writers
+            .as_ref()
+            .and_then(|s| serde_json::from_str(s).ok())
+            .unwrap_or_default()
+    }
+    
+    /// 获取制片人列表
+    pub fn get_producers(&self) -> Vec<String> {
+        self.producers
+            .as_ref()
+            .and_then(|s| serde_json::from_str(s).ok())
+            .unwrap_or_default()
+    }
+    
     /// 获取海报 URL 列表
     pub fn get_poster_urls(&self) -> Vec<String> {
         self.poster_urls
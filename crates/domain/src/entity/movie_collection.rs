@@ -0,0 +1,111 @@
+//! MovieCollection Entity - 电影系列（合集）实体
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "MovieCollection")]
+pub struct Model {
+    /// 主键 ID
+    #[sea_orm(column_name = "Id", primary_key)]
+    pub id: i32,
+
+    /// 创建时间
+    #[sea_orm(column_name = "CreateTime", column_type = "custom(\"DATETIME\")")]
+    pub create_time: String,
+
+    /// 更新时间
+    #[sea_orm(column_name = "UpdateTime", column_type = "custom(\"DATETIME\")")]
+    pub update_time: String,
+
+    /// TMDB 系列 ID（唯一）
+    #[sea_orm(column_name = "TmdbId")]
+    pub tmdb_id: i64,
+
+    /// 系列名称
+    #[sea_orm(column_name = "Name", column_type = "Text")]
+    pub name: String,
+
+    /// 简介
+    #[sea_orm(column_name = "Overview", column_type = "Text", nullable)]
+    pub overview: Option<String>,
+
+    /// 海报 URL
+    #[sea_orm(column_name = "PosterUrl", column_type = "Text", nullable)]
+    pub poster_url: Option<String>,
+
+    /// 背景图 URL
+    #[sea_orm(column_name = "BackdropUrl", column_type = "Text", nullable)]
+    pub backdrop_url: Option<String>,
+
+    /// 系列中的所有电影（JSON 数组，按发行日期排序，见 [`CollectionPart`]）
+    #[sea_orm(column_name = "Parts", column_type = "Text", nullable)]
+    pub parts: Option<String>,
+}
+
+/// 系列中的一部电影（不一定在媒体库中）
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CollectionPart {
+    /// TMDB 电影 ID
+    pub tmdb_id: i64,
+    /// 电影标题
+    pub title: String,
+    /// 原始标题
+    pub original_title: Option<String>,
+    /// 发行日期（YYYY-MM-DD）
+    pub release_date: Option<String>,
+    /// 海报 URL
+    pub poster_url: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    /// 关联到 Movie（一对多）
+    #[sea_orm(has_many = "super::movie::Entity")]
+    Movie,
+}
+
+impl Related<super::movie::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Movie.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl Model {
+    /// 创建新系列实体
+    ///
+    /// # 参数
+    /// - `tmdb_id`: TMDB 系列 ID
+    /// - `name`: 系列名称
+    pub fn new(tmdb_id: i64, name: String) -> Self {
+        let now = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        Self {
+            id: 0, // 数据库会自动生成
+            create_time: now.clone(),
+            update_time: now,
+            tmdb_id,
+            name,
+            overview: None,
+            poster_url: None,
+            backdrop_url: None,
+            parts: None,
+        }
+    }
+
+    /// 获取系列中的所有电影
+    pub fn get_parts(&self) -> Vec<CollectionPart> {
+        self.parts
+            .as_ref()
+            .and_then(|s| serde_json::from_str(s).ok())
+            .unwrap_or_default()
+    }
+
+    /// 设置系列中的所有电影
+    pub fn set_parts(&mut self, parts: &[CollectionPart]) -> anyhow::Result<()> {
+        self.parts = Some(serde_json::to_string(parts)?);
+        Ok(())
+    }
+}
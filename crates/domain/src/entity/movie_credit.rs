@@ -0,0 +1,72 @@
+//! MovieCredit Entity - 电影演职人员实体
+//!
+//! 电影与人物的多对多关联，记录职责（actor / director / writer / producer）、
+//! 饰演的角色和排序。
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "MovieCredit")]
+pub struct Model {
+    /// 主键 ID
+    #[sea_orm(column_name = "Id", primary_key)]
+    pub id: i32,
+
+    /// 电影 ID
+    #[sea_orm(column_name = "MovieId")]
+    pub movie_id: i32,
+
+    /// 人物 ID
+    #[sea_orm(column_name = "PersonId")]
+    pub person_id: i32,
+
+    /// 职责：actor / director / writer / producer
+    #[sea_orm(column_name = "Role", column_type = "Text")]
+    pub role: String,
+
+    /// 饰演的角色（仅演员）
+    #[sea_orm(column_name = "CharacterName", column_type = "Text", nullable)]
+    pub character_name: Option<String>,
+
+    /// 同一职责内的排序（演员为出场顺序）
+    #[sea_orm(column_name = "SortOrder")]
+    pub sort_order: i32,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    /// 关联到 Movie（多对一）
+    #[sea_orm(
+        belongs_to = "super::movie::Entity",
+        from = "Column::MovieId",
+        to = "super::movie::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Movie,
+
+    /// 关联到 Person（多对一）
+    #[sea_orm(
+        belongs_to = "super::person::Entity",
+        from = "Column::PersonId",
+        to = "super::person::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Person,
+}
+
+impl Related<super::movie::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Movie.def()
+    }
+}
+
+impl Related<super::person::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Person.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
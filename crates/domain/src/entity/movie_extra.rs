@@ -0,0 +1,63 @@
+//! MovieExtra Entity - 电影附加视频实体
+//!
+//! 预告片、幕后花絮、访谈、删减片段等不作为电影入库的视频，挂在所属电影下。
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "MovieExtra")]
+pub struct Model {
+    /// 主键 ID
+    #[sea_orm(column_name = "Id", primary_key)]
+    pub id: i32,
+
+    /// 所属电影 ID
+    #[sea_orm(column_name = "MovieId")]
+    pub movie_id: i32,
+
+    /// 类型：trailer / sample / behind_the_scenes / interview / deleted_scene / featurette / extra
+    #[sea_orm(column_name = "ExtraType", column_type = "Text")]
+    pub extra_type: String,
+
+    /// 标题（文件名）
+    #[sea_orm(column_name = "Title", column_type = "Text")]
+    pub title: String,
+
+    /// 视频文件路径
+    #[sea_orm(column_name = "Path", column_type = "Text")]
+    pub path: String,
+
+    /// 文件大小（字节）
+    #[sea_orm(column_name = "ByteSize")]
+    pub byte_size: i64,
+
+    /// 文件扩展名
+    #[sea_orm(column_name = "Extension", column_type = "Text", nullable)]
+    pub extension: Option<String>,
+
+    /// 时长（秒）
+    #[sea_orm(column_name = "Duration")]
+    pub duration: i32,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    /// 关联到 Movie（多对一）
+    #[sea_orm(
+        belongs_to = "super::movie::Entity",
+        from = "Column::MovieId",
+        to = "super::movie::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Movie,
+}
+
+impl Related<super::movie::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Movie.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
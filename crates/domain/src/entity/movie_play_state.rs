@@ -0,0 +1,200 @@
+//! MoviePlayState Entity - 电影播放状态实体
+//!
+//! 每个用户每部电影一条记录，保存播放位置、播放次数和已看标记，用于跨设备续播。
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 播放到时长的这个比例视为看完（自动标记为已看）
+pub const WATCHED_THRESHOLD: f64 = 0.9;
+
+/// 播放位置少于这个秒数时不算开始观看（不出现在继续观看中）
+pub const MIN_RESUME_SECONDS: i32 = 60;
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "MoviePlayState")]
+pub struct Model {
+    /// 主键 ID
+    #[sea_orm(column_name = "Id", primary_key)]
+    pub id: i32,
+
+    /// 创建时间
+    #[sea_orm(column_name = "CreateTime", column_type = "custom(\"DATETIME\")")]
+    pub create_time: String,
+
+    /// 更新时间
+    #[sea_orm(column_name = "UpdateTime", column_type = "custom(\"DATETIME\")")]
+    pub update_time: String,
+
+    /// 用户 ID
+    #[sea_orm(column_name = "UserId")]
+    pub user_id: i32,
+
+    /// 电影 ID
+    #[sea_orm(column_name = "MovieId")]
+    pub movie_id: i32,
+
+    /// 播放位置（秒）
+    #[sea_orm(column_name = "Position")]
+    pub position: i32,
+
+    /// 播放时的总时长（秒）
+    #[sea_orm(column_name = "Duration")]
+    pub duration: i32,
+
+    /// 看完的次数
+    #[sea_orm(column_name = "PlayCount")]
+    pub play_count: i32,
+
+    /// 是否已看
+    #[sea_orm(column_name = "Watched")]
+    pub watched: bool,
+
+    /// 最后播放时间
+    #[sea_orm(column_name = "LastPlayedTime", column_type = "custom(\"DATETIME\")")]
+    pub last_played_time: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    /// 关联到 User（多对一）
+    #[sea_orm(
+        belongs_to = "super::user::Entity",
+        from = "Column::UserId",
+        to = "super::user::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    User,
+
+    /// 关联到 Movie（多对一）
+    #[sea_orm(
+        belongs_to = "super::movie::Entity",
+        from = "Column::MovieId",
+        to = "super::movie::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Movie,
+}
+
+impl Related<super::user::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::User.def()
+    }
+}
+
+impl Related<super::movie::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Movie.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl Model {
+    /// 创建用户对某部电影的空播放状态
+    pub fn new(user_id: i32, movie_id: i32) -> Self {
+        let now = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        Self {
+            id: 0, // 数据库会自动生成
+            create_time: now.clone(),
+            update_time: now.clone(),
+            user_id,
+            movie_id,
+            position: 0,
+            duration: 0,
+            play_count: 0,
+            watched: false,
+            last_played_time: now,
+        }
+    }
+
+    /// 是否已经播放到看完的位置
+    pub fn is_finished(&self) -> bool {
+        self.duration > 0 && self.position as f64 >= self.duration as f64 * WATCHED_THRESHOLD
+    }
+
+    /// 是否可以续播（开始观看但还没看完）
+    pub fn is_resumable(&self) -> bool {
+        self.position >= MIN_RESUME_SECONDS && !self.is_finished()
+    }
+
+    /// 续播位置（秒），看完或刚开始时从头播放
+    pub fn resume_position(&self) -> i32 {
+        if self.is_resumable() { self.position } else { 0 }
+    }
+
+    /// 记录播放进度
+    ///
+    /// 从未看完的位置播放到看完时自动标记为已看，并增加一次播放次数；
+    /// 看完后继续上报（如片尾字幕）不会重复计数。`duration` 为 0 时沿用之前的时长
+    pub fn record_progress(&mut self, position: i32, duration: i32) {
+        let was_finished = self.is_finished();
+        if duration > 0 {
+            self.duration = duration;
+        }
+        self.position = if self.duration > 0 { position.clamp(0, self.duration) } else { position.max(0) };
+        if !was_finished && self.is_finished() {
+            self.watched = true;
+            self.play_count += 1;
+        }
+        self.touch(true);
+    }
+
+    /// 手动标记为已看（播放位置归零，下次从头播放）
+    pub fn mark_watched(&mut self) {
+        self.watched = true;
+        self.play_count += 1;
+        self.position = 0;
+        self.touch(true);
+    }
+
+    /// 手动标记为未看（清除播放位置和播放次数）
+    pub fn mark_unwatched(&mut self) {
+        self.watched = false;
+        self.play_count = 0;
+        self.position = 0;
+        self.touch(false);
+    }
+
+    /// 更新修改时间，播放相关的操作同时更新最后播放时间
+    fn touch(&mut self, played: bool) {
+        let now = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+        if played {
+            self.last_played_time = now.clone();
+        }
+        self.update_time = now;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_progress() {
+        let mut state = Model::new(1, 1);
+        state.record_progress(30, 6000);
+        assert!(!state.is_resumable());
+        assert_eq!(state.resume_position(), 0);
+
+        state.record_progress(3000, 6000);
+        assert!(state.is_resumable());
+        assert_eq!((state.resume_position(), state.watched, state.play_count), (3000, false, 0));
+
+        // 越过阈值自动标记已看，片尾继续上报不重复计数
+        state.record_progress(5500, 0);
+        state.record_progress(5900, 6000);
+        assert_eq!((state.resume_position(), state.watched, state.play_count), (0, true, 1));
+
+        // 重新从头看完算第二次
+        state.record_progress(10, 6000);
+        state.record_progress(5800, 6000);
+        assert_eq!(state.play_count, 2);
+
+        state.mark_unwatched();
+        assert_eq!((state.position, state.watched, state.play_count), (0, false, 0));
+    }
+}
@@ -0,0 +1,80 @@
+//! MovieSource Entity - 电影媒体源实体
+//!
+//! 同一部电影的每个版本（`1080p` / `2160p`、导演剪辑版……）和每个分段文件
+//! （`CD1` / `CD2`、`part1` / `part2`）各占一行，分段按 `part_index` 顺序播放。
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "MovieSource")]
+pub struct Model {
+    /// 主键 ID
+    #[sea_orm(column_name = "Id", primary_key)]
+    pub id: i32,
+
+    /// 所属电影 ID
+    #[sea_orm(column_name = "MovieId")]
+    pub movie_id: i32,
+
+    /// 版本序号（0 为默认版本）
+    #[sea_orm(column_name = "VersionIndex")]
+    pub version_index: i32,
+
+    /// 分段序号（从 1 开始，未分段时为空）
+    #[sea_orm(column_name = "PartIndex", nullable)]
+    pub part_index: Option<i32>,
+
+    /// 版本名称（如 `2160p`、`Director's Cut 1080p`）
+    #[sea_orm(column_name = "Label", column_type = "Text")]
+    pub label: String,
+
+    /// 视频文件路径
+    #[sea_orm(column_name = "Path", column_type = "Text")]
+    pub path: String,
+
+    /// 文件大小（字节）
+    #[sea_orm(column_name = "ByteSize")]
+    pub byte_size: i64,
+
+    /// 文件扩展名
+    #[sea_orm(column_name = "Extension", column_type = "Text", nullable)]
+    pub extension: Option<String>,
+
+    /// 时长（秒）
+    #[sea_orm(column_name = "Duration")]
+    pub duration: i32,
+
+    /// 视频宽度（像素）
+    #[sea_orm(column_name = "Width")]
+    pub width: i32,
+
+    /// 视频高度（像素）
+    #[sea_orm(column_name = "Height")]
+    pub height: i32,
+
+    /// 分辨率（如 `1920x1080`）
+    #[sea_orm(column_name = "Resolution", column_type = "Text", nullable)]
+    pub resolution: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    /// 关联到 Movie（多对一）
+    #[sea_orm(
+        belongs_to = "super::movie::Entity",
+        from = "Column::MovieId",
+        to = "super::movie::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Movie,
+}
+
+impl Related<super::movie::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Movie.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
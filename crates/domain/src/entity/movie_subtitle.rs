@@ -0,0 +1,65 @@
+//! MovieSubtitle Entity - 电影外挂字幕实体
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "MovieSubtitle")]
+pub struct Model {
+    /// 主键 ID
+    #[sea_orm(column_name = "Id", primary_key)]
+    pub id: i32,
+
+    /// 所属电影 ID
+    #[sea_orm(column_name = "MovieId")]
+    pub movie_id: i32,
+
+    /// 字幕文件路径
+    #[sea_orm(column_name = "Path", column_type = "Text")]
+    pub path: String,
+
+    /// 字幕格式（srt / ass / ssa / vtt）
+    #[sea_orm(column_name = "Format", column_type = "Text")]
+    pub format: String,
+
+    /// 语言（BCP 47，如 zh-Hans、en）
+    #[sea_orm(column_name = "Language", column_type = "Text", nullable)]
+    pub language: Option<String>,
+
+    /// 标题（文件名中视频名之后的部分）
+    #[sea_orm(column_name = "Title", column_type = "Text", nullable)]
+    pub title: Option<String>,
+
+    /// 是否为默认字幕
+    #[sea_orm(column_name = "IsDefault")]
+    pub is_default: bool,
+
+    /// 是否为强制字幕
+    #[sea_orm(column_name = "IsForced")]
+    pub is_forced: bool,
+
+    /// 是否为听障字幕（SDH / CC）
+    #[sea_orm(column_name = "IsSdh")]
+    pub is_sdh: bool,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    /// 关联到 Movie（多对一）
+    #[sea_orm(
+        belongs_to = "super::movie::Entity",
+        from = "Column::MovieId",
+        to = "super::movie::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Movie,
+}
+
+impl Related<super::movie::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Movie.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,117 @@
+//! MovieTrack Entity - 电影音视频及字幕轨道实体
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// 视频轨道
+pub const TRACK_TYPE_VIDEO: &str = "video";
+/// 音频轨道
+pub const TRACK_TYPE_AUDIO: &str = "audio";
+/// 字幕轨道
+pub const TRACK_TYPE_SUBTITLE: &str = "subtitle";
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "MovieTrack")]
+pub struct Model {
+    /// 主键 ID
+    #[sea_orm(column_name = "Id", primary_key)]
+    pub id: i32,
+
+    /// 所属电影 ID
+    #[sea_orm(column_name = "MovieId")]
+    pub movie_id: i32,
+
+    /// 轨道类型（video / audio / subtitle）
+    #[sea_orm(column_name = "TrackType", column_type = "Text")]
+    pub track_type: String,
+
+    /// 容器内的轨道编号
+    #[sea_orm(column_name = "TrackIndex")]
+    pub track_index: i32,
+
+    /// 编码（如 h264、hevc、aac、subrip、pgs）
+    #[sea_orm(column_name = "Codec", column_type = "Text")]
+    pub codec: String,
+
+    /// 语言（ISO 639-2 或 BCP 47）
+    #[sea_orm(column_name = "Language", column_type = "Text", nullable)]
+    pub language: Option<String>,
+
+    /// 轨道名称
+    #[sea_orm(column_name = "Title", column_type = "Text", nullable)]
+    pub title: Option<String>,
+
+    /// 是否为默认轨道
+    #[sea_orm(column_name = "IsDefault")]
+    pub is_default: bool,
+
+    /// 是否为强制字幕
+    #[sea_orm(column_name = "IsForced")]
+    pub is_forced: bool,
+
+    /// 视频宽度（像素）
+    #[sea_orm(column_name = "Width", nullable)]
+    pub width: Option<i32>,
+
+    /// 视频高度（像素）
+    #[sea_orm(column_name = "Height", nullable)]
+    pub height: Option<i32>,
+
+    /// 帧率
+    #[sea_orm(column_name = "FrameRate", nullable)]
+    pub frame_rate: Option<f64>,
+
+    /// 是否为 HDR
+    #[sea_orm(column_name = "IsHdr")]
+    pub is_hdr: bool,
+
+    /// 码率（bit/s）
+    #[sea_orm(column_name = "BitRate", nullable)]
+    pub bit_rate: Option<i64>,
+
+    /// 声道数
+    #[sea_orm(column_name = "Channels", nullable)]
+    pub channels: Option<i32>,
+
+    /// 采样率（Hz）
+    #[sea_orm(column_name = "SampleRate", nullable)]
+    pub sample_rate: Option<i32>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    /// 关联到 Movie（多对一）
+    #[sea_orm(
+        belongs_to = "super::movie::Entity",
+        from = "Column::MovieId",
+        to = "super::movie::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    Movie,
+}
+
+impl Related<super::movie::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::Movie.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl Model {
+    /// 是否为视频轨道
+    pub fn is_video(&self) -> bool {
+        self.track_type == TRACK_TYPE_VIDEO
+    }
+
+    /// 是否为音频轨道
+    pub fn is_audio(&self) -> bool {
+        self.track_type == TRACK_TYPE_AUDIO
+    }
+
+    /// 是否为字幕轨道
+    pub fn is_subtitle(&self) -> bool {
+        self.track_type == TRACK_TYPE_SUBTITLE
+    }
+}
@@ -0,0 +1,70 @@
+//! Person Entity - 人物实体
+//!
+//! 演员、导演、编剧、制片人。有 TMDB 人物 ID 的按 ID 唯一，
+//! 没有的（NFO 或旧数据迁移）按姓名唯一。
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "Person")]
+pub struct Model {
+    /// 主键 ID
+    #[sea_orm(column_name = "Id", primary_key)]
+    pub id: i32,
+
+    /// 创建时间
+    #[sea_orm(column_name = "CreateTime", column_type = "custom(\"DATETIME\")")]
+    pub create_time: String,
+
+    /// 更新时间
+    #[sea_orm(column_name = "UpdateTime", column_type = "custom(\"DATETIME\")")]
+    pub update_time: String,
+
+    /// 姓名
+    #[sea_orm(column_name = "Name", column_type = "Text")]
+    pub name: String,
+
+    /// TMDB 人物 ID
+    #[sea_orm(column_name = "TmdbId", nullable)]
+    pub tmdb_id: Option<i64>,
+
+    /// 头像 URL
+    #[sea_orm(column_name = "ProfileUrl", column_type = "Text", nullable)]
+    pub profile_url: Option<String>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    /// 关联到 MovieCredit（一对多）
+    #[sea_orm(has_many = "super::movie_credit::Entity")]
+    MovieCredit,
+}
+
+impl Related<super::movie_credit::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::MovieCredit.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl Model {
+    /// 创建新人物实体
+    ///
+    /// # 参数
+    /// - `name`: 姓名
+    /// - `tmdb_id`: TMDB 人物 ID
+    pub fn new(name: String, tmdb_id: Option<i64>) -> Self {
+        let now = chrono::Local::now().format("%Y-%m-%d %H:%M:%S").to_string();
+
+        Self {
+            id: 0, // 数据库会自动生成
+            create_time: now.clone(),
+            update_time: now,
+            name,
+            tmdb_id,
+            profile_url: None,
+        }
+    }
+}
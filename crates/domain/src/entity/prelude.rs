@@ -3,10 +3,22 @@
 pub use super::author::Entity as Author;
 pub use super::game::Entity as Game;
 pub use super::manga::Entity as Manga;
+pub use super::manga_series::Entity as MangaSeries;
 pub use super::media_library::Entity as MediaLibrary;
 pub use super::movie::Entity as Movie;
+pub use super::movie_collection::Entity as MovieCollection;
+pub use super::movie_credit::Entity as MovieCredit;
+pub use super::movie_extra::Entity as MovieExtra;
+pub use super::movie_play_state::Entity as MoviePlayState;
+pub use super::movie_source::Entity as MovieSource;
+pub use super::movie_subtitle::Entity as MovieSubtitle;
+pub use super::movie_track::Entity as MovieTrack;
+pub use super::person::Entity as Person;
 pub use super::photo::Entity as Photo;
 pub use super::photo_album::Entity as PhotoAlbum;
 pub use super::photo_album_item::Entity as PhotoAlbumItem;
 pub use super::photo_exif::Entity as PhotoExif;
+pub use super::tv_episode::Entity as TvEpisode;
+pub use super::tv_season::Entity as TvSeason;
+pub use super::tv_series::Entity as TvSeries;
 pub use super::user::Entity as User;
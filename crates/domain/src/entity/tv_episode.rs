@@ -0,0 +1,145 @@
+//! TvEpisode Entity - 电视剧单集实体
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "TvEpisode")]
+pub struct Model {
+    /// 主键 ID
+    #[sea_orm(column_name = "Id", primary_key)]
+    pub id: i32,
+
+    /// 创建时间
+    #[sea_orm(column_name = "CreateTime", column_type = "custom(\"DATETIME\")")]
+    pub create_time: String,
+
+    /// 更新时间
+    #[sea_orm(column_name = "UpdateTime", column_type = "custom(\"DATETIME\")")]
+    pub update_time: String,
+
+    /// 所属电视剧 ID
+    #[sea_orm(column_name = "SeriesId")]
+    pub series_id: i32,
+
+    /// 所属季 ID
+    #[sea_orm(column_name = "SeasonId")]
+    pub season_id: i32,
+
+    /// 季号
+    #[sea_orm(column_name = "SeasonNumber")]
+    pub season_number: i32,
+
+    /// 集号
+    #[sea_orm(column_name = "EpisodeNumber")]
+    pub episode_number: i32,
+
+    /// 结束集号（一个文件包含多集时，如 `S01E01-E02`）
+    #[sea_orm(column_name = "EpisodeNumberEnd", nullable)]
+    pub episode_number_end: Option<i32>,
+
+    /// 绝对集号（动漫按字幕组命名的连续集号，如 `- 13`）
+    #[sea_orm(column_name = "AbsoluteNumber", nullable)]
+    pub absolute_number: Option<i32>,
+
+    /// 发布组（字幕组，如 `SubsPlease`）
+    #[sea_orm(column_name = "ReleaseGroup", column_type = "Text", nullable)]
+    pub release_group: Option<String>,
+
+    /// 单集标题
+    #[sea_orm(column_name = "Title", column_type = "Text", nullable)]
+    pub title: Option<String>,
+
+    /// 简介
+    #[sea_orm(column_name = "Description", column_type = "Text", nullable)]
+    pub description: Option<String>,
+
+    /// 播出日期（YYYY-MM-DD）
+    #[sea_orm(column_name = "AirDate", column_type = "Text", nullable)]
+    pub air_date: Option<String>,
+
+    /// 视频文件路径
+    #[sea_orm(column_name = "Path", column_type = "Text")]
+    pub path: String,
+
+    /// 文件大小（字节）
+    #[sea_orm(column_name = "ByteSize")]
+    pub byte_size: i64,
+
+    /// 文件扩展名
+    #[sea_orm(column_name = "Extension", column_type = "Text", nullable)]
+    pub extension: Option<String>,
+
+    /// 时长（秒）
+    #[sea_orm(column_name = "Duration")]
+    pub duration: i32,
+
+    /// 视频宽度（像素）
+    #[sea_orm(column_name = "Width")]
+    pub width: i32,
+
+    /// 视频高度（像素）
+    #[sea_orm(column_name = "Height")]
+    pub height: i32,
+
+    /// 分辨率字符串（如 "1920x1080"）
+    #[sea_orm(column_name = "Resolution", column_type = "Text", nullable)]
+    pub resolution: Option<String>,
+
+    /// 剧照 URL
+    #[sea_orm(column_name = "Still", column_type = "Text", nullable)]
+    pub still: Option<String>,
+
+    /// 评分（0-10）
+    #[sea_orm(column_name = "Rating")]
+    pub rating: f32,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    /// 关联到 TvSeries（多对一）
+    #[sea_orm(
+        belongs_to = "super::tv_series::Entity",
+        from = "Column::SeriesId",
+        to = "super::tv_series::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    TvSeries,
+
+    /// 关联到 TvSeason（多对一）
+    #[sea_orm(
+        belongs_to = "super::tv_season::Entity",
+        from = "Column::SeasonId",
+        to = "super::tv_season::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    TvSeason,
+}
+
+impl Related<super::tv_series::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TvSeries.def()
+    }
+}
+
+impl Related<super::tv_season::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TvSeason.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl Model {
+    /// 集号标签（如 `S01E02`、`S01E01-E02`）
+    pub fn episode_label(&self) -> String {
+        match self.episode_number_end {
+            Some(end) if end > self.episode_number => {
+                format!("S{:02}E{:02}-E{:02}", self.season_number, self.episode_number, end)
+            }
+            _ => format!("S{:02}E{:02}", self.season_number, self.episode_number),
+        }
+    }
+}
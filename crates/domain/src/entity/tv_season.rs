@@ -0,0 +1,78 @@
+//! TvSeason Entity - 电视剧季实体
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "TvSeason")]
+pub struct Model {
+    /// 主键 ID
+    #[sea_orm(column_name = "Id", primary_key)]
+    pub id: i32,
+
+    /// 所属电视剧 ID
+    #[sea_orm(column_name = "SeriesId")]
+    pub series_id: i32,
+
+    /// 季号（0 为特别篇）
+    #[sea_orm(column_name = "SeasonNumber")]
+    pub season_number: i32,
+
+    /// 季标题
+    #[sea_orm(column_name = "Title", column_type = "Text", nullable)]
+    pub title: Option<String>,
+
+    /// 简介
+    #[sea_orm(column_name = "Description", column_type = "Text", nullable)]
+    pub description: Option<String>,
+
+    /// 首播日期（YYYY-MM-DD）
+    #[sea_orm(column_name = "AirDate", column_type = "Text", nullable)]
+    pub air_date: Option<String>,
+
+    /// 封面图片路径
+    #[sea_orm(column_name = "Cover", column_type = "Text", nullable)]
+    pub cover: Option<String>,
+
+    /// 本地集数
+    #[sea_orm(column_name = "EpisodeCount")]
+    pub episode_count: i32,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    /// 关联到 TvSeries（多对一）
+    #[sea_orm(
+        belongs_to = "super::tv_series::Entity",
+        from = "Column::SeriesId",
+        to = "super::tv_series::Column::Id",
+        on_update = "NoAction",
+        on_delete = "Cascade"
+    )]
+    TvSeries,
+
+    /// 关联到 TvEpisode（一对多）
+    #[sea_orm(has_many = "super::tv_episode::Entity")]
+    TvEpisode,
+}
+
+impl Related<super::tv_series::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TvSeries.def()
+    }
+}
+
+impl Related<super::tv_episode::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TvEpisode.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl Model {
+    /// 是否为特别篇（第 0 季）
+    pub fn is_specials(&self) -> bool {
+        self.season_number == 0
+    }
+}
@@ -0,0 +1,144 @@
+//! TvSeries Entity - 电视剧实体
+
+use sea_orm::entity::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Serialize, Deserialize)]
+#[sea_orm(table_name = "TvSeries")]
+pub struct Model {
+    /// 主键 ID
+    #[sea_orm(column_name = "Id", primary_key)]
+    pub id: i32,
+
+    /// 创建时间
+    #[sea_orm(column_name = "CreateTime", column_type = "custom(\"DATETIME\")")]
+    pub create_time: String,
+
+    /// 更新时间
+    #[sea_orm(column_name = "UpdateTime", column_type = "custom(\"DATETIME\")")]
+    pub update_time: String,
+
+    /// 剧名
+    #[sea_orm(column_name = "Title", column_type = "Text")]
+    pub title: String,
+
+    /// 原始剧名
+    #[sea_orm(column_name = "OriginalTitle", column_type = "Text", nullable)]
+    pub original_title: Option<String>,
+
+    /// 简介
+    #[sea_orm(column_name = "Description", column_type = "Text", nullable)]
+    pub description: Option<String>,
+
+    /// 剧集文件夹路径（文件直接放在媒体库根目录时为 `根目录/剧名`）
+    #[sea_orm(column_name = "Path", column_type = "Text")]
+    pub path: String,
+
+    /// 首播日期（YYYY-MM-DD）
+    #[sea_orm(column_name = "FirstAirDate", column_type = "Text", nullable)]
+    pub first_air_date: Option<String>,
+
+    /// 评分（0-10）
+    #[sea_orm(column_name = "Rating")]
+    pub rating: f32,
+
+    /// 评价人数
+    #[sea_orm(column_name = "Votes")]
+    pub votes: i32,
+
+    /// 类型/流派列表（JSON 数组）
+    #[sea_orm(column_name = "Genres", column_type = "Text", nullable)]
+    pub genres: Option<String>,
+
+    /// 主演列表（JSON 数组）
+    #[sea_orm(column_name = "Actors", column_type = "Text", nullable)]
+    pub actors: Option<String>,
+
+    /// 海报 URL 列表（JSON 数组）
+    #[sea_orm(column_name = "PosterUrls", column_type = "Text", nullable)]
+    pub poster_urls: Option<String>,
+
+    /// 封面图片路径
+    #[sea_orm(column_name = "Cover", column_type = "Text", nullable)]
+    pub cover: Option<String>,
+
+    /// TMDB 剧集 ID
+    #[sea_orm(column_name = "TmdbId", nullable)]
+    pub tmdb_id: Option<i64>,
+
+    /// 本地季数
+    #[sea_orm(column_name = "SeasonCount")]
+    pub season_count: i32,
+
+    /// 本地集数
+    #[sea_orm(column_name = "EpisodeCount")]
+    pub episode_count: i32,
+
+    /// 所属媒体库 ID
+    #[sea_orm(column_name = "MediaLibraryId")]
+    pub media_library_id: i32,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[sea_orm(
+        belongs_to = "super::media_library::Entity",
+        from = "Column::MediaLibraryId",
+        to = "super::media_library::Column::Id"
+    )]
+    MediaLibrary,
+
+    /// 关联到 TvSeason（一对多）
+    #[sea_orm(has_many = "super::tv_season::Entity")]
+    TvSeason,
+
+    /// 关联到 TvEpisode（一对多）
+    #[sea_orm(has_many = "super::tv_episode::Entity")]
+    TvEpisode,
+}
+
+impl Related<super::media_library::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::MediaLibrary.def()
+    }
+}
+
+impl Related<super::tv_season::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TvSeason.def()
+    }
+}
+
+impl Related<super::tv_episode::Entity> for Entity {
+    fn to() -> RelationDef {
+        Relation::TvEpisode.def()
+    }
+}
+
+impl ActiveModelBehavior for ActiveModel {}
+
+impl Model {
+    /// 获取类型列表
+    pub fn get_genres(&self) -> Vec<String> {
+        self.genres
+            .as_ref()
+            .and_then(|s| serde_json::from_str(s).ok())
+            .unwrap_or_default()
+    }
+
+    /// 获取主演列表
+    pub fn get_actors(&self) -> Vec<String> {
+        self.actors
+            .as_ref()
+            .and_then(|s| serde_json::from_str(s).ok())
+            .unwrap_or_default()
+    }
+
+    /// 获取海报 URL 列表
+    pub fn get_poster_urls(&self) -> Vec<String> {
+        self.poster_urls
+            .as_ref()
+            .and_then(|s| serde_json::from_str(s).ok())
+            .unwrap_or_default()
+    }
+}
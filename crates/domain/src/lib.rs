@@ -13,7 +13,9 @@ pub use repository::UserRepository;
 // 重新导出领域服务
 pub use service::GameDomainService;
 pub use service::MangaDomainService;
+pub use service::MangaSeriesDomainService;
 pub use service::MediaLibraryDomainService;
+pub use service::MovieDomainService;
 
 // 重新导出聚合根
 pub use aggregate::MediaLibraryAggregate;
@@ -30,6 +30,12 @@ pub trait MangaRepository: Send + Sync {
     /// 根据媒体库 ID 查询所有漫画
     async fn find_by_media_library_id(&self, media_library_id: i32) -> anyhow::Result<Vec<MangaModel>>;
 
+    /// 根据系列 ID 查询所有漫画
+    async fn find_by_series_id(&self, series_id: i32) -> anyhow::Result<Vec<MangaModel>>;
+
+    /// 查询存在损坏页面的漫画
+    async fn find_with_bad_pages(&self) -> anyhow::Result<Vec<MangaModel>>;
+
     /// 删除漫画
     async fn delete(&self, id: i32) -> anyhow::Result<()>;
 
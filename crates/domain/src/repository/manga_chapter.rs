@@ -10,6 +10,9 @@ pub trait MangaChapterRepository: Send + Sync {
     /// 根据漫画 ID 查询所有章节
     async fn find_by_manga_id(&self, manga_id: i32) -> anyhow::Result<Vec<MangaChapterModel>>;
 
+    /// 查询存在损坏页面的章节
+    async fn find_with_bad_pages(&self) -> anyhow::Result<Vec<MangaChapterModel>>;
+
     /// 创建新章节
     async fn create(&self, chapter: MangaChapterModel) -> anyhow::Result<MangaChapterModel>;
 
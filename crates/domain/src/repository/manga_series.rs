@@ -0,0 +1,35 @@
+use crate::entity::manga_series::Model as MangaSeriesModel;
+use async_trait::async_trait;
+
+/// 漫画系列仓储接口
+/// 定义所有漫画系列数据访问操作的抽象接口
+#[async_trait]
+pub trait MangaSeriesRepository: Send + Sync {
+    /// 根据 ID 查询系列
+    async fn find_by_id(&self, id: i32) -> anyhow::Result<Option<MangaSeriesModel>>;
+
+    /// 分页查询系列（按标题排序）
+    async fn find_by_paged(
+        &self,
+        page_size: i32,
+        page_index: i32,
+    ) -> anyhow::Result<Option<Vec<MangaSeriesModel>>>;
+
+    /// 根据媒体库 ID 查询所有系列
+    async fn find_by_media_library_id(&self, media_library_id: i32) -> anyhow::Result<Vec<MangaSeriesModel>>;
+
+    /// 创建新系列
+    async fn create(&self, series: MangaSeriesModel) -> anyhow::Result<MangaSeriesModel>;
+
+    /// 更新系列
+    async fn update(&self, series: MangaSeriesModel) -> anyhow::Result<MangaSeriesModel>;
+
+    /// 删除系列
+    async fn delete(&self, id: i32) -> anyhow::Result<()>;
+
+    /// 删除媒体库下的所有系列
+    async fn delete_by_media_library_id(&self, media_library_id: i32) -> anyhow::Result<()>;
+
+    /// 获取所有系列的总数
+    async fn count_all(&self) -> anyhow::Result<i32>;
+}
@@ -4,15 +4,35 @@
 pub mod game;
 pub mod manga;
 pub mod manga_chapter;
+pub mod manga_series;
 pub mod media_library;
 pub mod movie;
+pub mod movie_collection;
+pub mod movie_credit;
+pub mod movie_extra;
+pub mod movie_play_state;
+pub mod movie_source;
+pub mod movie_subtitle;
+pub mod movie_track;
+pub mod person;
 pub mod photo;
+pub mod tv_series;
 pub mod user;
 
 pub use game::GameRepository;
 pub use manga::MangaRepository;
 pub use manga_chapter::MangaChapterRepository;
+pub use manga_series::MangaSeriesRepository;
 pub use media_library::MediaLibraryRepository;
 pub use movie::MovieRepository;
+pub use movie_collection::MovieCollectionRepository;
+pub use movie_credit::MovieCreditRepository;
+pub use movie_extra::MovieExtraRepository;
+pub use movie_play_state::MoviePlayStateRepository;
+pub use movie_source::MovieSourceRepository;
+pub use movie_subtitle::MovieSubtitleRepository;
+pub use movie_track::MovieTrackRepository;
+pub use person::PersonRepository;
 pub use photo::{PhotoRepository, PhotoExifRepository, PhotoAlbumRepository, PhotoAlbumItemRepository};
+pub use tv_series::{TvEpisodeRepository, TvSeasonRepository, TvSeriesRepository};
 pub use user::UserRepository;
\ No newline at end of file
@@ -1,5 +1,7 @@
 use crate::entity::movie::Model as MovieModel;
+use crate::value_object::{MovieFacets, MovieFilter};
 use async_trait::async_trait;
+use std::collections::HashMap;
 
 /// 电影仓储接口
 /// 定义所有电影数据访问操作的抽象接口
@@ -15,6 +17,17 @@ pub trait MovieRepository: Send + Sync {
         page_index: i32,
     ) -> anyhow::Result<Option<Vec<MovieModel>>>;
 
+    /// 按条件筛选、排序并分页查询电影，返回当前页和符合条件的总数
+    async fn find_by_filter(
+        &self,
+        filter: &MovieFilter,
+        page_size: i32,
+        page_index: i32,
+    ) -> anyhow::Result<(Vec<MovieModel>, i32)>;
+
+    /// 查询可选的筛选值（类型和年份），指定媒体库时只统计该媒体库的电影
+    async fn find_facets(&self, media_library_id: Option<i32>) -> anyhow::Result<MovieFacets>;
+
     /// 创建新电影
     async fn create(&self, movie: MovieModel) -> anyhow::Result<MovieModel>;
 
@@ -27,9 +40,15 @@ pub trait MovieRepository: Send + Sync {
     /// 批量更新电影（使用事务）
     async fn update_batch(&self, movies: Vec<MovieModel>) -> anyhow::Result<Vec<MovieModel>>;
 
+    /// 根据 ID 批量查询电影
+    async fn find_by_ids(&self, ids: Vec<i32>) -> anyhow::Result<Vec<MovieModel>>;
+
     /// 根据媒体库 ID 查询所有电影
     async fn find_by_media_library_id(&self, media_library_id: i32) -> anyhow::Result<Vec<MovieModel>>;
 
+    /// 根据系列 ID 查询所有电影
+    async fn find_by_collection_id(&self, collection_id: i32) -> anyhow::Result<Vec<MovieModel>>;
+
     /// 删除电影
     async fn delete(&self, id: i32) -> anyhow::Result<()>;
 
@@ -38,5 +57,7 @@ pub trait MovieRepository: Send + Sync {
 
     /// 根据媒体库 ID 获取电影数量
     async fn count_by_media_library_id(&self, media_library_id: i32) -> anyhow::Result<i32>;
-}
 
+    /// 按系列统计电影数量（Key = 系列 ID，不包含没有电影的系列）
+    async fn count_by_collection(&self) -> anyhow::Result<HashMap<i32, i32>>;
+}
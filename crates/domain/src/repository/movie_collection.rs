@@ -0,0 +1,18 @@
+use crate::entity::movie_collection::Model as MovieCollectionModel;
+use async_trait::async_trait;
+
+/// 电影系列仓储接口
+#[async_trait]
+pub trait MovieCollectionRepository: Send + Sync {
+    /// 根据 ID 查询系列
+    async fn find_by_id(&self, id: i32) -> anyhow::Result<Option<MovieCollectionModel>>;
+
+    /// 根据 TMDB 系列 ID 查询系列
+    async fn find_by_tmdb_id(&self, tmdb_id: i64) -> anyhow::Result<Option<MovieCollectionModel>>;
+
+    /// 查询所有系列（按名称排序）
+    async fn find_all(&self) -> anyhow::Result<Vec<MovieCollectionModel>>;
+
+    /// 保存系列（按 TMDB 系列 ID 新建或更新）
+    async fn upsert(&self, collection: MovieCollectionModel) -> anyhow::Result<MovieCollectionModel>;
+}
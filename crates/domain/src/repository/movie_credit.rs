@@ -0,0 +1,18 @@
+use crate::entity::movie_credit::Model as MovieCreditModel;
+use async_trait::async_trait;
+
+/// 电影演职人员仓储接口
+#[async_trait]
+pub trait MovieCreditRepository: Send + Sync {
+    /// 根据电影 ID 查询所有演职人员（按职责和排序）
+    async fn find_by_movie_id(&self, movie_id: i32) -> anyhow::Result<Vec<MovieCreditModel>>;
+
+    /// 根据人物 ID 查询参与的所有电影
+    async fn find_by_person_id(&self, person_id: i32) -> anyhow::Result<Vec<MovieCreditModel>>;
+
+    /// 批量创建演职人员
+    async fn create_batch(&self, credits: Vec<MovieCreditModel>) -> anyhow::Result<Vec<MovieCreditModel>>;
+
+    /// 删除电影的所有演职人员
+    async fn delete_by_movie_id(&self, movie_id: i32) -> anyhow::Result<()>;
+}
@@ -0,0 +1,18 @@
+use crate::entity::movie_extra::Model as MovieExtraModel;
+use async_trait::async_trait;
+
+/// 电影附加视频仓储接口
+#[async_trait]
+pub trait MovieExtraRepository: Send + Sync {
+    /// 根据 ID 查询附加视频
+    async fn find_by_id(&self, id: i32) -> anyhow::Result<Option<MovieExtraModel>>;
+
+    /// 根据电影 ID 查询所有附加视频（按类型和标题排序）
+    async fn find_by_movie_id(&self, movie_id: i32) -> anyhow::Result<Vec<MovieExtraModel>>;
+
+    /// 批量创建附加视频
+    async fn create_batch(&self, extras: Vec<MovieExtraModel>) -> anyhow::Result<Vec<MovieExtraModel>>;
+
+    /// 删除电影的所有附加视频
+    async fn delete_by_movie_id(&self, movie_id: i32) -> anyhow::Result<()>;
+}
@@ -0,0 +1,18 @@
+use crate::entity::movie_play_state::Model as MoviePlayStateModel;
+use async_trait::async_trait;
+
+/// 电影播放状态仓储接口
+#[async_trait]
+pub trait MoviePlayStateRepository: Send + Sync {
+    /// 查询用户对某部电影的播放状态
+    async fn find_by_user_and_movie(&self, user_id: i32, movie_id: i32) -> anyhow::Result<Option<MoviePlayStateModel>>;
+
+    /// 查询用户的所有播放状态（按最后播放时间从新到旧）
+    async fn find_by_user_id(&self, user_id: i32) -> anyhow::Result<Vec<MoviePlayStateModel>>;
+
+    /// 保存播放状态（按用户和电影 upsert，已有记录时覆盖）
+    async fn save(&self, state: MoviePlayStateModel) -> anyhow::Result<MoviePlayStateModel>;
+
+    /// 删除电影的所有播放状态
+    async fn delete_by_movie_id(&self, movie_id: i32) -> anyhow::Result<()>;
+}
@@ -0,0 +1,15 @@
+use crate::entity::movie_source::Model as MovieSourceModel;
+use async_trait::async_trait;
+
+/// 电影媒体源仓储接口
+#[async_trait]
+pub trait MovieSourceRepository: Send + Sync {
+    /// 根据电影 ID 查询所有媒体源（按版本和分段排序）
+    async fn find_by_movie_id(&self, movie_id: i32) -> anyhow::Result<Vec<MovieSourceModel>>;
+
+    /// 批量创建媒体源
+    async fn create_batch(&self, sources: Vec<MovieSourceModel>) -> anyhow::Result<Vec<MovieSourceModel>>;
+
+    /// 删除电影的所有媒体源
+    async fn delete_by_movie_id(&self, movie_id: i32) -> anyhow::Result<()>;
+}
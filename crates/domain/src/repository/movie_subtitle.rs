@@ -0,0 +1,18 @@
+use crate::entity::movie_subtitle::Model as MovieSubtitleModel;
+use async_trait::async_trait;
+
+/// 电影外挂字幕仓储接口
+#[async_trait]
+pub trait MovieSubtitleRepository: Send + Sync {
+    /// 根据 ID 查询字幕
+    async fn find_by_id(&self, id: i32) -> anyhow::Result<Option<MovieSubtitleModel>>;
+
+    /// 根据电影 ID 查询所有字幕
+    async fn find_by_movie_id(&self, movie_id: i32) -> anyhow::Result<Vec<MovieSubtitleModel>>;
+
+    /// 批量创建字幕
+    async fn create_batch(&self, subtitles: Vec<MovieSubtitleModel>) -> anyhow::Result<Vec<MovieSubtitleModel>>;
+
+    /// 删除电影的所有字幕
+    async fn delete_by_movie_id(&self, movie_id: i32) -> anyhow::Result<()>;
+}
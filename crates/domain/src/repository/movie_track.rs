@@ -0,0 +1,15 @@
+use crate::entity::movie_track::Model as MovieTrackModel;
+use async_trait::async_trait;
+
+/// 电影轨道仓储接口
+#[async_trait]
+pub trait MovieTrackRepository: Send + Sync {
+    /// 根据电影 ID 查询所有轨道（按轨道类型和编号排序）
+    async fn find_by_movie_id(&self, movie_id: i32) -> anyhow::Result<Vec<MovieTrackModel>>;
+
+    /// 批量创建轨道
+    async fn create_batch(&self, tracks: Vec<MovieTrackModel>) -> anyhow::Result<Vec<MovieTrackModel>>;
+
+    /// 删除电影的所有轨道
+    async fn delete_by_movie_id(&self, movie_id: i32) -> anyhow::Result<()>;
+}
@@ -0,0 +1,15 @@
+use crate::entity::person::Model as PersonModel;
+use async_trait::async_trait;
+
+/// 人物仓储接口
+#[async_trait]
+pub trait PersonRepository: Send + Sync {
+    /// 根据 ID 查询人物
+    async fn find_by_id(&self, id: i32) -> anyhow::Result<Option<PersonModel>>;
+
+    /// 根据 ID 批量查询人物
+    async fn find_by_ids(&self, ids: Vec<i32>) -> anyhow::Result<Vec<PersonModel>>;
+
+    /// 批量保存人物（有 TMDB ID 的按 ID、没有的按姓名新建或更新），按传入顺序返回
+    async fn upsert_batch(&self, persons: Vec<PersonModel>) -> anyhow::Result<Vec<PersonModel>>;
+}
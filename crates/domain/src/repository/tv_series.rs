@@ -0,0 +1,62 @@
+use crate::entity::tv_episode::Model as TvEpisodeModel;
+use crate::entity::tv_season::Model as TvSeasonModel;
+use crate::entity::tv_series::Model as TvSeriesModel;
+use async_trait::async_trait;
+
+/// 电视剧仓储接口
+#[async_trait]
+pub trait TvSeriesRepository: Send + Sync {
+    /// 根据 ID 查询电视剧
+    async fn find_by_id(&self, id: i32) -> anyhow::Result<Option<TvSeriesModel>>;
+
+    /// 分页查询电视剧
+    async fn find_by_paged(
+        &self,
+        page_size: i32,
+        page_index: i32,
+    ) -> anyhow::Result<Option<Vec<TvSeriesModel>>>;
+
+    /// 根据媒体库 ID 查询所有电视剧
+    async fn find_by_media_library_id(&self, media_library_id: i32) -> anyhow::Result<Vec<TvSeriesModel>>;
+
+    /// 批量创建电视剧
+    async fn create_batch(&self, series: Vec<TvSeriesModel>) -> anyhow::Result<Vec<TvSeriesModel>>;
+
+    /// 删除电视剧
+    async fn delete(&self, id: i32) -> anyhow::Result<()>;
+
+    /// 获取所有电视剧的总数
+    async fn count_all(&self) -> anyhow::Result<i32>;
+}
+
+/// 电视剧季仓储接口
+#[async_trait]
+pub trait TvSeasonRepository: Send + Sync {
+    /// 根据电视剧 ID 查询所有季（按季号排序）
+    async fn find_by_series_id(&self, series_id: i32) -> anyhow::Result<Vec<TvSeasonModel>>;
+
+    /// 批量创建季
+    async fn create_batch(&self, seasons: Vec<TvSeasonModel>) -> anyhow::Result<Vec<TvSeasonModel>>;
+
+    /// 删除电视剧的所有季
+    async fn delete_by_series_id(&self, series_id: i32) -> anyhow::Result<()>;
+}
+
+/// 电视剧单集仓储接口
+#[async_trait]
+pub trait TvEpisodeRepository: Send + Sync {
+    /// 根据 ID 查询单集
+    async fn find_by_id(&self, id: i32) -> anyhow::Result<Option<TvEpisodeModel>>;
+
+    /// 根据季 ID 查询所有单集（按集号排序）
+    async fn find_by_season_id(&self, season_id: i32) -> anyhow::Result<Vec<TvEpisodeModel>>;
+
+    /// 根据电视剧 ID 查询所有单集（按季号、集号排序）
+    async fn find_by_series_id(&self, series_id: i32) -> anyhow::Result<Vec<TvEpisodeModel>>;
+
+    /// 批量创建单集
+    async fn create_batch(&self, episodes: Vec<TvEpisodeModel>) -> anyhow::Result<Vec<TvEpisodeModel>>;
+
+    /// 删除电视剧的所有单集
+    async fn delete_by_series_id(&self, series_id: i32) -> anyhow::Result<()>;
+}
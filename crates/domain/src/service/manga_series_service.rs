@@ -0,0 +1,137 @@
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// 卷号匹配规则（按优先级排列），`num` 分组为卷号
+static VOLUME_PATTERNS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
+    [
+        // 第3卷 / 第03巻 / 第1册
+        r"第\s*(?P<num>\d+(?:\.\d+)?)\s*[卷巻册冊]",
+        // v01 / Vol.1 / Vol 1 / Volume 1
+        r"(?i)(?:^|[\s_\-.(（])(?:vol(?:ume)?\.?|v)\s*(?P<num>\d+(?:\.\d+)?)\b",
+        // #1
+        r"#\s*(?P<num>\d+(?:\.\d+)?)\b",
+        // 结尾的编号：Title 01 / Title - 01 / Title (01)
+        r"[\s_\-]\s*[(（]?(?P<num>\d{1,3})[)）]?\s*$",
+    ]
+    .iter()
+    .map(|pattern| Regex::new(pattern).expect("invalid volume pattern"))
+    .collect()
+});
+
+/// 漫画系列领域服务
+///
+/// 职责：
+/// - 从漫画标题中解析系列名和卷号
+/// - 生成用于分组的规范化系列标题
+pub struct MangaSeriesDomainService;
+
+impl MangaSeriesDomainService {
+    /// 从漫画标题中解析系列名和卷号
+    ///
+    /// 业务规则：
+    /// - 识别 `v01`、`Vol.1`、`Volume 1`、`第1卷`、`#1` 以及结尾的 1~3 位编号
+    /// - 卷号之后的内容（如 `(Digital)`）会被丢弃
+    /// - 4 位数字视为年份，不作为卷号
+    /// - 如果卷号之前没有内容，系列名使用完整标题
+    ///
+    /// # 参数
+    /// - `title`: 漫画标题（通常来自 `MangaDomainService::extract_title_from_path`）
+    ///
+    /// # 返回
+    /// - `(String, Option<f32>)` - 系列名和卷号
+    pub fn parse_series_title(title: &str) -> (String, Option<f32>) {
+        let title = title.trim();
+
+        for pattern in VOLUME_PATTERNS.iter() {
+            let Some(captures) = pattern.captures_iter(title).last() else {
+                continue;
+            };
+            let (Some(whole), Some(num)) = (captures.get(0), captures.name("num")) else {
+                continue;
+            };
+            let Ok(volume) = num.as_str().parse::<f32>() else {
+                continue;
+            };
+
+            let series_title = title[..whole.start()]
+                .trim_end_matches(|c: char| c.is_whitespace() || "-_.:：~～(（,，".contains(c))
+                .trim();
+
+            let series_title = if series_title.is_empty() { title } else { series_title };
+            return (series_title.to_string(), Some(volume));
+        }
+
+        (title.to_string(), None)
+    }
+
+    /// 生成规范化的系列标题，用于判断两本漫画是否属于同一系列
+    ///
+    /// 业务规则：
+    /// - 全角字符转换为半角
+    /// - 忽略大小写
+    /// - 只保留字母和数字（包括中日韩文字），忽略空格和标点
+    pub fn normalize_title(title: &str) -> String {
+        title
+            .chars()
+            .map(|c| match c {
+                // 全角 ASCII 字符（！ ~ ～）转换为半角
+                '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+                _ => c,
+            })
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(|c| c.to_lowercase())
+            .collect()
+    }
+
+    /// 验证系列标题
+    ///
+    /// 业务规则：
+    /// - 标题不能为空
+    /// - 标题长度不能超过 200 个字符
+    pub fn validate_title(title: &str) -> anyhow::Result<()> {
+        if title.trim().is_empty() {
+            return Err(anyhow::anyhow!("系列标题不能为空"));
+        }
+        if title.chars().count() > 200 {
+            return Err(anyhow::anyhow!("系列标题过长（最多 200 个字符）"));
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_series_title() {
+        assert_eq!(MangaSeriesDomainService::parse_series_title("One Piece v01"), ("One Piece".to_string(), Some(1.0)));
+        assert_eq!(MangaSeriesDomainService::parse_series_title("One Piece Vol.2 (Digital)"), ("One Piece".to_string(), Some(2.0)));
+        assert_eq!(MangaSeriesDomainService::parse_series_title("Berserk Volume 10"), ("Berserk".to_string(), Some(10.0)));
+        assert_eq!(MangaSeriesDomainService::parse_series_title("进击的巨人 第3卷"), ("进击的巨人".to_string(), Some(3.0)));
+        assert_eq!(MangaSeriesDomainService::parse_series_title("Berserk - 05"), ("Berserk".to_string(), Some(5.0)));
+        assert_eq!(MangaSeriesDomainService::parse_series_title("Berserk (12)"), ("Berserk".to_string(), Some(12.0)));
+        assert_eq!(MangaSeriesDomainService::parse_series_title("Title v10.5"), ("Title".to_string(), Some(10.5)));
+    }
+
+    #[test]
+    fn test_parse_series_title_without_volume() {
+        assert_eq!(MangaSeriesDomainService::parse_series_title("Naruto"), ("Naruto".to_string(), None));
+        // 4 位数字视为年份
+        assert_eq!(MangaSeriesDomainService::parse_series_title("Title 2023"), ("Title 2023".to_string(), None));
+        // 单词中的 v 不是卷号
+        assert_eq!(MangaSeriesDomainService::parse_series_title("Love2"), ("Love2".to_string(), None));
+    }
+
+    #[test]
+    fn test_normalize_title() {
+        assert_eq!(MangaSeriesDomainService::normalize_title("One-Piece"), "onepiece");
+        assert_eq!(MangaSeriesDomainService::normalize_title("ＯＮＥ　ＰＩＥＣＥ"), "onepiece");
+        let (first, _) = MangaSeriesDomainService::parse_series_title("One Piece v01");
+        let (second, _) = MangaSeriesDomainService::parse_series_title("one piece - 02");
+        assert_eq!(
+            MangaSeriesDomainService::normalize_title(&first),
+            MangaSeriesDomainService::normalize_title(&second)
+        );
+    }
+}
@@ -176,6 +176,71 @@ impl MangaDomainService {
         }
     }
 
+    /// 判断图片文件名是否像汉化组/扫图组的声明页、招募页或广告页
+    ///
+    /// 业务规则：
+    /// - 只检查文件名（不含扩展名），忽略大小写
+    /// - 命中关键词的页面不参与自动封面选择
+    pub fn is_credit_page_name(file_name: &str) -> bool {
+        let stem = std::path::Path::new(file_name)
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or(file_name)
+            .to_lowercase();
+
+        let credit_keywords = [
+            "credit", "scanlat", "recruit", "staff", "thanks",
+            "advert", "banner", "promo", "discord", "patreon",
+            "汉化", "漢化", "翻译", "翻譯", "招募", "嵌字", "广告", "廣告", "声明", "聲明",
+        ];
+
+        credit_keywords.iter().any(|keyword| stem.contains(keyword))
+    }
+
+    /// 判断图片文件名是否明确标记为封面
+    ///
+    /// 业务规则：
+    /// - 文件名（不含扩展名）包含 cover / 封面 / 表紙，忽略大小写
+    pub fn is_cover_page_name(file_name: &str) -> bool {
+        let stem = std::path::Path::new(file_name)
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or(file_name)
+            .to_lowercase();
+
+        ["cover", "封面", "表紙", "表纸"].iter().any(|keyword| stem.contains(keyword))
+    }
+
+    /// 生成手动封面标记
+    ///
+    /// 手动封面以 API 路径的形式保存在 `Manga.cover` / `MangaChapter.cover` 中：
+    /// - 单文件夹漫画：`/manga/{manga_id}/images/{page_index}`
+    /// - 章节中的某一页：`/manga_chapter/{manga_id}/{chapter_id}/images/{page_index}`
+    pub fn cover_page_marker(manga_id: i32, chapter_id: Option<i32>, page_index: i32) -> String {
+        match chapter_id {
+            Some(chapter_id) => format!("/manga_chapter/{}/{}/images/{}", manga_id, chapter_id, page_index),
+            None => format!("/manga/{}/images/{}", manga_id, page_index),
+        }
+    }
+
+    /// 解析手动封面标记
+    ///
+    /// # 返回
+    /// - `Some((chapter_id, page_index))` - 封面指向的章节（单文件夹漫画为 None）和页码
+    /// - `None` - 不是手动封面标记（自动封面或文件系统路径）
+    pub fn parse_cover_page_marker(cover: &str) -> Option<(Option<i32>, i32)> {
+        let cover = cover.strip_prefix("/api").unwrap_or(cover);
+        let segments: Vec<&str> = cover.trim_start_matches('/').split('/').collect();
+
+        match segments.as_slice() {
+            ["manga", _manga_id, "images", index] => Some((None, index.parse().ok()?)),
+            ["manga_chapter", _manga_id, chapter_id, "images", index] => {
+                Some((Some(chapter_id.parse().ok()?), index.parse().ok()?))
+            }
+            _ => None,
+        }
+    }
+
     /// 验证漫画类型
     /// 
     /// 业务规则：
@@ -191,4 +256,46 @@ impl MangaDomainService {
         }
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_credit_page_name() {
+        assert!(MangaDomainService::is_credit_page_name("999_credits.png"));
+        assert!(MangaDomainService::is_credit_page_name("ScanlatorRecruit.jpg"));
+        assert!(MangaDomainService::is_credit_page_name("00_汉化组招募.jpg"));
+
+        assert!(!MangaDomainService::is_credit_page_name("001.jpg"));
+        assert!(!MangaDomainService::is_credit_page_name("cover.jpg"));
+    }
+
+    #[test]
+    fn test_is_cover_page_name() {
+        assert!(MangaDomainService::is_cover_page_name("Cover.jpg"));
+        assert!(MangaDomainService::is_cover_page_name("00_封面.png"));
+        assert!(!MangaDomainService::is_cover_page_name("001.jpg"));
+    }
+
+    #[test]
+    fn test_cover_page_marker_round_trip() {
+        let marker = MangaDomainService::cover_page_marker(12, None, 3);
+        assert_eq!(marker, "/manga/12/images/3");
+        assert_eq!(MangaDomainService::parse_cover_page_marker(&marker), Some((None, 3)));
+
+        let marker = MangaDomainService::cover_page_marker(12, Some(5), 0);
+        assert_eq!(marker, "/manga_chapter/12/5/images/0");
+        assert_eq!(MangaDomainService::parse_cover_page_marker(&marker), Some((Some(5), 0)));
+
+        // 带 /api 前缀也能解析
+        assert_eq!(MangaDomainService::parse_cover_page_marker("/api/manga/12/images/7"), Some((None, 7)));
+
+        // 自动封面标记和文件系统路径不是手动封面
+        assert_eq!(MangaDomainService::parse_cover_page_marker("/manga/12/cover"), None);
+        assert_eq!(MangaDomainService::parse_cover_page_marker("/manga_chapter/12/cover"), None);
+        assert_eq!(MangaDomainService::parse_cover_page_marker("/manga/12/chapter/5/cover"), None);
+        assert_eq!(MangaDomainService::parse_cover_page_marker("D:/manga/cover.jpg"), None);
+    }
+}
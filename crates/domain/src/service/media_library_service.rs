@@ -53,7 +53,7 @@ impl MediaLibraryDomainService {
     /// - 媒体类型必须是有效的类型
     pub fn validate_media_type(media_type: &str) -> anyhow::Result<()> {
         let valid_types = vec![
-            "电影", "视频", "音乐", "电视节目", "有声读物", "书籍",
+            "电影", "电视剧", "动漫", "视频", "音乐", "电视节目", "有声读物", "书籍",
             "游戏", "漫画", "音乐视频", "照片", "混合内容"
         ];
         if !valid_types.contains(&media_type) {
@@ -99,9 +99,11 @@ impl MediaLibraryDomainService {
     /// - "漫画" 类型支持扫描（扫描图片文件夹）
     /// - "游戏" 类型支持扫描（使用 gamebox 库扫描游戏）
     /// - "电影" 类型支持扫描（扫描视频文件并从 TMDB 获取元数据）
+    /// - "电视剧" 类型支持扫描（按剧集 / 季文件夹和文件名中的集号组织单集）
+    /// - "动漫" 类型支持扫描（解析字幕组命名和绝对集号，与电视剧共用剧集表）
     /// - "照片" 类型支持扫描（扫描照片文件夹并提取 EXIF 信息）
     pub fn is_scannable_media_type(media_type: &str) -> bool {
-        matches!(media_type, "漫画" | "游戏" | "电影" | "照片")
+        matches!(media_type, "漫画" | "游戏" | "电影" | "电视剧" | "动漫" | "照片")
     }
 
     /// 生成媒体库封面 URL
@@ -159,6 +161,8 @@ mod tests {
         assert!(MediaLibraryDomainService::validate_media_type("音乐").is_ok());
         assert!(MediaLibraryDomainService::validate_media_type("电影").is_ok());
         assert!(MediaLibraryDomainService::validate_media_type("视频").is_ok());
+        assert!(MediaLibraryDomainService::validate_media_type("电视剧").is_ok());
+        assert!(MediaLibraryDomainService::validate_media_type("动漫").is_ok());
 
         // 无效类型
         assert!(MediaLibraryDomainService::validate_media_type("invalid").is_err());
@@ -180,6 +184,8 @@ mod tests {
         assert!(MediaLibraryDomainService::is_scannable_media_type("游戏"));
         assert!(MediaLibraryDomainService::is_scannable_media_type("电影"));
         assert!(MediaLibraryDomainService::is_scannable_media_type("照片"));
+        assert!(MediaLibraryDomainService::is_scannable_media_type("电视剧"));
+        assert!(MediaLibraryDomainService::is_scannable_media_type("动漫"));
 
         // 不支持扫描的类型
         assert!(!MediaLibraryDomainService::is_scannable_media_type("音乐"));
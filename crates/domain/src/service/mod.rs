@@ -12,9 +12,13 @@
 
 pub mod game_service;
 pub mod manga_service;
+pub mod manga_series_service;
 pub mod media_library_service;
+pub mod movie_service;
 
 pub use game_service::GameDomainService;
 pub use manga_service::MangaDomainService;
+pub use manga_series_service::MangaSeriesDomainService;
 pub use media_library_service::MediaLibraryDomainService;
+pub use movie_service::MovieDomainService;
 
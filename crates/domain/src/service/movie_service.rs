@@ -0,0 +1,103 @@
+use crate::entity::movie_track::Model as MovieTrackModel;
+
+/// 浏览器普遍支持的容器格式（按扩展名）
+const BROWSER_CONTAINERS: &[&str] = &["mp4", "m4v", "mov", "webm"];
+
+/// 浏览器普遍支持的视频编码
+const BROWSER_VIDEO_CODECS: &[&str] = &["h264", "vp8", "vp9", "av1"];
+
+/// 浏览器普遍支持的音频编码
+const BROWSER_AUDIO_CODECS: &[&str] = &["aac", "mp3", "opus", "vorbis", "flac"];
+
+/// 电影领域服务
+///
+/// 职责：
+/// - 根据容器和轨道信息判断视频能否在浏览器中直接播放
+pub struct MovieDomainService;
+
+impl MovieDomainService {
+    /// 判断视频能否在浏览器中直接播放（无需转码）
+    ///
+    /// 业务规则：
+    /// - 容器必须是 MP4 / MOV / WebM
+    /// - 必须探测到视频轨道，且第一条视频轨道的编码为 H.264 / VP8 / VP9 / AV1
+    /// - 有音频轨道时，播放的音频轨道（默认轨道，没有则取第一条）编码必须为 AAC / MP3 / Opus / Vorbis / FLAC
+    /// - 字幕轨道不影响判断（字幕由客户端单独加载）
+    ///
+    /// # 参数
+    /// - `extension`: 文件扩展名
+    /// - `tracks`: 电影的所有轨道
+    pub fn can_direct_play(extension: Option<&str>, tracks: &[MovieTrackModel]) -> bool {
+        let Some(extension) = extension else {
+            return false;
+        };
+        if !BROWSER_CONTAINERS.contains(&extension.to_lowercase().as_str()) {
+            return false;
+        }
+
+        let Some(video) = tracks.iter().find(|t| t.is_video()) else {
+            return false;
+        };
+        if !BROWSER_VIDEO_CODECS.contains(&video.codec.as_str()) {
+            return false;
+        }
+
+        let audio = tracks
+            .iter()
+            .find(|t| t.is_audio() && t.is_default)
+            .or_else(|| tracks.iter().find(|t| t.is_audio()));
+        audio.is_none_or(|audio| BROWSER_AUDIO_CODECS.contains(&audio.codec.as_str()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::entity::movie_track::{TRACK_TYPE_AUDIO, TRACK_TYPE_SUBTITLE, TRACK_TYPE_VIDEO};
+
+    fn track(track_type: &str, codec: &str, is_default: bool) -> MovieTrackModel {
+        MovieTrackModel {
+            id: 0,
+            movie_id: 1,
+            track_type: track_type.to_string(),
+            track_index: 0,
+            codec: codec.to_string(),
+            language: None,
+            title: None,
+            is_default,
+            is_forced: false,
+            width: None,
+            height: None,
+            frame_rate: None,
+            is_hdr: false,
+            bit_rate: None,
+            channels: None,
+            sample_rate: None,
+        }
+    }
+
+    #[test]
+    fn test_can_direct_play() {
+        let h264_aac = vec![
+            track(TRACK_TYPE_VIDEO, "h264", true),
+            track(TRACK_TYPE_AUDIO, "aac", true),
+            track(TRACK_TYPE_SUBTITLE, "mov_text", false),
+        ];
+        assert!(MovieDomainService::can_direct_play(Some("MP4"), &h264_aac));
+        // 同样的编码放在 MKV 中需要转封装
+        assert!(!MovieDomainService::can_direct_play(Some("mkv"), &h264_aac));
+        // 没有探测到轨道时无法判断
+        assert!(!MovieDomainService::can_direct_play(Some("mp4"), &[]));
+
+        let hevc = vec![track(TRACK_TYPE_VIDEO, "hevc", true)];
+        assert!(!MovieDomainService::can_direct_play(Some("mp4"), &hevc));
+
+        // 默认音轨为 AC-3，即使还有 AAC 音轨也不能直接播放
+        let ac3_default = vec![
+            track(TRACK_TYPE_VIDEO, "h264", true),
+            track(TRACK_TYPE_AUDIO, "aac", false),
+            track(TRACK_TYPE_AUDIO, "ac3", true),
+        ];
+        assert!(!MovieDomainService::can_direct_play(Some("mp4"), &ac3_default));
+    }
+}
@@ -41,3 +41,103 @@ impl JwtClaims {
     }
 }
 
+
+/// 电影分辨率档位（按宽或高判断，兼容宽银幕裁切后的高度）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResolutionClass {
+    /// 标清（低于 720p）
+    Sd,
+    /// 高清（720p / 1080p）
+    Hd,
+    /// 超高清（4K）
+    Uhd,
+}
+
+impl ResolutionClass {
+    /// 高清的最小宽度和高度（满足其一即可）
+    pub const HD_MIN_WIDTH: i32 = 1200;
+    pub const HD_MIN_HEIGHT: i32 = 700;
+    /// 超高清的最小宽度和高度（满足其一即可）
+    pub const UHD_MIN_WIDTH: i32 = 3200;
+    pub const UHD_MIN_HEIGHT: i32 = 1800;
+
+    /// 解析查询参数：sd / hd / 4k（也接受 uhd）
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "sd" => Some(ResolutionClass::Sd),
+            "hd" => Some(ResolutionClass::Hd),
+            "4k" | "uhd" => Some(ResolutionClass::Uhd),
+            _ => None,
+        }
+    }
+}
+
+/// 电影列表排序字段
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MovieSortKey {
+    /// 标题
+    #[default]
+    Title,
+    /// 上映日期
+    ReleaseDate,
+    /// 评分
+    Rating,
+    /// 入库时间
+    DateAdded,
+    /// 文件大小
+    FileSize,
+    /// 时长
+    Duration,
+}
+
+impl MovieSortKey {
+    /// 解析查询参数：title / release_date / rating / date_added / file_size / duration
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "title" => Some(MovieSortKey::Title),
+            "release_date" => Some(MovieSortKey::ReleaseDate),
+            "rating" => Some(MovieSortKey::Rating),
+            "date_added" => Some(MovieSortKey::DateAdded),
+            "file_size" => Some(MovieSortKey::FileSize),
+            "duration" => Some(MovieSortKey::Duration),
+            _ => None,
+        }
+    }
+
+    /// 未指定排序方向时的默认方向：标题升序，其余降序（最新、最高、最大的在前）
+    pub fn default_descending(&self) -> bool {
+        *self != MovieSortKey::Title
+    }
+}
+
+/// 电影列表筛选条件（未设置的条件不参与筛选）
+#[derive(Debug, Clone, Default)]
+pub struct MovieFilter {
+    /// 类型（精确匹配类型列表中的一项）
+    pub genre: Option<String>,
+    /// 上映年份下限（包含）
+    pub year_from: Option<i32>,
+    /// 上映年份上限（包含）
+    pub year_to: Option<i32>,
+    /// 最低评分
+    pub min_rating: Option<f32>,
+    /// 分辨率档位
+    pub resolution: Option<ResolutionClass>,
+    /// 演员或导演姓名（模糊匹配）
+    pub person: Option<String>,
+    /// 所属媒体库 ID
+    pub media_library_id: Option<i32>,
+    /// 排序字段
+    pub sort: MovieSortKey,
+    /// 是否降序
+    pub descending: bool,
+}
+
+/// 电影列表的可选筛选值
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MovieFacets {
+    /// 所有出现过的类型（按名称排序）
+    pub genres: Vec<String>,
+    /// 所有出现过的上映年份（从新到旧）
+    pub years: Vec<i32>,
+}
@@ -0,0 +1,375 @@
+use chrono::{Datelike, Timelike};
+use std::path::Path;
+use tokio::io::{AsyncReadExt, AsyncWrite, AsyncWriteExt};
+
+/// 单次从文件读取的块大小
+const CHUNK_SIZE: usize = 64 * 1024;
+
+const LOCAL_FILE_HEADER_SIGNATURE: u32 = 0x0403_4b50;
+const DATA_DESCRIPTOR_SIGNATURE: u32 = 0x0807_4b50;
+const CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x0201_4b50;
+const END_OF_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x0605_4b50;
+
+/// 通用标志位：bit 3 = 大小和 CRC 写在数据之后，bit 11 = 文件名使用 UTF-8
+const GENERAL_PURPOSE_FLAGS: u16 = 0x0008 | 0x0800;
+/// ZIP 2.0
+const ZIP_VERSION: u16 = 20;
+
+/// 本地文件头、数据描述符和中央目录条目的固定长度（不含文件名）
+const LOCAL_FILE_HEADER_LEN: u64 = 30;
+const DATA_DESCRIPTOR_LEN: u64 = 16;
+const CENTRAL_DIRECTORY_HEADER_LEN: u64 = 46;
+/// 中央目录结束记录的长度
+const END_OF_CENTRAL_DIRECTORY_LEN: u64 = 22;
+
+/// 在开始写入前检查压缩包是否超出不支持 ZIP64 的限制
+///
+/// `entries` 为 (条目名, 数据大小)。响应头发出后写入失败只能中断连接，
+/// 因此导出前先按 [`CbzWriter`] 的布局算出压缩包大小，提前返回错误
+pub fn check_archive_limits(entries: &[(&str, u64)]) -> anyhow::Result<()> {
+    if entries.len() > u16::MAX as usize {
+        return Err(anyhow::anyhow!(
+            "Too many entries for a ZIP archive without ZIP64: {} (max {})",
+            entries.len(),
+            u16::MAX
+        ));
+    }
+
+    let archive_size = archive_size(entries);
+    // 中央目录结束记录中的偏移量和大小都是 32 位
+    if archive_size - END_OF_CENTRAL_DIRECTORY_LEN > u32::MAX as u64 {
+        return Err(anyhow::anyhow!(
+            "Archive too large for a ZIP archive without ZIP64: {} bytes (max 4GB)",
+            archive_size
+        ));
+    }
+
+    Ok(())
+}
+
+/// 按 [`CbzWriter`] 的布局计算压缩包的总字节数
+fn archive_size(entries: &[(&str, u64)]) -> u64 {
+    entries
+        .iter()
+        .map(|(name, size)| {
+            LOCAL_FILE_HEADER_LEN + DATA_DESCRIPTOR_LEN + CENTRAL_DIRECTORY_HEADER_LEN + 2 * name.len() as u64 + size
+        })
+        .sum::<u64>()
+        + END_OF_CENTRAL_DIRECTORY_LEN
+}
+
+/// 已写入条目的中央目录信息
+struct CentralEntry {
+    name: String,
+    crc32: u32,
+    size: u32,
+    local_header_offset: u32,
+}
+
+/// 流式 CBZ（ZIP）写入器
+///
+/// 漫画图片本身已经是压缩格式，因此所有条目都以 Stored（不压缩）方式写入。
+/// 每个条目使用数据描述符（Data Descriptor）在数据之后记录 CRC 和大小，
+/// 写入过程中只需要一个固定大小的缓冲区，不会把整个压缩包加载到内存。
+///
+/// # 限制
+/// - 不支持 ZIP64：单个文件和整个压缩包都不能超过 4GB，条目数不能超过 65535
+pub struct CbzWriter<W> {
+    writer: W,
+    offset: u64,
+    entries: Vec<CentralEntry>,
+    dos_time: u16,
+    dos_date: u16,
+}
+
+impl<W: AsyncWrite + Unpin> CbzWriter<W> {
+    /// 创建写入器，所有条目的修改时间为当前时间
+    pub fn new(writer: W) -> Self {
+        let now = chrono::Local::now();
+        let dos_time = ((now.hour() << 11) | (now.minute() << 5) | (now.second() / 2)) as u16;
+        let dos_date = (((now.year().clamp(1980, 2107) - 1980) as u32) << 9 | (now.month() << 5) | now.day()) as u16;
+
+        Self {
+            writer,
+            offset: 0,
+            entries: Vec::new(),
+            dos_time,
+            dos_date,
+        }
+    }
+
+    /// 写入内存中的数据作为一个条目（例如 ComicInfo.xml）
+    pub async fn add_bytes(&mut self, name: &str, data: &[u8]) -> anyhow::Result<()> {
+        let local_header_offset = self.begin_entry(name).await?;
+
+        let mut hasher = crc32fast::Hasher::new();
+        hasher.update(data);
+        self.write_all(data).await?;
+
+        self.end_entry(name, local_header_offset, hasher.finalize(), data.len() as u64).await
+    }
+
+    /// 以流的方式把磁盘文件写入为一个条目
+    pub async fn add_file(&mut self, name: &str, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let path = path.as_ref();
+        let mut file = tokio::fs::File::open(path).await
+            .map_err(|e| anyhow::anyhow!("Failed to open {}: {}", path.display(), e))?;
+
+        let local_header_offset = self.begin_entry(name).await?;
+
+        let mut hasher = crc32fast::Hasher::new();
+        let mut size: u64 = 0;
+        let mut buffer = vec![0u8; CHUNK_SIZE];
+
+        loop {
+            let read = file.read(&mut buffer).await
+                .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", path.display(), e))?;
+            if read == 0 {
+                break;
+            }
+
+            hasher.update(&buffer[..read]);
+            self.write_all(&buffer[..read]).await?;
+            size += read as u64;
+        }
+
+        self.end_entry(name, local_header_offset, hasher.finalize(), size).await
+    }
+
+    /// 写入中央目录并结束压缩包，返回底层写入器
+    pub async fn finish(mut self) -> anyhow::Result<W> {
+        let central_directory_offset = self.checked_offset()?;
+        let entry_count = u16::try_from(self.entries.len())
+            .map_err(|_| anyhow::anyhow!("Too many entries for a ZIP archive without ZIP64"))?;
+
+        let entries = std::mem::take(&mut self.entries);
+        for entry in &entries {
+            let mut header = Vec::with_capacity(46 + entry.name.len());
+            header.extend_from_slice(&CENTRAL_DIRECTORY_SIGNATURE.to_le_bytes());
+            header.extend_from_slice(&ZIP_VERSION.to_le_bytes()); // version made by
+            header.extend_from_slice(&ZIP_VERSION.to_le_bytes()); // version needed
+            header.extend_from_slice(&GENERAL_PURPOSE_FLAGS.to_le_bytes());
+            header.extend_from_slice(&0u16.to_le_bytes()); // method: stored
+            header.extend_from_slice(&self.dos_time.to_le_bytes());
+            header.extend_from_slice(&self.dos_date.to_le_bytes());
+            header.extend_from_slice(&entry.crc32.to_le_bytes());
+            header.extend_from_slice(&entry.size.to_le_bytes()); // compressed size
+            header.extend_from_slice(&entry.size.to_le_bytes()); // uncompressed size
+            header.extend_from_slice(&(entry.name.len() as u16).to_le_bytes());
+            header.extend_from_slice(&0u16.to_le_bytes()); // extra field length
+            header.extend_from_slice(&0u16.to_le_bytes()); // comment length
+            header.extend_from_slice(&0u16.to_le_bytes()); // disk number start
+            header.extend_from_slice(&0u16.to_le_bytes()); // internal attributes
+            header.extend_from_slice(&0u32.to_le_bytes()); // external attributes
+            header.extend_from_slice(&entry.local_header_offset.to_le_bytes());
+            header.extend_from_slice(entry.name.as_bytes());
+            self.write_all(&header).await?;
+        }
+
+        let central_directory_size = self.checked_offset()? - central_directory_offset;
+
+        let mut end = Vec::with_capacity(22);
+        end.extend_from_slice(&END_OF_CENTRAL_DIRECTORY_SIGNATURE.to_le_bytes());
+        end.extend_from_slice(&0u16.to_le_bytes()); // number of this disk
+        end.extend_from_slice(&0u16.to_le_bytes()); // disk with central directory
+        end.extend_from_slice(&entry_count.to_le_bytes());
+        end.extend_from_slice(&entry_count.to_le_bytes());
+        end.extend_from_slice(&central_directory_size.to_le_bytes());
+        end.extend_from_slice(&central_directory_offset.to_le_bytes());
+        end.extend_from_slice(&0u16.to_le_bytes()); // comment length
+        self.write_all(&end).await?;
+
+        self.writer.flush().await?;
+        Ok(self.writer)
+    }
+
+    /// 写入本地文件头，返回该文件头的偏移量
+    async fn begin_entry(&mut self, name: &str) -> anyhow::Result<u32> {
+        let local_header_offset = self.checked_offset()?;
+        let name_len = u16::try_from(name.len())
+            .map_err(|_| anyhow::anyhow!("Entry name too long: {}", name))?;
+
+        let mut header = Vec::with_capacity(30 + name.len());
+        header.extend_from_slice(&LOCAL_FILE_HEADER_SIGNATURE.to_le_bytes());
+        header.extend_from_slice(&ZIP_VERSION.to_le_bytes());
+        header.extend_from_slice(&GENERAL_PURPOSE_FLAGS.to_le_bytes());
+        header.extend_from_slice(&0u16.to_le_bytes()); // method: stored
+        header.extend_from_slice(&self.dos_time.to_le_bytes());
+        header.extend_from_slice(&self.dos_date.to_le_bytes());
+        header.extend_from_slice(&0u32.to_le_bytes()); // crc32，写在数据描述符中
+        header.extend_from_slice(&0u32.to_le_bytes()); // compressed size
+        header.extend_from_slice(&0u32.to_le_bytes()); // uncompressed size
+        header.extend_from_slice(&name_len.to_le_bytes());
+        header.extend_from_slice(&0u16.to_le_bytes()); // extra field length
+        header.extend_from_slice(name.as_bytes());
+        self.write_all(&header).await?;
+
+        Ok(local_header_offset)
+    }
+
+    /// 写入数据描述符并记录中央目录信息
+    async fn end_entry(&mut self, name: &str, local_header_offset: u32, crc32: u32, size: u64) -> anyhow::Result<()> {
+        let size = u32::try_from(size)
+            .map_err(|_| anyhow::anyhow!("Entry too large for a ZIP archive without ZIP64: {}", name))?;
+
+        let mut descriptor = Vec::with_capacity(16);
+        descriptor.extend_from_slice(&DATA_DESCRIPTOR_SIGNATURE.to_le_bytes());
+        descriptor.extend_from_slice(&crc32.to_le_bytes());
+        descriptor.extend_from_slice(&size.to_le_bytes()); // compressed size
+        descriptor.extend_from_slice(&size.to_le_bytes()); // uncompressed size
+        self.write_all(&descriptor).await?;
+
+        self.entries.push(CentralEntry {
+            name: name.to_string(),
+            crc32,
+            size,
+            local_header_offset,
+        });
+
+        Ok(())
+    }
+
+    async fn write_all(&mut self, data: &[u8]) -> anyhow::Result<()> {
+        self.writer.write_all(data).await?;
+        self.offset += data.len() as u64;
+        Ok(())
+    }
+
+    fn checked_offset(&self) -> anyhow::Result<u32> {
+        u32::try_from(self.offset)
+            .map_err(|_| anyhow::anyhow!("Archive too large for a ZIP archive without ZIP64"))
+    }
+}
+
+/// ComicInfo.xml 元数据（ComicRack 格式，主流阅读器均支持）
+#[derive(Debug, Clone, Default)]
+pub struct ComicInfo {
+    pub title: String,
+    pub series: Option<String>,
+    pub number: Option<String>,
+    pub summary: Option<String>,
+    pub writer: Option<String>,
+    pub genre: Option<String>,
+    pub page_count: usize,
+}
+
+impl ComicInfo {
+    /// 生成 ComicInfo.xml 内容
+    pub fn to_xml(&self) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"utf-8\"?>\n");
+        xml.push_str("<ComicInfo xmlns:xsd=\"http://www.w3.org/2001/XMLSchema\" xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\">\n");
+
+        let mut push_element = |tag: &str, value: &str| {
+            xml.push_str(&format!("  <{}>{}</{}>\n", tag, escape_xml(value), tag));
+        };
+
+        push_element("Title", &self.title);
+        if let Some(series) = &self.series {
+            push_element("Series", series);
+        }
+        if let Some(number) = &self.number {
+            push_element("Number", number);
+        }
+        if let Some(summary) = &self.summary {
+            push_element("Summary", summary);
+        }
+        if let Some(writer) = &self.writer {
+            push_element("Writer", writer);
+        }
+        if let Some(genre) = &self.genre {
+            push_element("Genre", genre);
+        }
+        push_element("PageCount", &self.page_count.to_string());
+
+        xml.push_str("</ComicInfo>\n");
+        xml
+    }
+}
+
+/// 转义 XML 特殊字符
+fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_u16(data: &[u8], offset: usize) -> u16 {
+        u16::from_le_bytes([data[offset], data[offset + 1]])
+    }
+
+    fn read_u32(data: &[u8], offset: usize) -> u32 {
+        u32::from_le_bytes([data[offset], data[offset + 1], data[offset + 2], data[offset + 3]])
+    }
+
+    #[tokio::test]
+    async fn test_cbz_writer_layout() {
+        let mut writer = CbzWriter::new(Vec::new());
+        writer.add_bytes("ComicInfo.xml", b"<ComicInfo/>").await.unwrap();
+        writer.add_bytes("0001.jpg", b"hello").await.unwrap();
+        let archive = writer.finish().await.unwrap();
+
+        // 第一个本地文件头
+        assert_eq!(read_u32(&archive, 0), LOCAL_FILE_HEADER_SIGNATURE);
+        assert_eq!(&archive[30..43], b"ComicInfo.xml");
+
+        // 结束记录位于末尾 22 字节
+        let end = archive.len() - 22;
+        assert_eq!(read_u32(&archive, end), END_OF_CENTRAL_DIRECTORY_SIGNATURE);
+        assert_eq!(read_u16(&archive, end + 10), 2);
+
+        // 中央目录中的第二个条目记录了正确的 CRC、大小和偏移量
+        let central_directory_offset = read_u32(&archive, end + 16) as usize;
+        assert_eq!(read_u32(&archive, central_directory_offset), CENTRAL_DIRECTORY_SIGNATURE);
+        let second = central_directory_offset + 46 + "ComicInfo.xml".len();
+        assert_eq!(read_u32(&archive, second), CENTRAL_DIRECTORY_SIGNATURE);
+        assert_eq!(read_u32(&archive, second + 16), crc32fast::hash(b"hello"));
+        assert_eq!(read_u32(&archive, second + 24), 5);
+
+        let local_offset = read_u32(&archive, second + 42) as usize;
+        assert_eq!(read_u32(&archive, local_offset), LOCAL_FILE_HEADER_SIGNATURE);
+        assert_eq!(&archive[local_offset + 30 + 8..local_offset + 30 + 8 + 5], b"hello");
+    }
+
+    #[tokio::test]
+    async fn test_check_archive_limits() {
+        let mut writer = CbzWriter::new(Vec::new());
+        writer.add_bytes("ComicInfo.xml", b"<ComicInfo/>").await.unwrap();
+        writer.add_bytes("0001.jpg", b"hello").await.unwrap();
+        let archive = writer.finish().await.unwrap();
+        assert_eq!(archive_size(&[("ComicInfo.xml", 12), ("0001.jpg", 5)]), archive.len() as u64);
+
+        assert!(check_archive_limits(&[("0001.jpg", 3 * 1024 * 1024 * 1024)]).is_ok());
+        assert!(check_archive_limits(&[("0001.jpg", 3 * 1024 * 1024 * 1024), ("0002.jpg", 1024 * 1024 * 1024)]).is_err());
+        assert!(check_archive_limits(&vec![("0001.jpg", 1); u16::MAX as usize + 1]).is_err());
+    }
+
+    #[test]
+    fn test_comic_info_escapes_values() {
+        let info = ComicInfo {
+            title: "Tom & Jerry <1>".to_string(),
+            series: Some("Tom & Jerry".to_string()),
+            number: Some("1".to_string()),
+            page_count: 20,
+            ..Default::default()
+        };
+
+        let xml = info.to_xml();
+        assert!(xml.contains("<Title>Tom &amp; Jerry &lt;1&gt;</Title>"));
+        assert!(xml.contains("<Series>Tom &amp; Jerry</Series>"));
+        assert!(xml.contains("<PageCount>20</PageCount>"));
+        assert!(!xml.contains("<Summary>"));
+    }
+}
@@ -0,0 +1,32 @@
+//! 动漫扫描库
+//!
+//! 解析字幕组风格的文件名（`[字幕组] 作品名 - 13v2 [1080p][CRC32].mkv`），
+//! 识别发布组、标题、集号、版本、分辨率和校验码，按作品和季分组，
+//! OVA、SP 等特别篇归入第 0 季；绝对集号在获取元数据后按各季集数换算。
+//!
+//! 扫描结果与电视剧共用 [`SeriesScanResult`]，入库后同样存放在电视剧表中。
+
+pub mod parser;
+pub mod scanner;
+
+pub use parser::{AnimeFileInfo, parse_anime_file};
+pub use scanner::AnimeScan;
+
+use super::movie_scaner::models::language::Language;
+use super::tv_scanner::SeriesScanResult;
+
+/// 扫描指定目录中的动漫并使用指定语言获取 TMDB 元数据
+///
+/// # 参数
+/// * `dir_path` - 要扫描的目录路径
+/// * `language` - 元数据的语言类型
+pub async fn anime_scan_with_language(dir_path: String, language: Language) -> Result<Vec<SeriesScanResult>, String> {
+    // 从配置中读取 TMDB API Key
+    let tmdb_api_key = shared::config::get().movie().tmdb().api_key().to_string();
+
+    AnimeScan::new()
+        .with_language(language)
+        .with_tmdb_provider(tmdb_api_key).await
+        .scan(dir_path)
+        .await
+}
@@ -0,0 +1,232 @@
+//! 字幕组风格的动漫文件名解析
+//!
+//! 典型命名：`[字幕组] 标题 - 13v2 [1080p][ABCD1234].mkv`、`[字幕组][标题][07][1080p].mp4`
+
+use super::super::tv_scanner::parser::{parse_chinese_number, parse_episode};
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// 文件名开头的发布组 `[SubsPlease]`
+static RELEASE_GROUP: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\s*\[([^\]]+)\]").expect("invalid release group pattern"));
+
+/// 方括号、圆括号和【】标签
+static TAG: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\[([^\]]*)\]|\(([^)]*)\)|【([^】]*)】").expect("invalid tag pattern"));
+
+/// CRC32 校验码 `ABCD1234`
+static CRC32: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"^[0-9A-Fa-f]{8}$").expect("invalid crc32 pattern"));
+
+/// 分辨率 `1080p`、`1920x1080`、`4K`
+static RESOLUTION: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\b(?:(\d{3,4})[pP]|\d{3,4}x(\d{3,4})|(4K))\b").expect("invalid resolution pattern"));
+
+/// `标题 - 13`、`标题 - 13v2`、`标题 - 01-02`、`标题 - 13.5`、`标题 - OVA 2`
+static DASH_EPISODE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(
+        r"(?i)^(.*?)\s+-\s+(?:(OVA|OAD|ONA|SP|Specials?)\s*(\d{1,3})?|(\d{1,4})(?:\.(\d))?(?:-(\d{1,4}))?(?:v(\d))?)(?:\s|$)",
+    )
+    .expect("invalid dash episode pattern")
+});
+
+/// 全方括号命名中的集号标签 `[07]`、`[07v2]`、`[01-02]`
+static EPISODE_TAG: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(\d{1,4})(?:-(\d{1,4}))?(?:v(\d))?$").expect("invalid episode tag pattern"));
+
+/// 全方括号命名中的特别篇标签 `[OVA]`、`[SP01]`
+static SPECIAL_TAG: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)^(?:OVA|OAD|ONA|SP|Specials?)\s*(\d{1,3})?$").expect("invalid special tag pattern"));
+
+/// 标题末尾的季号 `S2`、`Season 2`、`2nd Season`
+static TITLE_SEASON: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\s+(?:S(\d{1,2})|Season\s*(\d{1,2})|(\d{1,2})(?:st|nd|rd|th)\s+Season)$")
+        .expect("invalid title season pattern")
+});
+
+/// 标题末尾的 `第二季`
+static TITLE_CHINESE_SEASON: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\s*第\s*([0-9零〇一二两三四五六七八九十]+)\s*季$").expect("invalid chinese season pattern"));
+
+/// 从动漫文件名中解析出的信息
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AnimeFileInfo {
+    /// 发布组（字幕组）
+    pub release_group: Option<String>,
+    /// 标题（已去掉季号）
+    pub title: Option<String>,
+    /// 标题或文件名中明确写出的季号（没有时集号视为绝对集号）
+    pub season: Option<u32>,
+    /// 集号（未编号的特别篇为 None）
+    pub episode: Option<u32>,
+    /// 结束集号（合集文件）
+    pub episode_end: Option<u32>,
+    /// 版本号（`v2` 为修正版）
+    pub version: Option<u32>,
+    /// 分辨率（如 `1080p`）
+    pub resolution: Option<String>,
+    /// CRC32 校验码
+    pub crc32: Option<String>,
+    /// 是否为特别篇（OVA、OAD、SP、总集篇 `13.5` 等）
+    pub special: bool,
+}
+
+impl AnimeFileInfo {
+    /// 是否使用绝对集号（文件名中没有季号的正篇）
+    pub fn is_absolute(&self) -> bool {
+        self.season.is_none() && !self.special
+    }
+}
+
+/// 从标题末尾拆出季号，返回 `(标题, 季号)`
+fn split_title_season(title: &str) -> (String, Option<u32>) {
+    let title = title.trim();
+    if let Some(captures) = TITLE_SEASON.captures(title) {
+        let season = captures.iter().skip(1).flatten().next().and_then(|m| m.as_str().parse().ok());
+        return (title[..captures.get(0).map_or(title.len(), |m| m.start())].trim().to_string(), season);
+    }
+    if let Some(captures) = TITLE_CHINESE_SEASON.captures(title) {
+        let season = parse_chinese_number(&captures[1]);
+        return (title[..captures.get(0).map_or(title.len(), |m| m.start())].trim().to_string(), season);
+    }
+    (title.to_string(), None)
+}
+
+/// 标题为空时返回 None
+fn non_empty(title: String) -> Option<String> {
+    Some(title).filter(|t| !t.is_empty())
+}
+
+/// 解析字幕组风格的动漫文件名（不含扩展名）
+///
+/// 依次识别：
+/// 1. 开头的 `[发布组]`，以及任意位置的分辨率和 CRC32 标签
+/// 2. `标题 - 13v2`、`标题 - OVA 2`、`标题 - 13.5`（总集篇视为特别篇）
+/// 3. `[发布组][标题][07][1080p]` 全方括号命名
+/// 4. 电视剧命名（`S01E02`、`第2集`、`EP02`）
+///
+/// 标题末尾的 `S2`、`2nd Season`、`第二季` 作为季号；
+/// 没有季号的正篇集号视为绝对集号，刮削时再按各季集数换算
+pub fn parse_anime_file(stem: &str) -> Option<AnimeFileInfo> {
+    // `[Group]_Title_-_05_[720p]` 用下划线代替空格
+    let stem = if stem.contains(' ') { stem.to_string() } else { stem.replace('_', " ") };
+    let mut info = AnimeFileInfo::default();
+
+    let mut rest = stem.as_str();
+    if let Some(captures) = RELEASE_GROUP.captures(rest) {
+        info.release_group = non_empty(captures[1].trim().to_string());
+        rest = &rest[captures.get(0)?.end()..];
+    }
+
+    let mut tags = Vec::new();
+    for captures in TAG.captures_iter(rest) {
+        let tag = captures.iter().skip(1).flatten().next().map_or("", |m| m.as_str()).trim();
+        if CRC32.is_match(tag) && !tag.chars().all(|c| c.is_ascii_digit()) {
+            info.crc32 = Some(tag.to_uppercase());
+        } else if let Some(resolution) = RESOLUTION.captures(tag) {
+            info.resolution = resolution
+                .get(1)
+                .or(resolution.get(2))
+                .map(|height| format!("{}p", height.as_str()))
+                .or_else(|| resolution.get(3).map(|_| "2160p".to_string()));
+        } else {
+            tags.push(tag.to_string());
+        }
+    }
+    let body = TAG.replace_all(rest, " ");
+    let body = body.split_whitespace().collect::<Vec<_>>().join(" ");
+
+    if let Some(captures) = DASH_EPISODE.captures(&body) {
+        let (title, season) = split_title_season(&captures[1]);
+        info.title = non_empty(title);
+        info.season = season;
+        if captures.get(2).is_some() {
+            info.special = true;
+            info.episode = captures.get(3).and_then(|m| m.as_str().parse().ok());
+        } else if captures.get(5).is_some() {
+            // `13.5` 一般是总集篇，放进特别篇且不占用正篇集号
+            info.special = true;
+        } else {
+            let episode = captures[4].parse().ok()?;
+            info.episode = Some(episode);
+            info.episode_end = captures.get(6).and_then(|m| m.as_str().parse().ok()).filter(|e| *e > episode);
+            info.version = captures.get(7).and_then(|m| m.as_str().parse().ok());
+        }
+        return Some(info);
+    }
+
+    if body.is_empty() {
+        for tag in &tags {
+            if let Some(captures) = EPISODE_TAG.captures(tag) {
+                if info.episode.is_none() && !info.special {
+                    let episode = captures[1].parse().ok()?;
+                    info.episode = Some(episode);
+                    info.episode_end = captures.get(2).and_then(|m| m.as_str().parse().ok()).filter(|e| *e > episode);
+                    info.version = captures.get(3).and_then(|m| m.as_str().parse().ok());
+                }
+            } else if let Some(captures) = SPECIAL_TAG.captures(tag) {
+                if info.episode.is_none() {
+                    info.special = true;
+                    info.episode = captures.get(1).and_then(|m| m.as_str().parse().ok());
+                }
+            } else if info.title.is_none() && !tag.is_empty() {
+                let (title, season) = split_title_season(tag);
+                info.title = non_empty(title);
+                info.season = season;
+            }
+        }
+        return (info.episode.is_some() || info.special).then_some(info);
+    }
+
+    let episode = parse_episode(&body)?;
+    let (title, season) = split_title_season(episode.series_title.as_deref().unwrap_or_default());
+    info.title = non_empty(title);
+    info.season = episode.season.or(season);
+    info.episode = Some(episode.episode);
+    info.episode_end = episode.episode_end;
+    Some(info)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_anime_file() {
+        assert_eq!(
+            parse_anime_file("[SubsPlease] Sousou no Frieren - 13v2 (1080p) [ABCD1234]"),
+            Some(AnimeFileInfo {
+                release_group: Some("SubsPlease".to_string()),
+                title: Some("Sousou no Frieren".to_string()),
+                episode: Some(13),
+                version: Some(2),
+                resolution: Some("1080p".to_string()),
+                crc32: Some("ABCD1234".to_string()),
+                ..Default::default()
+            })
+        );
+
+        let info = parse_anime_file("[LoliHouse] Spy x Family S2 - 05 [WebRip 1920x1080 HEVC-10bit AAC]").unwrap();
+        assert_eq!((info.title.as_deref(), info.season, info.episode), (Some("Spy x Family"), Some(2), Some(5)));
+        assert_eq!(info.resolution.as_deref(), Some("1080p"));
+        assert!(!info.is_absolute());
+
+        let info = parse_anime_file("[Nekomoe kissaten][Kimetsu no Yaiba][07][1080p][CHS]").unwrap();
+        assert_eq!((info.title.as_deref(), info.episode, info.resolution.as_deref()), (Some("Kimetsu no Yaiba"), Some(7), Some("1080p")));
+        assert!(info.is_absolute());
+
+        let info = parse_anime_file("[Group] Shingeki no Kyojin - OVA 2 [720p]").unwrap();
+        assert_eq!((info.special, info.episode), (true, Some(2)));
+
+        let info = parse_anime_file("[Group] Re Zero 2nd Season - 13.5 [1080p]").unwrap();
+        assert_eq!((info.title.as_deref(), info.season, info.special, info.episode), (Some("Re Zero"), Some(2), true, None));
+
+        let info = parse_anime_file("[Group]_Mob_Psycho_100_-_05_[720p]").unwrap();
+        assert_eq!((info.title.as_deref(), info.episode), (Some("Mob Psycho 100"), Some(5)));
+
+        let info = parse_anime_file("咒术回战 第二季 第05集").unwrap();
+        assert_eq!((info.title.as_deref(), info.season, info.episode), (Some("咒术回战"), Some(2), Some(5)));
+
+        // 没有集号的文件不是剧集
+        assert_eq!(parse_anime_file("[Group] Kimi no Na wa [1080p]"), None);
+    }
+}
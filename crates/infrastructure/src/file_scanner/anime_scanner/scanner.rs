@@ -0,0 +1,412 @@
+//! 动漫扫描器
+
+use super::parser::{AnimeFileInfo, parse_anime_file};
+use super::super::movie_scaner::models::language::Language;
+use super::super::movie_scaner::models::video::VideoScanQueryResult;
+use super::super::movie_scaner::provider::{MetadataProvider, TvSeasonSummary};
+use super::super::movie_scaner::scanner::VIDEO_EXTENSIONS;
+use super::super::tv_scanner::parser::{clean_series_title, parse_season_folder};
+use super::super::tv_scanner::scanner::{apply_series_details, enrich_seasons, into_seasons};
+use super::super::tv_scanner::{EpisodeScanResult, SeriesScanResult};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// 文件名包含这些关键词的视频不是正片
+const EXCLUDE_KEYWORDS: &[&str] = &["sample", "trailer", "preview", "ncop", "nced", "预告"];
+
+/// 动漫扫描器
+///
+/// 与电视剧扫描器的目录结构相同，但按字幕组命名解析文件名：
+/// - `根目录/作品名/[字幕组] 作品名 - 13 [1080p].mkv`（绝对集号）
+/// - `根目录/作品名/Season 2/[字幕组] 作品名 S2 - 01.mkv`
+/// - `根目录/[字幕组] 作品名 - OVA 1.mkv`（OVA、SP 等归入第 0 季）
+///
+/// 没有季号的集号视为绝对集号，刮削时按元数据中各季的集数换算为季号和集号
+pub struct AnimeScan {
+    /// 元数据语言
+    language: Language,
+    /// 元数据提供者列表（按优先级排序）
+    providers: Vec<Box<dyn MetadataProvider>>,
+}
+
+/// 扫描过程中的作品分组
+struct AnimeGroup {
+    title: String,
+    year: Option<u32>,
+    /// (文件名解析结果, 单集扫描结果)
+    episodes: Vec<(AnimeFileInfo, EpisodeScanResult)>,
+}
+
+impl AnimeScan {
+    /// 创建新的动漫扫描器实例
+    pub fn new() -> Self {
+        Self {
+            language: Language::default(),
+            providers: Vec::new(),
+        }
+    }
+
+    /// 设置元数据语言
+    pub fn with_language(mut self, language: Language) -> Self {
+        self.language = language;
+        self
+    }
+
+    /// 添加 TMDB 元数据提供者
+    pub async fn with_tmdb_provider(mut self, api_key: String) -> Self {
+        let provider = super::super::movie_scaner::provider::tmdb_provider::TMDBProvider::new(api_key);
+        self.providers.push(Box::new(super::super::movie_scaner::provider::cache::CachedProvider::new(provider)));
+        self
+    }
+
+    /// 添加自定义元数据提供者
+    pub fn with_provider(mut self, provider: Box<dyn MetadataProvider>) -> Self {
+        self.providers.push(provider);
+        self
+    }
+
+    /// 扫描目录中的动漫并获取元数据
+    pub async fn scan(self, dir_path: String) -> Result<Vec<SeriesScanResult>, String> {
+        let mut series = self.scan_anime_files(&dir_path)?;
+
+        if !self.providers.is_empty() {
+            for item in series.iter_mut() {
+                self.enrich_series(item).await;
+            }
+        } else {
+            println!("⚠️  未配置元数据提供者，跳过元数据获取");
+        }
+
+        Ok(series)
+    }
+
+    /// 扫描目录中的动漫文件并按作品、季分组
+    fn scan_anime_files(&self, dir_path: &str) -> Result<Vec<SeriesScanResult>, String> {
+        println!("📂 扫描动漫目录: {}", dir_path);
+        let root = Path::new(dir_path);
+        let mut groups: BTreeMap<PathBuf, AnimeGroup> = BTreeMap::new();
+        let mut skipped = 0;
+
+        for result in ignore::Walk::new(dir_path) {
+            let entry = result.map_err(|e| format!("扫描错误: {}", e))?;
+            let path = entry.path();
+            if !entry.file_type().is_some_and(|t| t.is_file()) {
+                continue;
+            }
+            let Some(extension) = path.extension() else {
+                continue;
+            };
+            if !VIDEO_EXTENSIONS.contains(&extension.to_string_lossy().to_lowercase().as_str()) {
+                continue;
+            }
+
+            let (Some(file_name), Some(stem)) = (path.file_name(), path.file_stem()) else {
+                continue;
+            };
+            let file_name = file_name.to_string_lossy().to_string();
+            let stem = stem.to_string_lossy().to_string();
+            let lower = stem.to_lowercase();
+            if EXCLUDE_KEYWORDS.iter().any(|k| lower.contains(k)) {
+                skipped += 1;
+                continue;
+            }
+
+            let Some(info) = parse_anime_file(&stem) else {
+                println!("  ⏭️  跳过: {} (无法识别集号)", file_name);
+                skipped += 1;
+                continue;
+            };
+
+            let Some(parent) = path.parent() else {
+                continue;
+            };
+            let folder_season = parent.file_name().and_then(|n| parse_season_folder(&n.to_string_lossy()));
+
+            // 季文件夹的上一级是作品文件夹；直接放在根目录的文件按文件名中的标题分组
+            let series_dir = if folder_season.is_some() && parent != root {
+                parent.parent().unwrap_or(parent).to_path_buf()
+            } else if parent == root {
+                let Some(title) = info.title.clone() else {
+                    println!("  ⏭️  跳过: {} (无法识别标题)", file_name);
+                    skipped += 1;
+                    continue;
+                };
+                root.join(title)
+            } else {
+                parent.to_path_buf()
+            };
+
+            // 特别篇归入第 0 季；季文件夹中的集号视为该季的集号
+            let season_number = if info.special || folder_season == Some(0) {
+                0
+            } else {
+                info.season.or(folder_season).unwrap_or(1)
+            };
+            let absolute_number = if info.is_absolute() && folder_season.is_none() { info.episode } else { None };
+
+            let group = groups.entry(series_dir.clone()).or_insert_with(|| {
+                let folder_name = series_dir.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+                let (title, year) = clean_series_title(&folder_name);
+                let title = if title.is_empty() { info.title.clone().unwrap_or(folder_name) } else { title };
+                AnimeGroup {
+                    title,
+                    year,
+                    episodes: Vec::new(),
+                }
+            });
+
+            let video = VideoScanQueryResult::new(file_name, path.to_string_lossy().to_string());
+            let episode = EpisodeScanResult {
+                season_number,
+                episode_number: info.episode.unwrap_or(0),
+                episode_number_end: info.episode_end,
+                absolute_number,
+                release_group: info.release_group.clone(),
+                title: None,
+                description: None,
+                air_date: None,
+                still_url: None,
+                rating: 0.0,
+                video,
+            };
+            group.episodes.push((info, episode));
+        }
+
+        let series: Vec<SeriesScanResult> = groups
+            .into_iter()
+            .map(|(path, group)| {
+                let mut seasons: BTreeMap<u32, Vec<EpisodeScanResult>> = BTreeMap::new();
+                for episode in dedupe_versions(group.episodes) {
+                    seasons.entry(episode.season_number).or_default().push(episode);
+                }
+                number_unnumbered_specials(&mut seasons);
+
+                SeriesScanResult {
+                    title: group.title,
+                    original_title: None,
+                    year: group.year,
+                    path: path.to_string_lossy().to_string(),
+                    description: None,
+                    first_air_date: None,
+                    rating: 0.0,
+                    votes: 0,
+                    genres: Vec::new(),
+                    actors: Vec::new(),
+                    poster_urls: Vec::new(),
+                    tmdb_id: None,
+                    seasons: into_seasons(seasons),
+                }
+            })
+            .collect();
+
+        if skipped > 0 {
+            println!("🔍 跳过 {} 个非剧集文件", skipped);
+        }
+        println!(
+            "✅ 找到 {} 部动漫，共 {} 集",
+            series.len(),
+            series.iter().map(|s| s.episode_count()).sum::<usize>()
+        );
+        Ok(series)
+    }
+
+    /// 按优先级依次尝试提供者，换算绝对集号后补充作品、季和单集的元数据
+    async fn enrich_series(&self, series: &mut SeriesScanResult) {
+        println!("\n🔍 搜索动漫: {}", series.title);
+
+        for provider in &self.providers {
+            let results = match provider
+                .search_tv_with_language(&series.title, series.year.map(u64::from), self.language)
+                .await
+            {
+                Ok(results) => results,
+                Err(e) => {
+                    println!("  ⚠️  {} 搜索失败: {}", provider.name(), e);
+                    continue;
+                }
+            };
+            let Some(first) = results.first() else {
+                println!("  ⚠️  {} 未找到匹配结果", provider.name());
+                continue;
+            };
+
+            let details = match provider.get_tv_details_with_language(first.tmdb_id, self.language).await {
+                Ok(details) => details,
+                Err(e) => {
+                    println!("  ⚠️  获取详情失败: {}", e);
+                    continue;
+                }
+            };
+            println!("  ✅ 找到匹配: {} ({})", details.name, details.first_air_date);
+
+            let mut seasons: BTreeMap<u32, Vec<EpisodeScanResult>> = BTreeMap::new();
+            for mut episode in series.seasons.drain(..).flat_map(|s| s.episodes) {
+                map_absolute_episode(&mut episode, &details.seasons);
+                seasons.entry(episode.season_number).or_default().push(episode);
+            }
+            series.seasons = into_seasons(seasons);
+
+            let tmdb_id = details.tmdb_id;
+            apply_series_details(series, details);
+            enrich_seasons(provider.as_ref(), tmdb_id, self.language, &mut series.seasons).await;
+            return;
+        }
+
+        println!("  ❌ 所有数据源均未找到匹配结果");
+    }
+}
+
+impl Default for AnimeScan {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// 同一集有多个版本（`v2` 修正版）时只保留版本号最高的文件
+fn dedupe_versions(episodes: Vec<(AnimeFileInfo, EpisodeScanResult)>) -> Vec<EpisodeScanResult> {
+    let mut best: BTreeMap<(u32, u32), (u32, EpisodeScanResult)> = BTreeMap::new();
+    let mut unnumbered = Vec::new();
+    for (info, episode) in episodes {
+        if info.episode.is_none() {
+            unnumbered.push(episode);
+            continue;
+        }
+        let version = info.version.unwrap_or(1);
+        let key = (episode.season_number, episode.episode_number);
+        if best.get(&key).is_none_or(|(current, _)| version > *current) {
+            best.insert(key, (version, episode));
+        }
+    }
+    best.into_values().map(|(_, episode)| episode).chain(unnumbered).collect()
+}
+
+/// 为没有编号的特别篇（`OVA`、总集篇）按文件名顺序分配集号
+fn number_unnumbered_specials(seasons: &mut BTreeMap<u32, Vec<EpisodeScanResult>>) {
+    let Some(specials) = seasons.get_mut(&0) else {
+        return;
+    };
+    let first = specials.iter().map(|e| e.episode_number).max().unwrap_or(0) + 1;
+    let mut unnumbered: Vec<_> = specials.iter_mut().filter(|e| e.episode_number == 0).collect();
+    unnumbered.sort_by(|a, b| a.video.path.cmp(&b.video.path));
+    for (number, episode) in (first..).zip(unnumbered) {
+        episode.episode_number = number;
+    }
+}
+
+/// 按元数据中各季的集数把绝对集号换算为季号和集号
+///
+/// 例如第 1 季 12 集、第 2 季 12 集时，绝对集号 13 对应 `S02E01`；
+/// 超出元数据总集数的集号保持不变
+pub fn map_absolute_episode(episode: &mut EpisodeScanResult, seasons: &[TvSeasonSummary]) {
+    let Some(absolute) = episode.absolute_number else {
+        return;
+    };
+
+    let mut regular: Vec<_> = seasons.iter().filter(|s| s.season_number > 0 && s.episode_count > 0).collect();
+    regular.sort_by_key(|s| s.season_number);
+
+    let mut remaining = absolute;
+    for season in regular {
+        if remaining <= season.episode_count {
+            let offset = absolute - remaining;
+            episode.season_number = season.season_number;
+            episode.episode_number = remaining;
+            episode.episode_number_end = episode.episode_number_end.map(|end| end.saturating_sub(offset));
+            return;
+        }
+        remaining -= season.episode_count;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn season_summary(season_number: u32, episode_count: u32) -> TvSeasonSummary {
+        TvSeasonSummary {
+            season_number,
+            name: String::new(),
+            overview: String::new(),
+            air_date: String::new(),
+            episode_count,
+            poster_path: None,
+        }
+    }
+
+    #[test]
+    fn test_scan_anime_files() {
+        let dir = std::env::temp_dir().join(format!("modream_anime_{}", std::process::id()));
+        for name in [
+            "Frieren/[SubsPlease] Sousou no Frieren - 13 (1080p) [ABCD1234].mkv",
+            "Frieren/[SubsPlease] Sousou no Frieren - 13v2 (1080p) [1234ABCD].mkv",
+            "Frieren/[SubsPlease] Sousou no Frieren - 14 (1080p).mkv",
+            "Frieren/[SubsPlease] Sousou no Frieren - NCOP (1080p).mkv",
+            "Frieren/[SubsPlease] Sousou no Frieren - OVA (1080p).mkv",
+            "[Group] Spy x Family S2 - 05 [1080p].mkv",
+        ] {
+            let path = dir.join(name);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, b"").unwrap();
+        }
+
+        let series = AnimeScan::new().scan_anime_files(&dir.to_string_lossy()).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        let summary: Vec<_> = series
+            .iter()
+            .map(|s| {
+                let seasons: Vec<_> = s
+                    .seasons
+                    .iter()
+                    .map(|season| {
+                        let episodes: Vec<_> = season.episodes.iter().map(|e| (e.episode_number, e.absolute_number)).collect();
+                        (season.season_number, episodes)
+                    })
+                    .collect();
+                (s.title.as_str(), seasons)
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("Frieren", vec![(0, vec![(1, None)]), (1, vec![(13, Some(13)), (14, Some(14))])]),
+                ("Spy x Family", vec![(2, vec![(5, None)])]),
+            ]
+        );
+        // 保留 v2 修正版
+        assert!(series[0].seasons[1].episodes[0].video.path.contains("1234ABCD"));
+        assert_eq!(series[0].seasons[1].episodes[0].release_group.as_deref(), Some("SubsPlease"));
+    }
+
+    #[test]
+    fn test_map_absolute_episode() {
+        let seasons = vec![season_summary(0, 2), season_summary(1, 12), season_summary(2, 12)];
+        let episode = |absolute: u32, end: Option<u32>| EpisodeScanResult {
+            season_number: 1,
+            episode_number: absolute,
+            episode_number_end: end,
+            absolute_number: Some(absolute),
+            release_group: None,
+            title: None,
+            description: None,
+            air_date: None,
+            still_url: None,
+            rating: 0.0,
+            video: VideoScanQueryResult::new(format!("{}.mkv", absolute), format!("/nonexistent/{}.mkv", absolute)),
+        };
+
+        let mut result = episode(13, Some(14));
+        map_absolute_episode(&mut result, &seasons);
+        assert_eq!((result.season_number, result.episode_number, result.episode_number_end), (2, 1, Some(2)));
+
+        let mut result = episode(12, None);
+        map_absolute_episode(&mut result, &seasons);
+        assert_eq!((result.season_number, result.episode_number), (1, 12));
+
+        // 超出总集数时保持不变
+        let mut result = episode(30, None);
+        map_absolute_episode(&mut result, &seasons);
+        assert_eq!((result.season_number, result.episode_number), (1, 30));
+    }
+}
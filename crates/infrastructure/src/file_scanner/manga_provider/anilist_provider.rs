@@ -0,0 +1,468 @@
+use super::{MangaDetails, MangaMetadataProvider, MangaSearchResult, MangaStatus};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::sync::LazyLock;
+
+/// 搜索结果最多返回的条数
+const SEARCH_PAGE_SIZE: u32 = 10;
+
+/// 搜索查询：按标题搜索漫画
+const SEARCH_QUERY: &str = r#"
+query ($search: String, $perPage: Int) {
+  Page(page: 1, perPage: $perPage) {
+    media(search: $search, type: MANGA) {
+      id
+      title { romaji english native }
+      startDate { year }
+      coverImage { extraLarge large }
+    }
+  }
+}
+"#;
+
+/// 详情查询：包含简介、类型、状态和作者
+const DETAILS_QUERY: &str = r#"
+query ($id: Int) {
+  Media(id: $id, type: MANGA) {
+    id
+    title { romaji english native }
+    description(asHtml: false)
+    genres
+    status
+    startDate { year }
+    volumes
+    chapters
+    coverImage { extraLarge large }
+    siteUrl
+    staff(perPage: 25) {
+      edges {
+        role
+        node { name { full native } }
+      }
+    }
+  }
+}
+"#;
+
+/// 匹配 HTML 标签
+static HTML_TAG: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"<[^>]+>").expect("invalid html tag pattern"));
+
+/// 匹配 `<br>` 换行标签
+static HTML_BREAK: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"(?i)<br\s*/?>").expect("invalid br pattern"));
+
+/// GraphQL 请求体
+#[derive(Debug, Serialize)]
+struct GraphQLRequest<'a, V: Serialize> {
+    query: &'a str,
+    variables: V,
+}
+
+/// GraphQL 响应
+#[derive(Debug, Deserialize)]
+struct GraphQLResponse<T> {
+    data: Option<T>,
+    errors: Option<Vec<GraphQLError>>,
+}
+
+/// GraphQL 错误
+#[derive(Debug, Deserialize)]
+struct GraphQLError {
+    message: String,
+}
+
+/// 搜索变量
+#[derive(Debug, Serialize)]
+struct SearchVariables<'a> {
+    search: &'a str,
+    #[serde(rename = "perPage")]
+    per_page: u32,
+}
+
+/// 详情变量
+#[derive(Debug, Serialize)]
+struct DetailsVariables {
+    id: u64,
+}
+
+/// 搜索响应数据
+#[derive(Debug, Deserialize)]
+struct AniListPageData {
+    #[serde(rename = "Page")]
+    page: AniListPage,
+}
+
+/// 搜索结果页
+#[derive(Debug, Deserialize)]
+struct AniListPage {
+    media: Vec<AniListMedia>,
+}
+
+/// 详情响应数据
+#[derive(Debug, Deserialize)]
+struct AniListMediaData {
+    #[serde(rename = "Media")]
+    media: Option<AniListMedia>,
+}
+
+/// AniList 作品信息
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AniListMedia {
+    id: u64,
+    title: AniListTitle,
+    description: Option<String>,
+    genres: Option<Vec<String>>,
+    status: Option<String>,
+    start_date: Option<AniListDate>,
+    volumes: Option<i32>,
+    chapters: Option<i32>,
+    cover_image: Option<AniListCoverImage>,
+    site_url: Option<String>,
+    staff: Option<AniListStaffConnection>,
+}
+
+/// AniList 标题
+#[derive(Debug, Deserialize)]
+struct AniListTitle {
+    romaji: Option<String>,
+    english: Option<String>,
+    native: Option<String>,
+}
+
+/// AniList 日期（各部分均可能缺失）
+#[derive(Debug, Deserialize)]
+struct AniListDate {
+    year: Option<i32>,
+}
+
+/// AniList 封面
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct AniListCoverImage {
+    extra_large: Option<String>,
+    large: Option<String>,
+}
+
+/// AniList 工作人员列表
+#[derive(Debug, Deserialize)]
+struct AniListStaffConnection {
+    edges: Vec<AniListStaffEdge>,
+}
+
+/// AniList 工作人员及其职责
+#[derive(Debug, Deserialize)]
+struct AniListStaffEdge {
+    role: Option<String>,
+    node: AniListStaff,
+}
+
+/// AniList 工作人员
+#[derive(Debug, Deserialize)]
+struct AniListStaff {
+    name: AniListStaffName,
+}
+
+/// AniList 工作人员姓名
+#[derive(Debug, Deserialize)]
+struct AniListStaffName {
+    full: Option<String>,
+    native: Option<String>,
+}
+
+impl AniListMedia {
+    /// 显示标题：英文 > 罗马音 > 原文
+    fn display_title(&self) -> String {
+        self.title.english.clone()
+            .or_else(|| self.title.romaji.clone())
+            .or_else(|| self.title.native.clone())
+            .unwrap_or_default()
+    }
+
+    /// 封面 URL：优先使用最大尺寸
+    fn cover_url(&self) -> Option<String> {
+        self.cover_image.as_ref()
+            .and_then(|cover| cover.extra_large.clone().or_else(|| cover.large.clone()))
+    }
+
+    /// 开始连载年份
+    fn start_year(&self) -> Option<i32> {
+        self.start_date.as_ref().and_then(|date| date.year)
+    }
+
+    /// 转换为 MangaSearchResult
+    fn to_search_result(&self) -> MangaSearchResult {
+        MangaSearchResult {
+            provider_id: self.id,
+            title: self.display_title(),
+            original_title: self.title.native.clone(),
+            start_year: self.start_year(),
+            cover_url: self.cover_url(),
+        }
+    }
+
+    /// 转换为 MangaDetails
+    fn to_manga_details(&self) -> MangaDetails {
+        // 只保留原作和作画，忽略翻译、助手等职责；同一人可能同时负责两者
+        let mut authors: Vec<String> = Vec::new();
+        if let Some(staff) = &self.staff {
+            for edge in &staff.edges {
+                let role = edge.role.as_deref().unwrap_or_default();
+                if !(role.starts_with("Story") || role.starts_with("Art")) {
+                    continue;
+                }
+                let Some(name) = edge.node.name.full.clone().or_else(|| edge.node.name.native.clone()) else {
+                    continue;
+                };
+                if !authors.contains(&name) {
+                    authors.push(name);
+                }
+            }
+        }
+
+        MangaDetails {
+            provider_id: self.id,
+            title: self.display_title(),
+            original_title: self.title.native.clone(),
+            synopsis: self.description.as_deref().map(strip_html).unwrap_or_default(),
+            authors,
+            genres: self.genres.clone().unwrap_or_default(),
+            status: parse_status(self.status.as_deref()),
+            start_year: self.start_year(),
+            volumes: self.volumes,
+            chapters: self.chapters,
+            cover_url: self.cover_url(),
+            site_url: self.site_url.clone(),
+        }
+    }
+}
+
+/// 将 AniList 的状态枚举转换为 MangaStatus
+fn parse_status(status: Option<&str>) -> MangaStatus {
+    match status {
+        Some("RELEASING") => MangaStatus::Ongoing,
+        Some("FINISHED") => MangaStatus::Completed,
+        Some("HIATUS") => MangaStatus::Hiatus,
+        Some("CANCELLED") => MangaStatus::Cancelled,
+        Some("NOT_YET_RELEASED") => MangaStatus::NotYetReleased,
+        _ => MangaStatus::Unknown,
+    }
+}
+
+/// 去除简介中的 HTML 标签（AniList 即使请求纯文本也会保留 `<br>` 和 `<i>`）
+fn strip_html(text: &str) -> String {
+    let text = HTML_BREAK.replace_all(text, "\n");
+    let text = HTML_TAG.replace_all(&text, "");
+    text.replace("&amp;", "&")
+        .replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#039;", "'")
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim()
+        .to_string()
+}
+
+/// AniList 漫画元数据提供者
+///
+/// 使用 AniList 的公开 GraphQL 接口，无需 API Key
+pub struct AniListProvider {
+    api_url: String,
+    client: reqwest::Client,
+}
+
+impl AniListProvider {
+    pub fn new(api_url: String) -> Self {
+        Self {
+            api_url,
+            client: reqwest::Client::new(),
+        }
+    }
+
+    /// 发送 GraphQL 查询并解析 `data` 字段
+    async fn query<V, T>(&self, query: &str, variables: V) -> Result<T, String>
+    where
+        V: Serialize + Send,
+        T: for<'de> Deserialize<'de>,
+    {
+        let response = self.client
+            .post(&self.api_url)
+            .header(reqwest::header::ACCEPT, "application/json")
+            .json(&GraphQLRequest { query, variables })
+            .send()
+            .await
+            .map_err(|e| format!("请求失败: {}", e))?;
+
+        let status = response.status();
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_else(|_| "无法读取错误信息".to_string());
+            return Err(format!("API 返回错误 {}: {}", status, error_text));
+        }
+
+        let body: GraphQLResponse<T> = response
+            .json()
+            .await
+            .map_err(|e| format!("解析 JSON 失败: {}", e))?;
+
+        if let Some(errors) = body.errors.filter(|errors| !errors.is_empty()) {
+            let messages: Vec<String> = errors.into_iter().map(|e| e.message).collect();
+            return Err(format!("GraphQL 错误: {}", messages.join("; ")));
+        }
+
+        body.data.ok_or_else(|| "响应中缺少 data 字段".to_string())
+    }
+}
+
+#[async_trait::async_trait]
+impl MangaMetadataProvider for AniListProvider {
+    fn name(&self) -> &str {
+        "AniList"
+    }
+
+    async fn search(&self, title: &str) -> Result<Vec<MangaSearchResult>, String> {
+        let data: AniListPageData = self
+            .query(SEARCH_QUERY, SearchVariables { search: title, per_page: SEARCH_PAGE_SIZE })
+            .await?;
+
+        tracing::debug!("AniList search '{}' returned {} results", title, data.page.media.len());
+
+        Ok(data.page.media.iter().map(|media| media.to_search_result()).collect())
+    }
+
+    async fn get_details(&self, provider_id: u64) -> Result<MangaDetails, String> {
+        let data: AniListMediaData = self
+            .query(DETAILS_QUERY, DetailsVariables { id: provider_id })
+            .await?;
+
+        data.media
+            .map(|media| media.to_manga_details())
+            .ok_or_else(|| format!("未找到漫画: {}", provider_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// 启动只处理一次请求的本地 HTTP 服务，返回服务地址和收到的请求体
+    async fn serve_once(status: &'static str, body: &'static str) -> (String, tokio::task::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+
+        let handle = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+
+            let mut request = Vec::new();
+            let mut buf = [0u8; 4096];
+            let body_start = loop {
+                let n = socket.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..n]);
+                if let Some(pos) = request.windows(4).position(|w| w == b"\r\n\r\n") {
+                    break pos + 4;
+                }
+            };
+
+            let headers = String::from_utf8_lossy(&request[..body_start]).to_lowercase();
+            let content_length: usize = headers
+                .lines()
+                .find_map(|line| line.strip_prefix("content-length:"))
+                .map(|v| v.trim().parse().unwrap())
+                .unwrap_or(0);
+            while request.len() < body_start + content_length {
+                let n = socket.read(&mut buf).await.unwrap();
+                request.extend_from_slice(&buf[..n]);
+            }
+
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+            socket.shutdown().await.unwrap();
+
+            String::from_utf8_lossy(&request[body_start..]).to_string()
+        });
+
+        (format!("http://{}", addr), handle)
+    }
+
+    #[tokio::test]
+    async fn test_search() {
+        let (url, handle) = serve_once("200 OK", r#"{"data":{"Page":{"media":[
+            {"id":30013,"title":{"romaji":"ONE PIECE","english":"One Piece","native":"ONE PIECE"},
+             "startDate":{"year":1997},"coverImage":{"extraLarge":"https://img/xl.jpg","large":"https://img/l.jpg"}},
+            {"id":1,"title":{"romaji":"One Piece Party","english":null,"native":null},
+             "startDate":{"year":null},"coverImage":null}
+        ]}}}"#).await;
+
+        let provider = AniListProvider::new(url);
+        let results = provider.search("One Piece").await.unwrap();
+
+        let request = handle.await.unwrap();
+        let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+        assert_eq!(request["variables"]["search"], "One Piece");
+        assert_eq!(request["variables"]["perPage"], SEARCH_PAGE_SIZE);
+        assert!(request["query"].as_str().unwrap().contains("type: MANGA"));
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].provider_id, 30013);
+        assert_eq!(results[0].title, "One Piece");
+        assert_eq!(results[0].start_year, Some(1997));
+        assert_eq!(results[0].cover_url.as_deref(), Some("https://img/xl.jpg"));
+        assert_eq!(results[1].title, "One Piece Party");
+        assert_eq!(results[1].cover_url, None);
+    }
+
+    #[tokio::test]
+    async fn test_get_details() {
+        let (url, handle) = serve_once("200 OK", r#"{"data":{"Media":{
+            "id":30002,"title":{"romaji":"Berserk","english":"Berserk","native":"ベルセルク"},
+            "description":"Guts, a former mercenary.<br><br>\nHe <i>wanders</i> &amp; fights.",
+            "genres":["Action","Drama"],"status":"HIATUS","startDate":{"year":1989},
+            "volumes":null,"chapters":null,
+            "coverImage":{"extraLarge":null,"large":"https://img/berserk.jpg"},
+            "siteUrl":"https://anilist.co/manga/30002",
+            "staff":{"edges":[
+                {"role":"Story & Art","node":{"name":{"full":"Kentarou Miura","native":"三浦建太郎"}}},
+                {"role":"Translator (English)","node":{"name":{"full":"Jason DeAngelis","native":null}}},
+                {"role":"Art","node":{"name":{"full":"Kouji Mori","native":"森恒二"}}}
+            ]}
+        }}}"#).await;
+
+        let provider = AniListProvider::new(url);
+        let details = provider.get_details(30002).await.unwrap();
+
+        let request = handle.await.unwrap();
+        let request: serde_json::Value = serde_json::from_str(&request).unwrap();
+        assert_eq!(request["variables"]["id"], 30002);
+
+        assert_eq!(details.title, "Berserk");
+        assert_eq!(details.original_title.as_deref(), Some("ベルセルク"));
+        assert_eq!(details.synopsis, "Guts, a former mercenary.\n\n\nHe wanders & fights.");
+        assert_eq!(details.authors, vec!["Kentarou Miura", "Kouji Mori"]);
+        assert_eq!(details.genres, vec!["Action", "Drama"]);
+        assert_eq!(details.status, MangaStatus::Hiatus);
+        assert_eq!(details.start_year, Some(1989));
+        assert_eq!(details.cover_url.as_deref(), Some("https://img/berserk.jpg"));
+    }
+
+    #[tokio::test]
+    async fn test_graphql_error() {
+        let (url, handle) = serve_once(
+            "404 Not Found",
+            r#"{"data":{"Media":null},"errors":[{"message":"Not Found.","status":404}]}"#,
+        ).await;
+
+        let provider = AniListProvider::new(url);
+        let result = provider.get_details(999_999_999).await;
+        handle.await.unwrap();
+
+        assert!(result.unwrap_err().contains("404"));
+    }
+}
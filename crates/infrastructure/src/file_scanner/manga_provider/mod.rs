@@ -0,0 +1,96 @@
+use async_trait::async_trait;
+
+pub mod anilist_provider;
+
+pub use anilist_provider::AniListProvider;
+
+/// 漫画连载状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MangaStatus {
+    /// 连载中
+    Ongoing,
+    /// 已完结
+    Completed,
+    /// 休刊
+    Hiatus,
+    /// 已腰斩/取消
+    Cancelled,
+    /// 尚未开始连载
+    NotYetReleased,
+    /// 未知
+    Unknown,
+}
+
+impl MangaStatus {
+    /// 状态的英文标识（用于 API 输出）
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MangaStatus::Ongoing => "ongoing",
+            MangaStatus::Completed => "completed",
+            MangaStatus::Hiatus => "hiatus",
+            MangaStatus::Cancelled => "cancelled",
+            MangaStatus::NotYetReleased => "not_yet_released",
+            MangaStatus::Unknown => "unknown",
+        }
+    }
+}
+
+/// 漫画搜索结果
+#[derive(Debug, Clone)]
+pub struct MangaSearchResult {
+    /// 数据源中的漫画 ID
+    pub provider_id: u64,
+    /// 标题（优先英文/罗马音）
+    pub title: String,
+    /// 原始标题（通常为日文）
+    pub original_title: Option<String>,
+    /// 开始连载年份
+    pub start_year: Option<i32>,
+    /// 封面 URL
+    pub cover_url: Option<String>,
+}
+
+/// 漫画详细信息
+#[derive(Debug, Clone)]
+pub struct MangaDetails {
+    /// 数据源中的漫画 ID
+    pub provider_id: u64,
+    /// 标题（优先英文/罗马音）
+    pub title: String,
+    /// 原始标题（通常为日文）
+    pub original_title: Option<String>,
+    /// 简介（纯文本）
+    pub synopsis: String,
+    /// 作者列表（原作和作画）
+    pub authors: Vec<String>,
+    /// 类型列表
+    pub genres: Vec<String>,
+    /// 连载状态
+    pub status: MangaStatus,
+    /// 开始连载年份
+    pub start_year: Option<i32>,
+    /// 卷数
+    pub volumes: Option<i32>,
+    /// 话数
+    pub chapters: Option<i32>,
+    /// 封面 URL
+    pub cover_url: Option<String>,
+    /// 数据源中的页面 URL
+    pub site_url: Option<String>,
+}
+
+/// 漫画元数据提供者 trait
+///
+/// 所有漫画元数据提供者必须实现此 trait，
+/// 与电影的 `MetadataProvider` 保持一致，错误以字符串形式返回
+#[async_trait]
+pub trait MangaMetadataProvider: Send + Sync {
+    /// 获取提供者名称
+    fn name(&self) -> &str;
+
+    /// 按标题搜索漫画
+    async fn search(&self, title: &str) -> Result<Vec<MangaSearchResult>, String>;
+
+    /// 获取漫画详细信息
+    async fn get_details(&self, provider_id: u64) -> Result<MangaDetails, String>;
+}
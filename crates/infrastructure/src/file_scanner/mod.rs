@@ -2,22 +2,36 @@ mod scan;
 mod scan_by_game;
 mod scan_by_manga;
 mod scan_by_video;
+pub mod manga_provider;
+pub mod page_check;
 pub mod movie_scaner;
 pub mod photo_scanner;
+pub mod tv_scanner;
+pub mod anime_scanner;
 
 pub use scan::scan;
 pub use scan_by_game::scan_game_folders;
 pub use scan_by_manga::{scan_folders_v2, MangaScanResult, ChapterInfo};
 pub use scan_by_video::scan_by_video;
+pub use page_check::{check_page, find_unreadable_pages};
 
 // 重新导出电影扫描相关的类型和函数
 pub use movie_scaner::{
     MovieScan,
+    get_movie_collection,
+    get_movie_metadata,
+    search_movie_metadata,
     video_scan,
     video_scan_with_language,
     video_scan_with_options,
 };
 
+// 重新导出电视剧扫描相关的类型和函数
+pub use tv_scanner::{TvScan, tv_scan_with_language};
+
+// 重新导出动漫扫描相关的类型和函数
+pub use anime_scanner::{AnimeScan, anime_scan_with_language};
+
 // 重新导出照片扫描相关的类型和函数
 pub use photo_scanner::{
     PhotoScanner,
@@ -0,0 +1,127 @@
+//! 电影图片本地缓存
+//!
+//! 扫描时把 TMDB 海报和背景图下载到 data 目录，之后由服务端直接提供，
+//! 离线时也能显示，客户端不需要再访问 `image.tmdb.org`。
+//!
+//! 目录结构：`{缓存目录}/{电影 ID}/poster.jpg`、`backdrop.jpg`，
+//! 缩放后的版本为 `poster_{宽}x{高}.jpg`。
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// 图片类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArtworkKind {
+    /// 海报
+    Poster,
+    /// 背景图
+    Backdrop,
+}
+
+impl ArtworkKind {
+    /// 缓存文件名（不含扩展名）
+    pub fn file_stem(&self) -> &'static str {
+        match self {
+            ArtworkKind::Poster => "poster",
+            ArtworkKind::Backdrop => "backdrop",
+        }
+    }
+}
+
+/// 电影图片缓存
+pub struct ArtworkCache {
+    dir: PathBuf,
+    client: reqwest::Client,
+}
+
+impl ArtworkCache {
+    /// 创建图片缓存，`dir` 为缓存根目录
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(30))
+            .build()
+            .unwrap_or_default();
+        Self { dir: dir.into(), client }
+    }
+
+    /// 电影的缓存目录
+    pub fn movie_dir(&self, movie_id: i32) -> PathBuf {
+        self.dir.join(movie_id.to_string())
+    }
+
+    /// 原图的缓存路径
+    pub fn original_path(&self, movie_id: i32, kind: ArtworkKind) -> PathBuf {
+        self.movie_dir(movie_id).join(format!("{}.jpg", kind.file_stem()))
+    }
+
+    /// 缩放版本的缓存路径，宽或高为 `None` 时按比例自适应
+    pub fn variant_path(&self, movie_id: i32, kind: ArtworkKind, width: Option<u32>, height: Option<u32>) -> PathBuf {
+        let dimension = |value: Option<u32>| value.map_or_else(|| "auto".to_string(), |v| v.to_string());
+        self.movie_dir(movie_id).join(format!(
+            "{}_{}x{}.jpg",
+            kind.file_stem(),
+            dimension(width),
+            dimension(height)
+        ))
+    }
+
+    /// 下载图片到缓存，已缓存时直接返回缓存路径
+    pub async fn fetch(&self, movie_id: i32, kind: ArtworkKind, url: &str) -> Result<PathBuf, String> {
+        let path = self.original_path(movie_id, kind);
+        if tokio::fs::try_exists(&path).await.unwrap_or(false) {
+            return Ok(path);
+        }
+
+        let response = self.client.get(url).send().await.map_err(|e| format!("下载图片失败: {}", e))?;
+        if !response.status().is_success() {
+            return Err(format!("下载图片失败: {} 返回 {}", url, response.status()));
+        }
+        let bytes = response.bytes().await.map_err(|e| format!("读取图片失败: {}", e))?;
+        if bytes.is_empty() {
+            return Err(format!("下载图片失败: {} 返回空内容", url));
+        }
+
+        write_atomically(&path, &bytes).await.map_err(|e| format!("写入图片缓存失败: {}", e))?;
+        tracing::debug!("Cached {} for movie {}: {}", kind.file_stem(), movie_id, path.display());
+        Ok(path)
+    }
+
+    /// 删除电影的所有缓存图片
+    pub async fn remove(&self, movie_id: i32) {
+        let dir = self.movie_dir(movie_id);
+        if let Err(e) = tokio::fs::remove_dir_all(&dir).await
+            && e.kind() != std::io::ErrorKind::NotFound
+        {
+            tracing::warn!("Failed to remove artwork cache {}: {}", dir.display(), e);
+        }
+    }
+}
+
+/// 先写临时文件再重命名，避免并发请求读到写了一半的图片
+pub async fn write_atomically(path: &Path, bytes: &[u8]) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    let temp_path = path.with_extension("jpg.part");
+    tokio::fs::write(&temp_path, bytes).await?;
+    tokio::fs::rename(&temp_path, path).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_paths() {
+        let cache = ArtworkCache::new("/data/cache/movies");
+
+        assert_eq!(
+            cache.original_path(42, ArtworkKind::Poster),
+            PathBuf::from("/data/cache/movies/42/poster.jpg")
+        );
+        assert_eq!(
+            cache.variant_path(42, ArtworkKind::Backdrop, Some(1280), None),
+            PathBuf::from("/data/cache/movies/42/backdrop_1280xauto.jpg")
+        );
+    }
+}
@@ -0,0 +1,252 @@
+//! AVI 容器解析（RIFF）
+
+use super::tracks::{self, TrackInfo, TrackKind};
+use super::{read_payload, read_up_to};
+use std::io::{self, Read, Seek};
+
+/// `hdrl` 列表的最大读取大小
+const MAX_HDRL_SIZE: u64 = 1024 * 1024;
+
+/// 内存中的 RIFF chunk 迭代器，依次返回 `(类型, 内容)`
+pub(super) struct ChunkIter<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> ChunkIter<'a> {
+    pub(super) fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+}
+
+impl<'a> Iterator for ChunkIter<'a> {
+    type Item = ([u8; 4], &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.len() < 8 {
+            return None;
+        }
+
+        let kind: [u8; 4] = self.data[0..4].try_into().ok()?;
+        let size = u32::from_le_bytes(self.data[4..8].try_into().ok()?) as usize;
+        let end = (8 + size).min(self.data.len());
+        let body = &self.data[8..end];
+
+        // chunk 按 2 字节对齐
+        let next = (end + (size & 1)).min(self.data.len());
+        self.data = &self.data[next..];
+        Some((kind, body))
+    }
+}
+
+/// 读取文件开头的 `hdrl` 列表内容（不含 `hdrl` 类型标记）
+pub(super) fn read_hdrl<R: Read + Seek>(reader: &mut R) -> io::Result<Option<Vec<u8>>> {
+    // RIFF 头（12 字节）之后紧跟 LIST hdrl
+    let mut header = [0u8; 24];
+    if read_up_to(reader, &mut header)? < 24 {
+        return Ok(None);
+    }
+    if &header[0..4] != b"RIFF" || &header[8..12] != b"AVI " || &header[12..16] != b"LIST" || &header[20..24] != b"hdrl" {
+        return Ok(None);
+    }
+
+    let list_size = u32::from_le_bytes([header[16], header[17], header[18], header[19]]) as u64;
+    read_payload(reader, list_size.saturating_sub(4).min(MAX_HDRL_SIZE), MAX_HDRL_SIZE).map(Some)
+}
+
+/// 从 `hdrl` 中计算时长（秒）
+///
+/// `avih` 中的总帧数在超过 1GB 的 OpenDML 文件中只统计第一个 RIFF 块，
+/// 此时使用 `odml/dmlh` 中的总帧数
+pub(super) fn duration_from_hdrl(hdrl: &[u8]) -> Option<f64> {
+    let mut micros_per_frame = None;
+    let mut total_frames = 0u32;
+
+    for (kind, body) in ChunkIter::new(hdrl) {
+        match &kind {
+            b"avih" if body.len() >= 20 => {
+                micros_per_frame = Some(u32::from_le_bytes(body[0..4].try_into().ok()?));
+                total_frames = total_frames.max(u32::from_le_bytes(body[16..20].try_into().ok()?));
+            }
+            b"LIST" if body.starts_with(b"odml") => {
+                for (kind, body) in ChunkIter::new(&body[4..]) {
+                    if &kind == b"dmlh" && body.len() >= 4 {
+                        total_frames = total_frames.max(u32::from_le_bytes(body[0..4].try_into().ok()?));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let micros_per_frame = micros_per_frame.filter(|m| *m > 0)?;
+    (total_frames > 0).then(|| micros_per_frame as f64 * total_frames as f64 / 1_000_000.0)
+}
+
+/// 读取 AVI 文件的时长（秒）
+pub(super) fn probe_duration<R: Read + Seek>(reader: &mut R) -> io::Result<Option<f64>> {
+    Ok(read_hdrl(reader)?.and_then(|hdrl| duration_from_hdrl(&hdrl)))
+}
+
+fn le_u16(body: &[u8], at: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(body.get(at..at + 2)?.try_into().ok()?))
+}
+
+fn le_u32(body: &[u8], at: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(body.get(at..at + 4)?.try_into().ok()?))
+}
+
+/// 解析一个 `strl` 列表（`strh` 流头 + `strf` 流格式 + 可选的 `strn` 名称）
+fn parse_strl(index: u32, strl: &[u8]) -> Option<TrackInfo> {
+    let mut strh = None;
+    let mut strf = None;
+    let mut name = None;
+    for (kind, body) in ChunkIter::new(strl) {
+        match &kind {
+            b"strh" => strh = Some(body),
+            b"strf" => strf = Some(body),
+            b"strn" => {
+                name = Some(String::from_utf8_lossy(body).trim_end_matches(char::from(0)).to_string())
+                    .filter(|n| !n.is_empty())
+            }
+            _ => {}
+        }
+    }
+
+    // fccType(4) fccHandler(4) flags(4) priority(2) language(2) initialFrames(4) scale(4) rate(4)
+    let strh = strh?;
+    let strf = strf.unwrap_or_default();
+    let mut track = match strh.get(0..4)? {
+        b"vids" => {
+            // BITMAPINFOHEADER.biCompression 比 fccHandler 更可靠
+            let fourcc: [u8; 4] = strf.get(16..20).or(strh.get(4..8))?.try_into().ok()?;
+            let mut track = TrackInfo::new(TrackKind::Video, index, tracks::avi_video_codec_name(&fourcc));
+            track.width = le_u32(strf, 4).filter(|w| *w > 0);
+            track.height = le_u32(strf, 8).map(|h| (h as i32).unsigned_abs()).filter(|h| *h > 0);
+            if let (Some(scale), Some(rate)) = (le_u32(strh, 20).filter(|s| *s > 0), le_u32(strh, 24)) {
+                track.frame_rate = tracks::round_frame_rate(rate as f64 / scale as f64);
+            }
+            track
+        }
+        b"auds" => {
+            // WAVEFORMATEX：wFormatTag(2) nChannels(2) nSamplesPerSec(4) nAvgBytesPerSec(4)
+            let mut track = TrackInfo::new(TrackKind::Audio, index, tracks::avi_audio_codec_name(le_u16(strf, 0)?));
+            track.channels = le_u16(strf, 2).map(|c| c as u32).filter(|c| *c > 0);
+            track.sample_rate = le_u32(strf, 4).filter(|r| *r > 0);
+            track.bit_rate = le_u32(strf, 8).filter(|b| *b > 0).map(|b| b as u64 * 8);
+            track
+        }
+        _ => return None,
+    };
+    track.title = name;
+    Some(track)
+}
+
+/// 从 `hdrl` 中读取所有轨道，AVI 没有默认轨道标记，每种类型的第一条轨道视为默认
+pub(super) fn tracks_from_hdrl(hdrl: &[u8]) -> Vec<TrackInfo> {
+    let mut tracks: Vec<TrackInfo> = Vec::new();
+    let strls = ChunkIter::new(hdrl).filter(|(kind, body)| kind == b"LIST" && body.starts_with(b"strl"));
+    for (index, (_, body)) in strls.enumerate() {
+        if let Some(mut track) = parse_strl(index as u32, &body[4..]) {
+            track.is_default = !tracks.iter().any(|t| t.kind == track.kind);
+            tracks.push(track);
+        }
+    }
+    tracks
+}
+
+/// 读取 AVI 文件的轨道信息
+pub(super) fn probe_tracks<R: Read + Seek>(reader: &mut R) -> io::Result<Vec<TrackInfo>> {
+    Ok(read_hdrl(reader)?.map(|hdrl| tracks_from_hdrl(&hdrl)).unwrap_or_default())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::super::tests::{probe_bytes, probe_track_bytes};
+    use super::super::TrackKind;
+
+    pub(crate) fn chunk(kind: &[u8; 4], body: &[u8]) -> Vec<u8> {
+        let mut data = kind.to_vec();
+        data.extend_from_slice(&(body.len() as u32).to_le_bytes());
+        data.extend_from_slice(body);
+        if body.len() % 2 == 1 {
+            data.push(0);
+        }
+        data
+    }
+
+    pub(crate) fn list(kind: &[u8; 4], children: &[u8]) -> Vec<u8> {
+        let mut body = kind.to_vec();
+        body.extend_from_slice(children);
+        chunk(b"LIST", &body)
+    }
+
+    pub(crate) fn avih(micros_per_frame: u32, total_frames: u32) -> Vec<u8> {
+        let mut body = vec![0u8; 56];
+        body[0..4].copy_from_slice(&micros_per_frame.to_le_bytes());
+        body[16..20].copy_from_slice(&total_frames.to_le_bytes());
+        chunk(b"avih", &body)
+    }
+
+    pub(crate) fn riff(hdrl_children: &[u8]) -> Vec<u8> {
+        let mut body = b"AVI ".to_vec();
+        body.extend(list(b"hdrl", hdrl_children));
+        body.extend(list(b"movi", &[0u8; 64]));
+        chunk(b"RIFF", &body)
+    }
+
+    #[test]
+    fn test_avi_duration() {
+        // 25 fps，共 2500 帧
+        let data = riff(&avih(40_000, 2500));
+        assert_eq!(probe_bytes("duration.avi", &data), Some(100.0));
+    }
+
+    #[test]
+    fn test_opendml_total_frames() {
+        let mut hdrl = avih(40_000, 1000);
+        hdrl.extend(list(b"strl", &chunk(b"strh", &[0u8; 56])));
+        hdrl.extend(list(b"odml", &chunk(b"dmlh", &90_000u32.to_le_bytes())));
+
+        let data = riff(&hdrl);
+        assert_eq!(probe_bytes("opendml.avi", &data), Some(3600.0));
+    }
+
+    #[test]
+    fn test_avi_tracks() {
+        // XviD 720x480，25 fps
+        let mut strh = b"vidsxvid".to_vec();
+        strh.extend_from_slice(&[0u8; 12]);
+        strh.extend_from_slice(&1u32.to_le_bytes());
+        strh.extend_from_slice(&25u32.to_le_bytes());
+        strh.resize(56, 0);
+        let mut strf = vec![0u8; 40];
+        strf[4..8].copy_from_slice(&720u32.to_le_bytes());
+        strf[8..12].copy_from_slice(&480u32.to_le_bytes());
+        strf[16..20].copy_from_slice(b"XVID");
+        let mut video = chunk(b"strh", &strh);
+        video.extend(chunk(b"strf", &strf));
+
+        // MP3 立体声 44.1kHz 128kbps
+        let mut strh = b"auds".to_vec();
+        strh.resize(56, 0);
+        let mut strf = 0x55u16.to_le_bytes().to_vec();
+        strf.extend_from_slice(&2u16.to_le_bytes());
+        strf.extend_from_slice(&44_100u32.to_le_bytes());
+        strf.extend_from_slice(&16_000u32.to_le_bytes());
+        strf.resize(30, 0);
+        let mut audio = chunk(b"strh", &strh);
+        audio.extend(chunk(b"strf", &strf));
+
+        let mut hdrl = avih(40_000, 2500);
+        hdrl.extend(list(b"strl", &video));
+        hdrl.extend(list(b"strl", &audio));
+
+        let tracks = probe_track_bytes("tracks.avi", &riff(&hdrl));
+        assert_eq!(tracks.len(), 2);
+        assert_eq!((tracks[0].kind, tracks[0].codec.as_str()), (TrackKind::Video, "mpeg4"));
+        assert_eq!((tracks[0].width, tracks[0].height, tracks[0].frame_rate), (Some(720), Some(480), Some(25.0)));
+        assert_eq!((tracks[1].kind, tracks[1].codec.as_str()), (TrackKind::Audio, "mp3"));
+        assert_eq!((tracks[1].channels, tracks[1].bit_rate), (Some(2), Some(128_000)));
+        assert!(tracks[0].is_default && tracks[1].is_default);
+    }
+}
@@ -0,0 +1,791 @@
+//! Matroska / WebM 容器解析（EBML）
+
+use super::read_payload;
+use super::tracks::{self, TrackInfo, TrackKind};
+use std::io::{self, Read, Seek, SeekFrom};
+
+pub(super) const EBML_HEADER: u32 = 0x1A45_DFA3;
+pub(super) const SEGMENT: u32 = 0x1853_8067;
+pub(super) const INFO: u32 = 0x1549_A966;
+pub(super) const TIMESTAMP_SCALE: u32 = 0x2A_D7B1;
+pub(super) const DURATION: u32 = 0x4489;
+pub(super) const CLUSTER: u32 = 0x1F43_B675;
+pub(super) const TRACKS: u32 = 0x1654_AE6B;
+
+// TrackEntry 及其子元素
+const TRACK_ENTRY: u32 = 0xAE;
+const TRACK_NUMBER: u32 = 0xD7;
+const TRACK_TYPE: u32 = 0x83;
+const CODEC_ID: u32 = 0x86;
+const NAME: u32 = 0x536E;
+const LANGUAGE: u32 = 0x22_B59C;
+const LANGUAGE_BCP47: u32 = 0x22_B59D;
+const FLAG_DEFAULT: u32 = 0x88;
+const FLAG_FORCED: u32 = 0x55AA;
+const DEFAULT_DURATION: u32 = 0x23_E383;
+const BLOCK_ADDITION_MAPPING: u32 = 0x41E4;
+const BLOCK_ADD_ID_TYPE: u32 = 0x41E7;
+const VIDEO: u32 = 0xE0;
+const PIXEL_WIDTH: u32 = 0xB0;
+const PIXEL_HEIGHT: u32 = 0xBA;
+const COLOUR: u32 = 0x55B0;
+const TRANSFER_CHARACTERISTICS: u32 = 0x55BA;
+const AUDIO: u32 = 0xE1;
+const SAMPLING_FREQUENCY: u32 = 0xB5;
+const CHANNELS: u32 = 0x9F;
+const CODEC_PRIVATE: u32 = 0x63A2;
+const CONTENT_ENCODINGS: u32 = 0x6D80;
+const CONTENT_ENCODING: u32 = 0x6240;
+const CONTENT_ENCODING_SCOPE: u32 = 0x5032;
+const CONTENT_COMPRESSION: u32 = 0x5034;
+const CONTENT_COMP_ALGO: u32 = 0x4254;
+const CONTENT_COMP_SETTINGS: u32 = 0x4255;
+const CONTENT_ENCRYPTION: u32 = 0x5035;
+
+// Cluster 及其子元素
+const CLUSTER_TIMESTAMP: u32 = 0xE7;
+const SIMPLE_BLOCK: u32 = 0xA3;
+const BLOCK_GROUP: u32 = 0xA0;
+const BLOCK: u32 = 0xA1;
+const BLOCK_DURATION: u32 = 0x9B;
+
+/// TrackType 取值
+const TRACK_TYPE_VIDEO: u64 = 1;
+const TRACK_TYPE_AUDIO: u64 = 2;
+const TRACK_TYPE_SUBTITLE: u64 = 17;
+
+/// 顶层元素（Info / Tracks 等）的最大读取大小
+const MAX_ELEMENT_SIZE: u64 = 16 * 1024 * 1024;
+
+/// 默认时间戳精度：1 毫秒（单位：纳秒）
+const DEFAULT_TIMESTAMP_SCALE: u64 = 1_000_000;
+
+/// Block 头部：轨道号（最多 8 字节）+ 相对时间戳（2 字节）+ 标志（1 字节）
+const MAX_BLOCK_HEADER_SIZE: u64 = 11;
+
+/// 从文件中读取 EBML 变长整数
+///
+/// - `keep_marker = true` 用于元素 ID（保留长度标记位）
+/// - `keep_marker = false` 用于元素大小，全 1 表示未知大小，返回 None
+fn read_vint<R: Read>(reader: &mut R, keep_marker: bool) -> io::Result<Option<u64>> {
+    let mut first = [0u8; 1];
+    reader.read_exact(&mut first)?;
+    let len = first[0].leading_zeros() as usize + 1;
+    if len > 8 {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "invalid EBML vint"));
+    }
+
+    let mut value = if keep_marker { first[0] as u64 } else { first[0] as u64 & (0xFF >> len) };
+    let mut rest = [0u8; 7];
+    reader.read_exact(&mut rest[..len - 1])?;
+    for byte in &rest[..len - 1] {
+        value = (value << 8) | *byte as u64;
+    }
+
+    if !keep_marker && value == (1u64 << (7 * len)) - 1 {
+        return Ok(None);
+    }
+    Ok(Some(value))
+}
+
+/// 从内存中读取 EBML 变长整数，见 [`read_vint`]
+fn parse_vint(data: &[u8], pos: &mut usize, keep_marker: bool) -> Option<Option<u64>> {
+    let first = *data.get(*pos)?;
+    let len = first.leading_zeros() as usize + 1;
+    if len > 8 || *pos + len > data.len() {
+        return None;
+    }
+
+    let mut value = if keep_marker { first as u64 } else { first as u64 & (0xFF >> len) };
+    for byte in &data[*pos + 1..*pos + len] {
+        value = (value << 8) | *byte as u64;
+    }
+    *pos += len;
+
+    if !keep_marker && value == (1u64 << (7 * len)) - 1 {
+        return Some(None);
+    }
+    Some(Some(value))
+}
+
+/// 内存中的 EBML 元素迭代器，依次返回 `(ID, 内容)`
+pub(super) struct ElementIter<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ElementIter<'a> {
+    pub(super) fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+}
+
+impl<'a> Iterator for ElementIter<'a> {
+    type Item = (u32, &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let id = parse_vint(self.data, &mut self.pos, true)?? as u32;
+        // 子元素中出现未知大小时，视为延伸到父元素结尾
+        let size = parse_vint(self.data, &mut self.pos, false)?
+            .map_or(self.data.len() - self.pos, |size| size as usize);
+
+        let end = self.pos.checked_add(size)?.min(self.data.len());
+        let body = &self.data[self.pos..end];
+        self.pos = end;
+        Some((id, body))
+    }
+}
+
+/// 读取无符号整数元素
+pub(super) fn read_uint(body: &[u8]) -> Option<u64> {
+    if body.is_empty() || body.len() > 8 {
+        return None;
+    }
+    Some(body.iter().fold(0u64, |value, byte| (value << 8) | *byte as u64))
+}
+
+/// 读取浮点数元素（4 或 8 字节）
+pub(super) fn read_float(body: &[u8]) -> Option<f64> {
+    match body.len() {
+        4 => Some(f32::from_be_bytes(body.try_into().ok()?) as f64),
+        8 => Some(f64::from_be_bytes(body.try_into().ok()?)),
+        _ => None,
+    }
+}
+
+/// 跳过 EBML 头，定位到 Segment 的内容开头
+///
+/// # 返回
+/// - `Ok(Some(u64))` - Segment 结尾在文件中的位置
+/// - `Ok(None)` - Segment 大小未知（延伸到文件结尾）
+fn open_segment<R: Read + Seek>(reader: &mut R) -> io::Result<Option<u64>> {
+    if read_vint(reader, true)? != Some(EBML_HEADER as u64) {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "missing EBML header"));
+    }
+    let header_size = read_vint(reader, false)?
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "unknown EBML header size"))?;
+    reader.seek(SeekFrom::Current(header_size as i64))?;
+
+    if read_vint(reader, true)? != Some(SEGMENT as u64) {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, "missing Segment"));
+    }
+    let segment_size = read_vint(reader, false)?;
+    segment_size.map(|size| reader.stream_position().map(|pos| pos + size)).transpose()
+}
+
+/// 读取下一个元素的 ID 和大小，到达 Segment 或文件结尾时返回 None
+fn next_element<R: Read + Seek>(reader: &mut R, segment_end: Option<u64>) -> io::Result<Option<(u32, Option<u64>)>> {
+    if segment_end.is_some_and(|end| reader.stream_position().is_ok_and(|pos| pos >= end)) {
+        return Ok(None);
+    }
+
+    let id = match read_vint(reader, true) {
+        Ok(Some(id)) => id as u32,
+        Ok(None) => return Ok(None),
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+        Err(e) => return Err(e),
+    };
+    match read_vint(reader, false) {
+        Ok(size) => Ok(Some((id, size))),
+        Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// 依次读取 Segment 的顶层子元素，直到 `visit` 返回 false 或遇到第一个 Cluster
+///
+/// Info / Tracks 等元数据元素通常位于 Cluster 之前，读到 Cluster 即停止，
+/// 不会扫描整个文件
+pub(super) fn for_each_segment_child<R, F>(reader: &mut R, ids: &[u32], mut visit: F) -> io::Result<()>
+where
+    R: Read + Seek,
+    F: FnMut(u32, &[u8]) -> bool,
+{
+    let segment_end = open_segment(reader)?;
+
+    while let Some((id, size)) = next_element(reader, segment_end)? {
+
+        if id == CLUSTER {
+            return Ok(());
+        }
+
+        match size {
+            Some(size) if ids.contains(&id) => {
+                let body = read_payload(reader, size, MAX_ELEMENT_SIZE)?;
+                if !visit(id, &body) {
+                    return Ok(());
+                }
+            }
+            Some(size) => {
+                reader.seek(SeekFrom::Current(size as i64))?;
+            }
+            // 未知大小的非 Cluster 元素无法跳过
+            None => return Ok(()),
+        }
+    }
+    Ok(())
+}
+
+/// 从 `Info` 元素中计算时长（秒）
+pub(super) fn duration_from_info(info: &[u8]) -> Option<f64> {
+    let mut scale = DEFAULT_TIMESTAMP_SCALE;
+    let mut duration = None;
+
+    for (id, body) in ElementIter::new(info) {
+        match id {
+            TIMESTAMP_SCALE => scale = read_uint(body).filter(|s| *s > 0).unwrap_or(DEFAULT_TIMESTAMP_SCALE),
+            DURATION => duration = read_float(body),
+            _ => {}
+        }
+    }
+
+    duration.map(|duration| duration * scale as f64 / 1_000_000_000.0)
+}
+
+/// 读取 Matroska / WebM 文件的时长（秒）
+pub(super) fn probe_duration<R: Read + Seek>(reader: &mut R) -> io::Result<Option<f64>> {
+    let mut duration = None;
+    for_each_segment_child(reader, &[INFO], |_, body| {
+        duration = duration_from_info(body);
+        false
+    })?;
+    Ok(duration)
+}
+
+/// 读取字符串元素（去掉结尾的填充 0）
+fn read_string(body: &[u8]) -> String {
+    String::from_utf8_lossy(body).trim_end_matches(char::from(0)).to_string()
+}
+
+/// 解析一个 `TrackEntry`，不是音视频或字幕轨道时返回 None
+fn parse_track_entry(entry: &[u8]) -> Option<TrackInfo> {
+    let mut number = None;
+    let mut track_type = None;
+    let mut codec_id = String::new();
+    let mut name = None;
+    // Language 缺省值为 eng，LanguageBCP47 存在时优先
+    let mut language = Some("eng".to_string());
+    let mut language_bcp47 = None;
+    let mut is_default = true;
+    let mut is_forced = false;
+    let mut default_duration = None;
+    let mut dolby_vision = false;
+    let mut video = None;
+    let mut audio = None;
+
+    for (id, body) in ElementIter::new(entry) {
+        match id {
+            TRACK_NUMBER => number = read_uint(body),
+            TRACK_TYPE => track_type = read_uint(body),
+            CODEC_ID => codec_id = read_string(body),
+            NAME => name = Some(read_string(body)).filter(|n| !n.is_empty()),
+            LANGUAGE => language = tracks::normalize_language(&read_string(body)),
+            LANGUAGE_BCP47 => language_bcp47 = tracks::normalize_language(&read_string(body)),
+            FLAG_DEFAULT => is_default = read_uint(body) != Some(0),
+            FLAG_FORCED => is_forced = read_uint(body).is_some_and(|f| f != 0),
+            DEFAULT_DURATION => default_duration = read_uint(body).filter(|d| *d > 0),
+            BLOCK_ADDITION_MAPPING => {
+                // 'dvcC' / 'dvvC'：杜比视界配置
+                dolby_vision |= ElementIter::new(body).any(|(id, body)| {
+                    id == BLOCK_ADD_ID_TYPE && matches!(read_uint(body), Some(0x6476_6343 | 0x6476_7643))
+                });
+            }
+            VIDEO => video = Some(body),
+            AUDIO => audio = Some(body),
+            _ => {}
+        }
+    }
+
+    let kind = match track_type? {
+        TRACK_TYPE_VIDEO => TrackKind::Video,
+        TRACK_TYPE_AUDIO => TrackKind::Audio,
+        TRACK_TYPE_SUBTITLE => TrackKind::Subtitle,
+        _ => return None,
+    };
+
+    let mut track = TrackInfo::new(kind, number? as u32, tracks::matroska_codec_name(&codec_id));
+    track.title = name;
+    track.language = language_bcp47.or(language);
+    track.is_default = is_default;
+    track.is_forced = is_forced;
+
+    if kind == TrackKind::Video {
+        // DefaultDuration 为每帧的纳秒数
+        track.frame_rate = default_duration.and_then(|ns| tracks::round_frame_rate(1_000_000_000.0 / ns as f64));
+        track.hdr = dolby_vision;
+        for (id, body) in ElementIter::new(video.unwrap_or_default()) {
+            match id {
+                PIXEL_WIDTH => track.width = read_uint(body).map(|w| w as u32),
+                PIXEL_HEIGHT => track.height = read_uint(body).map(|h| h as u32),
+                COLOUR => {
+                    let transfer = ElementIter::new(body)
+                        .find(|(id, _)| *id == TRANSFER_CHARACTERISTICS)
+                        .and_then(|(_, body)| read_uint(body));
+                    if transfer.is_some_and(tracks::is_hdr_transfer) {
+                        track.hdr = true;
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    if kind == TrackKind::Audio {
+        for (id, body) in ElementIter::new(audio.unwrap_or_default()) {
+            match id {
+                SAMPLING_FREQUENCY => track.sample_rate = read_float(body).map(|rate| rate.round() as u32),
+                CHANNELS => track.channels = read_uint(body).map(|c| c as u32),
+                _ => {}
+            }
+        }
+    }
+
+    Some(track)
+}
+
+/// 从 `Tracks` 元素中读取所有轨道
+pub(super) fn tracks_from_element(tracks: &[u8]) -> Vec<TrackInfo> {
+    ElementIter::new(tracks)
+        .filter(|(id, _)| *id == TRACK_ENTRY)
+        .filter_map(|(_, entry)| parse_track_entry(entry))
+        .collect()
+}
+
+/// 读取 Matroska / WebM 文件的轨道信息
+pub(super) fn probe_tracks<R: Read + Seek>(reader: &mut R) -> io::Result<Vec<TrackInfo>> {
+    let mut tracks = Vec::new();
+    for_each_segment_child(reader, &[TRACKS], |_, body| {
+        tracks = tracks_from_element(body);
+        false
+    })?;
+    Ok(tracks)
+}
+
+/// 内嵌字幕的一个字幕块
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubtitleBlock {
+    /// 开始时间（毫秒）
+    pub start: u64,
+    /// 持续时间（毫秒），SimpleBlock 没有 BlockDuration 时为 None
+    pub duration: Option<u64>,
+    /// 块内容（已解压）
+    pub data: Vec<u8>,
+}
+
+/// 从 Matroska 文件中提取的字幕轨道
+#[derive(Debug, Clone, Default)]
+pub struct SubtitleTrackData {
+    /// CodecID（如 `S_TEXT/UTF8`、`S_TEXT/ASS`）
+    pub codec_id: String,
+    /// CodecPrivate（ASS / SSA 为脚本头部和样式，已解压）
+    pub codec_private: Vec<u8>,
+    /// 字幕块（按文件中的顺序）
+    pub blocks: Vec<SubtitleBlock>,
+}
+
+/// 轨道内容的压缩方式（ContentCompression）
+#[derive(Debug, Clone, PartialEq)]
+enum Compression {
+    /// zlib（mkvmerge 旧版本默认对字幕使用）
+    Zlib,
+    /// 头部剥离：每个块都省略了相同的前缀
+    HeaderStripping(Vec<u8>),
+}
+
+impl Compression {
+    fn decode(&self, data: Vec<u8>) -> io::Result<Vec<u8>> {
+        match self {
+            Compression::Zlib => {
+                let mut decoded = Vec::new();
+                flate2::read::ZlibDecoder::new(data.as_slice())
+                    .take(MAX_ELEMENT_SIZE)
+                    .read_to_end(&mut decoded)?;
+                Ok(decoded)
+            }
+            Compression::HeaderStripping(prefix) => Ok([prefix.as_slice(), data.as_slice()].concat()),
+        }
+    }
+}
+
+/// 解析 `ContentEncodings`，返回块内容和 CodecPrivate 各自的压缩方式
+///
+/// 加密的轨道无法读取，返回错误
+fn parse_content_encodings(body: &[u8]) -> io::Result<(Option<Compression>, Option<Compression>)> {
+    let mut frames = None;
+    let mut private = None;
+
+    for (_, encoding) in ElementIter::new(body).filter(|(id, _)| *id == CONTENT_ENCODING) {
+        // Scope 缺省为 1（块内容），2 为 CodecPrivate
+        let mut scope = 1;
+        let mut compression = None;
+        for (id, body) in ElementIter::new(encoding) {
+            match id {
+                CONTENT_ENCODING_SCOPE => scope = read_uint(body).unwrap_or(1),
+                CONTENT_ENCRYPTION => {
+                    return Err(io::Error::new(io::ErrorKind::Unsupported, "encrypted track"));
+                }
+                CONTENT_COMPRESSION => {
+                    let mut algo = 0;
+                    let mut settings = Vec::new();
+                    for (id, body) in ElementIter::new(body) {
+                        match id {
+                            CONTENT_COMP_ALGO => algo = read_uint(body).unwrap_or(0),
+                            CONTENT_COMP_SETTINGS => settings = body.to_vec(),
+                            _ => {}
+                        }
+                    }
+                    compression = Some(match algo {
+                        0 => Compression::Zlib,
+                        3 => Compression::HeaderStripping(settings),
+                        _ => {
+                            return Err(io::Error::new(
+                                io::ErrorKind::Unsupported,
+                                format!("unsupported compression algorithm: {}", algo),
+                            ));
+                        }
+                    });
+                }
+                _ => {}
+            }
+        }
+
+        if scope & 1 != 0 {
+            frames = compression.clone();
+        }
+        if scope & 2 != 0 {
+            private = compression;
+        }
+    }
+
+    Ok((frames, private))
+}
+
+/// 在 `Tracks` 元素中查找指定轨道号的 CodecID、CodecPrivate 和块内容的压缩方式
+fn find_track_entry(tracks: &[u8], track_number: u64) -> io::Result<Option<(SubtitleTrackData, Option<Compression>)>> {
+    for (_, entry) in ElementIter::new(tracks).filter(|(id, _)| *id == TRACK_ENTRY) {
+        let number = ElementIter::new(entry)
+            .find(|(id, _)| *id == TRACK_NUMBER)
+            .and_then(|(_, body)| read_uint(body));
+        if number != Some(track_number) {
+            continue;
+        }
+
+        let mut track = SubtitleTrackData::default();
+        let mut encodings = (None, None);
+        for (id, body) in ElementIter::new(entry) {
+            match id {
+                CODEC_ID => track.codec_id = read_string(body),
+                CODEC_PRIVATE => track.codec_private = body.to_vec(),
+                CONTENT_ENCODINGS => encodings = parse_content_encodings(body)?,
+                _ => {}
+            }
+        }
+
+        let (frames, private) = encodings;
+        if let Some(private) = private {
+            track.codec_private = private.decode(std::mem::take(&mut track.codec_private))?;
+        }
+        return Ok(Some((track, frames)));
+    }
+    Ok(None)
+}
+
+/// 读取一个 Block / SimpleBlock
+///
+/// 先只读取头部判断轨道号，不是目标轨道时直接跳过块内容（视频帧）。
+///
+/// # 返回
+/// - `Ok(Some((相对时间戳, 块内容)))` - 目标轨道的块
+/// - `Ok(None)` - 其他轨道的块，或使用了 lacing（字幕轨道不会使用）
+fn read_block<R: Read + Seek>(reader: &mut R, size: u64, track_number: u64) -> io::Result<Option<(i16, Vec<u8>)>> {
+    let start = reader.stream_position()?;
+    let number = read_vint(reader, false)?;
+    let mut header = [0u8; 3];
+    reader.read_exact(&mut header)?;
+    let header_size = reader.stream_position()? - start;
+
+    let laced = header[2] & 0x06 != 0;
+    if number != Some(track_number) || laced || header_size > size.min(MAX_BLOCK_HEADER_SIZE) {
+        reader.seek(SeekFrom::Start(start + size))?;
+        return Ok(None);
+    }
+
+    let timestamp = i16::from_be_bytes([header[0], header[1]]);
+    let data = read_payload(reader, size - header_size, MAX_ELEMENT_SIZE)?;
+    Ok(Some((timestamp, data)))
+}
+
+/// 提取 Matroska 文件中指定轨道的所有块（用于内嵌文本字幕）
+///
+/// 需要扫描整个文件的 Cluster：Cluster 和 BlockGroup 不整体读入内存，
+/// 而是逐个读取子元素，其他轨道的块只读取头部后跳过。
+/// 直播录制的文件中 Cluster 的大小可能未知，此时同样按子元素依次读取。
+///
+/// # 参数
+/// - `track_number`: 轨道号（TrackNumber）
+///
+/// # 返回
+/// - `Ok(Some(SubtitleTrackData))` - 轨道数据
+/// - `Ok(None)` - 文件中没有该轨道
+pub(super) fn read_track_blocks<R: Read + Seek>(
+    reader: &mut R,
+    track_number: u64,
+) -> io::Result<Option<SubtitleTrackData>> {
+    let segment_end = open_segment(reader)?;
+
+    let mut scale = DEFAULT_TIMESTAMP_SCALE;
+    let mut track: Option<(SubtitleTrackData, Option<Compression>)> = None;
+    let mut cluster_timestamp = 0u64;
+    // 上一个 Block 是否属于目标轨道（之后的 BlockDuration 属于这个 Block）
+    let mut last_block_matched = false;
+    let to_millis = |timestamp: u64, scale: u64| (timestamp as u128 * scale as u128 / 1_000_000) as u64;
+
+    while let Some((id, size)) = next_element(reader, segment_end)? {
+        match (id, size) {
+            // 进入 Cluster 和 BlockGroup 的子元素（子元素 ID 与顶层元素不会冲突）
+            (CLUSTER, _) | (BLOCK_GROUP, _) => last_block_matched = false,
+            (INFO, Some(size)) => {
+                let info = read_payload(reader, size, MAX_ELEMENT_SIZE)?;
+                scale = ElementIter::new(&info)
+                    .find(|(id, _)| *id == TIMESTAMP_SCALE)
+                    .and_then(|(_, body)| read_uint(body))
+                    .filter(|s| *s > 0)
+                    .unwrap_or(DEFAULT_TIMESTAMP_SCALE);
+            }
+            (TRACKS, Some(size)) => {
+                let tracks = read_payload(reader, size, MAX_ELEMENT_SIZE)?;
+                track = find_track_entry(&tracks, track_number)?;
+                if track.is_none() {
+                    return Ok(None);
+                }
+            }
+            (CLUSTER_TIMESTAMP, Some(size)) => {
+                cluster_timestamp = read_uint(&read_payload(reader, size, 8)?).unwrap_or(0);
+            }
+            (SIMPLE_BLOCK, Some(size)) | (BLOCK, Some(size)) => {
+                last_block_matched = false;
+                // Tracks 位于 Cluster 之前，没读到 Tracks 时无法判断
+                let Some((data, compression)) = track.as_mut() else {
+                    reader.seek(SeekFrom::Current(size as i64))?;
+                    continue;
+                };
+                let Some((relative, payload)) = read_block(reader, size, track_number)? else {
+                    continue;
+                };
+
+                let timestamp = (cluster_timestamp as i64 + relative as i64).max(0) as u64;
+                let payload = match compression {
+                    Some(compression) => compression.decode(payload)?,
+                    None => payload,
+                };
+                data.blocks.push(SubtitleBlock {
+                    start: to_millis(timestamp, scale),
+                    duration: None,
+                    data: payload,
+                });
+                last_block_matched = true;
+            }
+            (BLOCK_DURATION, Some(size)) => {
+                let duration = read_uint(&read_payload(reader, size, 8)?);
+                if last_block_matched && let Some(block) = track.as_mut().and_then(|(data, _)| data.blocks.last_mut()) {
+                    block.duration = duration.map(|d| to_millis(d, scale));
+                }
+            }
+            (_, Some(size)) => {
+                reader.seek(SeekFrom::Current(size as i64))?;
+            }
+            // 未知大小的其他元素无法跳过
+            (_, None) => break,
+        }
+    }
+
+    Ok(track.map(|(data, _)| data))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use super::super::tests::{probe_bytes, probe_track_bytes};
+
+    /// 编码元素 ID（ID 本身已包含长度标记位）
+    fn encode_id(id: u32) -> Vec<u8> {
+        let bytes = id.to_be_bytes();
+        let skip = bytes.iter().take_while(|b| **b == 0).count();
+        bytes[skip..].to_vec()
+    }
+
+    /// 构造一个元素（大小固定使用 8 字节编码）
+    pub(crate) fn element(id: u32, body: &[u8]) -> Vec<u8> {
+        let mut data = encode_id(id);
+        data.push(0x01);
+        data.extend_from_slice(&(body.len() as u64).to_be_bytes()[1..]);
+        data.extend_from_slice(body);
+        data
+    }
+
+    /// 构造 EBML 头和大小未知的 Segment 的开头
+    pub(crate) fn segment_start(doc_type: &str) -> Vec<u8> {
+        let mut data = element(EBML_HEADER, &element(0x4282, doc_type.as_bytes()));
+        data.extend(encode_id(SEGMENT));
+        data.push(0xFF); // 未知大小（直播录制的文件常见）
+        data
+    }
+
+    #[test]
+    fn test_mkv_duration() {
+        let mut info = element(TIMESTAMP_SCALE, &1_000_000u32.to_be_bytes()[1..]);
+        info.extend(element(DURATION, &6_125_250.0f64.to_be_bytes()));
+
+        let mut data = segment_start("matroska");
+        data.extend(element(0x114D_9B74, &[0u8; 32])); // SeekHead
+        data.extend(element(INFO, &info));
+        data.extend(element(CLUSTER, &[0u8; 256]));
+
+        assert_eq!(probe_bytes("duration.mkv", &data), Some(6125.25));
+    }
+
+    #[test]
+    fn test_webm_float32_and_default_scale() {
+        // 没有 TimestampScale 时使用默认的 1ms，Duration 使用 4 字节浮点数
+        let info = element(DURATION, &90_000.0f32.to_be_bytes());
+
+        let mut data = segment_start("webm");
+        data.extend(element(INFO, &info));
+
+        assert_eq!(probe_bytes("duration.webm", &data), Some(90.0));
+    }
+
+    #[test]
+    fn test_mkv_without_duration() {
+        let info = element(TIMESTAMP_SCALE, &1_000_000u32.to_be_bytes()[1..]);
+
+        let mut data = segment_start("matroska");
+        data.extend(element(INFO, &info));
+        data.extend(element(CLUSTER, &[0u8; 16]));
+
+        assert_eq!(probe_bytes("no_duration.mkv", &data), None);
+    }
+
+    #[test]
+    fn test_mkv_tracks() {
+        // HEVC 1920x1080，HLG，23.976 fps
+        let mut video = element(TRACK_NUMBER, &[1]);
+        video.extend(element(TRACK_TYPE, &[1]));
+        video.extend(element(CODEC_ID, b"V_MPEGH/ISO/HEVC"));
+        video.extend(element(DEFAULT_DURATION, &41_708_333u32.to_be_bytes()));
+        let mut video_settings = element(PIXEL_WIDTH, &1920u16.to_be_bytes());
+        video_settings.extend(element(PIXEL_HEIGHT, &1080u16.to_be_bytes()));
+        video_settings.extend(element(COLOUR, &element(TRANSFER_CHARACTERISTICS, &[18])));
+        video.extend(element(VIDEO, &video_settings));
+
+        // 未写 Language 时默认为 eng
+        let mut audio = element(TRACK_NUMBER, &[2]);
+        audio.extend(element(TRACK_TYPE, &[2]));
+        audio.extend(element(CODEC_ID, b"A_EAC3"));
+        let mut audio_settings = element(SAMPLING_FREQUENCY, &48_000.0f64.to_be_bytes());
+        audio_settings.extend(element(CHANNELS, &[6]));
+        audio.extend(element(AUDIO, &audio_settings));
+
+        // 非默认的强制 PGS 字幕
+        let mut subtitle = element(TRACK_NUMBER, &[3]);
+        subtitle.extend(element(TRACK_TYPE, &[17]));
+        subtitle.extend(element(CODEC_ID, b"S_HDMV/PGS"));
+        subtitle.extend(element(LANGUAGE, b"chi"));
+        subtitle.extend(element(LANGUAGE_BCP47, b"zh-Hans"));
+        subtitle.extend(element(NAME, "简体中文".as_bytes()));
+        subtitle.extend(element(FLAG_DEFAULT, &[0]));
+        subtitle.extend(element(FLAG_FORCED, &[1]));
+
+        let mut tracks = element(TRACK_ENTRY, &video);
+        tracks.extend(element(TRACK_ENTRY, &audio));
+        tracks.extend(element(TRACK_ENTRY, &subtitle));
+
+        let mut data = segment_start("matroska");
+        data.extend(element(INFO, &element(DURATION, &60_000.0f64.to_be_bytes())));
+        data.extend(element(TRACKS, &tracks));
+        data.extend(element(CLUSTER, &[0u8; 16]));
+
+        let tracks = probe_track_bytes("tracks.mkv", &data);
+        assert_eq!(tracks.len(), 3);
+
+        let video = &tracks[0];
+        assert_eq!((video.kind, video.index, video.codec.as_str()), (TrackKind::Video, 1, "hevc"));
+        assert_eq!((video.width, video.height), (Some(1920), Some(1080)));
+        assert_eq!(video.frame_rate, Some(23.976));
+        assert!(video.hdr);
+
+        let audio = &tracks[1];
+        assert_eq!((audio.codec.as_str(), audio.channels, audio.sample_rate), ("eac3", Some(6), Some(48_000)));
+        assert_eq!(audio.language.as_deref(), Some("eng"));
+        assert!(audio.is_default);
+
+        let subtitle = &tracks[2];
+        assert_eq!((subtitle.kind, subtitle.codec.as_str()), (TrackKind::Subtitle, "pgs"));
+        assert_eq!(subtitle.language.as_deref(), Some("zh-Hans"));
+        assert_eq!(subtitle.title.as_deref(), Some("简体中文"));
+        assert!(subtitle.is_forced && !subtitle.is_default);
+    }
+
+    /// 构造一个 Block / SimpleBlock 的内容（轨道号小于 127）
+    fn block(track: u8, timestamp: i16, data: &[u8]) -> Vec<u8> {
+        let mut body = vec![0x80 | track];
+        body.extend(timestamp.to_be_bytes());
+        body.push(0x80);
+        body.extend_from_slice(data);
+        body
+    }
+
+    #[test]
+    fn test_read_subtitle_track() {
+        let mut video = element(TRACK_NUMBER, &[1]);
+        video.extend(element(TRACK_TYPE, &[1]));
+        video.extend(element(CODEC_ID, b"V_MPEG4/ISO/AVC"));
+
+        // 头部剥离压缩：每个块省略了相同的前缀
+        let mut subtitle = element(TRACK_NUMBER, &[2]);
+        subtitle.extend(element(TRACK_TYPE, &[17]));
+        subtitle.extend(element(CODEC_ID, b"S_TEXT/UTF8"));
+        let mut compression = element(CONTENT_COMP_ALGO, &[3]);
+        compression.extend(element(CONTENT_COMP_SETTINGS, "字幕".as_bytes()));
+        let encoding = element(CONTENT_ENCODING, &element(CONTENT_COMPRESSION, &compression));
+        subtitle.extend(element(CONTENT_ENCODINGS, &encoding));
+
+        let mut tracks = element(TRACK_ENTRY, &video);
+        tracks.extend(element(TRACK_ENTRY, &subtitle));
+
+        let mut data = segment_start("matroska");
+        // 时间戳精度 100 微秒
+        data.extend(element(INFO, &element(TIMESTAMP_SCALE, &100_000u32.to_be_bytes())));
+        data.extend(element(TRACKS, &tracks));
+
+        let mut cluster = element(CLUSTER_TIMESTAMP, &10_000u16.to_be_bytes());
+        cluster.extend(element(SIMPLE_BLOCK, &block(1, 0, &[0u8; 64])));
+        let mut group = element(BLOCK, &block(2, 5_000, "一".as_bytes()));
+        group.extend(element(BLOCK_DURATION, &15_000u16.to_be_bytes()));
+        cluster.extend(element(BLOCK_GROUP, &group));
+        data.extend(element(CLUSTER, &cluster));
+
+        // 未知大小的 Cluster，字幕使用 SimpleBlock（没有持续时间）
+        data.extend([0x1F, 0x43, 0xB6, 0x75, 0xFF]);
+        data.extend(element(CLUSTER_TIMESTAMP, &50_000u16.to_be_bytes()));
+        data.extend(element(SIMPLE_BLOCK, &block(2, -1_000, "二".as_bytes())));
+        data.extend(element(SIMPLE_BLOCK, &block(1, 0, &[0u8; 64])));
+        let mut group = element(BLOCK, &block(1, 10, &[0u8; 8]));
+        group.extend(element(BLOCK_DURATION, &[1]));
+        data.extend(element(BLOCK_GROUP, &group));
+
+        let mut reader = std::io::Cursor::new(data);
+        let track = read_track_blocks(&mut reader, 2).unwrap().unwrap();
+        assert_eq!(track.codec_id, "S_TEXT/UTF8");
+        assert_eq!(
+            track.blocks,
+            vec![
+                SubtitleBlock { start: 1_500, duration: Some(1_500), data: "字幕一".as_bytes().to_vec() },
+                SubtitleBlock { start: 4_900, duration: None, data: "字幕二".as_bytes().to_vec() },
+            ]
+        );
+
+        reader.set_position(0);
+        assert!(read_track_blocks(&mut reader, 3).unwrap().is_none());
+    }
+}
@@ -0,0 +1,203 @@
+//! 视频容器解析
+//!
+//! 纯 Rust 实现，只读取容器头部的少量数据，不依赖 ffmpeg 等外部程序。
+//! - MP4 / MOV：`moov/mvhd`，必要时回退到 `mvex/mehd` 和 `trak/mdia/mdhd`
+//! - Matroska / WebM：`Segment/Info/Duration`
+//! - AVI：`hdrl/avih`（OpenDML 文件使用 `odml/dmlh` 中的总帧数）
+//! - MPEG-TS / M2TS：首尾 PCR 时间戳之差（尽力而为）
+//!
+//! 轨道信息（编码、语言、声道、HDR 等）支持 MP4、Matroska 和 AVI，
+//! MPEG-TS 需要解析 PMT 和 PES，暂不支持
+//!
+//! Matroska 支持提取指定轨道的全部数据块（用于内嵌的文本字幕）
+
+mod avi;
+mod matroska;
+mod mp4;
+mod mpegts;
+mod tracks;
+
+pub use matroska::{SubtitleBlock, SubtitleTrackData};
+pub use tracks::{estimate_video_bit_rate, TrackInfo, TrackKind};
+
+use std::fs::File;
+use std::io::{self, BufReader, Read, Seek, SeekFrom};
+use std::path::Path;
+
+/// 视频容器格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContainerFormat {
+    /// MP4 / MOV / M4V（ISO Base Media File Format）
+    Mp4,
+    /// Matroska / WebM
+    Matroska,
+    /// AVI
+    Avi,
+    /// MPEG 传输流（包括 188 字节的 TS 和 192 字节的 M2TS）
+    MpegTs,
+}
+
+/// 根据文件头识别容器格式（不依赖扩展名）
+pub fn detect_format(header: &[u8]) -> Option<ContainerFormat> {
+    if header.len() >= 4 && header[..4] == [0x1A, 0x45, 0xDF, 0xA3] {
+        return Some(ContainerFormat::Matroska);
+    }
+    if header.len() >= 12 && &header[..4] == b"RIFF" && &header[8..12] == b"AVI " {
+        return Some(ContainerFormat::Avi);
+    }
+    if header.len() >= 8 && matches!(&header[4..8], b"ftyp" | b"moov" | b"mdat" | b"free" | b"wide" | b"skip") {
+        return Some(ContainerFormat::Mp4);
+    }
+    if mpegts::detect_packet_size(header).is_some() {
+        return Some(ContainerFormat::MpegTs);
+    }
+    None
+}
+
+/// 读取视频时长（秒）
+///
+/// # 返回
+/// - `Ok(Some(f64))` - 时长
+/// - `Ok(None)` - 格式不支持或容器中没有记录时长
+/// - `Err` - 读取文件失败
+pub fn probe_duration(path: &Path) -> io::Result<Option<f64>> {
+    let mut file = BufReader::new(File::open(path)?);
+
+    let mut header = [0u8; 1024];
+    let header_len = read_up_to(&mut file, &mut header)?;
+    let Some(format) = detect_format(&header[..header_len]) else {
+        return Ok(None);
+    };
+    file.seek(SeekFrom::Start(0))?;
+
+    let duration = match format {
+        ContainerFormat::Mp4 => mp4::probe_duration(&mut file)?,
+        ContainerFormat::Matroska => matroska::probe_duration(&mut file)?,
+        ContainerFormat::Avi => avi::probe_duration(&mut file)?,
+        ContainerFormat::MpegTs => mpegts::probe_duration(&mut file)?,
+    };
+
+    Ok(duration.filter(|seconds| seconds.is_finite() && *seconds > 0.0))
+}
+
+/// 读取视频的音视频和字幕轨道
+///
+/// # 返回
+/// - `Ok(Vec<TrackInfo>)` - 轨道列表（格式不支持时为空）
+/// - `Err` - 读取文件失败
+pub fn probe_tracks(path: &Path) -> io::Result<Vec<TrackInfo>> {
+    let mut file = BufReader::new(File::open(path)?);
+
+    let mut header = [0u8; 1024];
+    let header_len = read_up_to(&mut file, &mut header)?;
+    let Some(format) = detect_format(&header[..header_len]) else {
+        return Ok(Vec::new());
+    };
+    file.seek(SeekFrom::Start(0))?;
+
+    match format {
+        ContainerFormat::Mp4 => mp4::probe_tracks(&mut file),
+        ContainerFormat::Matroska => matroska::probe_tracks(&mut file),
+        ContainerFormat::Avi => avi::probe_tracks(&mut file),
+        ContainerFormat::MpegTs => Ok(Vec::new()),
+    }
+}
+
+/// 提取 Matroska 文件中指定轨道的数据块（用于内嵌字幕）
+///
+/// 需要扫描整个文件，大文件耗时较长，调用方应缓存转换结果
+///
+/// # 参数
+/// - `track_number`: 轨道号（即 [`TrackInfo::index`]）
+///
+/// # 返回
+/// - `Ok(Some(SubtitleTrackData))` - 轨道的 CodecID、CodecPrivate 和数据块
+/// - `Ok(None)` - 不是 Matroska 文件或没有该轨道
+/// - `Err` - 读取文件失败，或轨道已加密 / 使用了不支持的压缩方式
+pub fn extract_subtitle_track(path: &Path, track_number: u32) -> io::Result<Option<SubtitleTrackData>> {
+    let mut file = BufReader::new(File::open(path)?);
+
+    let mut header = [0u8; 4];
+    let header_len = read_up_to(&mut file, &mut header)?;
+    if detect_format(&header[..header_len]) != Some(ContainerFormat::Matroska) {
+        return Ok(None);
+    }
+    file.seek(SeekFrom::Start(0))?;
+
+    matroska::read_track_blocks(&mut file, track_number as u64)
+}
+
+/// 尽量填满缓冲区，遇到文件结尾时提前返回，返回实际读取的字节数
+fn read_up_to<R: Read>(reader: &mut R, buf: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..])? {
+            0 => break,
+            n => filled += n,
+        }
+    }
+    Ok(filled)
+}
+
+/// 读取指定长度的数据，超过上限时返回错误（防止损坏的文件导致分配超大内存）
+fn read_payload<R: Read>(reader: &mut R, len: u64, limit: u64) -> io::Result<Vec<u8>> {
+    if len > limit {
+        return Err(io::Error::new(io::ErrorKind::InvalidData, format!("element too large: {} bytes", len)));
+    }
+    let mut payload = vec![0u8; len as usize];
+    let filled = read_up_to(reader, &mut payload)?;
+    payload.truncate(filled);
+    Ok(payload)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// 把测试数据写入临时文件并读取时长
+    pub(crate) fn probe_bytes(name: &str, data: &[u8]) -> Option<f64> {
+        let path = std::env::temp_dir().join(format!("modream_{}_{}", std::process::id(), name));
+        std::fs::write(&path, data).unwrap();
+        let duration = probe_duration(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        duration
+    }
+
+    /// 把测试数据写入临时文件并读取轨道
+    pub(crate) fn probe_track_bytes(name: &str, data: &[u8]) -> Vec<TrackInfo> {
+        let path = std::env::temp_dir().join(format!("modream_{}_{}", std::process::id(), name));
+        std::fs::write(&path, data).unwrap();
+        let tracks = probe_tracks(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        tracks
+    }
+
+    #[test]
+    fn test_detect_format() {
+        assert_eq!(detect_format(b"\x00\x00\x00\x18ftypisom"), Some(ContainerFormat::Mp4));
+        assert_eq!(detect_format(&[0x1A, 0x45, 0xDF, 0xA3, 0x9F]), Some(ContainerFormat::Matroska));
+        assert_eq!(detect_format(b"RIFF\x00\x00\x00\x00AVI LIST"), Some(ContainerFormat::Avi));
+        assert_eq!(detect_format(b"not a video"), None);
+    }
+
+    #[test]
+    fn test_estimate_video_bit_rate() {
+        let video = TrackInfo::new(TrackKind::Video, 1, "h264".to_string());
+        let mut audio = TrackInfo::new(TrackKind::Audio, 2, "aac".to_string());
+        audio.bit_rate = Some(192_000);
+
+        // 100 秒、100 MB 的文件，整体码率 8 Mbit/s，减去音频码率
+        let tracks = vec![video.clone(), audio.clone()];
+        assert_eq!(estimate_video_bit_rate(&tracks, 100_000_000, 100), Some(7_808_000));
+        // 时长未知
+        assert_eq!(estimate_video_bit_rate(&tracks, 100_000_000, 0), None);
+        // 音频码率未知时无法区分视频和音频
+        audio.bit_rate = None;
+        assert_eq!(estimate_video_bit_rate(&[video, audio], 100_000_000, 100), None);
+    }
+
+    #[test]
+    fn test_unknown_format() {
+        assert_eq!(probe_bytes("unknown.bin", b"hello world, this is not a video"), None);
+    }
+}
@@ -0,0 +1,492 @@
+//! MP4 / MOV 容器解析（ISO/IEC 14496-12）
+
+use super::tracks::{self, TrackInfo, TrackKind};
+use super::{read_payload, read_up_to};
+use std::io::{self, Read, Seek, SeekFrom};
+
+/// `moov` box 的最大读取大小
+const MAX_MOOV_SIZE: u64 = 64 * 1024 * 1024;
+
+/// 内存中的 box 迭代器，依次返回 `(类型, 内容)`
+pub(super) struct BoxIter<'a> {
+    data: &'a [u8],
+}
+
+impl<'a> BoxIter<'a> {
+    pub(super) fn new(data: &'a [u8]) -> Self {
+        Self { data }
+    }
+}
+
+impl<'a> Iterator for BoxIter<'a> {
+    type Item = ([u8; 4], &'a [u8]);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.data.len() < 8 {
+            return None;
+        }
+
+        let size = u32::from_be_bytes(self.data[0..4].try_into().ok()?) as u64;
+        let kind: [u8; 4] = self.data[4..8].try_into().ok()?;
+        let (header_len, size) = match size {
+            0 => (8, self.data.len() as u64),
+            1 => (16, u64::from_be_bytes(self.data.get(8..16)?.try_into().ok()?)),
+            size => (8, size),
+        };
+
+        if size < header_len as u64 || size > self.data.len() as u64 {
+            self.data = &[];
+            return None;
+        }
+
+        let body = &self.data[header_len..size as usize];
+        self.data = &self.data[size as usize..];
+        Some((kind, body))
+    }
+}
+
+/// 在文件中查找并读取 `moov` box 的内容
+///
+/// `moov` 可能位于 `mdat` 之后（未做 faststart 的文件），其他 box 直接跳过
+pub(super) fn read_moov<R: Read + Seek>(reader: &mut R) -> io::Result<Option<Vec<u8>>> {
+    loop {
+        let mut header = [0u8; 8];
+        if read_up_to(reader, &mut header)? < 8 {
+            return Ok(None);
+        }
+
+        let size = u32::from_be_bytes([header[0], header[1], header[2], header[3]]) as u64;
+        let kind = &header[4..8];
+        let (header_len, size) = match size {
+            0 => (8, None),
+            1 => {
+                let mut large = [0u8; 8];
+                reader.read_exact(&mut large)?;
+                (16, Some(u64::from_be_bytes(large)))
+            }
+            size => (8, Some(size)),
+        };
+
+        if kind == b"moov" {
+            let body_len = match size {
+                Some(size) => size.saturating_sub(header_len),
+                None => MAX_MOOV_SIZE,
+            };
+            return read_payload(reader, body_len.min(MAX_MOOV_SIZE), MAX_MOOV_SIZE).map(Some);
+        }
+
+        match size {
+            Some(size) if size >= header_len => {
+                reader.seek(SeekFrom::Current((size - header_len) as i64))?;
+            }
+            // 延伸到文件结尾的 box 或损坏的 size，后面不会再有 moov
+            _ => return Ok(None),
+        }
+    }
+}
+
+/// 解析 `mvhd` / `mdhd` 的时间基准和时长
+///
+/// 两者在 version 0 / 1 下的字段布局相同：
+/// version(1) flags(3) creation_time modification_time timescale(4) duration
+pub(super) fn parse_timed_header(body: &[u8]) -> Option<(u32, Option<u64>)> {
+    let version = *body.first()?;
+    let (timescale, duration) = if version == 1 {
+        let timescale = u32::from_be_bytes(body.get(20..24)?.try_into().ok()?);
+        let duration = u64::from_be_bytes(body.get(24..32)?.try_into().ok()?);
+        (timescale, (duration != u64::MAX).then_some(duration))
+    } else {
+        let timescale = u32::from_be_bytes(body.get(12..16)?.try_into().ok()?);
+        let duration = u32::from_be_bytes(body.get(16..20)?.try_into().ok()?);
+        (timescale, (duration != u32::MAX).then_some(duration as u64))
+    };
+    Some((timescale, duration))
+}
+
+/// 查找子 box
+pub(super) fn find_child<'a>(body: &'a [u8], kind: &[u8; 4]) -> Option<&'a [u8]> {
+    BoxIter::new(body).find(|(k, _)| k == kind).map(|(_, b)| b)
+}
+
+/// 将时长换算为秒（时间基准为 0 或时长为 0 时视为未知）
+fn to_seconds(timescale: u32, duration: Option<u64>) -> Option<f64> {
+    match (timescale, duration) {
+        (0, _) | (_, None) | (_, Some(0)) => None,
+        (timescale, Some(duration)) => Some(duration as f64 / timescale as f64),
+    }
+}
+
+/// 从 `moov` 内容中计算时长
+///
+/// 优先使用 `mvhd`，分片 MP4 的 `mvhd` 时长通常为 0，此时依次尝试
+/// `mvex/mehd`（整体分片时长）和各轨道 `mdhd` 中的最大值
+pub(super) fn duration_from_moov(moov: &[u8]) -> Option<f64> {
+    let mut movie_timescale = 0;
+    let mut movie_duration = None;
+    let mut fragment_duration = None;
+    let mut track_duration: Option<f64> = None;
+
+    for (kind, body) in BoxIter::new(moov) {
+        match &kind {
+            b"mvhd" => {
+                if let Some((timescale, duration)) = parse_timed_header(body) {
+                    movie_timescale = timescale;
+                    movie_duration = duration;
+                }
+            }
+            b"mvex" => {
+                if let Some(mehd) = find_child(body, b"mehd") {
+                    fragment_duration = match mehd.first() {
+                        Some(1) => mehd.get(4..12).map(|b| u64::from_be_bytes(b.try_into().unwrap())),
+                        Some(_) => mehd.get(4..8).map(|b| u32::from_be_bytes(b.try_into().unwrap()) as u64),
+                        None => None,
+                    };
+                }
+            }
+            b"trak" => {
+                let seconds = find_child(body, b"mdia")
+                    .and_then(|mdia| find_child(mdia, b"mdhd"))
+                    .and_then(parse_timed_header)
+                    .and_then(|(timescale, duration)| to_seconds(timescale, duration));
+                if let Some(seconds) = seconds {
+                    track_duration = Some(track_duration.map_or(seconds, |max| max.max(seconds)));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    to_seconds(movie_timescale, movie_duration)
+        .or_else(|| to_seconds(movie_timescale, fragment_duration))
+        .or(track_duration)
+}
+
+/// 读取 MP4 文件的时长（秒）
+pub(super) fn probe_duration<R: Read + Seek>(reader: &mut R) -> io::Result<Option<f64>> {
+    Ok(read_moov(reader)?.and_then(|moov| duration_from_moov(&moov)))
+}
+
+fn be_u16(body: &[u8], at: usize) -> Option<u16> {
+    Some(u16::from_be_bytes(body.get(at..at + 2)?.try_into().ok()?))
+}
+
+fn be_u32(body: &[u8], at: usize) -> Option<u32> {
+    Some(u32::from_be_bytes(body.get(at..at + 4)?.try_into().ok()?))
+}
+
+/// 解析 `tkhd` 的 track_ID 和 flags
+fn parse_tkhd(body: &[u8]) -> Option<(u32, u32)> {
+    let flags = be_u32(body, 0)? & 0x00FF_FFFF;
+    let track_id = if body.first() == Some(&1) { be_u32(body, 20)? } else { be_u32(body, 12)? };
+    Some((track_id, flags))
+}
+
+/// 解析 `mdhd` 中的语言（3 个 5 位字符，每个加 0x60）
+fn parse_mdhd_language(body: &[u8]) -> Option<String> {
+    let packed = if body.first() == Some(&1) { be_u16(body, 32)? } else { be_u16(body, 20)? };
+    let language: String = [10, 5, 0]
+        .iter()
+        .map(|shift| (((packed >> shift) & 0x1F) as u8 + 0x60) as char)
+        .collect();
+    if !language.chars().all(|c| c.is_ascii_lowercase()) {
+        return None;
+    }
+    tracks::normalize_language(&language)
+}
+
+/// 统计 `stts` 中的样本总数
+fn sample_count(stts: &[u8]) -> Option<u64> {
+    let entries = be_u32(stts, 4)? as usize;
+    let total = (0..entries)
+        .map_while(|i| be_u32(stts, 8 + i * 8))
+        .map(|count| count as u64)
+        .sum();
+    Some(total)
+}
+
+/// 从样本描述的子 box 中读取视频附加信息（HDR、平均码率）
+fn apply_sample_children(track: &mut TrackInfo, children: &[u8]) {
+    for (kind, body) in BoxIter::new(children) {
+        match &kind {
+            // nclx：colour_type(4) colour_primaries(2) transfer_characteristics(2) ...
+            b"colr" if body.starts_with(b"nclx")
+                && be_u16(body, 6).is_some_and(|transfer| tracks::is_hdr_transfer(transfer as u64)) =>
+            {
+                track.hdr = true
+            }
+            // 杜比视界配置
+            b"dvcC" | b"dvvC" | b"dvwC" => track.hdr = true,
+            // bufferSizeDB(4) maxBitrate(4) avgBitrate(4)
+            b"btrt" => track.bit_rate = be_u32(body, 8).filter(|rate| *rate > 0).map(|rate| rate as u64),
+            _ => {}
+        }
+    }
+}
+
+/// 解析一个 `trak`，不是音视频或字幕轨道时返回 None
+fn parse_trak(trak: &[u8]) -> Option<TrackInfo> {
+    let (track_id, flags) = find_child(trak, b"tkhd").and_then(parse_tkhd)?;
+    let mdia = find_child(trak, b"mdia")?;
+    let handler: [u8; 4] = find_child(mdia, b"hdlr")?.get(8..12)?.try_into().ok()?;
+    let kind = match &handler {
+        b"vide" => TrackKind::Video,
+        b"soun" => TrackKind::Audio,
+        b"subt" | b"text" | b"sbtl" | b"clcp" => TrackKind::Subtitle,
+        _ => return None,
+    };
+
+    let stbl = find_child(mdia, b"minf").and_then(|minf| find_child(minf, b"stbl"))?;
+    // stsd：version/flags(4) entry_count(4)，之后是样本描述 box，只取第一个
+    let (fourcc, entry) = BoxIter::new(find_child(stbl, b"stsd")?.get(8..)?).next()?;
+
+    let mut track = TrackInfo::new(kind, track_id, tracks::mp4_codec_name(&fourcc));
+    track.is_default = flags & 0x1 != 0;
+
+    let mdhd = find_child(mdia, b"mdhd");
+    track.language = mdhd.and_then(parse_mdhd_language);
+
+    // 样本描述的公共头：reserved(6) data_reference_index(2)
+    match kind {
+        TrackKind::Video => {
+            // pre_defined(2) reserved(2) pre_defined(12) width(2) height(2) ... 共 78 字节
+            track.width = be_u16(entry, 24).map(|w| w as u32).filter(|w| *w > 0);
+            track.height = be_u16(entry, 26).map(|h| h as u32).filter(|h| *h > 0);
+            track.hdr = matches!(&fourcc, b"dvh1" | b"dvhe" | b"dva1" | b"dvav" | b"dav1");
+            if let Some(children) = entry.get(78..) {
+                apply_sample_children(&mut track, children);
+            }
+
+            // 帧率 = 样本数 / 轨道时长
+            let timed = mdhd.and_then(parse_timed_header);
+            let samples = find_child(stbl, b"stts").and_then(sample_count);
+            if let (Some((timescale, Some(duration))), Some(samples)) = (timed, samples)
+                && timescale > 0
+                && duration > 0
+            {
+                track.frame_rate = tracks::round_frame_rate(samples as f64 * timescale as f64 / duration as f64);
+            }
+        }
+        TrackKind::Audio => {
+            // version(2) revision(2) vendor(4) channelcount(2) samplesize(2) pre_defined(2) reserved(2) samplerate(16.16)
+            track.channels = be_u16(entry, 16).map(|c| c as u32).filter(|c| *c > 0);
+            track.sample_rate = be_u16(entry, 24).map(|r| r as u32).filter(|r| *r > 0);
+            // QuickTime 声音描述 version 1 / 2 在公共字段之后还有额外字段
+            let children_at = match be_u16(entry, 8) {
+                Some(1) => 44,
+                Some(2) => 64,
+                _ => 28,
+            };
+            if let Some(children) = entry.get(children_at..) {
+                apply_sample_children(&mut track, children);
+            }
+        }
+        TrackKind::Subtitle => {
+            // tx3g：displayFlags 最高位表示所有样本强制显示
+            if &fourcc == b"tx3g" {
+                track.is_forced = be_u32(entry, 8).is_some_and(|flags| flags & 0x8000_0000 != 0);
+            }
+        }
+    }
+
+    Some(track)
+}
+
+/// 从 `moov` 内容中读取所有轨道
+pub(super) fn tracks_from_moov(moov: &[u8]) -> Vec<TrackInfo> {
+    BoxIter::new(moov)
+        .filter(|(kind, _)| kind == b"trak")
+        .filter_map(|(_, trak)| parse_trak(trak))
+        .collect()
+}
+
+/// 读取 MP4 文件的轨道信息
+pub(super) fn probe_tracks<R: Read + Seek>(reader: &mut R) -> io::Result<Vec<TrackInfo>> {
+    Ok(read_moov(reader)?.map(|moov| tracks_from_moov(&moov)).unwrap_or_default())
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::super::tests::{probe_bytes, probe_track_bytes};
+    use super::super::TrackKind;
+
+    /// 构造一个 box
+    pub(crate) fn mp4_box(kind: &[u8; 4], body: &[u8]) -> Vec<u8> {
+        let mut data = ((body.len() + 8) as u32).to_be_bytes().to_vec();
+        data.extend_from_slice(kind);
+        data.extend_from_slice(body);
+        data
+    }
+
+    /// 构造 version 0 的 `mvhd` / `mdhd`
+    pub(crate) fn timed_header_v0(kind: &[u8; 4], timescale: u32, duration: u32) -> Vec<u8> {
+        let mut body = vec![0u8; 12];
+        body.extend_from_slice(&timescale.to_be_bytes());
+        body.extend_from_slice(&duration.to_be_bytes());
+        body.extend_from_slice(&[0u8; 80]);
+        mp4_box(kind, &body)
+    }
+
+    fn ftyp() -> Vec<u8> {
+        mp4_box(b"ftyp", b"isom\x00\x00\x02\x00isomiso2mp41")
+    }
+
+    #[test]
+    fn test_mvhd_duration_after_mdat() {
+        // moov 位于 mdat 之后（未做 faststart）
+        let mut data = ftyp();
+        data.extend(mp4_box(b"mdat", &[0u8; 4096]));
+        data.extend(mp4_box(b"moov", &timed_header_v0(b"mvhd", 1000, 5_400_500)));
+
+        assert_eq!(probe_bytes("mvhd.mp4", &data), Some(5400.5));
+    }
+
+    #[test]
+    fn test_mvhd_version1() {
+        let mut mvhd = vec![1u8, 0, 0, 0];
+        mvhd.extend_from_slice(&[0u8; 16]);
+        mvhd.extend_from_slice(&90_000u32.to_be_bytes());
+        mvhd.extend_from_slice(&(90_000u64 * 7200).to_be_bytes());
+        mvhd.extend_from_slice(&[0u8; 80]);
+
+        let mut data = ftyp();
+        data.extend(mp4_box(b"moov", &mp4_box(b"mvhd", &mvhd)));
+
+        assert_eq!(probe_bytes("mvhd_v1.mp4", &data), Some(7200.0));
+    }
+
+    #[test]
+    fn test_fragmented_falls_back_to_tracks() {
+        // 分片 MP4：mvhd 时长为 0，没有 mehd，使用最长轨道的 mdhd
+        let video = mp4_box(b"trak", &mp4_box(b"mdia", &timed_header_v0(b"mdhd", 24_000, 24_000 * 90)));
+        let audio = mp4_box(b"trak", &mp4_box(b"mdia", &timed_header_v0(b"mdhd", 48_000, 48_000 * 91)));
+
+        let mut moov = timed_header_v0(b"mvhd", 1000, 0);
+        moov.extend(video);
+        moov.extend(audio);
+
+        let mut data = ftyp();
+        data.extend(mp4_box(b"moov", &moov));
+        data.extend(mp4_box(b"moof", &[0u8; 64]));
+
+        assert_eq!(probe_bytes("fragmented.mp4", &data), Some(91.0));
+    }
+
+    #[test]
+    fn test_fragmented_mehd() {
+        let mut mehd = vec![0u8; 4];
+        mehd.extend_from_slice(&(1000u32 * 120).to_be_bytes());
+
+        let mut moov = timed_header_v0(b"mvhd", 1000, 0);
+        moov.extend(mp4_box(b"mvex", &mp4_box(b"mehd", &mehd)));
+
+        let mut data = ftyp();
+        data.extend(mp4_box(b"moov", &moov));
+
+        assert_eq!(probe_bytes("mehd.mp4", &data), Some(120.0));
+    }
+
+    #[test]
+    fn test_missing_moov() {
+        let mut data = ftyp();
+        data.extend(mp4_box(b"mdat", &[0u8; 128]));
+
+        assert_eq!(probe_bytes("no_moov.mp4", &data), None);
+    }
+
+    fn tkhd(track_id: u32, flags: u32) -> Vec<u8> {
+        let mut body = flags.to_be_bytes().to_vec();
+        body.extend_from_slice(&[0u8; 8]);
+        body.extend_from_slice(&track_id.to_be_bytes());
+        body.extend_from_slice(&[0u8; 68]);
+        mp4_box(b"tkhd", &body)
+    }
+
+    fn mdhd(timescale: u32, duration: u32, language: &str) -> Vec<u8> {
+        let packed = language.bytes().fold(0u16, |packed, c| (packed << 5) | (c - 0x60) as u16);
+        let mut body = vec![0u8; 12];
+        body.extend_from_slice(&timescale.to_be_bytes());
+        body.extend_from_slice(&duration.to_be_bytes());
+        body.extend_from_slice(&packed.to_be_bytes());
+        body.extend_from_slice(&[0u8; 2]);
+        mp4_box(b"mdhd", &body)
+    }
+
+    fn hdlr(handler: &[u8; 4]) -> Vec<u8> {
+        let mut body = vec![0u8; 8];
+        body.extend_from_slice(handler);
+        body.extend_from_slice(&[0u8; 13]);
+        mp4_box(b"hdlr", &body)
+    }
+
+    fn trak(track_id: u32, flags: u32, mdhd: Vec<u8>, handler: &[u8; 4], entry: Vec<u8>, stts: Option<Vec<u8>>) -> Vec<u8> {
+        let mut stsd = vec![0u8, 0, 0, 0, 0, 0, 0, 1];
+        stsd.extend(entry);
+        let mut stbl = mp4_box(b"stsd", &stsd);
+        stbl.extend(stts.unwrap_or_default());
+
+        let mut mdia = mdhd;
+        mdia.extend(hdlr(handler));
+        mdia.extend(mp4_box(b"minf", &mp4_box(b"stbl", &stbl)));
+
+        let mut body = tkhd(track_id, flags);
+        body.extend(mp4_box(b"mdia", &mdia));
+        mp4_box(b"trak", &body)
+    }
+
+    #[test]
+    fn test_mp4_tracks() {
+        // HEVC 3840x2160，PQ 传输特性，24000/1001 fps
+        let mut video_entry = vec![0u8; 78];
+        video_entry[24..26].copy_from_slice(&3840u16.to_be_bytes());
+        video_entry[26..28].copy_from_slice(&2160u16.to_be_bytes());
+        let mut colr = b"nclx".to_vec();
+        colr.extend_from_slice(&[0, 9, 0, 16, 0, 9, 0]);
+        video_entry.extend(mp4_box(b"colr", &colr));
+        let mut stts = vec![0u8, 0, 0, 0, 0, 0, 0, 1];
+        stts.extend_from_slice(&2400u32.to_be_bytes());
+        stts.extend_from_slice(&1001u32.to_be_bytes());
+        let video = trak(1, 3, mdhd(24_000, 2400 * 1001, "und"), b"vide", mp4_box(b"hvc1", &video_entry), Some(mp4_box(b"stts", &stts)));
+
+        // AAC 立体声 48kHz，日语
+        let mut audio_entry = vec![0u8; 28];
+        audio_entry[16..18].copy_from_slice(&2u16.to_be_bytes());
+        audio_entry[24..26].copy_from_slice(&48_000u16.to_be_bytes());
+        let audio = trak(2, 3, mdhd(48_000, 48_000 * 100, "jpn"), b"soun", mp4_box(b"mp4a", &audio_entry), None);
+
+        // 强制显示的 tx3g 字幕
+        let mut text_entry = vec![0u8; 8];
+        text_entry.extend_from_slice(&0x8000_0000u32.to_be_bytes());
+        let subtitle = trak(3, 0, mdhd(1000, 100_000, "chi"), b"sbtl", mp4_box(b"tx3g", &text_entry), None);
+
+        let mut moov = timed_header_v0(b"mvhd", 1000, 100_000);
+        moov.extend(video);
+        moov.extend(audio);
+        moov.extend(subtitle);
+        moov.extend(mp4_box(b"udta", &[0u8; 16]));
+
+        let mut data = ftyp();
+        data.extend(mp4_box(b"moov", &moov));
+
+        let tracks = probe_track_bytes("tracks.mp4", &data);
+        assert_eq!(tracks.len(), 3);
+
+        let video = &tracks[0];
+        assert_eq!((video.kind, video.index, video.codec.as_str()), (TrackKind::Video, 1, "hevc"));
+        assert_eq!((video.width, video.height), (Some(3840), Some(2160)));
+        assert_eq!(video.frame_rate, Some(23.976));
+        assert!(video.hdr);
+        assert_eq!(video.language, None);
+
+        let audio = &tracks[1];
+        assert_eq!((audio.kind, audio.codec.as_str()), (TrackKind::Audio, "aac"));
+        assert_eq!((audio.channels, audio.sample_rate), (Some(2), Some(48_000)));
+        assert_eq!(audio.language.as_deref(), Some("jpn"));
+        assert!(audio.is_default);
+
+        let subtitle = &tracks[2];
+        assert_eq!((subtitle.kind, subtitle.codec.as_str()), (TrackKind::Subtitle, "mov_text"));
+        assert_eq!(subtitle.language.as_deref(), Some("chi"));
+        assert!(subtitle.is_forced);
+        assert!(!subtitle.is_default);
+    }
+}
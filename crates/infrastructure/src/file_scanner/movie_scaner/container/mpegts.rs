@@ -0,0 +1,151 @@
+//! MPEG-TS / M2TS 容器解析
+//!
+//! 传输流没有记录总时长，只能读取文件开头和结尾的 PCR 时间戳相减（尽力而为）
+
+use super::read_up_to;
+use std::io::{self, Read, Seek, SeekFrom};
+
+const SYNC_BYTE: u8 = 0x47;
+
+/// 读取文件首尾时使用的窗口大小
+const PROBE_WINDOW: u64 = 2 * 1024 * 1024;
+
+/// PCR 基准时钟频率（90kHz）
+const PCR_CLOCK: f64 = 90_000.0;
+
+/// PCR 基准值是 33 位计数器，会回绕
+const PCR_WRAP: u64 = 1 << 33;
+
+/// 根据同步字节判断包大小
+///
+/// # 返回
+/// - `Some((包大小, 包内 TS 数据的偏移))`：TS 为 `(188, 0)`，带 4 字节时间码的 M2TS 为 `(192, 4)`
+pub(super) fn detect_packet_size(data: &[u8]) -> Option<(usize, usize)> {
+    [(188, 0), (192, 4)].into_iter().find(|&(size, offset)| {
+        (0..3).all(|i| data.get(offset + i * size) == Some(&SYNC_BYTE))
+    })
+}
+
+/// 从一个 TS 包中读取 PCR
+///
+/// # 返回
+/// - `Some((PID, PCR 基准值))`
+fn read_pcr(packet: &[u8]) -> Option<(u16, u64)> {
+    if packet.len() < 12 || packet[0] != SYNC_BYTE {
+        return None;
+    }
+
+    let pid = (((packet[1] & 0x1F) as u16) << 8) | packet[2] as u16;
+    let has_adaptation = packet[3] & 0x20 != 0;
+    let adaptation_len = packet[4];
+    let has_pcr = packet[5] & 0x10 != 0;
+    if !has_adaptation || adaptation_len < 7 || !has_pcr {
+        return None;
+    }
+
+    let base = ((packet[6] as u64) << 25)
+        | ((packet[7] as u64) << 17)
+        | ((packet[8] as u64) << 9)
+        | ((packet[9] as u64) << 1)
+        | ((packet[10] as u64) >> 7);
+    Some((pid, base))
+}
+
+/// 遍历缓冲区中的所有 PCR
+fn pcrs(data: &[u8], packet_size: usize, offset: usize) -> impl Iterator<Item = (u16, u64)> + '_ {
+    data.chunks_exact(packet_size).filter_map(move |chunk| read_pcr(&chunk[offset..]))
+}
+
+/// 读取 MPEG-TS 文件的时长（秒）
+pub(super) fn probe_duration<R: Read + Seek>(reader: &mut R) -> io::Result<Option<f64>> {
+    let file_len = reader.seek(SeekFrom::End(0))?;
+    reader.seek(SeekFrom::Start(0))?;
+
+    let mut head = vec![0u8; PROBE_WINDOW.min(file_len) as usize];
+    let head_len = read_up_to(reader, &mut head)?;
+    head.truncate(head_len);
+
+    let Some((packet_size, offset)) = detect_packet_size(&head) else {
+        return Ok(None);
+    };
+    let Some((pid, first)) = pcrs(&head, packet_size, offset).next() else {
+        return Ok(None);
+    };
+
+    // 结尾窗口按包大小对齐，保证与文件开头的包边界一致
+    let tail_start = file_len.saturating_sub(PROBE_WINDOW) / packet_size as u64 * packet_size as u64;
+    reader.seek(SeekFrom::Start(tail_start))?;
+    let mut tail = vec![0u8; (file_len - tail_start) as usize];
+    let tail_len = read_up_to(reader, &mut tail)?;
+    tail.truncate(tail_len);
+
+    let Some(last) = pcrs(&tail, packet_size, offset)
+        .filter(|(p, _)| *p == pid)
+        .map(|(_, pcr)| pcr)
+        .last()
+    else {
+        return Ok(None);
+    };
+
+    let ticks = (last + PCR_WRAP - first) % PCR_WRAP;
+    Ok(Some(ticks as f64 / PCR_CLOCK))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::tests::probe_bytes;
+
+    /// 构造带 PCR 的 TS 包（不带 PCR 时为普通负载包）
+    fn packet(pid: u16, pcr: Option<u64>) -> Vec<u8> {
+        let mut data = vec![SYNC_BYTE, (pid >> 8) as u8 & 0x1F, pid as u8];
+        match pcr {
+            Some(base) => {
+                data.push(0x30); // 自适应字段 + 负载
+                data.push(7);
+                data.push(0x10); // PCR 标志
+                data.push((base >> 25) as u8);
+                data.push((base >> 17) as u8);
+                data.push((base >> 9) as u8);
+                data.push((base >> 1) as u8);
+                data.push(((base & 1) << 7) as u8 | 0x7E);
+                data.push(0);
+            }
+            None => data.push(0x10),
+        }
+        data.resize(188, 0xFF);
+        data
+    }
+
+    fn stream(first_pcr: u64, seconds: u64, m2ts: bool) -> Vec<u8> {
+        let mut data = Vec::new();
+        let mut push = |p: Vec<u8>| {
+            if m2ts {
+                data.extend_from_slice(&[0u8; 4]);
+            }
+            data.extend(p);
+        };
+
+        push(packet(0x100, Some(first_pcr)));
+        for _ in 0..20 {
+            push(packet(0x101, None));
+        }
+        // 其他 PID 的 PCR 不参与计算
+        push(packet(0x200, Some(0)));
+        push(packet(0x100, Some((first_pcr + seconds * 90_000) % PCR_WRAP)));
+        push(packet(0x101, None));
+        data
+    }
+
+    #[test]
+    fn test_ts_duration() {
+        assert_eq!(probe_bytes("duration.ts", &stream(90_000, 1800, false)), Some(1800.0));
+    }
+
+    #[test]
+    fn test_m2ts_duration_with_wrap() {
+        // PCR 在录制过程中回绕
+        let first = PCR_WRAP - 90_000 * 10;
+        assert_eq!(probe_bytes("wrap.m2ts", &stream(first, 60, true)), Some(60.0));
+    }
+}
@@ -0,0 +1,220 @@
+//! 轨道信息
+//!
+//! 各容器解析出的编码标识（MP4 的 fourcc、Matroska 的 CodecID、AVI 的 fourcc / wFormatTag）
+//! 统一转换为 ffmpeg 风格的小写编码名（如 `h264`、`hevc`、`aac`、`subrip`），
+//! 方便客户端判断浏览器能否直接播放
+
+/// 轨道类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrackKind {
+    Video,
+    Audio,
+    Subtitle,
+}
+
+impl TrackKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TrackKind::Video => "video",
+            TrackKind::Audio => "audio",
+            TrackKind::Subtitle => "subtitle",
+        }
+    }
+}
+
+/// 容器中的一条轨道
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackInfo {
+    /// 轨道类型
+    pub kind: TrackKind,
+    /// 容器内的轨道编号（MP4 的 track_ID、Matroska 的 TrackNumber、AVI 的流序号）
+    pub index: u32,
+    /// 编码名
+    pub codec: String,
+    /// 语言（ISO 639-2 或 BCP 47，未指定时为 None）
+    pub language: Option<String>,
+    /// 轨道名称
+    pub title: Option<String>,
+    /// 是否为默认轨道
+    pub is_default: bool,
+    /// 是否为强制字幕
+    pub is_forced: bool,
+    /// 视频宽度（像素）
+    pub width: Option<u32>,
+    /// 视频高度（像素）
+    pub height: Option<u32>,
+    /// 帧率
+    pub frame_rate: Option<f64>,
+    /// 是否为 HDR（PQ / HLG 传输特性或杜比视界）
+    pub hdr: bool,
+    /// 码率（bit/s）
+    pub bit_rate: Option<u64>,
+    /// 声道数
+    pub channels: Option<u32>,
+    /// 采样率（Hz）
+    pub sample_rate: Option<u32>,
+}
+
+impl TrackInfo {
+    pub(super) fn new(kind: TrackKind, index: u32, codec: String) -> Self {
+        Self {
+            kind,
+            index,
+            codec,
+            language: None,
+            title: None,
+            is_default: false,
+            is_forced: false,
+            width: None,
+            height: None,
+            frame_rate: None,
+            hdr: false,
+            bit_rate: None,
+            channels: None,
+            sample_rate: None,
+        }
+    }
+}
+
+/// 容器中没有记录视频码率时，估算视频轨道的码率
+///
+/// 用文件整体码率（文件大小 / 时长）减去所有音频轨道的码率；
+/// 时长未知或有音频轨道没有记录码率时无法估算，返回 None
+pub fn estimate_video_bit_rate(tracks: &[TrackInfo], byte_size: u64, duration: u64) -> Option<u64> {
+    let audio_bit_rate: Option<u64> = tracks
+        .iter()
+        .filter(|t| t.kind == TrackKind::Audio)
+        .map(|t| t.bit_rate)
+        .sum();
+    (byte_size * 8)
+        .checked_div(duration)?
+        .checked_sub(audio_bit_rate?)
+        .filter(|bit_rate| *bit_rate > 0)
+}
+
+/// H.273 传输特性：SMPTE ST 2084（PQ，HDR10 / HDR10+）
+const TRANSFER_PQ: u64 = 16;
+/// H.273 传输特性：ARIB STD-B67（HLG）
+const TRANSFER_HLG: u64 = 18;
+
+/// 根据 H.273 传输特性判断是否为 HDR
+pub(super) fn is_hdr_transfer(transfer: u64) -> bool {
+    transfer == TRANSFER_PQ || transfer == TRANSFER_HLG
+}
+
+/// 规范化语言代码，`und`（未定义）和空字符串视为未指定
+pub(super) fn normalize_language(language: &str) -> Option<String> {
+    let language = language.trim_matches(char::from(0)).trim();
+    if language.is_empty() || language.eq_ignore_ascii_case("und") {
+        None
+    } else {
+        Some(language.to_string())
+    }
+}
+
+/// 帧率保留 3 位小数（23.976、29.97 等）
+pub(super) fn round_frame_rate(fps: f64) -> Option<f64> {
+    (fps.is_finite() && fps > 0.0).then(|| (fps * 1000.0).round() / 1000.0)
+}
+
+/// MP4 / MOV 样本描述的 fourcc 转换为编码名
+pub(super) fn mp4_codec_name(fourcc: &[u8; 4]) -> String {
+    let name = match fourcc {
+        b"avc1" | b"avc3" | b"dva1" | b"dvav" => "h264",
+        b"hvc1" | b"hev1" | b"dvh1" | b"dvhe" => "hevc",
+        b"av01" | b"dav1" => "av1",
+        b"vp09" => "vp9",
+        b"vp08" => "vp8",
+        b"mp4v" => "mpeg4",
+        b"mjpa" | b"mjpb" | b"jpeg" => "mjpeg",
+        b"apch" | b"apcn" | b"apcs" | b"apco" | b"ap4h" => "prores",
+        b"mp4a" => "aac",
+        b"ac-3" => "ac3",
+        b"ec-3" => "eac3",
+        b"ac-4" => "ac4",
+        b"Opus" => "opus",
+        b"fLaC" => "flac",
+        b"alac" => "alac",
+        b".mp3" => "mp3",
+        b"dtsc" | b"dtsh" | b"dtsl" | b"dtse" => "dts",
+        b"mlpa" => "truehd",
+        b"sowt" | b"twos" | b"lpcm" | b"ipcm" => "pcm",
+        b"tx3g" | b"text" => "mov_text",
+        b"wvtt" => "webvtt",
+        b"stpp" => "ttml",
+        b"c608" => "eia_608",
+        _ => return String::from_utf8_lossy(fourcc).trim().to_lowercase(),
+    };
+    name.to_string()
+}
+
+/// Matroska CodecID 转换为编码名
+pub(super) fn matroska_codec_name(codec_id: &str) -> String {
+    let name = match codec_id {
+        "V_MPEG4/ISO/AVC" => "h264",
+        "V_MPEGH/ISO/HEVC" => "hevc",
+        "V_AV1" => "av1",
+        "V_VP9" => "vp9",
+        "V_VP8" => "vp8",
+        "V_MPEG4/ISO/ASP" | "V_MPEG4/ISO/SP" | "V_MPEG4/ISO/AP" | "V_MPEG4/MS/V3" => "mpeg4",
+        "V_MPEG1" => "mpeg1video",
+        "V_MPEG2" => "mpeg2video",
+        "V_MJPEG" => "mjpeg",
+        "V_THEORA" => "theora",
+        "A_AC3" => "ac3",
+        "A_EAC3" => "eac3",
+        "A_TRUEHD" => "truehd",
+        "A_OPUS" => "opus",
+        "A_FLAC" => "flac",
+        "A_VORBIS" => "vorbis",
+        "A_ALAC" => "alac",
+        "A_MPEG/L3" => "mp3",
+        "A_MPEG/L2" => "mp2",
+        "S_TEXT/UTF8" | "S_TEXT/ASCII" => "subrip",
+        "S_TEXT/ASS" | "S_ASS" => "ass",
+        "S_TEXT/SSA" | "S_SSA" => "ssa",
+        "S_TEXT/WEBVTT" => "webvtt",
+        "S_HDMV/PGS" => "pgs",
+        "S_HDMV/TEXTST" => "hdmv_text",
+        "S_VOBSUB" => "vobsub",
+        "S_DVBSUB" => "dvb_subtitle",
+        id if id.starts_with("A_AAC") => "aac",
+        id if id.starts_with("A_DTS") => "dts",
+        id if id.starts_with("A_PCM") => "pcm",
+        id => return id.to_lowercase(),
+    };
+    name.to_string()
+}
+
+/// AVI 视频流的 fourcc（`strh.fccHandler` 或 `BITMAPINFOHEADER.biCompression`）转换为编码名
+pub(super) fn avi_video_codec_name(fourcc: &[u8; 4]) -> String {
+    let mut upper = *fourcc;
+    upper.make_ascii_uppercase();
+    let name = match &upper {
+        b"XVID" | b"DIVX" | b"DX50" | b"FMP4" | b"MP4V" | b"DIV3" | b"MP43" => "mpeg4",
+        b"H264" | b"X264" | b"AVC1" | b"DAVC" => "h264",
+        b"HEVC" | b"H265" | b"X265" | b"HVC1" => "hevc",
+        b"MJPG" => "mjpeg",
+        b"MPG2" | b"MPEG" => "mpeg2video",
+        b"WMV3" => "wmv3",
+        b"WVC1" => "vc1",
+        _ => return String::from_utf8_lossy(fourcc).trim_matches(char::from(0)).trim().to_lowercase(),
+    };
+    name.to_string()
+}
+
+/// AVI 音频流的 `WAVEFORMATEX.wFormatTag` 转换为编码名
+pub(super) fn avi_audio_codec_name(format_tag: u16) -> String {
+    let name = match format_tag {
+        0x0001 | 0x0003 | 0xFFFE => "pcm",
+        0x0050 => "mp2",
+        0x0055 => "mp3",
+        0x00FF | 0x1610 | 0x706D => "aac",
+        0x0161 | 0x0162 => "wmav2",
+        0x2000 => "ac3",
+        0x2001 => "dts",
+        0x566F => "vorbis",
+        tag => return format!("0x{:04x}", tag),
+    };
+    name.to_string()
+}
@@ -0,0 +1,264 @@
+//! 光盘结构电影（Blu-ray 的 `BDMV/`、DVD 的 `VIDEO_TS/`）
+//!
+//! 光盘备份中有大量 `.m2ts` / `.vob` 文件，整个光盘目录只对应一部电影。
+//! 正片取总大小最大的播放列表（Blu-ray 的 `BDMV/PLAYLIST/*.mpls`）
+//! 或标题集（DVD 的 `VTS_XX_1.VOB`、`VTS_XX_2.VOB`……），按播放顺序返回其中的流文件。
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 光盘类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DiscKind {
+    /// Blu-ray（`BDMV/`）
+    BluRay,
+    /// DVD（`VIDEO_TS/`）
+    Dvd,
+}
+
+impl DiscKind {
+    /// 光盘结构的顶层文件夹名
+    fn folder_name(&self) -> &'static str {
+        match self {
+            DiscKind::BluRay => "BDMV",
+            DiscKind::Dvd => "VIDEO_TS",
+        }
+    }
+
+    /// 对应的来源标记（与文件名解析出的来源一致）
+    pub fn source(&self) -> &'static str {
+        match self {
+            DiscKind::BluRay => "BluRay",
+            DiscKind::Dvd => "DVD",
+        }
+    }
+}
+
+/// 路径所在的光盘目录（`BDMV/` 或 `VIDEO_TS/` 的上一级）
+///
+/// 路径本身是 `BDMV/`、`VIDEO_TS/` 文件夹或其中的文件时返回光盘目录和类型
+pub fn disc_root(path: &Path) -> Option<(PathBuf, DiscKind)> {
+    path.ancestors().find_map(|ancestor| {
+        let name = ancestor.file_name()?.to_string_lossy();
+        let kind = [DiscKind::BluRay, DiscKind::Dvd]
+            .into_iter()
+            .find(|kind| name.eq_ignore_ascii_case(kind.folder_name()))?;
+        Some((ancestor.parent()?.to_path_buf(), kind))
+    })
+}
+
+/// 查找光盘的正片，返回按播放顺序排列的流文件
+///
+/// 找不到任何流文件时返回 `None`
+pub fn main_title(root: &Path, kind: DiscKind) -> Option<Vec<PathBuf>> {
+    let dir = find_child(root, kind.folder_name())?;
+    let streams = match kind {
+        DiscKind::BluRay => bluray_main_title(&dir),
+        DiscKind::Dvd => dvd_main_title(&dir),
+    };
+    (!streams.is_empty()).then_some(streams)
+}
+
+/// Blu-ray 正片：总大小最大的播放列表；没有可读的播放列表时取最大的流文件
+fn bluray_main_title(bdmv: &Path) -> Vec<PathBuf> {
+    let Some(stream_dir) = find_child(bdmv, "STREAM") else {
+        return Vec::new();
+    };
+    let streams = list_files(&stream_dir);
+    let find_stream = |clip: &str| {
+        let name = format!("{}.M2TS", clip);
+        streams.iter().find(|(file_name, _, _)| *file_name == name)
+    };
+
+    let mut playlists: Vec<(String, PathBuf)> = find_child(bdmv, "PLAYLIST")
+        .map(|dir| list_files(&dir))
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|(name, _, _)| name.ends_with(".MPLS"))
+        .map(|(name, path, _)| (name, path))
+        .collect();
+    playlists.sort();
+
+    let mut best: Option<(u64, Vec<PathBuf>)> = None;
+    for (_, path) in playlists {
+        let Some(clips) = fs::read(&path).ok().and_then(|data| read_playlist_clips(&data)) else {
+            continue;
+        };
+        let mut files: Vec<PathBuf> = Vec::new();
+        let mut size = 0;
+        for clip in clips {
+            if let Some((_, stream, len)) = find_stream(&clip)
+                && !files.contains(stream)
+            {
+                files.push(stream.clone());
+                size += len;
+            }
+        }
+        if !files.is_empty() && best.as_ref().is_none_or(|(best_size, _)| size > *best_size) {
+            best = Some((size, files));
+        }
+    }
+
+    match best {
+        Some((_, files)) => files,
+        None => streams
+            .iter()
+            .filter(|(name, _, _)| name.ends_with(".M2TS"))
+            .max_by_key(|(_, _, len)| *len)
+            .map(|(_, path, _)| vec![path.clone()])
+            .unwrap_or_default(),
+    }
+}
+
+/// 读取 MPLS 播放列表中按顺序引用的片段名（`00001`）
+fn read_playlist_clips(data: &[u8]) -> Option<Vec<String>> {
+    if data.get(..4)? != b"MPLS" {
+        return None;
+    }
+    let start = u32::from_be_bytes(data.get(8..12)?.try_into().ok()?) as usize;
+    // PlayList：长度(4) 保留(2) PlayItem 数量(2) SubPath 数量(2)
+    let count = u16::from_be_bytes(data.get(start + 6..start + 8)?.try_into().ok()?) as usize;
+
+    let mut pos = start + 10;
+    let mut clips = Vec::with_capacity(count);
+    for _ in 0..count {
+        // PlayItem：长度(2) 片段名(5) 编码标识(4) ……
+        let len = u16::from_be_bytes(data.get(pos..pos + 2)?.try_into().ok()?) as usize;
+        let name = data.get(pos + 2..pos + 7)?;
+        clips.push(String::from_utf8_lossy(name).to_string());
+        pos += 2 + len;
+    }
+    Some(clips)
+}
+
+/// DVD 标题集
+struct TitleSet {
+    /// 标题集编号（`VTS_01_1.VOB` 中的 `01`）
+    number: String,
+    /// 所有分段的总大小
+    size: u64,
+    /// (分段序号, 路径)
+    parts: Vec<(u32, PathBuf)>,
+}
+
+/// DVD 正片：总大小最大的标题集（`VTS_XX_0.VOB` 是菜单，不计入）
+fn dvd_main_title(video_ts: &Path) -> Vec<PathBuf> {
+    let mut title_sets: Vec<TitleSet> = Vec::new();
+    for (name, path, len) in list_files(video_ts) {
+        let Some((number, part)) = parse_vob_name(&name) else {
+            continue;
+        };
+        if part == 0 {
+            continue;
+        }
+        match title_sets.iter_mut().find(|set| set.number == number) {
+            Some(set) => {
+                set.size += len;
+                set.parts.push((part, path));
+            }
+            None => title_sets.push(TitleSet { number, size: len, parts: vec![(part, path)] }),
+        }
+    }
+
+    title_sets.sort_by(|a, b| a.number.cmp(&b.number));
+    let Some(mut main) = title_sets.into_iter().reduce(|best, set| if set.size > best.size { set } else { best })
+    else {
+        return Vec::new();
+    };
+    main.parts.sort();
+    main.parts.into_iter().map(|(_, path)| path).collect()
+}
+
+/// 解析 `VTS_01_2.VOB`，返回标题集编号和分段序号
+fn parse_vob_name(name: &str) -> Option<(String, u32)> {
+    let rest = name.strip_prefix("VTS_")?.strip_suffix(".VOB")?;
+    let (title_set, part) = rest.split_once('_')?;
+    Some((title_set.to_string(), part.parse().ok()?))
+}
+
+/// 忽略大小写查找子文件夹
+fn find_child(dir: &Path, name: &str) -> Option<PathBuf> {
+    fs::read_dir(dir)
+        .ok()?
+        .flatten()
+        .map(|entry| entry.path())
+        .find(|path| path.is_dir() && path.file_name().is_some_and(|n| n.to_string_lossy().eq_ignore_ascii_case(name)))
+}
+
+/// 列出目录中的文件：(大写文件名, 路径, 大小)
+fn list_files(dir: &Path) -> Vec<(String, PathBuf, u64)> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .flatten()
+        .filter_map(|entry| {
+            let metadata = entry.metadata().ok().filter(|m| m.is_file())?;
+            let name = entry.file_name().to_string_lossy().to_uppercase();
+            Some((name, entry.path(), metadata.len()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 构造只包含片段列表的 MPLS 文件
+    fn mpls(clips: &[&str]) -> Vec<u8> {
+        let mut data = b"MPLS0200".to_vec();
+        data.extend(20u32.to_be_bytes());
+        data.extend([0u8; 8]);
+        data.extend([0u8; 6]);
+        data.extend((clips.len() as u16).to_be_bytes());
+        data.extend([0u8; 2]);
+        for clip in clips {
+            data.extend(9u16.to_be_bytes());
+            data.extend(clip.as_bytes());
+            data.extend(b"M2TS");
+        }
+        data
+    }
+
+    fn write(path: &Path, len: usize) {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, vec![0u8; len]).unwrap();
+    }
+
+    #[test]
+    fn test_disc_root() {
+        let (root, kind) = disc_root(Path::new("/movies/Heat (1995)/BDMV/STREAM/00001.m2ts")).unwrap();
+        assert_eq!((root.as_path(), kind), (Path::new("/movies/Heat (1995)"), DiscKind::BluRay));
+        let (root, kind) = disc_root(Path::new("/movies/Heat/video_ts")).unwrap();
+        assert_eq!((root.as_path(), kind), (Path::new("/movies/Heat"), DiscKind::Dvd));
+        assert_eq!(disc_root(Path::new("/movies/Heat (1995)/Heat.mkv")), None);
+    }
+
+    #[test]
+    fn test_main_title() {
+        let dir = std::env::temp_dir().join(format!("modream-disc-{}", std::process::id()));
+
+        let bluray = dir.join("Heat (1995)");
+        write(&bluray.join("BDMV/STREAM/00001.m2ts"), 10);
+        write(&bluray.join("BDMV/STREAM/00002.m2ts"), 300);
+        write(&bluray.join("BDMV/STREAM/00003.m2ts"), 200);
+        fs::create_dir_all(bluray.join("BDMV/PLAYLIST")).unwrap();
+        fs::write(bluray.join("BDMV/PLAYLIST/00000.mpls"), mpls(&["00001"])).unwrap();
+        fs::write(bluray.join("BDMV/PLAYLIST/00800.mpls"), mpls(&["00003", "00002", "00003"])).unwrap();
+        let streams = main_title(&bluray, DiscKind::BluRay).unwrap();
+        let names: Vec<_> = streams.iter().map(|p| p.file_name().unwrap().to_string_lossy().to_string()).collect();
+        assert_eq!(names, ["00003.m2ts", "00002.m2ts"]);
+
+        let dvd = dir.join("Up (2009)");
+        write(&dvd.join("VIDEO_TS/VTS_01_0.VOB"), 500);
+        write(&dvd.join("VIDEO_TS/VTS_01_1.VOB"), 50);
+        write(&dvd.join("VIDEO_TS/VTS_02_2.VOB"), 100);
+        write(&dvd.join("VIDEO_TS/VTS_02_1.VOB"), 100);
+        let streams = main_title(&dvd, DiscKind::Dvd).unwrap();
+        let names: Vec<_> = streams.iter().map(|p| p.file_name().unwrap().to_string_lossy().to_string()).collect();
+        assert_eq!(names, ["VTS_02_1.VOB", "VTS_02_2.VOB"]);
+
+        assert_eq!(main_title(&dir, DiscKind::Dvd), None);
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
@@ -0,0 +1,240 @@
+//! 预告片和花絮
+//!
+//! 识别两种常见的整理方式（与 Jellyfin / Plex 一致）：
+//! - 电影目录下的 `Trailers/`、`Extras/`、`Featurettes/`、`Behind The Scenes/` 等子文件夹
+//! - 文件名以类型结尾：`Heat (1995)-trailer.mkv`、`Heat.making.of.mkv`、`trailer2.mp4`
+//!
+//! 识别出的文件不作为电影入库，而是挂到所属电影下。
+
+use super::container;
+use super::disc::disc_root;
+use super::models::video::{MovieExtra, VideoScanQueryResult};
+use regex::Regex;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+/// 花絮类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExtraKind {
+    /// 预告片
+    Trailer,
+    /// 样片
+    Sample,
+    /// 幕后花絮
+    BehindTheScenes,
+    /// 访谈
+    Interview,
+    /// 删减片段
+    DeletedScene,
+    /// 特辑
+    Featurette,
+    /// 其他附加内容
+    Extra,
+}
+
+impl ExtraKind {
+    /// 类型标识，用于数据库和 API
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExtraKind::Trailer => "trailer",
+            ExtraKind::Sample => "sample",
+            ExtraKind::BehindTheScenes => "behind_the_scenes",
+            ExtraKind::Interview => "interview",
+            ExtraKind::DeletedScene => "deleted_scene",
+            ExtraKind::Featurette => "featurette",
+            ExtraKind::Extra => "extra",
+        }
+    }
+}
+
+/// 花絮子文件夹名（忽略大小写）
+const EXTRA_FOLDERS: &[(&str, ExtraKind)] = &[
+    ("trailers", ExtraKind::Trailer),
+    ("预告片", ExtraKind::Trailer),
+    ("samples", ExtraKind::Sample),
+    ("sample", ExtraKind::Sample),
+    ("behind the scenes", ExtraKind::BehindTheScenes),
+    ("花絮", ExtraKind::BehindTheScenes),
+    ("interviews", ExtraKind::Interview),
+    ("deleted scenes", ExtraKind::DeletedScene),
+    ("featurettes", ExtraKind::Featurette),
+    ("extras", ExtraKind::Extra),
+    ("shorts", ExtraKind::Extra),
+    ("特典", ExtraKind::Extra),
+];
+
+/// 文件名末尾的类型标记（分隔符已换成空格），可以带编号 `trailer2`
+static EXTRA_SUFFIXES: LazyLock<Vec<(Regex, ExtraKind)>> = LazyLock::new(|| {
+    [
+        (r"(?i)(?:^| )(?:trailer|teaser)(?: ?\d{1,2})?$|预告片?\d{0,2}$", ExtraKind::Trailer),
+        (r"(?i)(?:^| )sample(?: ?\d{1,2})?$|样片$", ExtraKind::Sample),
+        (r"(?i)(?:^| )(?:making of|behind the scenes|bts)(?: ?\d{1,2})?$|(?:花絮|幕后)\d{0,2}$", ExtraKind::BehindTheScenes),
+        (r"(?i)(?:^| )interview(?: ?\d{1,2})?$|(?:访谈|采访)\d{0,2}$", ExtraKind::Interview),
+        (r"(?i)(?:^| )deleted(?: scenes?)?(?: ?\d{1,2})?$|删减片段\d{0,2}$", ExtraKind::DeletedScene),
+        (r"(?i)(?:^| )featurette(?: ?\d{1,2})?$", ExtraKind::Featurette),
+        (r"(?i)(?:^| )(?:extra|bonus)(?: ?\d{1,2})?$|特典\d{0,2}$", ExtraKind::Extra),
+    ]
+    .into_iter()
+    .map(|(pattern, kind)| (Regex::new(pattern).expect("invalid extra pattern"), kind))
+    .collect()
+});
+
+/// 识别出的花絮文件
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtraFile {
+    /// 文件路径
+    pub path: PathBuf,
+    /// 花絮类型
+    pub kind: ExtraKind,
+    /// 所属电影所在的目录
+    pub movie_dir: PathBuf,
+    /// 文件名中类型标记之前的部分（小写），用于在同目录多部电影时确定归属
+    pub movie_name: Option<String>,
+}
+
+impl ExtraFile {
+    /// 读取文件大小和时长
+    fn to_movie_extra(&self) -> MovieExtra {
+        let path = &self.path;
+        MovieExtra {
+            kind: self.kind,
+            title: path.file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_default(),
+            path: path.to_string_lossy().to_string(),
+            byte_size: fs::metadata(path).map(|m| m.len()).unwrap_or(0),
+            duration: container::probe_duration(path).ok().flatten().map_or(0, |s| s.round() as u64),
+            extension: path.extension().map(|e| e.to_string_lossy().to_lowercase()).unwrap_or_default(),
+        }
+    }
+}
+
+/// 判断视频是否为花絮
+pub fn detect_extra(path: &Path) -> Option<ExtraFile> {
+    let parent = path.parent()?;
+
+    // 花絮子文件夹：所属电影在上一级目录
+    let folder = parent.file_name().map(|n| n.to_string_lossy().to_lowercase()).unwrap_or_default();
+    if let Some((_, kind)) = EXTRA_FOLDERS.iter().find(|(name, _)| *name == folder) {
+        return Some(ExtraFile {
+            path: path.to_path_buf(),
+            kind: *kind,
+            movie_dir: parent.parent()?.to_path_buf(),
+            movie_name: None,
+        });
+    }
+
+    // 文件名以类型标记结尾
+    let stem = path.file_stem()?.to_string_lossy();
+    let normalized: String = stem
+        .chars()
+        .map(|c| if matches!(c, '.' | '_' | '-') { ' ' } else { c })
+        .collect();
+    let normalized = normalized.trim();
+    EXTRA_SUFFIXES.iter().find_map(|(re, kind)| {
+        let m = re.find(normalized)?;
+        let movie_name = normalized[..m.start()].trim().to_lowercase();
+        Some(ExtraFile {
+            path: path.to_path_buf(),
+            kind: *kind,
+            movie_dir: parent.to_path_buf(),
+            movie_name: (!movie_name.is_empty()).then_some(movie_name),
+        })
+    })
+}
+
+/// 把花絮挂到所属电影下
+///
+/// 在花絮所在目录的电影中查找：文件名带电影名时取文件名以此开头的电影，
+/// 否则目录中只有一部电影时归属这部电影。找不到归属的花絮被忽略
+pub fn attach_extras(videos: &mut [VideoScanQueryResult], extras: &[ExtraFile]) {
+    let mut orphans = 0;
+    for extra in extras {
+        let candidates: Vec<usize> = videos
+            .iter()
+            .enumerate()
+            .filter(|(_, video)| movie_dir(&video.path) == extra.movie_dir)
+            .map(|(i, _)| i)
+            .collect();
+
+        let owner = match &extra.movie_name {
+            Some(name) => candidates
+                .iter()
+                .copied()
+                .find(|&i| normalized_stem(&videos[i].path).starts_with(name.as_str()))
+                .or_else(|| (candidates.len() == 1).then(|| candidates[0])),
+            None => (candidates.len() == 1).then(|| candidates[0]),
+        };
+
+        match owner {
+            Some(i) => videos[i].extras.push(extra.to_movie_extra()),
+            None => orphans += 1,
+        }
+    }
+    if orphans > 0 {
+        println!("  ⚠️  {} 个花絮没有找到所属电影", orphans);
+    }
+}
+
+/// 电影所在目录（光盘结构为光盘目录）
+fn movie_dir(path: &str) -> PathBuf {
+    let path = Path::new(path);
+    disc_root(path)
+        .map(|(root, _)| root)
+        .or_else(|| path.parent().map(Path::to_path_buf))
+        .unwrap_or_default()
+}
+
+/// 小写、分隔符换成空格的文件名
+fn normalized_stem(path: &str) -> String {
+    let stem = Path::new(path).file_stem().map(|s| s.to_string_lossy().to_lowercase()).unwrap_or_default();
+    stem.chars().map(|c| if matches!(c, '.' | '_' | '-') { ' ' } else { c }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_extra() {
+        let extra = detect_extra(Path::new("/movies/Heat (1995)/Trailers/Teaser.mkv")).unwrap();
+        assert_eq!((extra.kind, extra.movie_dir.as_path()), (ExtraKind::Trailer, Path::new("/movies/Heat (1995)")));
+
+        let extra = detect_extra(Path::new("/movies/Heat (1995)-trailer.mkv")).unwrap();
+        assert_eq!((extra.kind, extra.movie_name.as_deref()), (ExtraKind::Trailer, Some("heat (1995)")));
+
+        let extra = detect_extra(Path::new("/movies/Heat/Heat.Making.Of.mkv")).unwrap();
+        assert_eq!(extra.kind, ExtraKind::BehindTheScenes);
+
+        let extra = detect_extra(Path::new("/movies/Heat/sample.mkv")).unwrap();
+        assert_eq!((extra.kind, extra.movie_name), (ExtraKind::Sample, None));
+
+        // 标题中间出现的关键词不算
+        assert_eq!(detect_extra(Path::new("/movies/Trailer Park Boys (2014).mkv")), None);
+        assert_eq!(detect_extra(Path::new("/movies/The Interview (2014).mkv")), None);
+    }
+
+    #[test]
+    fn test_attach_extras() {
+        let mut videos = vec![
+            VideoScanQueryResult::new("Heat (1995).mkv".to_string(), "/movies/Heat (1995).mkv".to_string()),
+            VideoScanQueryResult::new("Up (2009).mkv".to_string(), "/movies/Up (2009).mkv".to_string()),
+            VideoScanQueryResult::new("Alien.mkv".to_string(), "/movies/Alien/Alien.mkv".to_string()),
+        ];
+        let extras: Vec<ExtraFile> = [
+            "/movies/Heat (1995)-trailer.mkv",
+            "/movies/Alien/Extras/Scene.mkv",
+            "/movies/Alien/trailer.mkv",
+            "/movies/trailer.mkv",
+        ]
+        .iter()
+        .filter_map(|path| detect_extra(Path::new(path)))
+        .collect();
+        attach_extras(&mut videos, &extras);
+
+        assert_eq!(videos[0].extras.len(), 1);
+        assert_eq!(videos[0].extras[0].kind, ExtraKind::Trailer);
+        assert!(videos[1].extras.is_empty());
+        let alien: Vec<_> = videos[2].extras.iter().map(|e| (e.kind, e.title.as_str())).collect();
+        assert_eq!(alien, [(ExtraKind::Extra, "Scene"), (ExtraKind::Trailer, "trailer")]);
+    }
+}
@@ -6,6 +6,7 @@
 use super::models::language::Language;
 use super::models::scan_mode::ScanMode;
 use super::models::video::VideoScanQueryResult;
+use super::provider::{CollectionDetails, MovieDetails, SearchMetadataResult};
 use super::scanner::MovieScan;
 
 /// 扫描指定目录中的视频文件并自动获取 TMDB 元数据
@@ -114,18 +115,53 @@ pub async fn video_scan_with_options(
     language: Language,
     scan_mode: ScanMode,
 ) -> Result<Vec<VideoScanQueryResult>, String> {
-    // 从配置中读取 TMDB API Key
-    let tmdb_api_key = shared::config::get().movie().tmdb().api_key().to_string();
+    // 使用配置中的 TMDB API Key 构建扫描器并执行扫描
+    configured_scanner(language)
+        .await
+        .with_scan_mode(scan_mode)
+        .scan(dir_path)
+        .await
+}
+
 
-    // 使用 MovieScan 构建器执行扫描
+/// 使用配置中的 TMDB API Key 创建元数据扫描器
+async fn configured_scanner(language: Language) -> MovieScan {
+    let tmdb_api_key = shared::config::get().movie().tmdb().api_key().to_string();
     MovieScan::new()
         .with_language(language)
-        .with_scan_mode(scan_mode)
-        .with_tmdb_provider(tmdb_api_key).await
-        .scan(dir_path)
+        .with_tmdb_provider(tmdb_api_key)
         .await
 }
 
+/// 搜索候选电影元数据（手动识别时使用）
+///
+/// 返回 `(提供者名称, 搜索结果)` 列表
+pub async fn search_movie_metadata(
+    query: &str,
+    year: Option<u32>,
+    language: Language,
+) -> Result<Vec<(String, SearchMetadataResult)>, String> {
+    configured_scanner(language).await.search_candidates(query, year).await
+}
+
+/// 按提供者 ID 获取电影元数据详情
+pub async fn get_movie_metadata(
+    provider: Option<&str>,
+    provider_id: u64,
+    language: Language,
+) -> Result<MovieDetails, String> {
+    configured_scanner(language).await.fetch_details(provider, provider_id).await
+}
+
+/// 按提供者 ID 获取电影系列详情
+pub async fn get_movie_collection(
+    provider: Option<&str>,
+    collection_id: u64,
+    language: Language,
+) -> Result<CollectionDetails, String> {
+    configured_scanner(language).await.fetch_collection(provider, collection_id).await
+}
+
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,134 @@
+//! 搜索结果排序
+//!
+//! 提供者按自身的相关度返回结果，翻拍片和同名电影经常排在前面。
+//! 这里按标题相似度和年份接近程度重新排序，分数相同时保持原有顺序。
+
+use super::provider::SearchMetadataResult;
+
+/// 按标题相似度和年份接近程度排序搜索结果（最匹配的在前）
+pub fn rank_search_results(title: &str, year: Option<u32>, results: &mut [SearchMetadataResult]) {
+    let mut scored: Vec<(f64, usize)> = results
+        .iter()
+        .enumerate()
+        .map(|(index, result)| (match_score(title, year, result), index))
+        .collect();
+    scored.sort_by(|a, b| b.0.total_cmp(&a.0).then(a.1.cmp(&b.1)));
+
+    let ranked: Vec<SearchMetadataResult> = scored.iter().map(|(_, index)| results[*index].clone()).collect();
+    results.clone_from_slice(&ranked);
+}
+
+/// 搜索结果的匹配分数
+///
+/// 标题相似度为 0-1，年份相同加 0.5，相差一年加 0.25（跨年上映），
+/// 相差更多时每年扣 0.1，最多扣 0.5
+pub fn match_score(title: &str, year: Option<u32>, result: &SearchMetadataResult) -> f64 {
+    let similarity = title_variants(title)
+        .iter()
+        .flat_map(|query| [&result.title, &result.original_title].map(|candidate| title_similarity(query, candidate)))
+        .fold(0.0, f64::max);
+
+    let result_year = result.release_date.get(..4).and_then(|y| y.parse::<u32>().ok());
+    let year_score = match (year, result_year) {
+        (Some(year), Some(result_year)) => match year.abs_diff(result_year) {
+            0 => 0.5,
+            1 => 0.25,
+            diff => -(f64::from(diff.min(5)) * 0.1),
+        },
+        _ => 0.0,
+    };
+
+    similarity + year_score
+}
+
+/// 文件名中常同时出现中文名和英文名，分别和候选标题比较
+fn title_variants(title: &str) -> Vec<String> {
+    let cjk: String = title.chars().filter(|c| is_cjk(*c)).collect();
+    let latin: String = title.chars().filter(|c| !is_cjk(*c)).collect();
+
+    let mut variants = vec![title.to_string()];
+    for variant in [cjk, latin] {
+        let variant = variant.split_whitespace().collect::<Vec<_>>().join(" ");
+        if !variant.is_empty() && !variants.contains(&variant) {
+            variants.push(variant);
+        }
+    }
+    variants
+}
+
+fn is_cjk(c: char) -> bool {
+    matches!(c, '\u{3040}'..='\u{30ff}' | '\u{3400}'..='\u{4dbf}' | '\u{4e00}'..='\u{9fff}' | '\u{ac00}'..='\u{d7af}')
+}
+
+/// 标题相似度（0-1），忽略大小写和标点，使用字符二元组的 Dice 系数
+fn title_similarity(a: &str, b: &str) -> f64 {
+    let normalize = |s: &str| -> Vec<char> { s.chars().filter(|c| c.is_alphanumeric()).flat_map(char::to_lowercase).collect() };
+    let (a, b) = (normalize(a), normalize(b));
+    if a.is_empty() || b.is_empty() {
+        return 0.0;
+    }
+    if a == b {
+        return 1.0;
+    }
+    if a.len() < 2 || b.len() < 2 {
+        return 0.0;
+    }
+
+    let mut b_bigrams: Vec<(char, char)> = b.windows(2).map(|w| (w[0], w[1])).collect();
+    let total = (a.len() - 1 + b_bigrams.len()) as f64;
+    let mut matches = 0;
+    for bigram in a.windows(2).map(|w| (w[0], w[1])) {
+        if let Some(pos) = b_bigrams.iter().position(|b| *b == bigram) {
+            b_bigrams.swap_remove(pos);
+            matches += 1;
+        }
+    }
+    2.0 * matches as f64 / total
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn result(tmdb_id: u64, title: &str, original_title: &str, release_date: &str) -> SearchMetadataResult {
+        SearchMetadataResult {
+            tmdb_id,
+            title: title.to_string(),
+            original_title: original_title.to_string(),
+            overview: String::new(),
+            release_date: release_date.to_string(),
+            poster_path: None,
+            backdrop_path: None,
+            vote_average: 0.0,
+            vote_count: 0,
+            popularity: 0.0,
+            original_language: String::new(),
+            genre_ids: Vec::new(),
+            adult: false,
+        }
+    }
+
+    #[test]
+    fn test_rank_search_results() {
+        // 翻拍片按年份区分
+        let mut results = vec![
+            result(1, "沙丘", "Dune", "2021-09-15"),
+            result(2, "沙丘", "Dune", "1984-12-14"),
+        ];
+        rank_search_results("Dune", Some(1984), &mut results);
+        assert_eq!(results[0].tmdb_id, 2);
+
+        // 中英文混合的文件名标题
+        let mut results = vec![
+            result(3, "星际穿越：幕后", "The Science of Interstellar", "2015-03-31"),
+            result(4, "星际穿越", "Interstellar", "2014-11-05"),
+        ];
+        rank_search_results("星际穿越 Interstellar", Some(2014), &mut results);
+        assert_eq!(results[0].tmdb_id, 4);
+
+        // 没有年份时保持提供者的顺序
+        let mut results = vec![result(5, "Heat", "Heat", "1995-12-15"), result(6, "Heat", "Heat", "1986-03-14")];
+        rank_search_results("Heat", None, &mut results);
+        assert_eq!(results[0].tmdb_id, 5);
+    }
+}
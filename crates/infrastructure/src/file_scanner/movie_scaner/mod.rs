@@ -3,11 +3,23 @@
 //! 提供视频文件扫描和元数据提取的功能。
 //! 支持多种元数据提供者（TMDB、豆瓣等）和灵活的扫描模式。
 
+pub mod artwork;
+pub mod container;
+pub mod disc;
+pub mod extras;
 pub mod models;
+pub mod nfo;
+pub mod parser;
 pub mod provider;
 pub mod scanner;
+pub mod subtitle;
+pub mod versions;
 pub mod helpers;
+pub mod matcher;
 
 // 重新导出主要类型
 pub use scanner::MovieScan;
-pub use helpers::{video_scan, video_scan_with_language, video_scan_with_options};
\ No newline at end of file
+pub use helpers::{
+    get_movie_collection, get_movie_metadata, search_movie_metadata, video_scan, video_scan_with_language,
+    video_scan_with_options,
+};
\ No newline at end of file
@@ -43,6 +43,25 @@ impl Language {
         }
     }
 
+    /// 根据 TMDB 语言代码获取语言类型，未知代码返回 `None`
+    pub fn from_code(code: &str) -> Option<Self> {
+        [
+            Language::English,
+            Language::ChineseSimplified,
+            Language::ChineseTraditional,
+            Language::Japanese,
+            Language::Korean,
+            Language::French,
+            Language::German,
+            Language::Spanish,
+            Language::Italian,
+            Language::Portuguese,
+            Language::Russian,
+        ]
+        .into_iter()
+        .find(|language| language.code() == code)
+    }
+
     /// 获取语言的显示名称
     pub fn display_name(&self) -> &'static str {
         match self {
@@ -1,3 +1,5 @@
+use super::super::parser::parse_movie_file;
+
 /// 视频扫描模式
 #[derive(Debug, Clone, PartialEq)]
 pub enum ScanMode {
@@ -77,90 +79,9 @@ impl VideoFilter {
 
     /// 从文件名中提取可能的电影标题
     ///
-    /// 清理文件名中的无关信息：
-    /// 1. 移除年份标记 (2015)、[2015]
-    /// 2. 移除分辨率标记 1080p、720p、4K
-    /// 3. 移除编码格式 x264、h265、HEVC
-    /// 4. 移除音频格式 AAC、DTS、AC3
-    /// 5. 移除发布组标记
-    /// 6. 移除多余的符号和空格
+    /// 只保留标题部分，年份、分辨率、编码等标记见 [`parse_movie_file`]
     pub fn extract_movie_title(file_name: &str) -> String {
-        // 移除扩展名
-        let name = if let Some(pos) = file_name.rfind('.') {
-            &file_name[..pos]
-        } else {
-            file_name
-        };
-
-        let mut title = name.to_string();
-
-        // 先移除常见的分隔符和发布组标记（在 [] 或 {} 中）
-        title = regex::Regex::new(r"\[.*?\]")
-            .unwrap()
-            .replace_all(&title, " ")
-            .to_string();
-        title = regex::Regex::new(r"\{.*?\}")
-            .unwrap()
-            .replace_all(&title, " ")
-            .to_string();
-
-        // 替换常见分隔符为空格（这样可以创建单词边界）
-        for sep in &['.', '_', '-'] {
-            title = title.replace(*sep, " ");
-        }
-
-        // 移除年份 (2015)、(2015)
-        title = regex::Regex::new(r"\(\d{4}\)")
-            .unwrap()
-            .replace_all(&title, " ")
-            .to_string();
-
-        // 移除单独的年份数字
-        title = regex::Regex::new(r"\b\d{4}\b")
-            .unwrap()
-            .replace_all(&title, " ")
-            .to_string();
-
-        // 移除分辨率标记
-        let resolution_patterns = [
-            "2160p", "1080p", "720p", "480p", "360p",
-            "4K", "UHD", "BluRay", "BDRip", "WEB DL", "WEBRip", "DVDRip", "HDR",
-        ];
-        for pattern in &resolution_patterns {
-            let regex_pattern = format!(r"(?i)\b{}\b", regex::escape(pattern));
-            if let Ok(re) = regex::Regex::new(&regex_pattern) {
-                title = re.replace_all(&title, " ").to_string();
-            }
-        }
-
-        // 移除编码格式
-        let codec_patterns = [
-            "x264", "x265", "H264", "H265", "HEVC", "AVC",
-            "10bit", "8bit",
-        ];
-        for pattern in &codec_patterns {
-            let regex_pattern = format!(r"(?i)\b{}\b", regex::escape(pattern));
-            if let Ok(re) = regex::Regex::new(&regex_pattern) {
-                title = re.replace_all(&title, " ").to_string();
-            }
-        }
-
-        // 移除音频格式
-        let audio_patterns = [
-            "AAC", "AC3", "DTS", "TrueHD", "Atmos",
-        ];
-        for pattern in &audio_patterns {
-            let regex_pattern = format!(r"(?i)\b{}\b", regex::escape(pattern));
-            if let Ok(re) = regex::Regex::new(&regex_pattern) {
-                title = re.replace_all(&title, " ").to_string();
-            }
-        }
-
-        // 移除多余空格
-        title = title.split_whitespace().collect::<Vec<_>>().join(" ");
-
-        // 去除首尾空格
-        title.trim().to_string()
+        parse_movie_file(file_name).title
     }
 }
 
@@ -1,9 +1,15 @@
+use super::super::container::{self, TrackInfo, TrackKind};
+use super::super::extras::ExtraKind;
+use super::super::parser::{parse_movie_path, MovieFileInfo};
+use super::super::provider::{CollectionDetails, MovieCredit};
+use super::super::subtitle::{find_external_subtitles, ExternalSubtitle};
 use std::fs;
+use std::path::Path;
 
 /// 从视频文件提取元数据
 ///
-/// 使用 mediameta 库提取分辨率，使用 mp4parse 和 matroska 库提取时长。
-/// 纯 Rust 实现，无需外部依赖。支持 MP4、MKV、WebM 等格式。
+/// 使用 mediameta 库提取分辨率，使用 `container` 模块解析容器头提取时长。
+/// 纯 Rust 实现，无需外部依赖。支持 MP4、MKV、WebM、AVI、TS 等格式。
 fn extract_metadata(path: &str) -> Result<VideoMetadata, String> {
     let metadata = mediameta::extract_file_metadata(path)
         .map_err(|e| format!("Failed to extract metadata: {}", e))?;
@@ -21,11 +27,34 @@ fn extract_metadata(path: &str) -> Result<VideoMetadata, String> {
     })
 }
 
-/// 从视频文件提取时长（秒）
-/// 暂时所有格式都返回 0
-fn extract_duration(_path: &str) -> Result<u64, String> {
-    // TODO: 后续实现时长提取功能
-    Ok(0)
+/// 从视频文件提取时长（秒，四舍五入）
+///
+/// 容器中没有记录时长或格式不支持时返回错误，由调用方决定回退值
+fn extract_duration(path: &str) -> Result<u64, String> {
+    container::probe_duration(Path::new(path))
+        .map_err(|e| format!("Failed to read container: {}", e))?
+        .map(|seconds| seconds.round() as u64)
+        .ok_or_else(|| "Duration not found in container".to_string())
+}
+
+/// 读取视频的音视频和字幕轨道
+///
+/// 容器中没有记录视频码率时，用文件整体码率减去音频码率估算，无法估算时保持为空
+fn extract_tracks(path: &str, byte_size: u64, duration: u64) -> Vec<TrackInfo> {
+    let mut tracks = match container::probe_tracks(Path::new(path)) {
+        Ok(tracks) => tracks,
+        Err(e) => {
+            println!("⚠ Failed to read tracks for {}: {}", path, e);
+            return Vec::new();
+        }
+    };
+
+    if let Some(video_bit_rate) = container::estimate_video_bit_rate(&tracks, byte_size, duration) {
+        for track in tracks.iter_mut().filter(|t| t.kind == TrackKind::Video && t.bit_rate.is_none()) {
+            track.bit_rate = Some(video_bit_rate);
+        }
+    }
+    tracks
 }
 
 /// 视频扫描查询结果
@@ -35,6 +64,8 @@ fn extract_duration(_path: &str) -> Result<u64, String> {
 pub struct VideoScanQueryResult {
     /// 视频标题
     pub title: String,
+    /// 原始标题
+    pub original_title: Option<String>,
     /// 视频大小（字节）
     pub byte_size: u64,
     /// 视频文件路径
@@ -67,8 +98,85 @@ pub struct VideoScanQueryResult {
     pub votes: u32,
     /// 视频海报 URL 列表
     pub poster_urls: Vec<String>,
+    /// 视频背景图 URL
+    pub backdrop_url: Option<String>,
+    /// TMDB 电影 ID
+    pub tmdb_id: Option<u64>,
+    /// IMDb 电影 ID（如 `tt0113277`）
+    pub imdb_id: Option<String>,
+    /// 所属电影系列
+    pub collection: Option<CollectionDetails>,
+    /// 提供者返回的演职人员（TMDB 人物 ID、饰演角色、头像）
+    pub credits: Vec<MovieCredit>,
+    /// 从文件名解析出的年份、分辨率、来源、编码、版本和发布组
+    pub file_info: MovieFileInfo,
     /// 视频文件扩展名
     pub extension: String,
+    /// 音视频和字幕轨道
+    pub tracks: Vec<TrackInfo>,
+    /// 外挂字幕
+    pub subtitles: Vec<ExternalSubtitle>,
+    /// 所有版本和分段文件，由电影扫描器合并同一电影的文件后填充
+    pub sources: Vec<MediaSource>,
+    /// 预告片、花絮等附加视频，由电影扫描器在合并版本后挂载
+    pub extras: Vec<MovieExtra>,
+}
+
+/// 电影的一个媒体源（某个版本的某个分段文件）
+#[derive(Debug, Clone, PartialEq)]
+pub struct MediaSource {
+    /// 版本序号，0 为默认版本
+    pub version: u32,
+    /// 分段序号（从 1 开始），未分段时为 `None`
+    pub part: Option<u32>,
+    /// 版本名称（如 `2160p`、`Director's Cut 1080p`）
+    pub label: String,
+    /// 视频文件路径
+    pub path: String,
+    /// 文件大小（字节）
+    pub byte_size: u64,
+    /// 文件扩展名
+    pub extension: String,
+    /// 时长（秒）
+    pub duration: u64,
+    /// 视频宽度（像素）
+    pub width: u32,
+    /// 视频高度（像素）
+    pub height: u32,
+}
+
+impl MediaSource {
+    /// 用扫描结果的文件信息创建媒体源
+    pub fn from_video(video: &VideoScanQueryResult, version: u32, part: Option<u32>, label: String) -> Self {
+        Self {
+            version,
+            part,
+            label,
+            path: video.path.clone(),
+            byte_size: video.byte_size,
+            extension: video.extension.clone(),
+            duration: video.duration,
+            width: video.width,
+            height: video.height,
+        }
+    }
+}
+
+/// 电影的一个附加视频（预告片、花絮等）
+#[derive(Debug, Clone, PartialEq)]
+pub struct MovieExtra {
+    /// 附加视频类型
+    pub kind: ExtraKind,
+    /// 标题（文件名）
+    pub title: String,
+    /// 视频文件路径
+    pub path: String,
+    /// 文件大小（字节）
+    pub byte_size: u64,
+    /// 时长（秒）
+    pub duration: u64,
+    /// 文件扩展名
+    pub extension: String,
 }
 
 impl VideoScanQueryResult {
@@ -88,11 +196,15 @@ impl VideoScanQueryResult {
         let title = file_name.split('.').next().unwrap_or("").to_string();
         let extension = path.split('.').last().unwrap_or("").to_string();
         let byte_size = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+        let subtitles = find_external_subtitles(Path::new(&path));
+        let file_info = parse_movie_path(Path::new(&path));
 
         match extract_metadata(&path) {
             Ok(metadata) => {
+                let tracks = extract_tracks(&path, byte_size, metadata.duration);
                 return Self {
                     title,
+                    original_title: None,
                     path,
                     byte_size,
                     duration: metadata.duration,
@@ -109,18 +221,36 @@ impl VideoScanQueryResult {
                     rating: 0.0,
                     votes: 0,
                     poster_urls: Vec::new(),
+                    backdrop_url: None,
+                    tmdb_id: None,
+                    imdb_id: None,
+                    collection: None,
+                    credits: Vec::new(),
+                    file_info,
                     extension,
+                    tracks,
+                    subtitles,
+                    sources: Vec::new(),
+                    extras: Vec::new(),
                 };
             }
             Err(e) => {
                 println!("⚠ Failed to extract metadata for {}: {}", title, e);
+                // 分辨率读取失败时（如 AVI/TS）仍然尝试从容器头读取时长
+                let duration = extract_duration(&path).unwrap_or(0);
+                let tracks = extract_tracks(&path, byte_size, duration);
+                // 分辨率同样可以从视频轨道中读取
+                let video = tracks.iter().find(|t| t.kind == TrackKind::Video);
+                let width = video.and_then(|t| t.width).unwrap_or(0);
+                let height = video.and_then(|t| t.height).unwrap_or(0);
                 return Self {
                     title,
+                    original_title: None,
                     path,
                     byte_size,
-                    duration: 0,
-                    width: 0,
-                    height: 0,
+                    duration,
+                    width,
+                    height,
                     description: String::new(),
                     tags: Vec::new(),
                     actors: Vec::new(),
@@ -132,7 +262,17 @@ impl VideoScanQueryResult {
                     rating: 0.0,
                     votes: 0,
                     poster_urls: Vec::new(),
+                    backdrop_url: None,
+                    tmdb_id: None,
+                    imdb_id: None,
+                    collection: None,
+                    credits: Vec::new(),
+                    file_info,
                     extension,
+                    tracks,
+                    subtitles,
+                    sources: Vec::new(),
+                    extras: Vec::new(),
                 };
             }
         }
@@ -0,0 +1,15 @@
+//! NFO 元数据文件模块
+//!
+//! 读取 Kodi / Jellyfin 在视频旁保存的 `movie.nfo` / `<视频名>.nfo`，
+//! 用其中的元数据和 TMDB / IMDb ID 代替或引导在线刮削；
+//! 也可以把元数据写回 NFO、`poster.jpg` 和 `fanart.jpg`，供其他播放器使用。
+
+mod reader;
+mod writer;
+mod xml;
+
+pub use reader::{find_movie_nfo, parse_movie_nfo, read_movie_nfo, MovieNfo};
+pub use writer::{
+    is_generated_nfo, render_movie_nfo, sidecar_paths, write_movie_nfo, write_movie_sidecars, SidecarPaths,
+    SidecarWriteResult,
+};
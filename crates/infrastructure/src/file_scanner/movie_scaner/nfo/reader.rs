@@ -0,0 +1,301 @@
+//! 读取 Kodi / Jellyfin 的电影 NFO 文件
+
+use super::xml::{self, XmlElement};
+use super::super::disc::disc_root;
+use super::super::models::video::VideoScanQueryResult;
+use super::super::subtitle::is_only_video;
+use regex::Regex;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+/// NFO 中的 TMDB 链接 `https://www.themoviedb.org/movie/949-heat`
+static TMDB_URL: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"themoviedb\.org/movie/(\d+)").expect("invalid tmdb url pattern"));
+
+/// IMDb ID `tt0113277`
+static IMDB_ID: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b(tt\d{7,8})\b").expect("invalid imdb id pattern"));
+
+/// 从 NFO 文件读取的电影元数据
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MovieNfo {
+    /// 标题
+    pub title: Option<String>,
+    /// 原始标题
+    pub original_title: Option<String>,
+    /// 年份
+    pub year: Option<u32>,
+    /// 上映日期（YYYY-MM-DD）
+    pub release_date: Option<String>,
+    /// 简介
+    pub plot: Option<String>,
+    /// 类型/流派列表
+    pub genres: Vec<String>,
+    /// 标签列表
+    pub tags: Vec<String>,
+    /// 演员列表
+    pub actors: Vec<String>,
+    /// 导演列表
+    pub directors: Vec<String>,
+    /// 编剧列表
+    pub writers: Vec<String>,
+    /// 评分（0-10）
+    pub rating: Option<f32>,
+    /// 评价人数
+    pub votes: Option<u32>,
+    /// TMDB 电影 ID
+    pub tmdb_id: Option<u64>,
+    /// IMDb ID（如 `tt0113277`）
+    pub imdb_id: Option<String>,
+    /// 海报 URL 列表（只保留 http/https 地址）
+    pub poster_urls: Vec<String>,
+    /// 背景图 URL（`<fanart><thumb>`）
+    pub backdrop_url: Option<String>,
+}
+
+impl MovieNfo {
+    /// NFO 是否包含完整的元数据（有标题和简介时不再需要在线刮削）
+    pub fn has_metadata(&self) -> bool {
+        self.title.is_some() && self.plot.is_some()
+    }
+
+    /// 用 NFO 中的非空字段覆盖视频元数据（NFO 是用户整理过的数据，优先级最高）
+    pub fn apply_to(&self, video: &mut VideoScanQueryResult) {
+        if let Some(title) = &self.title {
+            video.title = title.clone();
+        }
+        if self.original_title.is_some() {
+            video.original_title = self.original_title.clone();
+        }
+        if let Some(plot) = &self.plot {
+            video.description = plot.clone();
+        }
+        if let Some(release_date) = &self.release_date {
+            video.release_date = release_date.clone();
+        }
+        if let Some(rating) = self.rating {
+            video.rating = rating;
+        }
+        if let Some(votes) = self.votes {
+            video.votes = votes;
+        }
+
+        for (target, source) in [
+            (&mut video.genres, &self.genres),
+            (&mut video.tags, &self.tags),
+            (&mut video.actors, &self.actors),
+            (&mut video.directors, &self.directors),
+            (&mut video.writers, &self.writers),
+            (&mut video.poster_urls, &self.poster_urls),
+        ] {
+            if !source.is_empty() {
+                *target = source.clone();
+            }
+        }
+        if self.backdrop_url.is_some() {
+            video.backdrop_url = self.backdrop_url.clone();
+        }
+        if self.tmdb_id.is_some() {
+            video.tmdb_id = self.tmdb_id;
+        }
+        if self.imdb_id.is_some() {
+            video.imdb_id = self.imdb_id.clone();
+        }
+    }
+}
+
+/// 查找视频对应的 NFO 文件
+///
+/// 优先使用与视频同名的 `<视频名>.nfo`，
+/// 目录中只有这一个视频时也使用 `movie.nfo`；
+/// 光盘结构（`BDMV/`、`VIDEO_TS/`）使用光盘目录中的 `movie.nfo`
+pub fn find_movie_nfo(video_path: &Path) -> Option<PathBuf> {
+    if let Some((root, _)) = disc_root(video_path) {
+        return fs::read_dir(root)
+            .ok()?
+            .flatten()
+            .map(|e| e.path())
+            .find(|path| path.is_file() && path.file_name().is_some_and(|n| n.eq_ignore_ascii_case("movie.nfo")));
+    }
+
+    let dir = video_path.parent()?;
+    let stem = video_path.file_stem()?.to_string_lossy();
+
+    let entries: Vec<PathBuf> = fs::read_dir(dir).ok()?.flatten().map(|e| e.path()).collect();
+    let find = |name: &str| {
+        entries
+            .iter()
+            .find(|path| path.is_file() && path.file_name().is_some_and(|n| n.to_string_lossy().eq_ignore_ascii_case(name)))
+            .cloned()
+    };
+
+    find(&format!("{}.nfo", stem)).or_else(|| find("movie.nfo").filter(|_| is_only_video(dir)))
+}
+
+/// 是否为 http/https 地址（本地路径的图片由 NFO 所在目录的 poster.jpg 等文件提供）
+fn is_remote_url(url: &str) -> bool {
+    url.starts_with("http://") || url.starts_with("https://")
+}
+
+/// 解析评分（支持 `<rating>7.5</rating>` 和 `<ratings><rating default="true"><value>`）
+fn parse_rating(root: &XmlElement) -> (Option<f32>, Option<u32>) {
+    let votes = |element: &XmlElement| element.child_text("votes").and_then(|v| v.replace(',', "").parse().ok());
+
+    if let Some(ratings) = root.child("ratings") {
+        let rating = ratings
+            .children_named("rating")
+            .find(|r| r.attr("default") == Some("true"))
+            .or_else(|| ratings.child("rating"));
+        if let Some(rating) = rating {
+            let max: f32 = rating.attr("max").and_then(|m| m.parse().ok()).filter(|m| *m > 0.0).unwrap_or(10.0);
+            let value = rating.child_text("value").and_then(|v| v.parse::<f32>().ok()).map(|v| v * 10.0 / max);
+            if value.is_some() {
+                return (value, votes(rating).or_else(|| votes(root)));
+            }
+        }
+    }
+
+    let rating = root.child_text("rating").and_then(|r| r.parse().ok());
+    (rating, votes(root))
+}
+
+/// 解析 TMDB 和 IMDb ID（`<uniqueid type="tmdb">`、`<tmdbid>`、`<imdbid>`、`<id>`）
+fn parse_ids(root: &XmlElement) -> (Option<u64>, Option<String>) {
+    let mut tmdb_id = None;
+    let mut imdb_id = None;
+    for uniqueid in root.children_named("uniqueid") {
+        let value = uniqueid.text.trim();
+        match uniqueid.attr("type").map(|t| t.to_lowercase()).as_deref() {
+            Some("tmdb") => tmdb_id = tmdb_id.or(value.parse().ok()),
+            Some("imdb") => imdb_id = imdb_id.or(Some(value.to_string()).filter(|v| IMDB_ID.is_match(v))),
+            _ => {}
+        }
+    }
+
+    tmdb_id = tmdb_id.or_else(|| root.child_text("tmdbid").and_then(|id| id.parse().ok()));
+    imdb_id = imdb_id.or_else(|| root.child_text("imdbid").filter(|id| IMDB_ID.is_match(id)));
+
+    // 旧版 NFO 的 `<id>` 可能是 IMDb ID 或 TMDB ID
+    if let Some(id) = root.child_text("id") {
+        if IMDB_ID.is_match(&id) {
+            imdb_id = imdb_id.or(Some(id));
+        } else {
+            tmdb_id = tmdb_id.or(id.parse().ok());
+        }
+    }
+    (tmdb_id, imdb_id)
+}
+
+/// 解析 NFO 文件内容
+///
+/// 支持 Kodi / Jellyfin 的 `<movie>` 文档，以及只包含 TMDB / IMDb 链接的 URL NFO；
+/// 既不是 `<movie>` 文档也没有链接时返回 None
+pub fn parse_movie_nfo(content: &str) -> Option<MovieNfo> {
+    let mut nfo = MovieNfo::default();
+
+    if let Some(root) = xml::parse(content).filter(|root| root.name == "movie") {
+        nfo.title = root.child_text("title");
+        nfo.original_title = root.child_text("originaltitle").filter(|t| Some(t) != nfo.title.as_ref());
+        nfo.plot = root.child_text("plot").or_else(|| root.child_text("outline"));
+        nfo.release_date = root.child_text("premiered").or_else(|| root.child_text("releasedate"));
+        nfo.year = root
+            .child_text("year")
+            .and_then(|y| y.parse().ok())
+            .or_else(|| nfo.release_date.as_ref().and_then(|d| d.get(..4)).and_then(|y| y.parse().ok()))
+            .filter(|y| *y > 0);
+        nfo.genres = root.children_text("genre");
+        nfo.tags = root.children_text("tag");
+        nfo.actors = root.children_named("actor").filter_map(|a| a.child_text("name")).collect();
+        nfo.directors = root.children_text("director");
+        nfo.writers = root.children_text("credits");
+        nfo.writers.extend(root.children_text("writer"));
+        nfo.writers.dedup();
+        (nfo.rating, nfo.votes) = parse_rating(&root);
+        (nfo.tmdb_id, nfo.imdb_id) = parse_ids(&root);
+        nfo.poster_urls = root
+            .children_named("thumb")
+            .filter(|t| t.attr("aspect").is_none_or(|a| a == "poster"))
+            .map(|t| t.text.trim().to_string())
+            .filter(|url| is_remote_url(url))
+            .collect();
+        nfo.backdrop_url = root
+            .child("fanart")
+            .and_then(|fanart| fanart.child_text("thumb"))
+            .filter(|url| is_remote_url(url));
+    } else if !TMDB_URL.is_match(content) && !IMDB_ID.is_match(content) {
+        return None;
+    }
+
+    // NFO 中（或 XML 之后）的链接也可以提供 ID
+    nfo.tmdb_id = nfo.tmdb_id.or_else(|| TMDB_URL.captures(content).and_then(|c| c[1].parse().ok()));
+    nfo.imdb_id = nfo.imdb_id.or_else(|| IMDB_ID.captures(content).map(|c| c[1].to_string()));
+    Some(nfo)
+}
+
+/// 读取视频对应的 NFO 文件
+///
+/// 没有 NFO 或无法解析时返回 None
+pub fn read_movie_nfo(video_path: &Path) -> Option<MovieNfo> {
+    let path = find_movie_nfo(video_path)?;
+    let bytes = fs::read(&path).ok()?;
+    let nfo = parse_movie_nfo(&String::from_utf8_lossy(&bytes));
+    if nfo.is_none() {
+        println!("  ⚠️  无法解析 NFO: {}", path.display());
+    }
+    nfo
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_movie_nfo() {
+        let nfo = parse_movie_nfo(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes" ?>
+<movie>
+    <title>盗火线</title>
+    <originaltitle>Heat</originaltitle>
+    <ratings>
+        <rating name="imdb" max="10"><value>8.3</value><votes>700000</votes></rating>
+        <rating name="themoviedb" max="10" default="true"><value>7.9</value><votes>6,500</votes></rating>
+    </ratings>
+    <year>1995</year>
+    <plot>洛杉矶的职业大盗与警探之间的较量。</plot>
+    <thumb aspect="poster">https://image.tmdb.org/t/p/original/heat.jpg</thumb>
+    <thumb aspect="banner">https://example.com/banner.jpg</thumb>
+    <fanart><thumb>https://image.tmdb.org/t/p/original/heat-fanart.jpg</thumb></fanart>
+    <uniqueid type="imdb">tt0113277</uniqueid>
+    <uniqueid type="tmdb" default="true">949</uniqueid>
+    <genre>犯罪</genre>
+    <genre>剧情</genre>
+    <credits>Michael Mann</credits>
+    <director>Michael Mann</director>
+    <premiered>1995-12-15</premiered>
+    <actor><name>Al Pacino</name><role>Vincent Hanna</role></actor>
+    <actor><name>Robert De Niro</name><role>Neil McCauley</role></actor>
+</movie>"#,
+        )
+        .unwrap();
+
+        assert_eq!(nfo.title.as_deref(), Some("盗火线"));
+        assert_eq!(nfo.original_title.as_deref(), Some("Heat"));
+        assert_eq!((nfo.year, nfo.release_date.as_deref()), (Some(1995), Some("1995-12-15")));
+        assert_eq!((nfo.rating, nfo.votes), (Some(7.9), Some(6500)));
+        assert_eq!((nfo.tmdb_id, nfo.imdb_id.as_deref()), (Some(949), Some("tt0113277")));
+        assert_eq!(nfo.genres, vec!["犯罪", "剧情"]);
+        assert_eq!(nfo.actors, vec!["Al Pacino", "Robert De Niro"]);
+        assert_eq!((nfo.directors.clone(), nfo.writers.clone()), (vec!["Michael Mann".to_string()], vec!["Michael Mann".to_string()]));
+        assert_eq!(nfo.poster_urls, vec!["https://image.tmdb.org/t/p/original/heat.jpg"]);
+        assert_eq!(nfo.backdrop_url.as_deref(), Some("https://image.tmdb.org/t/p/original/heat-fanart.jpg"));
+        assert!(nfo.has_metadata());
+
+        // 只包含链接的 URL NFO
+        let nfo = parse_movie_nfo("https://www.imdb.com/title/tt0113277/\n").unwrap();
+        assert_eq!((nfo.imdb_id.as_deref(), nfo.has_metadata()), (Some("tt0113277"), false));
+        let nfo = parse_movie_nfo("<movie><id>949</id></movie>").unwrap();
+        assert_eq!(nfo.tmdb_id, Some(949));
+
+        assert_eq!(parse_movie_nfo("<tvshow><title>Friends</title></tvshow>"), None);
+    }
+}
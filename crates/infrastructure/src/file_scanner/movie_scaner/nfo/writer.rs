@@ -0,0 +1,260 @@
+//! 把电影元数据写成 Kodi / Jellyfin 兼容的 NFO 和图片文件
+
+use super::super::disc::disc_root;
+use super::reader::{find_movie_nfo, MovieNfo};
+use super::super::subtitle::is_only_video;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Modream 生成的 NFO 中的标记注释，没有这个标记的 NFO 视为用户编写
+const GENERATOR_COMMENT: &str = "<!-- generated by modream -->";
+
+/// 视频旁的 NFO 和图片文件路径
+#[derive(Debug, Clone, PartialEq)]
+pub struct SidecarPaths {
+    /// NFO 文件
+    pub nfo: PathBuf,
+    /// 海报图片
+    pub poster: PathBuf,
+    /// 背景图片
+    pub fanart: PathBuf,
+}
+
+/// 写入结果（跳过或下载失败的文件为 false）
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SidecarWriteResult {
+    /// 是否写入了 NFO
+    pub nfo_written: bool,
+    /// 是否写入了海报
+    pub poster_written: bool,
+    /// 是否写入了背景图
+    pub fanart_written: bool,
+}
+
+/// 计算视频旁的 NFO 和图片路径
+///
+/// - 目录中只有这一个视频时使用 `movie.nfo`、`poster.jpg`、`fanart.jpg`
+/// - 否则使用 `<视频名>.nfo`、`<视频名>-poster.jpg`、`<视频名>-fanart.jpg`
+/// - 光盘结构（`BDMV/`、`VIDEO_TS/`）写到光盘目录中的 `movie.nfo`、`poster.jpg`、`fanart.jpg`
+/// - 已经存在 NFO 时沿用已有的 NFO 路径
+pub fn sidecar_paths(video_path: &Path) -> Option<SidecarPaths> {
+    let dir = video_path.parent()?;
+    let stem = video_path.file_stem()?.to_string_lossy();
+
+    let paths = if let Some((root, _)) = disc_root(video_path) {
+        SidecarPaths {
+            nfo: root.join("movie.nfo"),
+            poster: root.join("poster.jpg"),
+            fanart: root.join("fanart.jpg"),
+        }
+    } else if is_only_video(dir) {
+        SidecarPaths {
+            nfo: dir.join("movie.nfo"),
+            poster: dir.join("poster.jpg"),
+            fanart: dir.join("fanart.jpg"),
+        }
+    } else {
+        SidecarPaths {
+            nfo: dir.join(format!("{}.nfo", stem)),
+            poster: dir.join(format!("{}-poster.jpg", stem)),
+            fanart: dir.join(format!("{}-fanart.jpg", stem)),
+        }
+    };
+
+    Some(SidecarPaths {
+        nfo: find_movie_nfo(video_path).unwrap_or(paths.nfo),
+        ..paths
+    })
+}
+
+/// 转义 XML 文本和属性值
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// 生成 NFO 文件内容
+pub fn render_movie_nfo(nfo: &MovieNfo) -> String {
+    let mut lines = vec![
+        r#"<?xml version="1.0" encoding="UTF-8" standalone="yes" ?>"#.to_string(),
+        GENERATOR_COMMENT.to_string(),
+        "<movie>".to_string(),
+    ];
+    let mut push = |tag: &str, value: &str| lines.push(format!("    <{tag}>{}</{tag}>", escape(value)));
+
+    if let Some(title) = &nfo.title {
+        push("title", title);
+    }
+    if let Some(original_title) = &nfo.original_title {
+        push("originaltitle", original_title);
+    }
+    if let Some(year) = nfo.year {
+        push("year", &year.to_string());
+    }
+    if let Some(plot) = &nfo.plot {
+        push("plot", plot);
+    }
+    if let Some(release_date) = &nfo.release_date {
+        push("premiered", release_date);
+    }
+    for genre in &nfo.genres {
+        push("genre", genre);
+    }
+    for tag in &nfo.tags {
+        push("tag", tag);
+    }
+    for director in &nfo.directors {
+        push("director", director);
+    }
+    for writer in &nfo.writers {
+        push("credits", writer);
+    }
+
+    if let Some(rating) = nfo.rating.filter(|r| *r > 0.0) {
+        lines.push("    <ratings>".to_string());
+        lines.push(r#"        <rating name="themoviedb" max="10" default="true">"#.to_string());
+        lines.push(format!("            <value>{:.1}</value>", rating));
+        if let Some(votes) = nfo.votes {
+            lines.push(format!("            <votes>{}</votes>", votes));
+        }
+        lines.push("        </rating>".to_string());
+        lines.push("    </ratings>".to_string());
+    }
+    if let Some(tmdb_id) = nfo.tmdb_id {
+        lines.push(format!(r#"    <uniqueid type="tmdb" default="true">{}</uniqueid>"#, tmdb_id));
+    }
+    if let Some(imdb_id) = &nfo.imdb_id {
+        lines.push(format!(r#"    <uniqueid type="imdb">{}</uniqueid>"#, escape(imdb_id)));
+    }
+    for url in &nfo.poster_urls {
+        lines.push(format!(r#"    <thumb aspect="poster">{}</thumb>"#, escape(url)));
+    }
+    if let Some(backdrop_url) = &nfo.backdrop_url {
+        lines.push(format!("    <fanart><thumb>{}</thumb></fanart>", escape(backdrop_url)));
+    }
+    for actor in &nfo.actors {
+        lines.push(format!("    <actor><name>{}</name></actor>", escape(actor)));
+    }
+
+    lines.push("</movie>".to_string());
+    lines.join("\n") + "\n"
+}
+
+/// NFO 是否由 Modream 生成
+pub fn is_generated_nfo(path: &Path) -> bool {
+    fs::read(path).is_ok_and(|bytes| String::from_utf8_lossy(&bytes).contains(GENERATOR_COMMENT))
+}
+
+/// 写入 NFO 文件
+///
+/// 已存在的 NFO 不是 Modream 生成时（用户编写或其他工具生成），除非 `force` 否则不覆盖
+///
+/// # 返回
+/// - `Ok(true)` - 已写入
+/// - `Ok(false)` - 保留了用户编写的 NFO
+pub fn write_movie_nfo(path: &Path, nfo: &MovieNfo, force: bool) -> io::Result<bool> {
+    if path.exists() && !force && !is_generated_nfo(path) {
+        return Ok(false);
+    }
+    fs::write(path, render_movie_nfo(nfo))?;
+    Ok(true)
+}
+
+/// 下载图片到指定路径
+///
+/// 文件已存在时除非 `force` 否则不覆盖
+pub async fn download_artwork(client: &reqwest::Client, url: &str, path: &Path, force: bool) -> Result<bool, String> {
+    if path.exists() && !force {
+        return Ok(false);
+    }
+
+    let response = client.get(url).send().await.map_err(|e| format!("下载图片失败: {}", e))?;
+    if !response.status().is_success() {
+        return Err(format!("下载图片失败: {} 返回 {}", url, response.status()));
+    }
+    let bytes = response.bytes().await.map_err(|e| format!("读取图片失败: {}", e))?;
+    if bytes.is_empty() {
+        return Err(format!("下载图片失败: {} 返回空内容", url));
+    }
+
+    fs::write(path, &bytes).map_err(|e| format!("写入图片失败: {}", e))?;
+    Ok(true)
+}
+
+/// 在视频旁写入 NFO、海报和背景图
+///
+/// 海报使用 `poster_urls` 的第一个地址，背景图使用 `backdrop_url`；
+/// 图片下载失败不影响 NFO 的写入
+pub async fn write_movie_sidecars(video_path: &Path, nfo: &MovieNfo, force: bool) -> Result<SidecarWriteResult, String> {
+    let paths = sidecar_paths(video_path).ok_or_else(|| format!("无效的视频路径: {}", video_path.display()))?;
+    let mut result = SidecarWriteResult {
+        nfo_written: write_movie_nfo(&paths.nfo, nfo, force).map_err(|e| format!("写入 NFO 失败: {}", e))?,
+        ..Default::default()
+    };
+
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(30))
+        .build()
+        .map_err(|e| format!("创建 HTTP 客户端失败: {}", e))?;
+    if let Some(url) = nfo.poster_urls.first() {
+        match download_artwork(&client, url, &paths.poster, force).await {
+            Ok(written) => result.poster_written = written,
+            Err(e) => println!("  ⚠️  {}", e),
+        }
+    }
+    if let Some(url) = &nfo.backdrop_url {
+        match download_artwork(&client, url, &paths.fanart, force).await {
+            Ok(written) => result.fanart_written = written,
+            Err(e) => println!("  ⚠️  {}", e),
+        }
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::reader::parse_movie_nfo;
+    use super::*;
+
+    #[test]
+    fn test_write_movie_nfo() {
+        let nfo = MovieNfo {
+            title: Some("Tom & Jerry".to_string()),
+            original_title: Some("Tom and Jerry".to_string()),
+            year: Some(2021),
+            release_date: Some("2021-02-11".to_string()),
+            plot: Some("<猫> 和老鼠".to_string()),
+            genres: vec!["动画".to_string(), "喜剧".to_string()],
+            actors: vec!["Chloë Grace Moretz".to_string()],
+            directors: vec!["Tim Story".to_string()],
+            rating: Some(7.3),
+            votes: Some(2500),
+            tmdb_id: Some(587807),
+            imdb_id: Some("tt1361336".to_string()),
+            poster_urls: vec!["https://image.tmdb.org/t/p/w500/poster.jpg".to_string()],
+            backdrop_url: Some("https://image.tmdb.org/t/p/original/backdrop.jpg".to_string()),
+            ..Default::default()
+        };
+        // 生成的 NFO 可以被读取回来
+        assert_eq!(parse_movie_nfo(&render_movie_nfo(&nfo)), Some(nfo.clone()));
+
+        let dir = std::env::temp_dir().join(format!("modream_nfo_{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("movie.nfo");
+
+        // 覆盖自己生成的 NFO，保留用户编写的 NFO
+        assert!(write_movie_nfo(&path, &nfo, false).unwrap());
+        assert!(write_movie_nfo(&path, &nfo, false).unwrap());
+        fs::write(&path, "<movie><title>用户编写</title></movie>").unwrap();
+        assert!(!write_movie_nfo(&path, &nfo, false).unwrap());
+        assert!(write_movie_nfo(&path, &nfo, true).unwrap());
+        assert!(is_generated_nfo(&path));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
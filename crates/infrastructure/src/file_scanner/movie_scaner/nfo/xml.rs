@@ -0,0 +1,226 @@
+//! NFO 使用的简易 XML 解析
+//!
+//! 只支持 NFO 用到的子集：元素、属性、文本、CDATA、注释和常用实体，
+//! 不校验文档结构，遇到未闭合的元素时按已读取的内容返回。
+
+/// XML 元素
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct XmlElement {
+    /// 元素名（小写）
+    pub name: String,
+    /// 属性列表（属性名小写）
+    pub attributes: Vec<(String, String)>,
+    /// 子元素
+    pub children: Vec<XmlElement>,
+    /// 直接包含的文本（已解码实体）
+    pub text: String,
+}
+
+impl XmlElement {
+    /// 获取属性值
+    pub fn attr(&self, name: &str) -> Option<&str> {
+        self.attributes.iter().find(|(key, _)| key == name).map(|(_, value)| value.as_str())
+    }
+
+    /// 第一个同名子元素
+    pub fn child(&self, name: &str) -> Option<&XmlElement> {
+        self.children.iter().find(|c| c.name == name)
+    }
+
+    /// 所有同名子元素
+    pub fn children_named<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a XmlElement> + 'a {
+        self.children.iter().filter(move |c| c.name == name)
+    }
+
+    /// 第一个同名子元素的文本（去掉首尾空白，空文本返回 None）
+    pub fn child_text(&self, name: &str) -> Option<String> {
+        self.child(name).map(|c| c.text.trim().to_string()).filter(|t| !t.is_empty())
+    }
+
+    /// 所有同名子元素的非空文本
+    pub fn children_text(&self, name: &str) -> Vec<String> {
+        self.children_named(name)
+            .map(|c| c.text.trim().to_string())
+            .filter(|t| !t.is_empty())
+            .collect()
+    }
+}
+
+/// 解码 XML 实体（`&amp;`、`&lt;`、`&#39;`、`&#x27;` 等）
+pub fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        result.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let Some(end) = rest.find(';').filter(|end| *end <= 10) else {
+            result.push('&');
+            rest = &rest[1..];
+            continue;
+        };
+
+        let entity = &rest[1..end];
+        let decoded = match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            _ => entity
+                .strip_prefix("#x")
+                .or_else(|| entity.strip_prefix("#X"))
+                .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                .or_else(|| entity.strip_prefix('#').and_then(|dec| dec.parse().ok()))
+                .and_then(char::from_u32),
+        };
+        match decoded {
+            Some(c) => {
+                result.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// 解析开始标签内容（`name attr="value" ...`），返回元素名和属性
+fn parse_start_tag(tag: &str) -> XmlElement {
+    let tag = tag.trim();
+    let name_end = tag.find(|c: char| c.is_whitespace()).unwrap_or(tag.len());
+    let mut element = XmlElement {
+        name: tag[..name_end].to_lowercase(),
+        ..Default::default()
+    };
+
+    let mut rest = tag[name_end..].trim_start();
+    while let Some(eq) = rest.find('=') {
+        let key = rest[..eq].trim().to_lowercase();
+        let after = rest[eq + 1..].trim_start();
+        let Some(quote) = after.chars().next().filter(|c| *c == '"' || *c == '\'') else {
+            break;
+        };
+        let Some(value_end) = after[1..].find(quote) else {
+            break;
+        };
+        element.attributes.push((key, decode_entities(&after[1..value_end + 1])));
+        rest = after[value_end + 2..].trim_start();
+    }
+    element
+}
+
+/// 解析 XML 文档，返回根元素
+///
+/// 忽略 XML 声明、注释和 DOCTYPE；找不到根元素时返回 None
+pub fn parse(content: &str) -> Option<XmlElement> {
+    let mut stack: Vec<XmlElement> = Vec::new();
+    let mut root = None;
+    let mut rest = content.trim_start_matches('\u{feff}');
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            if let Some(current) = stack.last_mut() {
+                current.text.push_str(&decode_entities(rest));
+            }
+            break;
+        };
+        if let Some(current) = stack.last_mut() {
+            current.text.push_str(&decode_entities(&rest[..start]));
+        }
+        rest = &rest[start..];
+
+        if let Some(after) = rest.strip_prefix("<!--") {
+            rest = after.find("-->").map_or("", |end| &after[end + 3..]);
+            continue;
+        }
+        if let Some(after) = rest.strip_prefix("<![CDATA[") {
+            let end = after.find("]]>").unwrap_or(after.len());
+            if let Some(current) = stack.last_mut() {
+                current.text.push_str(&after[..end]);
+            }
+            rest = after.get(end + 3..).unwrap_or("");
+            continue;
+        }
+
+        let Some(end) = rest.find('>') else {
+            break;
+        };
+        let tag = &rest[1..end];
+        rest = &rest[end + 1..];
+
+        if tag.starts_with('?') || tag.starts_with('!') {
+            continue;
+        }
+        if let Some(name) = tag.strip_prefix('/') {
+            let name = name.trim().to_lowercase();
+            // 关闭到同名元素为止，容忍未闭合的子元素
+            if !stack.iter().any(|e| e.name == name) {
+                continue;
+            }
+            while let Some(element) = stack.pop() {
+                let matched = element.name == name;
+                match stack.last_mut() {
+                    Some(parent) => parent.children.push(element),
+                    None => root = root.or(Some(element)),
+                }
+                if matched {
+                    break;
+                }
+            }
+            continue;
+        }
+
+        let self_closing = tag.ends_with('/');
+        let element = parse_start_tag(tag.trim_end_matches('/'));
+        if self_closing {
+            match stack.last_mut() {
+                Some(parent) => parent.children.push(element),
+                None => root = root.or(Some(element)),
+            }
+        } else {
+            stack.push(element);
+        }
+    }
+
+    // 未闭合的元素按已读取的内容返回
+    while let Some(element) = stack.pop() {
+        match stack.last_mut() {
+            Some(parent) => parent.children.push(element),
+            None => root = root.or(Some(element)),
+        }
+    }
+    root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let root = parse(
+            "\u{feff}<?xml version=\"1.0\" encoding=\"UTF-8\" standalone=\"yes\" ?>\n<!-- created by tool -->\n<movie>\n  <title>Tom &amp; Jerry</title>\n  <plot><![CDATA[A <cat> chases a mouse.]]></plot>\n  <uniqueid type=\"tmdb\" default='true'>949</uniqueid>\n  <thumb aspect=\"poster\" />\n  <actor><name>Al Pacino</name><role>Vincent</role></actor>\n  <actor><name>Robert De Niro</name></actor>\n</movie>",
+        )
+        .unwrap();
+
+        assert_eq!(root.name, "movie");
+        assert_eq!(root.child_text("title").as_deref(), Some("Tom & Jerry"));
+        assert_eq!(root.child_text("plot").as_deref(), Some("A <cat> chases a mouse."));
+        let uniqueid = root.child("uniqueid").unwrap();
+        assert_eq!((uniqueid.attr("type"), uniqueid.attr("default"), uniqueid.text.as_str()), (Some("tmdb"), Some("true"), "949"));
+        assert_eq!(root.child("thumb").unwrap().attr("aspect"), Some("poster"));
+        let actors: Vec<_> = root.children_named("actor").filter_map(|a| a.child_text("name")).collect();
+        assert_eq!(actors, vec!["Al Pacino", "Robert De Niro"]);
+
+        assert_eq!(decode_entities("&#39;&#x4E2D;&unknown; & x"), "'中&unknown; & x");
+        assert_eq!(parse("https://www.themoviedb.org/movie/949"), None);
+    }
+}
@@ -0,0 +1,343 @@
+//! 电影文件名解析
+//!
+//! 从 `星际穿越.Interstellar.2014.1080p.BluRay.x264-SPARKS.mkv` 这类文件名中
+//! 解析出标题、年份、分辨率、来源、编码、版本和发布组。
+//!
+//! 标题取第一个技术标记（年份、分辨率、来源等）之前的部分；
+//! 有多个年份时取最后一个作为上映年份，前面的算作标题（`Blade.Runner.2049.2017`）。
+//!
+//! 文件名或所在文件夹名中的 `{tmdb-949}`、`[imdbid-tt0113277]` 用于固定匹配结果，
+//! 扫描时直接按 ID 获取详情，不再搜索。
+//!
+//! `CD1`、`part2` 这类分段标记只记录到 [`MovieFileInfo::part`]，不从标题中去掉，
+//! 由扫描器确认同目录下确实有多个分段后再合并（`Harry Potter Part 1` 是完整的标题）。
+
+use regex::Regex;
+use std::path::Path;
+use std::sync::LazyLock;
+
+/// 电影文件名解析结果
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MovieFileInfo {
+    /// 清理后的标题
+    pub title: String,
+    /// 上映年份
+    pub year: Option<u32>,
+    /// 分辨率（`2160p`、`1080p`、`720p`……）
+    pub resolution: Option<String>,
+    /// 来源（`BluRay`、`WEB-DL`、`Remux`……）
+    pub source: Option<String>,
+    /// 视频编码（`x264`、`H.265`、`AV1`……）
+    pub video_codec: Option<String>,
+    /// 版本（`Director's Cut`、`Extended`……）
+    pub edition: Option<String>,
+    /// 发布组
+    pub release_group: Option<String>,
+    /// 强制指定的 TMDB ID（`{tmdb-949}`）
+    pub tmdb_id: Option<u64>,
+    /// 强制指定的 IMDb ID（`[imdbid-tt0113277]`）
+    pub imdb_id: Option<String>,
+    /// 分段序号（`CD1`、`disc2`、`part3`）
+    pub part: Option<u32>,
+}
+
+/// `{tmdb-949}`、`[tmdbid-949]`、`{tmdbid=949}`
+static TMDB_HINT: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)[\[{]tmdb(?:id)?[-=](\d+)[\]}]").expect("invalid tmdb hint pattern"));
+
+/// `{imdb-tt0113277}`、`[imdbid-tt0113277]`
+static IMDB_HINT: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)[\[{]imdb(?:id)?[-=](tt\d{7,})[\]}]").expect("invalid imdb hint pattern"));
+
+/// 分段标记 `cd1`、`disc 2`、`part3`、`pt 4`（分隔符已换成空格）
+static STACK: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?:cd|dvd|dis[ck]|part|pt) ?(\d{1,2})\b").expect("invalid stack pattern")
+});
+
+/// 独立的年份 `2014`、`(2015)`、`[2015]`
+static YEAR: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\b((?:18|19|20)\d{2})\b").expect("invalid year pattern"));
+
+/// 分辨率标记
+static RESOLUTION: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\b(2160p|1080[pi]|720p|576p|480p|360p|4k|uhd)\b").expect("invalid resolution pattern"));
+
+/// 来源标记及其规范名称（按优先级排列，`Remux` 优先于 `BluRay`）
+static SOURCES: LazyLock<Vec<(Regex, &'static str)>> = LazyLock::new(|| {
+    [
+        (r"(?i)\b(?:bd)?remux\b", "Remux"),
+        (r"(?i)\bblu[ -]?ray\b", "BluRay"),
+        (r"(?i)\b(?:bdrip|brrip)\b", "BDRip"),
+        (r"(?i)\bweb[ -]?dl\b", "WEB-DL"),
+        (r"(?i)\bweb[ -]?rip\b", "WEBRip"),
+        (r"(?i)\bhdtv\b", "HDTV"),
+        (r"(?i)\bdvd[ -]?rip\b", "DVDRip"),
+        (r"(?i)\bhd[ -]?rip\b", "HDRip"),
+        (r"(?i)\bdvd\b", "DVD"),
+    ]
+    .into_iter()
+    .map(|(pattern, name)| (Regex::new(pattern).expect("invalid source pattern"), name))
+    .collect()
+});
+
+/// 视频编码标记及其规范名称
+static CODECS: LazyLock<Vec<(Regex, &'static str)>> = LazyLock::new(|| {
+    [
+        (r"(?i)\bx264\b", "x264"),
+        (r"(?i)\bx265\b", "x265"),
+        (r"(?i)\b(?:h ?264|avc)\b", "H.264"),
+        (r"(?i)\b(?:h ?265|hevc)\b", "H.265"),
+        (r"(?i)\bav1\b", "AV1"),
+        (r"(?i)\bvp9\b", "VP9"),
+        (r"(?i)\bxvid\b", "XviD"),
+        (r"(?i)\bdivx\b", "DivX"),
+    ]
+    .into_iter()
+    .map(|(pattern, name)| (Regex::new(pattern).expect("invalid codec pattern"), name))
+    .collect()
+});
+
+/// 版本标记及其规范名称（中文标记没有单词边界）
+static EDITIONS: LazyLock<Vec<(Regex, &'static str)>> = LazyLock::new(|| {
+    [
+        (r"(?i)\bdirector'?s cut\b|导演剪辑版", "Director's Cut"),
+        (r"(?i)\bextended(?: cut| edition)?\b|加长版", "Extended"),
+        (r"(?i)\bunrated\b", "Unrated"),
+        (r"(?i)\buncut\b|未删减版", "Uncut"),
+        (r"(?i)\btheatrical(?: cut)?\b|院线版", "Theatrical"),
+        (r"(?i)\bfinal cut\b", "Final Cut"),
+        (r"(?i)\bspecial edition\b", "Special Edition"),
+        (r"(?i)\bultimate edition\b", "Ultimate Edition"),
+        (r"(?i)\bremastered\b|重制版", "Remastered"),
+        (r"(?i)\bcriterion\b", "Criterion"),
+        (r"(?i)\bimax\b", "IMAX"),
+    ]
+    .into_iter()
+    .map(|(pattern, name)| (Regex::new(pattern).expect("invalid edition pattern"), name))
+    .collect()
+});
+
+/// 其余不属于标题的技术标记（音频、色深、HDR）
+static OTHER_TAGS: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?:aac|ac3|e ?ac3|ddp?5 ?1|dts(?: hd)?|truehd|atmos|flac|10bit|8bit|hdr10|hdr|dv|dovi)\b")
+        .expect("invalid tag pattern")
+});
+
+/// 方括号和花括号（发布组、站点标记等）
+static BRACKETS: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\[.*?\]|\{.*?\}").expect("invalid bracket pattern"));
+
+/// 文件名末尾的 `-GROUP`
+static TRAILING_GROUP: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"-([A-Za-z0-9]+)$").expect("invalid release group pattern"));
+
+/// 文件名开头的 `[GROUP]`
+static LEADING_GROUP: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^\[([^\]]+)\]").expect("invalid release group pattern"));
+
+/// 解析电影文件名
+///
+/// 最后一个 `.` 之后的部分视为扩展名
+pub fn parse_movie_file(file_name: &str) -> MovieFileInfo {
+    let name = file_name.rfind('.').map_or(file_name, |pos| &file_name[..pos]);
+
+    // 先取出 ID 标记，避免 `{tmdb-1995}` 被当成年份
+    let (tmdb_id, imdb_id) = parse_provider_ids(name);
+    let name = &*IMDB_HINT.replace_all(&TMDB_HINT.replace_all(name, " "), " ").into_owned();
+
+    // 分隔符换成空格（同为单字节，位置与原文件名一一对应）
+    let normalized: String = name
+        .chars()
+        .map(|c| if matches!(c, '.' | '_' | '-') { ' ' } else { c })
+        .collect();
+
+    let resolution = RESOLUTION.find(&normalized);
+    let source = SOURCES.iter().find_map(|(re, name)| re.find(&normalized).map(|m| (m, *name)));
+    let codec = CODECS.iter().find_map(|(re, name)| re.find(&normalized).map(|m| (m, *name)));
+    let edition = EDITIONS.iter().find_map(|(re, name)| re.find(&normalized).map(|m| (m, *name)));
+
+    // 第一个技术标记的位置，标题和年份都在它之前
+    let first_tag = [
+        resolution.map(|m| m.start()),
+        source.map(|(m, _)| m.start()),
+        codec.map(|(m, _)| m.start()),
+        edition.map(|(m, _)| m.start()),
+        OTHER_TAGS.find(&normalized).map(|m| m.start()),
+    ]
+    .into_iter()
+    .flatten()
+    .min()
+    .unwrap_or(normalized.len());
+
+    // 年份前面必须有标题（`2012.2009.mkv` 的标题是 2012）
+    let year = YEAR
+        .captures_iter(&normalized[..first_tag])
+        .filter_map(|c| {
+            let m = c.get(1)?;
+            let year: u32 = m.as_str().parse().ok()?;
+            (is_plausible_year(year) && !clean_title(&normalized[..m.start()]).is_empty()).then_some((m.start(), year))
+        })
+        .last();
+
+    let title_end = year.map_or(first_tag, |(start, _)| start);
+    let mut title = clean_title(&normalized[..title_end]);
+    if title.is_empty() {
+        title = clean_title(&normalized);
+    }
+
+    MovieFileInfo {
+        title,
+        year: year.map(|(_, year)| year),
+        resolution: resolution.map(|m| normalize_resolution(m.as_str())),
+        source: source.map(|(_, name)| name.to_string()),
+        video_codec: codec.map(|(_, name)| name.to_string()),
+        edition: edition.map(|(_, name)| name.to_string()),
+        release_group: release_group(name, title_end),
+        tmdb_id,
+        imdb_id,
+        part: parse_stack_part(&normalized),
+    }
+}
+
+/// 解析分段序号，只认最后一个分段标记
+fn parse_stack_part(normalized: &str) -> Option<u32> {
+    STACK
+        .captures_iter(normalized)
+        .last()
+        .and_then(|c| c[1].parse().ok())
+        .filter(|part| *part > 0)
+}
+
+/// 去掉分段标记，用于判断多个文件是否属于同一部电影
+pub fn strip_stack_marker(name: &str) -> String {
+    let normalized: String = name
+        .chars()
+        .map(|c| if matches!(c, '.' | '_' | '-') { ' ' } else { c })
+        .collect();
+    STACK.replace_all(&normalized, " ").split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// 解析视频路径，文件名中没有 ID 标记和年份时使用所在文件夹名中的
+///
+/// 支持 `Heat (1995) {tmdb-949}/Heat.mkv` 这类按文件夹整理的电影
+pub fn parse_movie_path(path: &Path) -> MovieFileInfo {
+    let file_name = path.file_name().map(|n| n.to_string_lossy()).unwrap_or_default();
+    let mut info = parse_movie_file(&file_name);
+
+    if let Some(folder) = path.parent().and_then(|p| p.file_name()).map(|n| n.to_string_lossy()) {
+        let (tmdb_id, imdb_id) = parse_provider_ids(&folder);
+        info.tmdb_id = info.tmdb_id.or(tmdb_id);
+        info.imdb_id = info.imdb_id.or(imdb_id);
+        if info.year.is_none() {
+            // 文件夹名没有扩展名，补一个 `.` 防止被截断
+            info.year = parse_movie_file(&format!("{}.", folder)).year;
+        }
+    }
+    info
+}
+
+/// 解析 `{tmdb-949}` 和 `[imdbid-tt0113277]` 形式的 ID 标记
+pub fn parse_provider_ids(name: &str) -> (Option<u64>, Option<String>) {
+    let tmdb_id = TMDB_HINT.captures(name).and_then(|c| c[1].parse().ok());
+    let imdb_id = IMDB_HINT.captures(name).map(|c| c[1].to_lowercase());
+    (tmdb_id, imdb_id)
+}
+
+/// 年份是否合理（电影诞生之后，且不超过明年）
+fn is_plausible_year(year: u32) -> bool {
+    use chrono::Datelike;
+    (1888..=chrono::Local::now().year() as u32 + 1).contains(&year)
+}
+
+/// 去掉括号内容、残留的技术标记和多余空格
+fn clean_title(text: &str) -> String {
+    let text = BRACKETS.replace_all(text, " ");
+    let text = OTHER_TAGS.replace_all(&text, " ");
+    let text = text.replace(['(', ')'], " ");
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// 分辨率统一为小写 `p` 结尾，`4K` / `UHD` 记为 `2160p`
+fn normalize_resolution(resolution: &str) -> String {
+    match resolution.to_lowercase().as_str() {
+        "4k" | "uhd" => "2160p".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// 发布组：末尾的 `-GROUP` 或开头的 `[GROUP]`
+///
+/// 末尾的 `-GROUP` 必须在标题之后，避免把 `Spider-Man` 当成发布组；
+/// 也不能是 `WEB-DL` 这类技术标记的一部分
+fn release_group(name: &str, title_end: usize) -> Option<String> {
+    if let Some(c) = TRAILING_GROUP.captures(name) {
+        let m = c.get(1)?;
+        // 连同前一个单词一起完整匹配来源标记时（`WEB-DL`）不是发布组
+        let token_start = name[..m.start() - 1].rfind(['.', ' ', '_']).map_or(0, |pos| pos + 1);
+        let token = name[token_start..].replace('-', " ");
+        let is_tag = RESOLUTION.is_match(m.as_str())
+            || STACK.is_match(m.as_str())
+            || CODECS.iter().any(|(re, _)| re.is_match(m.as_str()))
+            || SOURCES
+                .iter()
+                .any(|(re, _)| re.find(&token).is_some_and(|f| f.start() == 0 && f.end() == token.len()));
+        if m.start() > title_end && !is_tag {
+            return Some(m.as_str().to_string());
+        }
+    }
+
+    LEADING_GROUP
+        .captures(name)
+        .map(|c| c[1].trim().to_string())
+        .filter(|group| !group.is_empty() && !YEAR.is_match(group) && !RESOLUTION.is_match(group))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_movie_file() {
+        let info = parse_movie_file("星际穿越.Interstellar.2014.1080p.BluRay.x264-SPARKS.mkv");
+        assert_eq!(info.title, "星际穿越 Interstellar");
+        assert_eq!(info.year, Some(2014));
+        assert_eq!(info.resolution.as_deref(), Some("1080p"));
+        assert_eq!(info.source.as_deref(), Some("BluRay"));
+        assert_eq!(info.video_codec.as_deref(), Some("x264"));
+        assert_eq!(info.release_group.as_deref(), Some("SPARKS"));
+
+        let info = parse_movie_file("Blade.Runner.2049.2017.2160p.UHD.BluRay.REMUX.HEVC.mkv");
+        assert_eq!((info.title.as_str(), info.year), ("Blade Runner 2049", Some(2017)));
+        assert_eq!(info.source.as_deref(), Some("Remux"));
+        assert_eq!(info.video_codec.as_deref(), Some("H.265"));
+
+        let info = parse_movie_file("Apocalypse Now (1979) Director's Cut [1080p].mkv");
+        assert_eq!((info.title.as_str(), info.year), ("Apocalypse Now", Some(1979)));
+        assert_eq!(info.edition.as_deref(), Some("Director's Cut"));
+        assert_eq!(info.release_group, None);
+
+        let info = parse_movie_file("2012.2009.WEB-DL.1080p.mkv");
+        assert_eq!((info.title.as_str(), info.year), ("2012", Some(2009)));
+        assert_eq!(info.source.as_deref(), Some("WEB-DL"));
+        assert_eq!(info.release_group, None);
+
+        let info = parse_movie_file("Heat (1995) {tmdb-949}.mkv");
+        assert_eq!((info.title.as_str(), info.year, info.tmdb_id), ("Heat", Some(1995), Some(949)));
+
+        let info = parse_movie_file("Heat.1995.1080p.BluRay[imdbid-tt0113277].mkv");
+        assert_eq!((info.title.as_str(), info.imdb_id.as_deref()), ("Heat", Some("tt0113277")));
+
+        let info = parse_movie_path(Path::new("/movies/Heat (1995) [tmdbid-949]/heat.bluray.mkv"));
+        assert_eq!((info.title.as_str(), info.year, info.tmdb_id), ("heat", Some(1995), Some(949)));
+
+        let info = parse_movie_file("Spider-Man.mkv");
+        assert_eq!((info.title.as_str(), info.year), ("Spider Man", None));
+        assert_eq!(info.release_group, None);
+
+        let info = parse_movie_file("Heat (1995) - CD2.avi");
+        assert_eq!((info.title.as_str(), info.year, info.part), ("Heat", Some(1995), Some(2)));
+
+        let info = parse_movie_file("Heat.part1.mkv");
+        assert_eq!((info.title.as_str(), info.part), ("Heat part1", Some(1)));
+        assert_eq!(strip_stack_marker(&info.title), "Heat");
+    }
+}
@@ -0,0 +1,261 @@
+//! 元数据响应缓存
+//!
+//! 每次重新扫描都会为每个文件重新请求元数据，大型媒体库既慢又容易被限流。
+//! [`CachedProvider`] 包装任意 [`MetadataProvider`]，把搜索和详情结果按
+//! 接口、查询、年份和语言缓存到 data 目录，过期前直接读取本地文件。
+//!
+//! 目录结构：`{缓存目录}/{提供者名称}/{键的 SHA-256}.json`。请求失败的结果不缓存。
+
+use super::super::models::language::Language;
+use super::{
+    CollectionDetails, MetadataProvider, MovieDetails, SearchMetadataResult, TvDetails, TvSearchResult,
+    TvSeasonDetails,
+};
+use async_trait::async_trait;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// 默认缓存目录
+pub const DEFAULT_CACHE_DIR: &str = "./data/cache/metadata";
+
+/// 默认缓存有效期（7 天）
+pub const DEFAULT_TTL: Duration = Duration::from_secs(7 * 24 * 60 * 60);
+
+/// 缓存文件内容
+#[derive(Serialize, Deserialize)]
+struct CacheEntry<T> {
+    /// 缓存时间（Unix 秒）
+    cached_at: i64,
+    value: T,
+}
+
+/// 元数据磁盘缓存
+pub struct MetadataCache {
+    dir: PathBuf,
+    ttl: Duration,
+}
+
+impl MetadataCache {
+    /// 创建缓存，`dir` 为缓存根目录
+    pub fn new(dir: impl Into<PathBuf>, ttl: Duration) -> Self {
+        Self { dir: dir.into(), ttl }
+    }
+
+    /// 缓存文件路径
+    pub fn entry_path(&self, provider: &str, key: &str) -> PathBuf {
+        let hash = Sha256::digest(key.as_bytes());
+        let file_name: String = hash.iter().map(|b| format!("{:02x}", b)).collect();
+        self.dir.join(sanitize(provider)).join(format!("{}.json", file_name))
+    }
+
+    /// 读取未过期的缓存
+    pub async fn get<T: DeserializeOwned>(&self, provider: &str, key: &str) -> Option<T> {
+        let content = tokio::fs::read(self.entry_path(provider, key)).await.ok()?;
+        let entry: CacheEntry<T> = serde_json::from_slice(&content).ok()?;
+        let age = chrono::Utc::now().timestamp().saturating_sub(entry.cached_at);
+        (age >= 0 && (age as u64) < self.ttl.as_secs()).then_some(entry.value)
+    }
+
+    /// 写入缓存，失败时只记录日志
+    pub async fn put<T: Serialize>(&self, provider: &str, key: &str, value: &T) {
+        let path = self.entry_path(provider, key);
+        let entry = CacheEntry {
+            cached_at: chrono::Utc::now().timestamp(),
+            value,
+        };
+        let result = match serde_json::to_vec(&entry) {
+            Ok(content) => write_atomically(&path, &content).await,
+            Err(e) => Err(std::io::Error::other(e)),
+        };
+        if let Err(e) = result {
+            tracing::warn!("Failed to write metadata cache {}: {}", path.display(), e);
+        }
+    }
+
+    /// 先读缓存，没有时执行 `fetch` 并缓存成功的结果
+    pub async fn get_or_fetch<T, F>(&self, provider: &str, key: &str, fetch: F) -> Result<T, String>
+    where
+        T: Serialize + DeserializeOwned,
+        F: Future<Output = Result<T, String>>,
+    {
+        if let Some(value) = self.get(provider, key).await {
+            tracing::debug!("Metadata cache hit: {} {}", provider, key);
+            return Ok(value);
+        }
+        let value = fetch.await?;
+        self.put(provider, key, &value).await;
+        Ok(value)
+    }
+}
+
+impl Default for MetadataCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_CACHE_DIR, DEFAULT_TTL)
+    }
+}
+
+/// 提供者名称用作目录名，去掉路径分隔符等字符
+fn sanitize(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+        .collect()
+}
+
+/// 先写临时文件再重命名，避免并发扫描读到写了一半的缓存
+async fn write_atomically(path: &Path, content: &[u8]) -> std::io::Result<()> {
+    if let Some(parent) = path.parent() {
+        tokio::fs::create_dir_all(parent).await?;
+    }
+    let temp_path = path.with_extension("json.part");
+    tokio::fs::write(&temp_path, content).await?;
+    tokio::fs::rename(&temp_path, path).await
+}
+
+/// 带磁盘缓存的元数据提供者
+///
+/// 缓存带语言参数的接口；旧的 `search` / `get_details` 直接转发
+pub struct CachedProvider<P> {
+    inner: P,
+    cache: MetadataCache,
+}
+
+impl<P: MetadataProvider> CachedProvider<P> {
+    /// 使用默认缓存目录和有效期包装提供者
+    pub fn new(inner: P) -> Self {
+        Self::with_cache(inner, MetadataCache::default())
+    }
+
+    /// 使用指定缓存包装提供者
+    pub fn with_cache(inner: P, cache: MetadataCache) -> Self {
+        Self { inner, cache }
+    }
+}
+
+#[async_trait]
+impl<P: MetadataProvider> MetadataProvider for CachedProvider<P> {
+    fn name(&self) -> &str {
+        self.inner.name()
+    }
+
+    async fn search(&self, title: &str, year: Option<u64>) -> Result<Vec<SearchMetadataResult>, String> {
+        self.inner.search(title, year).await
+    }
+
+    async fn get_details(&self, tmdb_id: u64) -> Result<MovieDetails, String> {
+        self.inner.get_details(tmdb_id).await
+    }
+
+    async fn search_with_language(
+        &self,
+        title: &str,
+        year: Option<u64>,
+        language: Language,
+    ) -> Result<Vec<SearchMetadataResult>, String> {
+        let key = cache_key("search/movie", title, year, language);
+        self.cache
+            .get_or_fetch(self.name(), &key, self.inner.search_with_language(title, year, language))
+            .await
+    }
+
+    async fn get_details_with_language(&self, id: u64, language: Language) -> Result<MovieDetails, String> {
+        let key = cache_key("movie", &id.to_string(), None, language);
+        self.cache
+            .get_or_fetch(self.name(), &key, self.inner.get_details_with_language(id, language))
+            .await
+    }
+
+    async fn find_by_imdb_id(&self, imdb_id: &str, language: Language) -> Result<Option<SearchMetadataResult>, String> {
+        let key = cache_key("find/imdb", imdb_id, None, language);
+        self.cache
+            .get_or_fetch(self.name(), &key, self.inner.find_by_imdb_id(imdb_id, language))
+            .await
+    }
+
+    async fn get_collection_with_language(&self, id: u64, language: Language) -> Result<CollectionDetails, String> {
+        let key = cache_key("collection", &id.to_string(), None, language);
+        self.cache
+            .get_or_fetch(self.name(), &key, self.inner.get_collection_with_language(id, language))
+            .await
+    }
+
+    async fn search_tv_with_language(
+        &self,
+        name: &str,
+        first_air_year: Option<u64>,
+        language: Language,
+    ) -> Result<Vec<TvSearchResult>, String> {
+        let key = cache_key("search/tv", name, first_air_year, language);
+        self.cache
+            .get_or_fetch(
+                self.name(),
+                &key,
+                self.inner.search_tv_with_language(name, first_air_year, language),
+            )
+            .await
+    }
+
+    async fn get_tv_details_with_language(&self, id: u64, language: Language) -> Result<TvDetails, String> {
+        let key = cache_key("tv", &id.to_string(), None, language);
+        self.cache
+            .get_or_fetch(self.name(), &key, self.inner.get_tv_details_with_language(id, language))
+            .await
+    }
+
+    async fn get_tv_season_with_language(
+        &self,
+        id: u64,
+        season_number: u32,
+        language: Language,
+    ) -> Result<TvSeasonDetails, String> {
+        let key = cache_key("tv/season", &format!("{}/{}", id, season_number), None, language);
+        self.cache
+            .get_or_fetch(
+                self.name(),
+                &key,
+                self.inner.get_tv_season_with_language(id, season_number, language),
+            )
+            .await
+    }
+}
+
+/// 缓存键：`接口|查询|年份|语言`，查询忽略大小写和首尾空白
+fn cache_key(endpoint: &str, query: &str, year: Option<u64>, language: Language) -> String {
+    format!(
+        "{}|{}|{}|{}",
+        endpoint,
+        query.trim().to_lowercase(),
+        year.map(|y| y.to_string()).unwrap_or_default(),
+        language.code()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_metadata_cache() {
+        let dir = std::env::temp_dir().join(format!("modream-metadata-cache-{}", std::process::id()));
+        let cache = MetadataCache::new(&dir, DEFAULT_TTL);
+        let key = cache_key("search/movie", " Heat ", Some(1995), Language::default());
+        assert_eq!(key, cache_key("search/movie", "heat", Some(1995), Language::default()));
+
+        let fetched = cache.get_or_fetch("TMDB", &key, async { Ok(vec![949u64]) }).await;
+        assert_eq!(fetched, Ok(vec![949]));
+        // 命中缓存时不再请求
+        let cached = cache
+            .get_or_fetch("TMDB", &key, async { Err::<Vec<u64>, _>("不应请求".to_string()) })
+            .await;
+        assert_eq!(cached, Ok(vec![949]));
+
+        // 过期后重新请求
+        let expired = MetadataCache::new(&dir, Duration::ZERO);
+        assert_eq!(expired.get::<Vec<u64>>("TMDB", &key).await, None);
+
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+    }
+}
@@ -1,11 +1,14 @@
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 
+pub mod cache;
+pub mod rate_limit;
 pub mod tmdb_provider;
 
 /// 搜索元数据结果
 ///
 /// 从 TMDB API 搜索返回的电影元数据
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SearchMetadataResult {
     /// TMDB 电影 ID
     pub tmdb_id: u64,
@@ -60,10 +63,13 @@ impl SearchMetadataResult {
 /// 电影详细信息
 ///
 /// 从 TMDB API 详情接口返回的完整电影信息
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MovieDetails {
     /// TMDB 电影 ID
     pub tmdb_id: u64,
+    /// IMDb 电影 ID（如 `tt0113277`）
+    #[serde(default)]
+    pub imdb_id: Option<String>,
     /// 电影标题
     pub title: String,
     /// 原始标题
@@ -100,6 +106,52 @@ pub struct MovieDetails {
     pub keywords: Vec<String>,
     /// 海报 URL 列表
     pub poster_urls: Vec<String>,
+    /// 所属系列的 TMDB ID
+    pub collection_id: Option<u64>,
+    /// 演职人员（演员在前，按出场顺序；之后是导演、编剧、制片人）
+    #[serde(default)]
+    pub credits: Vec<MovieCredit>,
+}
+
+/// 电影的一位演职人员
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MovieCredit {
+    /// TMDB 人物 ID（NFO 中的演职人员没有）
+    pub tmdb_id: Option<u64>,
+    /// 姓名
+    pub name: String,
+    /// 职责：actor / director / writer / producer
+    pub role: String,
+    /// 饰演的角色（仅演员）
+    pub character: Option<String>,
+    /// 同一职责内的排序（演员为出场顺序）
+    pub order: u32,
+    /// 头像 URL
+    pub profile_url: Option<String>,
+}
+
+impl MovieCredit {
+    /// 按姓名列表创建某一职责的演职人员
+    ///
+    /// 姓名列表可能来自 NFO，与提供者返回的演职人员不完全一致：
+    /// 在 `known` 中找到同名同职责的人员时沿用其 TMDB ID、角色和头像，否则只记录姓名
+    pub fn for_names(role: &str, names: &[String], known: &[MovieCredit]) -> Vec<Self> {
+        names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let known = known.iter().find(|c| c.role == role && c.name == *name);
+                Self {
+                    tmdb_id: known.and_then(|c| c.tmdb_id),
+                    name: name.clone(),
+                    role: role.to_string(),
+                    character: known.and_then(|c| c.character.clone()),
+                    order: i as u32,
+                    profile_url: known.and_then(|c| c.profile_url.clone()),
+                }
+            })
+            .collect()
+    }
 }
 
 impl MovieDetails {
@@ -118,6 +170,137 @@ impl MovieDetails {
     }
 }
 
+/// 电影系列（合集）中的一部电影
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CollectionPart {
+    /// TMDB 电影 ID
+    pub tmdb_id: u64,
+    /// 电影标题
+    pub title: String,
+    /// 原始标题
+    pub original_title: String,
+    /// 发行日期（格式：YYYY-MM-DD）
+    pub release_date: String,
+    /// 海报 URL
+    pub poster_url: Option<String>,
+}
+
+/// 电影系列（合集）详细信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CollectionDetails {
+    /// TMDB 系列 ID
+    pub tmdb_id: u64,
+    /// 系列名称
+    pub name: String,
+    /// 简介
+    pub overview: String,
+    /// 海报 URL
+    pub poster_url: Option<String>,
+    /// 背景图 URL
+    pub backdrop_url: Option<String>,
+    /// 系列中的所有电影（按发行日期排序）
+    pub parts: Vec<CollectionPart>,
+}
+
+/// 电视剧搜索结果
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TvSearchResult {
+    /// TMDB 剧集 ID
+    pub tmdb_id: u64,
+    /// 剧名
+    pub name: String,
+    /// 原始剧名
+    pub original_name: String,
+    /// 简介
+    pub overview: String,
+    /// 首播日期（格式：YYYY-MM-DD）
+    pub first_air_date: String,
+    /// 海报图片路径
+    pub poster_path: Option<String>,
+    /// 平均评分（0-10）
+    pub vote_average: f64,
+    /// 流行度分数
+    pub popularity: f64,
+}
+
+/// 电视剧中一季的概要信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TvSeasonSummary {
+    /// 季号（0 为特别篇）
+    pub season_number: u32,
+    /// 季标题
+    pub name: String,
+    /// 简介
+    pub overview: String,
+    /// 首播日期
+    pub air_date: String,
+    /// 集数
+    pub episode_count: u32,
+    /// 海报图片路径
+    pub poster_path: Option<String>,
+}
+
+/// 电视剧详细信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TvDetails {
+    /// TMDB 剧集 ID
+    pub tmdb_id: u64,
+    /// 剧名
+    pub name: String,
+    /// 原始剧名
+    pub original_name: String,
+    /// 简介
+    pub overview: String,
+    /// 首播日期（格式：YYYY-MM-DD）
+    pub first_air_date: String,
+    /// 平均评分（0-10）
+    pub vote_average: f64,
+    /// 投票数量
+    pub vote_count: u64,
+    /// 类型列表（名称）
+    pub genres: Vec<String>,
+    /// 演员列表
+    pub cast: Vec<String>,
+    /// 海报 URL 列表
+    pub poster_urls: Vec<String>,
+    /// 各季概要
+    pub seasons: Vec<TvSeasonSummary>,
+}
+
+/// 单集详细信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TvEpisodeDetails {
+    /// 集号
+    pub episode_number: u32,
+    /// 单集标题
+    pub name: String,
+    /// 简介
+    pub overview: String,
+    /// 播出日期
+    pub air_date: String,
+    /// 平均评分（0-10）
+    pub vote_average: f64,
+    /// 剧照 URL
+    pub still_url: Option<String>,
+}
+
+/// 一季的详细信息（包含所有单集）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TvSeasonDetails {
+    /// 季号
+    pub season_number: u32,
+    /// 季标题
+    pub name: String,
+    /// 简介
+    pub overview: String,
+    /// 首播日期
+    pub air_date: String,
+    /// 海报 URL
+    pub poster_url: Option<String>,
+    /// 单集列表
+    pub episodes: Vec<TvEpisodeDetails>,
+}
+
 /// 元数据提供者 trait
 ///
 /// 所有元数据提供者必须实现此 trait
@@ -147,6 +330,59 @@ pub trait MetadataProvider: Send + Sync {
         id: u64,
         language: super::models::language::Language,
     ) -> Result<MovieDetails, String>;
+
+    /// 通过 IMDb ID 查找电影，找不到时返回 `Ok(None)`
+    ///
+    /// 默认实现返回错误，不支持外部 ID 查找的提供者无需实现
+    async fn find_by_imdb_id(
+        &self,
+        _imdb_id: &str,
+        _language: super::models::language::Language,
+    ) -> Result<Option<SearchMetadataResult>, String> {
+        Err(format!("{} 不支持 IMDb ID 查找", self.name()))
+    }
+
+    /// 使用指定语言获取电影系列（合集）详情
+    ///
+    /// 默认实现返回错误，不支持电影系列的提供者无需实现
+    async fn get_collection_with_language(
+        &self,
+        _id: u64,
+        _language: super::models::language::Language,
+    ) -> Result<CollectionDetails, String> {
+        Err(format!("{} 不支持电影系列", self.name()))
+    }
+
+    /// 使用指定语言搜索电视剧
+    ///
+    /// 默认实现返回错误，不支持电视剧的提供者无需实现
+    async fn search_tv_with_language(
+        &self,
+        _name: &str,
+        _first_air_year: Option<u64>,
+        _language: super::models::language::Language,
+    ) -> Result<Vec<TvSearchResult>, String> {
+        Err(format!("{} 不支持电视剧搜索", self.name()))
+    }
+
+    /// 使用指定语言获取电视剧详情
+    async fn get_tv_details_with_language(
+        &self,
+        _id: u64,
+        _language: super::models::language::Language,
+    ) -> Result<TvDetails, String> {
+        Err(format!("{} 不支持电视剧详情", self.name()))
+    }
+
+    /// 使用指定语言获取电视剧某一季的详情（包含单集信息）
+    async fn get_tv_season_with_language(
+        &self,
+        _id: u64,
+        _season_number: u32,
+        _language: super::models::language::Language,
+    ) -> Result<TvSeasonDetails, String> {
+        Err(format!("{} 不支持电视剧季详情", self.name()))
+    }
 }
 
 /// 旧的 trait 别名，保持向后兼容
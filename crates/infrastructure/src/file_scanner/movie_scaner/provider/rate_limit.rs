@@ -0,0 +1,134 @@
+//! 元数据请求限流
+//!
+//! 扫描大型媒体库时会在短时间内发出大量请求，容易触发 TMDB 等服务的限流。
+//! [`RateLimiter`] 是令牌桶限流器，[`RateLimitedClient`] 在 `reqwest::Client` 外包一层，
+//! 每次请求前先取令牌，收到 HTTP 429 时按 `Retry-After` 等待后重试。
+//!
+//! 同一服务的多个提供者实例应共享同一个限流器。
+
+use reqwest::StatusCode;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio::time::Instant;
+
+/// 令牌桶限流器
+pub struct RateLimiter {
+    /// 桶容量（允许的突发请求数）
+    capacity: f64,
+    /// 每秒补充的令牌数
+    refill_per_sec: f64,
+    /// (当前令牌数, 上次补充时间)
+    state: Mutex<(f64, Instant)>,
+}
+
+impl RateLimiter {
+    /// 创建限流器，初始为满桶
+    pub fn new(capacity: u32, refill_per_sec: f64) -> Self {
+        Self {
+            capacity: f64::from(capacity),
+            refill_per_sec,
+            state: Mutex::new((f64::from(capacity), Instant::now())),
+        }
+    }
+
+    /// 取一个令牌，没有令牌时等待
+    pub async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut state = self.state.lock().await;
+                let now = Instant::now();
+                let (tokens, last) = *state;
+                let tokens = (tokens + now.duration_since(last).as_secs_f64() * self.refill_per_sec).min(self.capacity);
+                if tokens >= 1.0 {
+                    *state = (tokens - 1.0, now);
+                    return;
+                }
+                *state = (tokens, now);
+                Duration::from_secs_f64((1.0 - tokens) / self.refill_per_sec)
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+/// 带限流和 429 重试的 HTTP 客户端
+#[derive(Clone)]
+pub struct RateLimitedClient {
+    client: reqwest::Client,
+    limiter: Arc<RateLimiter>,
+    max_retries: u32,
+}
+
+impl RateLimitedClient {
+    /// 收到 429 时的最大重试次数
+    const DEFAULT_MAX_RETRIES: u32 = 3;
+
+    /// 创建客户端，`limiter` 在同一服务的所有客户端间共享
+    pub fn new(client: reqwest::Client, limiter: Arc<RateLimiter>) -> Self {
+        Self {
+            client,
+            limiter,
+            max_retries: Self::DEFAULT_MAX_RETRIES,
+        }
+    }
+
+    /// 发送 GET 请求
+    ///
+    /// 重试次数用完后返回最后一次 429 响应，由调用方按普通错误处理
+    pub async fn get(&self, url: &str) -> reqwest::Result<reqwest::Response> {
+        let mut attempt = 0;
+        loop {
+            self.limiter.acquire().await;
+            let response = self.client.get(url).send().await?;
+            if response.status() != StatusCode::TOO_MANY_REQUESTS || attempt >= self.max_retries {
+                return Ok(response);
+            }
+
+            attempt += 1;
+            let delay = retry_after(&response).unwrap_or_else(|| Duration::from_secs(1 << attempt));
+            tracing::warn!(
+                "Rate limited by {}, retrying in {:?} ({}/{})",
+                response.url().host_str().unwrap_or_default(),
+                delay,
+                attempt,
+                self.max_retries
+            );
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
+
+/// 解析 `Retry-After` 响应头（秒数），最多等待一分钟
+fn retry_after(response: &reqwest::Response) -> Option<Duration> {
+    response
+        .headers()
+        .get(reqwest::header::RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .map(|secs| Duration::from_secs(secs.clamp(1, 60)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_rate_limiter() {
+        let limiter = RateLimiter::new(2, 20.0);
+        let start = Instant::now();
+
+        // 满桶时的突发请求不等待
+        limiter.acquire().await;
+        limiter.acquire().await;
+        assert!(start.elapsed() < Duration::from_millis(20));
+
+        // 之后按补充速度放行
+        limiter.acquire().await;
+        limiter.acquire().await;
+        assert!(start.elapsed() >= Duration::from_millis(90));
+    }
+}
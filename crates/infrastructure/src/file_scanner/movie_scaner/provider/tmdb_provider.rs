@@ -1,7 +1,9 @@
-use super::{MovieMetadataProvider, MetadataProvider, MovieDetails};
-use super::SearchMetadataResult;
+use super::{CollectionDetails, CollectionPart, MovieCredit, MovieMetadataProvider, MetadataProvider, MovieDetails};
+use super::{SearchMetadataResult, TvDetails, TvEpisodeDetails, TvSearchResult, TvSeasonDetails, TvSeasonSummary};
 use super::super::models::language::Language;
+use super::rate_limit::{RateLimitedClient, RateLimiter};
 use serde::{Deserialize, Serialize};
+use std::sync::{Arc, LazyLock};
 
 /// TMDB API 搜索响应
 #[derive(Debug, Deserialize, Serialize)]
@@ -12,6 +14,13 @@ struct TMDBSearchResponse {
     total_results: u32,
 }
 
+/// TMDB 外部 ID 查找响应（`/find/{external_id}`）
+#[derive(Debug, Deserialize, Serialize)]
+struct TMDBFindResponse {
+    #[serde(default)]
+    movie_results: Vec<TMDBMovie>,
+}
+
 /// TMDB 电影信息
 #[derive(Debug, Deserialize, Serialize, Clone)]
 struct TMDBMovie {
@@ -55,6 +64,7 @@ impl TMDBMovie {
 #[derive(Debug, Deserialize, Serialize)]
 struct TMDBMovieDetails {
     id: u64,
+    imdb_id: Option<String>,
     title: String,
     original_title: String,
     overview: Option<String>,
@@ -69,6 +79,52 @@ struct TMDBMovieDetails {
     genres: Option<Vec<TMDBGenre>>,
     credits: Option<TMDBCredits>,
     keywords: Option<TMDBKeywords>,
+    belongs_to_collection: Option<TMDBCollectionRef>,
+}
+
+/// TMDB 电影详情中的所属系列
+#[derive(Debug, Deserialize, Serialize)]
+struct TMDBCollectionRef {
+    id: u64,
+}
+
+/// TMDB 系列详情响应
+#[derive(Debug, Deserialize, Serialize)]
+struct TMDBCollectionDetails {
+    id: u64,
+    name: String,
+    overview: Option<String>,
+    poster_path: Option<String>,
+    backdrop_path: Option<String>,
+    #[serde(default)]
+    parts: Vec<TMDBMovie>,
+}
+
+impl TMDBCollectionDetails {
+    /// 转换为 CollectionDetails（系列中的电影按发行日期排序，未定档的排在最后）
+    fn to_collection_details(&self) -> CollectionDetails {
+        let mut parts: Vec<CollectionPart> = self
+            .parts
+            .iter()
+            .map(|movie| CollectionPart {
+                tmdb_id: movie.id,
+                title: movie.title.clone(),
+                original_title: movie.original_title.clone(),
+                release_date: movie.release_date.clone().unwrap_or_default(),
+                poster_url: movie.poster_path.as_ref().map(|path| format!("https://image.tmdb.org/t/p/w500{}", path)),
+            })
+            .collect();
+        parts.sort_by(|a, b| (a.release_date.is_empty(), &a.release_date).cmp(&(b.release_date.is_empty(), &b.release_date)));
+
+        CollectionDetails {
+            tmdb_id: self.id,
+            name: self.name.clone(),
+            overview: self.overview.clone().unwrap_or_default(),
+            poster_url: self.poster_path.as_ref().map(|path| format!("https://image.tmdb.org/t/p/w500{}", path)),
+            backdrop_url: self.backdrop_path.as_ref().map(|path| format!("https://image.tmdb.org/t/p/original{}", path)),
+            parts,
+        }
+    }
 }
 
 /// TMDB 类型
@@ -88,17 +144,21 @@ struct TMDBCredits {
 /// TMDB 演员
 #[derive(Debug, Deserialize, Serialize)]
 struct TMDBCast {
+    id: Option<u64>,
     name: String,
     character: Option<String>,
     order: Option<u32>,
+    profile_path: Option<String>,
 }
 
 /// TMDB 工作人员
 #[derive(Debug, Deserialize, Serialize)]
 struct TMDBCrew {
+    id: Option<u64>,
     name: String,
     job: String,
     department: Option<String>,
+    profile_path: Option<String>,
 }
 
 /// TMDB 关键词
@@ -170,6 +230,49 @@ impl TMDBMovieDetails {
             })
             .unwrap_or_default();
 
+        // 演职人员：前 10 位演员，以及导演、编剧、制片人
+        let profile_url = |path: &Option<String>| path.as_ref().map(|p| format!("https://image.tmdb.org/t/p/w185{}", p));
+        let mut credits: Vec<MovieCredit> = self.credits.as_ref()
+            .and_then(|c| c.cast.as_ref())
+            .map(|cast| {
+                cast.iter()
+                    .take(10)
+                    .enumerate()
+                    .map(|(i, actor)| MovieCredit {
+                        tmdb_id: actor.id,
+                        name: actor.name.clone(),
+                        role: "actor".to_string(),
+                        character: actor.character.clone().filter(|c| !c.is_empty()),
+                        order: actor.order.unwrap_or(i as u32),
+                        profile_url: profile_url(&actor.profile_path),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        for (role, jobs) in [
+            ("director", &["Director"][..]),
+            ("writer", &["Writer", "Screenplay", "Story"][..]),
+            ("producer", &["Producer"][..]),
+        ] {
+            let crew = self.credits.as_ref().and_then(|c| c.crew.as_ref()).into_iter().flatten();
+            let mut order = 0;
+            for person in crew.filter(|person| jobs.contains(&person.job.as_str())) {
+                // 同一人担任多个编剧职位时只记录一次
+                if credits.iter().any(|c| c.role == role && c.name == person.name && c.tmdb_id == person.id) {
+                    continue;
+                }
+                credits.push(MovieCredit {
+                    tmdb_id: person.id,
+                    name: person.name.clone(),
+                    role: role.to_string(),
+                    character: None,
+                    order,
+                    profile_url: profile_url(&person.profile_path),
+                });
+                order += 1;
+            }
+        }
+
         // 提取关键词
         let tags = self.keywords.as_ref()
             .and_then(|k| k.keywords.as_ref())
@@ -196,6 +299,7 @@ impl TMDBMovieDetails {
 
         MovieDetails {
             tmdb_id: self.id,
+            imdb_id: self.imdb_id.clone().filter(|id| !id.is_empty()),
             title: self.title.clone(),
             original_title: self.original_title.clone(),
             overview: self.overview.clone().unwrap_or_default(),
@@ -214,20 +318,180 @@ impl TMDBMovieDetails {
             producers,
             keywords: tags,
             poster_urls,
+            collection_id: self.belongs_to_collection.as_ref().map(|c| c.id),
+            credits,
         }
     }
 }
 
+/// TMDB 电视剧搜索响应
+#[derive(Debug, Deserialize, Serialize)]
+struct TMDBTvSearchResponse {
+    results: Vec<TMDBTv>,
+}
+
+/// TMDB 电视剧搜索结果
+#[derive(Debug, Deserialize, Serialize)]
+struct TMDBTv {
+    id: u64,
+    name: String,
+    original_name: Option<String>,
+    overview: Option<String>,
+    first_air_date: Option<String>,
+    poster_path: Option<String>,
+    vote_average: Option<f64>,
+    popularity: Option<f64>,
+}
+
+/// TMDB 电视剧详情响应
+#[derive(Debug, Deserialize, Serialize)]
+struct TMDBTvDetails {
+    id: u64,
+    name: String,
+    original_name: Option<String>,
+    overview: Option<String>,
+    first_air_date: Option<String>,
+    poster_path: Option<String>,
+    backdrop_path: Option<String>,
+    vote_average: Option<f64>,
+    vote_count: Option<u64>,
+    genres: Option<Vec<TMDBGenre>>,
+    credits: Option<TMDBCredits>,
+    seasons: Option<Vec<TMDBTvSeason>>,
+}
+
+/// TMDB 电视剧详情中的季概要
+#[derive(Debug, Deserialize, Serialize)]
+struct TMDBTvSeason {
+    season_number: u32,
+    name: Option<String>,
+    overview: Option<String>,
+    air_date: Option<String>,
+    episode_count: Option<u32>,
+    poster_path: Option<String>,
+}
+
+/// TMDB 季详情响应
+#[derive(Debug, Deserialize, Serialize)]
+struct TMDBSeasonDetails {
+    season_number: u32,
+    name: Option<String>,
+    overview: Option<String>,
+    air_date: Option<String>,
+    poster_path: Option<String>,
+    episodes: Option<Vec<TMDBEpisode>>,
+}
+
+/// TMDB 单集
+#[derive(Debug, Deserialize, Serialize)]
+struct TMDBEpisode {
+    episode_number: u32,
+    name: Option<String>,
+    overview: Option<String>,
+    air_date: Option<String>,
+    vote_average: Option<f64>,
+    still_path: Option<String>,
+}
+
+impl TMDBTvDetails {
+    /// 转换为 TvDetails
+    fn to_tv_details(&self) -> TvDetails {
+        let genres = self.genres.as_ref()
+            .map(|g| g.iter().map(|genre| genre.name.clone()).collect())
+            .unwrap_or_default();
+
+        // 提取演员（前10位）
+        let cast = self.credits.as_ref()
+            .and_then(|c| c.cast.as_ref())
+            .map(|cast| cast.iter().take(10).map(|actor| actor.name.clone()).collect())
+            .unwrap_or_default();
+
+        let mut poster_urls = Vec::new();
+        if let Some(poster_path) = &self.poster_path {
+            poster_urls.push(format!("https://image.tmdb.org/t/p/w500{}", poster_path));
+            poster_urls.push(format!("https://image.tmdb.org/t/p/original{}", poster_path));
+        }
+        if let Some(backdrop_path) = &self.backdrop_path {
+            poster_urls.push(format!("https://image.tmdb.org/t/p/w1280{}", backdrop_path));
+        }
+
+        let seasons = self.seasons.as_ref()
+            .map(|seasons| {
+                seasons.iter()
+                    .map(|season| TvSeasonSummary {
+                        season_number: season.season_number,
+                        name: season.name.clone().unwrap_or_default(),
+                        overview: season.overview.clone().unwrap_or_default(),
+                        air_date: season.air_date.clone().unwrap_or_default(),
+                        episode_count: season.episode_count.unwrap_or(0),
+                        poster_path: season.poster_path.clone(),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        TvDetails {
+            tmdb_id: self.id,
+            name: self.name.clone(),
+            original_name: self.original_name.clone().unwrap_or_default(),
+            overview: self.overview.clone().unwrap_or_default(),
+            first_air_date: self.first_air_date.clone().unwrap_or_default(),
+            vote_average: self.vote_average.unwrap_or(0.0),
+            vote_count: self.vote_count.unwrap_or(0),
+            genres,
+            cast,
+            poster_urls,
+            seasons,
+        }
+    }
+}
+
+impl TMDBSeasonDetails {
+    /// 转换为 TvSeasonDetails
+    fn to_season_details(&self) -> TvSeasonDetails {
+        let episodes = self.episodes.as_ref()
+            .map(|episodes| {
+                episodes.iter()
+                    .map(|episode| TvEpisodeDetails {
+                        episode_number: episode.episode_number,
+                        name: episode.name.clone().unwrap_or_default(),
+                        overview: episode.overview.clone().unwrap_or_default(),
+                        air_date: episode.air_date.clone().unwrap_or_default(),
+                        vote_average: episode.vote_average.unwrap_or(0.0),
+                        still_url: episode.still_path.as_ref()
+                            .map(|path| format!("https://image.tmdb.org/t/p/w300{}", path)),
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        TvSeasonDetails {
+            season_number: self.season_number,
+            name: self.name.clone().unwrap_or_default(),
+            overview: self.overview.clone().unwrap_or_default(),
+            air_date: self.air_date.clone().unwrap_or_default(),
+            poster_url: self.poster_path.as_ref()
+                .map(|path| format!("https://image.tmdb.org/t/p/w500{}", path)),
+            episodes,
+        }
+    }
+}
+
+/// 所有 TMDB 提供者共享的限流器（突发 20 个请求，每秒补充 20 个）
+///
+/// TMDB 的限制约为每秒 50 个请求，这里留出余量给其他客户端
+static TMDB_RATE_LIMITER: LazyLock<Arc<RateLimiter>> = LazyLock::new(|| Arc::new(RateLimiter::new(20, 20.0)));
+
 pub struct TMDBProvider {
     api_key: String,
-    client: reqwest::Client,
+    client: RateLimitedClient,
 }
 
 impl TMDBProvider {
     pub fn new(api_key: String) -> Self {
         Self {
             api_key,
-            client: reqwest::Client::new(),
+            client: RateLimitedClient::new(reqwest::Client::new(), TMDB_RATE_LIMITER.clone()),
         }
     }
 
@@ -253,7 +517,6 @@ impl TMDBProvider {
 
         let response = self.client
             .get(&url)
-            .send()
             .await
             .map_err(|e| format!("请求失败: {}", e))?;
 
@@ -299,7 +562,6 @@ impl TMDBProvider {
 
         let response = self.client
             .get(&url)
-            .send()
             .await
             .map_err(|e| format!("请求失败: {}", e))?;
 
@@ -320,6 +582,141 @@ impl TMDBProvider {
 
         Ok(movie_details.to_movie_details())
     }
+
+    /// 通过 IMDb ID 查找电影（TMDB find 接口）
+    ///
+    /// 找不到对应电影时返回 `Ok(None)`
+    pub async fn find_by_imdb_id(
+        &self,
+        imdb_id: &str,
+        language: Language,
+    ) -> Result<Option<SearchMetadataResult>, String> {
+        let url = format!(
+            "https://api.themoviedb.org/3/find/{}?api_key={}&language={}&external_source=imdb_id",
+            urlencoding::encode(imdb_id),
+            self.api_key,
+            language.code()
+        );
+
+        let find_result: TMDBFindResponse = self.get_json(&url).await?;
+        Ok(find_result.movie_results.first().map(|m| m.to_search_result()))
+    }
+
+    /// 使用指定语言获取电影系列详情（包含系列中的所有电影）
+    pub async fn get_collection_with_language(
+        &self,
+        collection_id: u64,
+        language: Language,
+    ) -> Result<CollectionDetails, String> {
+        let url = format!(
+            "https://api.themoviedb.org/3/collection/{}?api_key={}&language={}",
+            collection_id,
+            self.api_key,
+            language.code()
+        );
+
+        let collection: TMDBCollectionDetails = self.get_json(&url).await?;
+        println!("✅ 获取系列成功: {} ({} 部)", collection.name, collection.parts.len());
+
+        Ok(collection.to_collection_details())
+    }
+
+    /// 请求 TMDB API 并解析 JSON 响应
+    async fn get_json<T: serde::de::DeserializeOwned>(&self, url: &str) -> Result<T, String> {
+        println!("🔍 请求 URL: {}", url);
+
+        let response = self.client
+            .get(url)
+            .await
+            .map_err(|e| format!("请求失败: {}", e))?;
+
+        let status = response.status();
+        println!("📡 响应状态: {}", status);
+
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_else(|_| "无法读取错误信息".to_string());
+            return Err(format!("API 返回错误 {}: {}", status, error_text));
+        }
+
+        response
+            .json()
+            .await
+            .map_err(|e| format!("解析 JSON 失败: {}", e))
+    }
+
+    /// 使用指定语言搜索电视剧
+    pub async fn search_tv_with_language(
+        &self,
+        name: &str,
+        first_air_year: Option<u64>,
+        language: Language,
+    ) -> Result<Vec<TvSearchResult>, String> {
+        let mut url = format!(
+            "https://api.themoviedb.org/3/search/tv?api_key={}&query={}&language={}",
+            self.api_key,
+            urlencoding::encode(name),
+            language.code()
+        );
+
+        if let Some(y) = first_air_year {
+            url.push_str(&format!("&first_air_date_year={}", y));
+        }
+
+        let search_result: TMDBTvSearchResponse = self.get_json(&url).await?;
+        println!("✅ 找到 {} 个结果", search_result.results.len());
+
+        Ok(search_result.results
+            .into_iter()
+            .map(|tv| TvSearchResult {
+                tmdb_id: tv.id,
+                original_name: tv.original_name.unwrap_or_else(|| tv.name.clone()),
+                name: tv.name,
+                overview: tv.overview.unwrap_or_default(),
+                first_air_date: tv.first_air_date.unwrap_or_default(),
+                poster_path: tv.poster_path,
+                vote_average: tv.vote_average.unwrap_or(0.0),
+                popularity: tv.popularity.unwrap_or(0.0),
+            })
+            .collect())
+    }
+
+    /// 使用指定语言获取电视剧详情（包含演员和各季概要）
+    pub async fn get_tv_details_with_language(
+        &self,
+        tmdb_id: u64,
+        language: Language,
+    ) -> Result<TvDetails, String> {
+        let url = format!(
+            "https://api.themoviedb.org/3/tv/{}?api_key={}&language={}&append_to_response=credits",
+            tmdb_id,
+            self.api_key,
+            language.code()
+        );
+
+        let tv_details: TMDBTvDetails = self.get_json(&url).await?;
+        println!("✅ 获取详情成功: {}", tv_details.name);
+
+        Ok(tv_details.to_tv_details())
+    }
+
+    /// 使用指定语言获取电视剧某一季的详情
+    pub async fn get_tv_season_with_language(
+        &self,
+        tmdb_id: u64,
+        season_number: u32,
+        language: Language,
+    ) -> Result<TvSeasonDetails, String> {
+        let url = format!(
+            "https://api.themoviedb.org/3/tv/{}/season/{}?api_key={}&language={}",
+            tmdb_id,
+            season_number,
+            self.api_key,
+            language.code()
+        );
+
+        let season_details: TMDBSeasonDetails = self.get_json(&url).await?;
+        Ok(season_details.to_season_details())
+    }
 }
 
 #[async_trait::async_trait]
@@ -339,7 +736,6 @@ impl MovieMetadataProvider for TMDBProvider {
 
         let response = self.client
             .get(&url)
-            .send()
             .await
             .map_err(|e| format!("请求失败: {}", e))?;
 
@@ -386,7 +782,6 @@ impl MovieMetadataProvider for TMDBProvider {
 
         let response = self.client
             .get(&url)
-            .send()
             .await
             .map_err(|e| format!("请求失败: {}", e))?;
 
@@ -442,6 +837,40 @@ mod tests {
         assert!(!details.directors.is_empty());
         assert!(!details.cast.is_empty());
     }
+
+    #[test]
+    fn test_movie_credits() {
+        let json = r#"{
+            "id": 949, "title": "Heat", "original_title": "Heat",
+            "credits": {
+                "cast": [
+                    {"id": 1158, "name": "Al Pacino", "character": "Vincent Hanna", "order": 0, "profile_path": "/a.jpg"},
+                    {"id": 380, "name": "Robert De Niro", "character": "Neil McCauley", "order": 1}
+                ],
+                "crew": [
+                    {"id": 638, "name": "Michael Mann", "job": "Director", "department": "Directing"},
+                    {"id": 638, "name": "Michael Mann", "job": "Screenplay", "department": "Writing"},
+                    {"id": 638, "name": "Michael Mann", "job": "Writer", "department": "Writing"}
+                ]
+            }
+        }"#;
+        let details: TMDBMovieDetails = serde_json::from_str(json).unwrap();
+        let credits = details.to_movie_details().credits;
+
+        let roles: Vec<_> = credits.iter().map(|c| (c.role.as_str(), c.name.as_str(), c.order)).collect();
+        assert_eq!(
+            roles,
+            [("actor", "Al Pacino", 0), ("actor", "Robert De Niro", 1), ("director", "Michael Mann", 0), ("writer", "Michael Mann", 0)]
+        );
+        assert_eq!(credits[0].character.as_deref(), Some("Vincent Hanna"));
+        assert_eq!(credits[0].profile_url.as_deref(), Some("https://image.tmdb.org/t/p/w185/a.jpg"));
+        assert_eq!(credits[2].tmdb_id, Some(638));
+
+        // NFO 中的姓名沿用同名演员的信息
+        let actors = MovieCredit::for_names("actor", &["Robert De Niro".to_string(), "Val Kilmer".to_string()], &credits);
+        assert_eq!((actors[0].tmdb_id, actors[0].order), (Some(380), 0));
+        assert_eq!((actors[1].tmdb_id, actors[1].order), (None, 1));
+    }
 }
 
 // 实现 MetadataProvider trait
@@ -475,4 +904,46 @@ impl MetadataProvider for TMDBProvider {
     ) -> Result<MovieDetails, String> {
         TMDBProvider::get_details_with_language(self, id, language).await
     }
+
+    async fn find_by_imdb_id(
+        &self,
+        imdb_id: &str,
+        language: Language,
+    ) -> Result<Option<SearchMetadataResult>, String> {
+        TMDBProvider::find_by_imdb_id(self, imdb_id, language).await
+    }
+
+    async fn get_collection_with_language(
+        &self,
+        id: u64,
+        language: Language,
+    ) -> Result<CollectionDetails, String> {
+        TMDBProvider::get_collection_with_language(self, id, language).await
+    }
+
+    async fn search_tv_with_language(
+        &self,
+        name: &str,
+        first_air_year: Option<u64>,
+        language: Language,
+    ) -> Result<Vec<TvSearchResult>, String> {
+        TMDBProvider::search_tv_with_language(self, name, first_air_year, language).await
+    }
+
+    async fn get_tv_details_with_language(
+        &self,
+        id: u64,
+        language: Language,
+    ) -> Result<TvDetails, String> {
+        TMDBProvider::get_tv_details_with_language(self, id, language).await
+    }
+
+    async fn get_tv_season_with_language(
+        &self,
+        id: u64,
+        season_number: u32,
+        language: Language,
+    ) -> Result<TvSeasonDetails, String> {
+        TMDBProvider::get_tv_season_with_language(self, id, season_number, language).await
+    }
 }
@@ -1,9 +1,23 @@
 //! 视频扫描器 - 使用中间件模式支持多数据源
 
+use super::disc::{self, DiscKind};
+use super::extras::{attach_extras, detect_extra, ExtraFile};
 use super::models::language::Language;
 use super::models::scan_mode::{ScanMode, VideoFilter};
-use super::models::video::VideoScanQueryResult;
-use super::provider::MetadataProvider;
+use super::matcher::rank_search_results;
+use super::models::video::{MediaSource, VideoScanQueryResult};
+use super::nfo::{read_movie_nfo, MovieNfo};
+use super::parser::parse_movie_file;
+use super::provider::{CollectionDetails, MetadataProvider, MovieDetails, SearchMetadataResult};
+use super::versions::group_movie_files;
+use std::collections::hash_map::{Entry, HashMap};
+use std::path::{Path, PathBuf};
+
+/// 扫描的视频文件扩展名
+pub const VIDEO_EXTENSIONS: &[&str] = &[
+    "mp4", "avi", "mkv", "mov", "wmv", "flv", "webm", "m4v", "mpg", "mpeg", "3gp", "ts",
+    "mts", "vob", "ogv", "divx",
+];
 
 /// 视频扫描器
 ///
@@ -69,7 +83,7 @@ impl MovieScan {
     /// * `api_key` - TMDB API 密钥
     pub async fn with_tmdb_provider(mut self, api_key: String) -> Self {
         let provider = super::provider::tmdb_provider::TMDBProvider::new(api_key);
-        self.providers.push(Box::new(provider));
+        self.providers.push(Box::new(super::provider::cache::CachedProvider::new(provider)));
         self
     }
 
@@ -82,6 +96,88 @@ impl MovieScan {
         self
     }
 
+    /// 在所有提供者中搜索候选电影，用于手动识别
+    ///
+    /// 返回 `(提供者名称, 搜索结果)` 列表，按提供者优先级排列，同一提供者的结果按匹配程度排序；
+    /// 单个提供者失败时跳过，全部失败才返回错误
+    pub async fn search_candidates(
+        &self,
+        query: &str,
+        year: Option<u32>,
+    ) -> Result<Vec<(String, SearchMetadataResult)>, String> {
+        let mut candidates = Vec::new();
+        let mut errors = Vec::new();
+
+        for provider in &self.providers {
+            match self.search_ranked(provider.as_ref(), query, year).await {
+                Ok(results) => {
+                    candidates.extend(results.into_iter().map(|result| (provider.name().to_string(), result)));
+                }
+                Err(e) => errors.push(format!("{}: {}", provider.name(), e)),
+            }
+        }
+
+        if candidates.is_empty() && !errors.is_empty() {
+            return Err(errors.join("; "));
+        }
+        Ok(candidates)
+    }
+
+    /// 搜索并按标题相似度和年份接近程度排序
+    ///
+    /// 带年份搜索没有结果时（文件名年份可能有误）去掉年份重试
+    async fn search_ranked(
+        &self,
+        provider: &dyn MetadataProvider,
+        title: &str,
+        year: Option<u32>,
+    ) -> Result<Vec<SearchMetadataResult>, String> {
+        let mut results = provider
+            .search_with_language(title, year.map(u64::from), self.language)
+            .await?;
+        if results.is_empty() && year.is_some() {
+            results = provider.search_with_language(title, None, self.language).await?;
+        }
+
+        rank_search_results(title, year, &mut results);
+        Ok(results)
+    }
+
+    /// 按提供者 ID 获取电影详情
+    ///
+    /// `provider` 为 `None` 时使用优先级最高的提供者
+    pub async fn fetch_details(&self, provider: Option<&str>, provider_id: u64) -> Result<MovieDetails, String> {
+        self.find_provider(provider)?
+            .get_details_with_language(provider_id, self.language)
+            .await
+    }
+
+    /// 按提供者 ID 获取电影系列详情
+    ///
+    /// `provider` 为 `None` 时使用优先级最高的提供者
+    pub async fn fetch_collection(
+        &self,
+        provider: Option<&str>,
+        collection_id: u64,
+    ) -> Result<CollectionDetails, String> {
+        self.find_provider(provider)?
+            .get_collection_with_language(collection_id, self.language)
+            .await
+    }
+
+    /// 按名称查找提供者，`None` 时返回优先级最高的提供者
+    fn find_provider(&self, name: Option<&str>) -> Result<&dyn MetadataProvider, String> {
+        let provider = match name {
+            Some(name) => self
+                .providers
+                .iter()
+                .find(|p| p.name().eq_ignore_ascii_case(name))
+                .ok_or_else(|| format!("未知的元数据提供者: {}", name))?,
+            None => self.providers.first().ok_or("没有可用的元数据提供者")?,
+        };
+        Ok(provider.as_ref())
+    }
+
     /// 扫描指定目录中的视频文件并获取元数据
     ///
     /// 按照添加提供者的顺序依次尝试获取元数据，直到成功为止
@@ -92,12 +188,17 @@ impl MovieScan {
     /// # 返回值
     /// 返回 `Result<Vec<VideoScanQueryResult>, String>`，包含扫描到的视频列表（含元数据）或错误信息
     pub async fn scan(self, dir_path: String) -> Result<Vec<VideoScanQueryResult>, String> {
-        // 1. 扫描视频文件
-        let mut video_files = self.scan_video_files(&dir_path)?;
+        // 1. 扫描视频文件，合并同一电影的多个版本和分段，再挂上预告片和花絮
+        let (video_files, extras) = self.scan_video_files(&dir_path)?;
+        let mut video_files = group_movie_files(video_files);
+        attach_extras(&mut video_files, &extras);
 
-        // 2. 如果有提供者，则补充元数据
+        // 2. 读取视频旁的 NFO 文件（NFO 中的元数据优先于在线刮削）
+        let nfos = Self::apply_nfo_files(&mut video_files);
+
+        // 3. 如果有提供者，则补充元数据
         if !self.providers.is_empty() {
-            self.enrich_with_metadata(&mut video_files).await?;
+            self.enrich_with_metadata(&mut video_files, &nfos).await?;
         } else {
             println!("⚠️  未配置元数据提供者，跳过元数据获取");
         }
@@ -105,8 +206,8 @@ impl MovieScan {
         Ok(video_files)
     }
 
-    /// 扫描目录中的视频文件
-    fn scan_video_files(&self, dir_path: &str) -> Result<Vec<VideoScanQueryResult>, String> {
+    /// 扫描目录中的视频文件，预告片和花絮单独返回
+    fn scan_video_files(&self, dir_path: &str) -> Result<(Vec<VideoScanQueryResult>, Vec<ExtraFile>), String> {
         let mode_desc = match &self.scan_mode {
             ScanMode::All => "所有视频".to_string(),
             ScanMode::MoviesOnly { min_file_size } => {
@@ -117,20 +218,24 @@ impl MovieScan {
 
         let mut video_files = Vec::<VideoScanQueryResult>::new();
         let mut filtered_count = 0;
-
-        let video_extensions = [
-            "mp4", "avi", "mkv", "mov", "wmv", "flv", "webm", "m4v", "mpg", "mpeg", "3gp", "ts",
-            "mts", "vob", "ogv", "divx",
-        ];
+        let mut discs: Vec<(PathBuf, DiscKind)> = Vec::new();
+        let mut extras: Vec<ExtraFile> = Vec::new();
 
         for result in ignore::Walk::new(dir_path) {
             match result {
                 Ok(entry) => {
+                    // 光盘结构整体作为一部电影，遍历完成后再选取正片
+                    if let Some(disc) = disc::disc_root(entry.path()) {
+                        if !discs.contains(&disc) {
+                            discs.push(disc);
+                        }
+                        continue;
+                    }
                     if let Some(file_type) = entry.file_type() {
                         if file_type.is_file() {
                             if let Some(extension) = entry.path().extension() {
                                 let ext_str = extension.to_string_lossy().to_lowercase();
-                                if video_extensions.contains(&ext_str.as_str()) {
+                                if VIDEO_EXTENSIONS.contains(&ext_str.as_str()) {
                                     let file_path =
                                         entry.path().to_string_lossy().to_string();
                                     let file_name = entry
@@ -140,6 +245,13 @@ impl MovieScan {
                                         .to_string_lossy()
                                         .to_string();
 
+                                    // 预告片和花絮挂到所属电影下，不作为电影入库
+                                    if let Some(extra) = detect_extra(entry.path()) {
+                                        println!("  🎬 {}: {}", extra.kind.as_str(), file_name);
+                                        extras.push(extra);
+                                        continue;
+                                    }
+
                                     // 获取文件大小
                                     let file_size = entry.metadata().map(|m| m.len()).unwrap_or(0);
 
@@ -163,22 +275,22 @@ impl MovieScan {
                                     if should_include {
                                         // 提取清理后的标题
                                         let clean_title = match &self.scan_mode {
-                                            ScanMode::MoviesOnly { .. } => {
-                                                VideoFilter::extract_movie_title(&file_name)
-                                            }
+                                            ScanMode::MoviesOnly { .. } => None,
                                             ScanMode::All => {
                                                 // 移除扩展名
                                                 if let Some(pos) = file_name.rfind('.') {
-                                                    file_name[..pos].to_string()
+                                                    Some(file_name[..pos].to_string())
                                                 } else {
-                                                    file_name.clone()
+                                                    Some(file_name.clone())
                                                 }
                                             }
                                         };
 
                                         let mut video =
                                             VideoScanQueryResult::new(file_name, file_path);
-                                        video.title = clean_title; // 使用清理后的标题
+                                        // 使用清理后的标题（电影模式使用文件名解析出的标题）
+                                        video.title = clean_title
+                                            .unwrap_or_else(|| video.file_info.title.clone());
                                         video_files.push(video);
                                     }
                                 }
@@ -192,17 +304,92 @@ impl MovieScan {
             }
         }
 
+        video_files.extend(discs.iter().filter_map(|(root, kind)| self.scan_disc(root, *kind)));
+
         if filtered_count > 0 {
             println!("🔍 过滤掉 {} 个非电影文件", filtered_count);
         }
-        println!("✅ 找到 {} 个视频文件", video_files.len());
-        Ok(video_files)
+        println!("✅ 找到 {} 个视频文件，{} 个预告片和花絮", video_files.len(), extras.len());
+        Ok((video_files, extras))
+    }
+
+    /// 扫描光盘结构（`BDMV/`、`VIDEO_TS/`）的正片
+    ///
+    /// 标题和年份从光盘目录名解析；正片由多个流文件组成时作为分段依次播放。
+    /// 光盘结构本身就是电影，不按文件大小和文件名过滤
+    fn scan_disc(&self, root: &Path, kind: DiscKind) -> Option<VideoScanQueryResult> {
+        let Some(streams) = disc::main_title(root, kind) else {
+            println!("  ⚠️  光盘目录中没有找到正片: {}", root.display());
+            return None;
+        };
+        let folder = root.file_name()?.to_string_lossy().to_string();
+        println!("  💿 光盘结构: {} ({} 个流文件)", folder, streams.len());
+
+        let parts: Vec<VideoScanQueryResult> = streams
+            .iter()
+            .map(|path| {
+                let file_name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+                VideoScanQueryResult::new(file_name, path.to_string_lossy().to_string())
+            })
+            .collect();
+
+        // 文件夹名没有扩展名，补一个 `.` 防止被截断
+        let mut file_info = parse_movie_file(&format!("{}.", folder));
+        file_info.source.get_or_insert_with(|| kind.source().to_string());
+        let label = [file_info.edition.as_deref(), file_info.resolution.as_deref(), file_info.source.as_deref()]
+            .into_iter()
+            .flatten()
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        let stacked = parts.len() > 1;
+        let sources = parts
+            .iter()
+            .enumerate()
+            .map(|(i, part)| MediaSource::from_video(part, 0, stacked.then_some(i as u32 + 1), label.clone()))
+            .collect();
+
+        let mut video = parts[0].clone();
+        video.title = match &self.scan_mode {
+            ScanMode::All => folder,
+            ScanMode::MoviesOnly { .. } => file_info.title.clone(),
+        };
+        video.duration = parts.iter().map(|p| p.duration).sum();
+        video.byte_size = parts.iter().map(|p| p.byte_size).sum();
+        video.file_info = file_info;
+        video.sources = sources;
+        Some(video)
+    }
+
+    /// 读取每个视频的 NFO 文件并用其中的元数据覆盖扫描结果
+    ///
+    /// 返回与 `video_files` 一一对应的 NFO 列表，用于引导后续的在线刮削
+    fn apply_nfo_files(video_files: &mut [VideoScanQueryResult]) -> Vec<Option<MovieNfo>> {
+        video_files
+            .iter_mut()
+            .map(|video| {
+                // 文件名或文件夹名中指定的 IMDb ID 随电影保存，NFO 中有时以 NFO 为准
+                video.imdb_id = video.file_info.imdb_id.clone();
+                let nfo = read_movie_nfo(Path::new(&video.path))?;
+                println!("  📄 读取 NFO: {}", video.path);
+                nfo.apply_to(video);
+                Some(nfo)
+            })
+            .collect()
     }
 
     /// 使用配置的提供者补充元数据
+    ///
+    /// - NFO 包含完整元数据（标题和简介）时直接使用，不再在线刮削
+    /// - 文件名、文件夹名（`{tmdb-949}`、`[imdbid-tt0113277]`）或 NFO 中有 ID 时跳过搜索，
+    ///   直接按 ID 获取详情，IMDb ID 先通过提供者换成 TMDB ID
+    /// - 否则使用 NFO 中的标题和年份（没有 NFO 时使用文件名）搜索
+    ///
+    /// 获取到在线详情后，NFO 中的非空字段仍然优先
     async fn enrich_with_metadata(
         &self,
         video_files: &mut [VideoScanQueryResult],
+        nfos: &[Option<MovieNfo>],
     ) -> Result<(), String> {
         println!(
             "\n📡 开始从 {} 个数据源获取元数据（语言: {}）...",
@@ -210,9 +397,26 @@ impl MovieScan {
             self.language.display_name()
         );
 
-        for video in video_files.iter_mut() {
+        // 同一系列的电影只请求一次系列详情
+        let mut collections: HashMap<u64, Option<CollectionDetails>> = HashMap::new();
+
+        for (video, nfo) in video_files.iter_mut().zip(nfos) {
+            if nfo.as_ref().is_some_and(|nfo| nfo.has_metadata()) {
+                println!("\n📄 使用 NFO 元数据: {}", video.title);
+                continue;
+            }
             println!("\n🔍 搜索: {}", video.title);
 
+            // 文件名和文件夹名中指定的 ID 优先，其次是 NFO 中的
+            let forced_tmdb_id = video.file_info.tmdb_id.or(nfo.as_ref().and_then(|nfo| nfo.tmdb_id));
+            let forced_imdb_id = video
+                .file_info
+                .imdb_id
+                .clone()
+                .or_else(|| nfo.as_ref().and_then(|nfo| nfo.imdb_id.clone()));
+            // NFO 中的年份优先，其次是文件名中的年份
+            let year = nfo.as_ref().and_then(|nfo| nfo.year).or(video.file_info.year);
+
             // 按优先级依次尝试每个提供者
             let mut found = false;
             for (index, provider) in self.providers.iter().enumerate() {
@@ -222,55 +426,99 @@ impl MovieScan {
                     provider.name()
                 );
 
-                match provider
-                    .search_with_language(&video.title, None, self.language)
-                    .await
-                {
-                    Ok(results) if !results.is_empty() => {
-                        println!("  ✅ 找到 {} 个结果", results.len());
+                let tmdb_id = match (forced_tmdb_id, &forced_imdb_id) {
+                    (Some(tmdb_id), _) => {
+                        println!("  📌 使用指定的 TMDB ID: {}", tmdb_id);
+                        tmdb_id
+                    }
+                    (None, Some(imdb_id)) => match provider.find_by_imdb_id(imdb_id, self.language).await {
+                        Ok(Some(result)) => {
+                            println!(
+                                "  📌 IMDb ID {} 对应: {} ({})",
+                                imdb_id, result.title, result.release_date
+                            );
+                            result.tmdb_id
+                        }
+                        Ok(None) => {
+                            println!("  ⚠️  未找到 IMDb ID {} 对应的电影", imdb_id);
+                            continue;
+                        }
+                        Err(e) => {
+                            println!("  ⚠️  IMDb ID 查找失败: {}", e);
+                            continue;
+                        }
+                    },
+                    (None, None) => match self.search_ranked(provider.as_ref(), &video.title, year).await {
+                        Ok(results) if !results.is_empty() => {
+                            println!("  ✅ 找到 {} 个结果", results.len());
 
-                        // 使用第一个匹配结果
-                        if let Some(first_result) = results.first() {
+                            // 使用标题和年份最匹配的结果
+                            let best = &results[0];
                             println!(
                                 "    ✅ 找到匹配: {} ({})",
-                                first_result.title, first_result.release_date
+                                best.title, best.release_date
                             );
-                            println!("    📡 获取详细信息...");
-
-                            // 获取详细信息
-                            match provider
-                                .get_details_with_language(first_result.tmdb_id, self.language)
-                                .await
-                            {
-                                Ok(details) => {
-                                    // 填充视频详情
-                                    video.description = details.overview;
-                                    video.release_date = details.release_date;
-                                    video.rating = details.vote_average as f32;
-                                    video.votes = details.vote_count as u32;
-                                    video.genres = details.genres;
-                                    video.actors = details.cast;
-                                    video.directors = details.directors;
-                                    video.writers = details.writers;
-                                    video.producers = details.producers;
-                                    video.tags = details.keywords;
-                                    video.poster_urls = details.poster_urls;
-
-                                    println!("    ✅ 详细信息获取成功");
-                                    found = true;
-                                    break; // 成功获取，跳出提供者循环
-                                }
-                                Err(e) => {
-                                    println!("    ⚠️  获取详情失败: {}", e);
-                                }
+                            best.tmdb_id
+                        }
+                        Ok(_) => {
+                            println!("  ⚠️  未找到匹配结果");
+                            continue;
+                        }
+                        Err(e) => {
+                            println!("  ⚠️  搜索失败: {}", e);
+                            continue;
+                        }
+                    },
+                };
+
+                // 获取详细信息
+                println!("    📡 获取详细信息...");
+                match provider.get_details_with_language(tmdb_id, self.language).await {
+                    Ok(details) => {
+                        if let Some(collection_id) = details.collection_id {
+                            if let Entry::Vacant(entry) = collections.entry(collection_id) {
+                                let collection = provider
+                                    .get_collection_with_language(collection_id, self.language)
+                                    .await
+                                    .inspect_err(|e| println!("    ⚠️  获取系列失败: {}", e))
+                                    .ok();
+                                entry.insert(collection);
                             }
+                            video.collection = collections[&collection_id].clone();
                         }
-                    }
-                    Ok(_) => {
-                        println!("  ⚠️  未找到匹配结果");
+
+                        // 填充视频详情
+                        video.original_title = Some(details.original_title)
+                            .filter(|t| !t.is_empty() && *t != details.title);
+                        video.description = details.overview;
+                        video.release_date = details.release_date;
+                        video.rating = details.vote_average as f32;
+                        video.votes = details.vote_count as u32;
+                        video.genres = details.genres;
+                        video.actors = details.cast;
+                        video.directors = details.directors;
+                        video.writers = details.writers;
+                        video.producers = details.producers;
+                        video.credits = details.credits;
+                        video.tags = details.keywords;
+                        video.poster_urls = details.poster_urls;
+                        video.backdrop_url = details
+                            .backdrop_path
+                            .map(|path| format!("https://image.tmdb.org/t/p/original{}", path));
+                        video.tmdb_id = Some(details.tmdb_id);
+                        video.imdb_id = video.imdb_id.take().or(details.imdb_id);
+
+                        // NFO 中用户整理过的字段优先
+                        if let Some(nfo) = nfo {
+                            nfo.apply_to(video);
+                        }
+
+                        println!("    ✅ 详细信息获取成功");
+                        found = true;
+                        break; // 成功获取，跳出提供者循环
                     }
                     Err(e) => {
-                        println!("  ⚠️  搜索失败: {}", e);
+                        println!("    ⚠️  获取详情失败: {}", e);
                     }
                 }
             }
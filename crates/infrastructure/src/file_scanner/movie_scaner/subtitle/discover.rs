@@ -0,0 +1,266 @@
+//! 外挂字幕发现
+
+use super::super::scanner::VIDEO_EXTENSIONS;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 存放字幕的子文件夹名称（小写比较）
+const SUBTITLE_DIRS: &[&str] = &["subs", "sub", "subtitles", "subtitle", "字幕"];
+
+/// 外挂字幕格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubtitleFormat {
+    Srt,
+    Ass,
+    Ssa,
+    Vtt,
+}
+
+impl SubtitleFormat {
+    /// 根据扩展名识别字幕格式
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension.to_lowercase().as_str() {
+            "srt" => Some(SubtitleFormat::Srt),
+            "ass" => Some(SubtitleFormat::Ass),
+            "ssa" => Some(SubtitleFormat::Ssa),
+            "vtt" => Some(SubtitleFormat::Vtt),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SubtitleFormat::Srt => "srt",
+            SubtitleFormat::Ass => "ass",
+            SubtitleFormat::Ssa => "ssa",
+            SubtitleFormat::Vtt => "vtt",
+        }
+    }
+}
+
+/// 外挂字幕文件
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExternalSubtitle {
+    /// 字幕文件路径
+    pub path: String,
+    /// 字幕格式
+    pub format: SubtitleFormat,
+    /// 语言（BCP 47，如 `zh-Hans`、`en`）
+    pub language: Option<String>,
+    /// 标题（文件名中视频名之后的部分，如 `chs&eng`、`English`）
+    pub title: Option<String>,
+    /// 是否为默认字幕
+    pub is_default: bool,
+    /// 是否为强制字幕（只翻译外语对白和标牌）
+    pub is_forced: bool,
+    /// 是否为听障字幕（SDH / CC）
+    pub is_sdh: bool,
+}
+
+/// 从文件名标记中识别语言
+///
+/// 支持 ISO 639-1 / 639-2 代码、英文名称和常见的中文字幕组写法（chs / cht / 简体 / 繁体 / 简英）
+fn parse_language(token: &str) -> Option<&'static str> {
+    let language = match token.to_lowercase().as_str() {
+        "chs" | "sc" | "gb" | "zh-cn" | "zh-hans" | "zh_cn" | "简" | "简体" | "简中" | "简体中文" | "简英" | "简日" => "zh-Hans",
+        "cht" | "tc" | "big5" | "zh-tw" | "zh-hk" | "zh-hant" | "zh_tw" | "繁" | "繁体" | "繁體" | "繁中" | "繁体中文" | "繁英" | "繁日" => "zh-Hant",
+        "zh" | "chi" | "zho" | "chn" | "chinese" | "中" | "中文" | "中字" | "中英" | "中日" => "zh",
+        "en" | "eng" | "english" | "英" | "英文" | "英语" => "en",
+        "ja" | "jpn" | "jp" | "japanese" | "日" | "日文" | "日语" => "ja",
+        "ko" | "kor" | "korean" | "韩" | "韩文" | "韩语" => "ko",
+        "fr" | "fre" | "fra" | "french" => "fr",
+        "de" | "ger" | "deu" | "german" => "de",
+        "es" | "spa" | "spanish" => "es",
+        "it" | "ita" | "italian" => "it",
+        "pt" | "por" | "portuguese" => "pt",
+        "ru" | "rus" | "russian" => "ru",
+        _ => return None,
+    };
+    Some(language)
+}
+
+/// 解析文件名中视频名之后的标记（如 `zh.forced`、`2_English`、`chs&eng.default`）
+fn parse_tags(path: &Path, format: SubtitleFormat, tags: &str) -> ExternalSubtitle {
+    let mut subtitle = ExternalSubtitle {
+        path: path.to_string_lossy().to_string(),
+        format,
+        language: None,
+        title: Some(tags.to_string()).filter(|t| !t.is_empty()),
+        is_default: false,
+        is_forced: false,
+        is_sdh: false,
+    };
+
+    for token in tags.split(['.', ' ']) {
+        // RARBG 等发布组的 `2_English` 写法
+        let token = token.trim_start_matches(|c: char| c.is_ascii_digit()).trim_start_matches('_');
+        match token.to_lowercase().as_str() {
+            "forced" | "强制" => subtitle.is_forced = true,
+            "default" | "默认" => subtitle.is_default = true,
+            "sdh" | "cc" | "hi" => subtitle.is_sdh = true,
+            _ => {
+                if subtitle.language.is_none() {
+                    // 双语字幕（chs&eng、简英）取第一种语言
+                    subtitle.language = token
+                        .split(['&', '+', '_'])
+                        .find_map(parse_language)
+                        .map(|language| language.to_string());
+                }
+            }
+        }
+    }
+
+    subtitle
+}
+
+/// 列出目录中的字幕文件，返回 `(路径, 格式, 文件名主干)`
+fn list_subtitles(dir: &Path) -> Vec<(PathBuf, SubtitleFormat, String)> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+
+    let mut files: Vec<_> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .filter_map(|path| {
+            let format = SubtitleFormat::from_extension(&path.extension()?.to_string_lossy())?;
+            let stem = path.file_stem()?.to_string_lossy().to_string();
+            Some((path, format, stem))
+        })
+        .collect();
+    files.sort_by(|a, b| a.0.cmp(&b.0));
+    files
+}
+
+/// 如果 `name` 以视频名开头（不区分大小写），返回之后的标记部分
+fn strip_video_stem<'a>(name: &'a str, video_stem: &str) -> Option<&'a str> {
+    let prefix = name.get(..video_stem.len())?;
+    if !prefix.eq_ignore_ascii_case(video_stem) {
+        return None;
+    }
+    let rest = &name[video_stem.len()..];
+    if rest.is_empty() {
+        Some(rest)
+    } else {
+        rest.strip_prefix('.')
+    }
+}
+
+/// 目录中是否只有一个视频文件（此时 `Subs/` 中的字幕和 `movie.nfo` 都属于这个视频）
+pub(crate) fn is_only_video(dir: &Path) -> bool {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return false;
+    };
+    let count = entries
+        .flatten()
+        .filter(|entry| {
+            entry
+                .path()
+                .extension()
+                .is_some_and(|ext| VIDEO_EXTENSIONS.contains(&ext.to_string_lossy().to_lowercase().as_str()))
+        })
+        .count();
+    count == 1
+}
+
+/// 查找视频文件的外挂字幕
+///
+/// 查找规则：
+/// - 同目录下以视频名开头的字幕：`Movie.srt`、`Movie.zh.srt`、`Movie.en.forced.ass`
+/// - `Subs/`、`Subtitles/`、`字幕/` 文件夹中以视频名开头的字幕
+/// - 目录中只有这一个视频时，`Subs/` 中的所有字幕（如 `Subs/2_English.srt`）
+/// - `Subs/<视频名>/` 文件夹中的所有字幕（剧集发布的常见结构）
+///
+/// # 参数
+/// - `video_path`: 视频文件路径
+///
+/// # 返回
+/// - `Vec<ExternalSubtitle>` - 外挂字幕列表（按路径排序）
+pub fn find_external_subtitles(video_path: &Path) -> Vec<ExternalSubtitle> {
+    let (Some(dir), Some(video_stem)) = (video_path.parent(), video_path.file_stem()) else {
+        return Vec::new();
+    };
+    let video_stem = video_stem.to_string_lossy();
+    let mut subtitles = Vec::new();
+
+    for (path, format, stem) in list_subtitles(dir) {
+        if let Some(tags) = strip_video_stem(&stem, &video_stem) {
+            subtitles.push(parse_tags(&path, format, tags));
+        }
+    }
+
+    let Ok(entries) = fs::read_dir(dir) else {
+        return subtitles;
+    };
+    let mut subtitle_dirs: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_dir())
+        .filter(|path| {
+            path.file_name()
+                .is_some_and(|name| SUBTITLE_DIRS.contains(&name.to_string_lossy().to_lowercase().as_str()))
+        })
+        .collect();
+    subtitle_dirs.sort();
+
+    let only_video = is_only_video(dir);
+    for subtitle_dir in subtitle_dirs {
+        for (path, format, stem) in list_subtitles(&subtitle_dir) {
+            if let Some(tags) = strip_video_stem(&stem, &video_stem) {
+                subtitles.push(parse_tags(&path, format, tags));
+            } else if only_video {
+                subtitles.push(parse_tags(&path, format, &stem));
+            }
+        }
+
+        for (path, format, stem) in list_subtitles(&subtitle_dir.join(video_stem.as_ref())) {
+            subtitles.push(parse_tags(&path, format, &stem));
+        }
+    }
+
+    subtitles
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_find_external_subtitles() {
+        let dir = std::env::temp_dir().join(format!("modream_subtitles_{}", std::process::id()));
+        let subs = dir.join("Subs");
+        fs::create_dir_all(&subs).unwrap();
+        for name in [
+            "Movie (2020).mkv",
+            "Movie (2020).srt",
+            "Movie (2020).chs&eng.ass",
+            "Movie (2020).en.forced.srt",
+            "Other.zh.srt",
+            "Subs/2_English.srt",
+            "Subs/Movie (2020).cht.sdh.srt",
+        ] {
+            fs::write(dir.join(name), b"").unwrap();
+        }
+
+        let subtitles = find_external_subtitles(&dir.join("Movie (2020).mkv"));
+        fs::remove_dir_all(&dir).unwrap();
+
+        let summary: Vec<_> = subtitles
+            .iter()
+            .map(|s| (s.format, s.language.as_deref(), s.is_forced, s.is_sdh))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (SubtitleFormat::Ass, Some("zh-Hans"), false, false),
+                (SubtitleFormat::Srt, Some("en"), true, false),
+                (SubtitleFormat::Srt, None, false, false),
+                (SubtitleFormat::Srt, Some("en"), false, false),
+                (SubtitleFormat::Srt, Some("zh-Hant"), false, true),
+            ]
+        );
+        assert_eq!(subtitles[0].title.as_deref(), Some("chs&eng"));
+        assert_eq!(subtitles[2].title, None);
+    }
+}
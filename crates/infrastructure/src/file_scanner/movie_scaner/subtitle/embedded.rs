@@ -0,0 +1,155 @@
+//! MKV 内嵌文本字幕提取
+
+use super::super::container::{self, SubtitleTrackData};
+use super::webvtt::{clean_ass_text, clean_srt_text, write_vtt, Cue};
+use std::io;
+use std::path::Path;
+
+/// 没有持续时间的字幕块，且之后没有其他字幕块时的显示时长（毫秒）
+const DEFAULT_CUE_DURATION: u64 = 5_000;
+
+/// 内嵌字幕的文本格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TextCodec {
+    /// `S_TEXT/UTF8`：块内容为 SRT 的字幕文本（不含序号和时间）
+    Srt,
+    /// `S_TEXT/ASS` / `S_TEXT/SSA`：块内容为 `ReadOrder,Layer,Style,Name,MarginL,MarginR,MarginV,Effect,Text`
+    Ass,
+    /// `S_TEXT/WEBVTT`：块内容为 WebVTT 字幕文本
+    WebVtt,
+}
+
+impl TextCodec {
+    fn from_codec_id(codec_id: &str) -> Option<Self> {
+        match codec_id {
+            "S_TEXT/UTF8" | "S_TEXT/ASCII" => Some(TextCodec::Srt),
+            "S_TEXT/ASS" | "S_ASS" | "S_TEXT/SSA" | "S_SSA" => Some(TextCodec::Ass),
+            "S_TEXT/WEBVTT" => Some(TextCodec::WebVtt),
+            _ => None,
+        }
+    }
+}
+
+/// 轨道编码（探测时记录的编码名称）是否为可以转换的文本字幕
+///
+/// PGS、VobSub 等图形字幕需要 OCR，无法转换为 WebVTT
+pub fn is_text_subtitle_codec(codec: &str) -> bool {
+    matches!(codec, "subrip" | "ass" | "ssa" | "webvtt")
+}
+
+/// 将一个字幕块的内容转换为 WebVTT 字幕文本
+fn block_text(codec: TextCodec, data: &[u8]) -> String {
+    let text = String::from_utf8_lossy(data);
+    let text = text.trim_end_matches(char::from(0)).replace("\r\n", "\n");
+    match codec {
+        TextCodec::Srt => text
+            .lines()
+            .map(|line| clean_srt_text(line.trim_end()))
+            .filter(|line| !line.trim().is_empty())
+            .collect::<Vec<_>>()
+            .join("\n"),
+        // Text 是最后一个字段，本身可能包含逗号
+        TextCodec::Ass => clean_ass_text(text.splitn(9, ',').nth(8).unwrap_or("")),
+        TextCodec::WebVtt => text.lines().filter(|line| !line.trim().is_empty()).collect::<Vec<_>>().join("\n"),
+    }
+}
+
+/// 将提取的字幕轨道转换为 WebVTT
+///
+/// 字幕块没有持续时间时（SimpleBlock），显示到下一条字幕开始
+///
+/// # 返回
+/// - `Some(String)` - WebVTT 文本
+/// - `None` - 不是文本字幕
+fn track_to_vtt(track: &SubtitleTrackData) -> Option<String> {
+    let codec = TextCodec::from_codec_id(&track.codec_id)?;
+
+    let mut blocks: Vec<_> = track.blocks.iter().collect();
+    blocks.sort_by_key(|block| block.start);
+
+    let mut cues = Vec::new();
+    for (i, block) in blocks.iter().enumerate() {
+        let end = match block.duration {
+            Some(duration) => block.start + duration,
+            None => blocks[i + 1..]
+                .iter()
+                .map(|next| next.start)
+                .find(|start| *start > block.start)
+                .unwrap_or(block.start + DEFAULT_CUE_DURATION),
+        };
+
+        let text = block_text(codec, &block.data);
+        if !text.is_empty() && end > block.start {
+            cues.push(Cue { start: block.start, end, text });
+        }
+    }
+
+    Some(write_vtt(&cues))
+}
+
+/// 提取 MKV 内嵌的文本字幕轨道并转换为 WebVTT
+///
+/// 需要扫描整个视频文件，调用方应缓存结果
+///
+/// # 参数
+/// - `video_path`: 视频文件路径
+/// - `track_number`: 字幕轨道号（探测轨道时记录的 `TrackInfo::index`）
+///
+/// # 返回
+/// - `Ok(String)` - WebVTT 文本
+/// - `Err` - 读取失败、没有该轨道或不是文本字幕
+pub fn extract_embedded_subtitle(video_path: &Path, track_number: u32) -> io::Result<String> {
+    let track = container::extract_subtitle_track(video_path, track_number)?.ok_or_else(|| {
+        io::Error::new(io::ErrorKind::NotFound, format!("subtitle track {} not found", track_number))
+    })?;
+
+    track_to_vtt(&track).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::Unsupported,
+            format!("unsupported subtitle codec: {}", track.codec_id),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use super::super::super::container::SubtitleBlock;
+
+    fn block(start: u64, duration: Option<u64>, text: &str) -> SubtitleBlock {
+        SubtitleBlock { start, duration, data: text.as_bytes().to_vec() }
+    }
+
+    #[test]
+    fn test_ass_track_to_vtt() {
+        let track = SubtitleTrackData {
+            codec_id: "S_TEXT/ASS".to_string(),
+            codec_private: b"[Script Info]\nScriptType: v4.00+\n".to_vec(),
+            blocks: vec![
+                block(3_000, Some(1_000), "1,0,Default,,0,0,0,,第二句, 带逗号"),
+                block(1_000, Some(1_500), "0,0,Default,,0,0,0,,{\\an8}第一句\\N换行"),
+                block(2_000, Some(500), "2,0,Sign,,0,0,0,,{\\p1}m 0 0 l 100 0{\\p0}"),
+            ],
+        };
+        assert_eq!(
+            track_to_vtt(&track).unwrap(),
+            "WEBVTT\n\n00:00:01.000 --> 00:00:02.500\n第一句\n换行\n\n00:00:03.000 --> 00:00:04.000\n第二句, 带逗号\n\n"
+        );
+    }
+
+    #[test]
+    fn test_srt_track_without_duration() {
+        let track = SubtitleTrackData {
+            codec_id: "S_TEXT/UTF8".to_string(),
+            codec_private: Vec::new(),
+            blocks: vec![block(1_000, None, "<font color=\"red\">你好</font>\r\n<i>世界</i>"), block(2_500, None, "最后")],
+        };
+        assert_eq!(
+            track_to_vtt(&track).unwrap(),
+            "WEBVTT\n\n00:00:01.000 --> 00:00:02.500\n你好\n<i>世界</i>\n\n00:00:02.500 --> 00:00:07.500\n最后\n\n"
+        );
+
+        let pgs = SubtitleTrackData { codec_id: "S_HDMV/PGS".to_string(), ..Default::default() };
+        assert!(track_to_vtt(&pgs).is_none());
+    }
+}
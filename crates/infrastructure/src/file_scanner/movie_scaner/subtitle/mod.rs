@@ -0,0 +1,14 @@
+//! 字幕模块
+//!
+//! - 发现视频旁的外挂字幕（`Movie.zh.srt`、`Movie.en.forced.ass`、`Subs/` 文件夹）
+//! - 将 SRT / ASS / SSA 转换为浏览器 `<track>` 元素使用的 WebVTT
+//! - 提取 MKV 内嵌的文本字幕轨道并转换为 WebVTT
+
+mod discover;
+mod embedded;
+mod webvtt;
+
+pub use discover::{find_external_subtitles, ExternalSubtitle, SubtitleFormat};
+pub(crate) use discover::is_only_video;
+pub use embedded::{extract_embedded_subtitle, is_text_subtitle_codec};
+pub use webvtt::{ass_to_vtt, decode_subtitle, srt_to_vtt, to_webvtt};
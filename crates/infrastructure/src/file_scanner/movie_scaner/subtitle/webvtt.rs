@@ -0,0 +1,294 @@
+//! 字幕转 WebVTT
+
+use super::discover::SubtitleFormat;
+use chardetng::EncodingDetector;
+use encoding_rs::{Encoding, UTF_16BE, UTF_16LE};
+
+/// 解码字幕文件内容
+///
+/// 中文字幕常见 GBK / GB18030、Big5 和不带 BOM 的 UTF-16 编码：
+/// 1. 有 BOM 时按 BOM 解码
+/// 2. 合法的 UTF-8 直接使用
+/// 3. 大量 0 字节时按 UTF-16 解码（根据 0 字节出现在奇数位还是偶数位判断字节序）
+/// 4. 其他情况使用 chardetng 猜测编码（优先考虑中文编码）
+pub fn decode_subtitle(bytes: &[u8]) -> String {
+    if let Some((encoding, bom_len)) = Encoding::for_bom(bytes) {
+        return encoding.decode_without_bom_handling(&bytes[bom_len..]).0.into_owned();
+    }
+
+    if let Ok(text) = std::str::from_utf8(bytes) {
+        return text.to_string();
+    }
+
+    let sample = &bytes[..bytes.len().min(4096)];
+    let even_zeros = sample.iter().step_by(2).filter(|b| **b == 0).count();
+    let odd_zeros = sample.iter().skip(1).step_by(2).filter(|b| **b == 0).count();
+    if odd_zeros > sample.len() / 4 && odd_zeros > even_zeros {
+        return UTF_16LE.decode_without_bom_handling(bytes).0.into_owned();
+    }
+    if even_zeros > sample.len() / 4 {
+        return UTF_16BE.decode_without_bom_handling(bytes).0.into_owned();
+    }
+
+    let mut detector = EncodingDetector::new();
+    detector.feed(bytes, true);
+    let encoding = detector.guess(Some(b"cn"), true);
+    encoding.decode_without_bom_handling(bytes).0.into_owned()
+}
+
+/// 格式化 WebVTT 时间戳（`HH:MM:SS.mmm`）
+fn format_timestamp(millis: u64) -> String {
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        millis / 3_600_000,
+        millis / 60_000 % 60,
+        millis / 1000 % 60,
+        millis % 1000
+    )
+}
+
+/// 解析 `H:MM:SS,mmm` / `H:MM:SS.cc` 格式的时间戳（毫秒）
+///
+/// 小数部分按位数换算，SRT 的 3 位毫秒和 ASS 的 2 位厘秒都能正确处理
+fn parse_timestamp(value: &str) -> Option<u64> {
+    let value = value.trim().replace(',', ".");
+    let (clock, fraction) = value.split_once('.').unwrap_or((&value, "0"));
+
+    let mut parts = clock.split(':').rev();
+    let seconds: u64 = parts.next()?.trim().parse().ok()?;
+    let minutes: u64 = parts.next().map_or(Some(0), |m| m.trim().parse().ok())?;
+    let hours: u64 = parts.next().map_or(Some(0), |h| h.trim().parse().ok())?;
+
+    let digits: String = fraction.chars().take_while(|c| c.is_ascii_digit()).take(3).collect();
+    let millis = if digits.is_empty() {
+        0
+    } else {
+        digits.parse::<u64>().ok()? * 10u64.pow(3 - digits.len() as u32)
+    };
+
+    Some(((hours * 60 + minutes) * 60 + seconds) * 1000 + millis)
+}
+
+/// WebVTT 中 `-->` 会被误认为时间行，`&` 和 `<` 需要转义
+fn escape_text(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace("-->", "--&gt;")
+}
+
+/// 一条字幕
+pub(super) struct Cue {
+    pub(super) start: u64,
+    pub(super) end: u64,
+    pub(super) text: String,
+}
+
+pub(super) fn write_vtt(cues: &[Cue]) -> String {
+    let mut vtt = String::from("WEBVTT\n\n");
+    for cue in cues {
+        vtt.push_str(&format!("{} --> {}\n{}\n\n", format_timestamp(cue.start), format_timestamp(cue.end), cue.text));
+    }
+    vtt
+}
+
+/// 去掉 SRT 文本中 WebVTT 不支持的标签（`<font>` 和字幕组常用的 `{\an8}` 等 ASS 标签），
+/// 保留 `<i>`、`<b>`、`<u>`，其余文本按 WebVTT 转义
+///
+/// `<` 后面不是字母或 `/`、或者没有闭合时按普通文本处理（例如 `a < b`）
+pub(super) fn clean_srt_text(line: &str) -> String {
+    let mut text = String::new();
+    let mut rest = line;
+    while let Some(pos) = rest.find(['<', '{']) {
+        let (open, close) = if rest[pos..].starts_with('<') { ('<', '>') } else { ('{', '}') };
+        let is_tag_start = open == '{' || rest[pos + 1..].starts_with(|c: char| c.is_ascii_alphabetic() || c == '/');
+        let end = rest[pos..].find(close).filter(|_| is_tag_start);
+        let Some(end) = end else {
+            text.push_str(&escape_text(&rest[..=pos]));
+            rest = &rest[pos + 1..];
+            continue;
+        };
+
+        text.push_str(&escape_text(&rest[..pos]));
+        let tag = &rest[pos..pos + end + 1];
+        let name = tag.trim_start_matches(['<', '/']).to_lowercase();
+        if open == '<' && ["i>", "b>", "u>"].contains(&name.as_str()) {
+            text.push_str(tag);
+        }
+        rest = &rest[pos + end + 1..];
+    }
+    text.push_str(&escape_text(rest));
+    text
+}
+
+/// SRT 转 WebVTT
+pub fn srt_to_vtt(text: &str) -> String {
+    let text = text.trim_start_matches('\u{FEFF}').replace("\r\n", "\n").replace('\r', "\n");
+    let mut cues = Vec::new();
+    let mut lines = text.lines().peekable();
+
+    while let Some(line) = lines.next() {
+        let Some((start, end)) = line.split_once("-->") else {
+            continue;
+        };
+        // 结束时间之后可能还有坐标（X1:... X2:...）
+        let end = end.split_whitespace().next().unwrap_or("");
+        let (Some(start), Some(end)) = (parse_timestamp(start), parse_timestamp(end)) else {
+            continue;
+        };
+
+        let mut body = Vec::new();
+        while let Some(line) = lines.peek() {
+            if line.trim().is_empty() {
+                break;
+            }
+            body.push(clean_srt_text(line.trim_end()));
+            lines.next();
+        }
+        cues.push(Cue { start, end, text: body.join("\n") });
+    }
+
+    write_vtt(&cues)
+}
+
+/// 去掉 ASS 文本中的样式覆盖标签（`{\pos(…)}` 等）和绘图指令（`{\p1}` 到 `{\p0}` 之间），
+/// 转换换行和硬空格
+pub(super) fn clean_ass_text(text: &str) -> String {
+    let mut plain = String::new();
+    let mut rest = text;
+    let mut drawing = false;
+    while let Some(start) = rest.find('{') {
+        if !drawing {
+            plain.push_str(&rest[..start]);
+        }
+        let Some(end) = rest[start..].find('}') else {
+            rest = &rest[start..];
+            break;
+        };
+
+        // \p<n>：n 不为 0 时进入绘图模式
+        let block = &rest[start + 1..start + end];
+        for tag in block.split('\\') {
+            if let Some(scale) = tag.strip_prefix('p').and_then(|n| n.trim().parse::<u32>().ok()) {
+                drawing = scale != 0;
+            }
+        }
+        rest = &rest[start + end + 1..];
+    }
+    if !drawing {
+        plain.push_str(rest);
+    }
+    let plain = plain.replace("\\N", "\n").replace("\\n", "\n").replace("\\h", " ");
+    plain.lines().map(|line| escape_text(line.trim())).filter(|line| !line.is_empty()).collect::<Vec<_>>().join("\n")
+}
+
+/// ASS / SSA 转 WebVTT
+///
+/// 只保留 `[Events]` 中 `Dialogue` 的时间和文本，样式和特效会被丢弃，
+/// 字幕按开始时间排序（ASS 中多个样式的字幕可能交错排列）
+pub fn ass_to_vtt(text: &str) -> String {
+    let text = text.trim_start_matches('\u{FEFF}');
+    let mut in_events = false;
+    // 默认字段顺序（缺少 Format 行时使用）
+    let mut fields: Vec<String> = ["layer", "start", "end", "style", "name", "marginl", "marginr", "marginv", "effect", "text"]
+        .iter()
+        .map(|f| f.to_string())
+        .collect();
+    let mut cues = Vec::new();
+
+    for line in text.lines() {
+        let line = line.trim();
+        if line.starts_with('[') {
+            in_events = line.eq_ignore_ascii_case("[events]");
+            continue;
+        }
+        if !in_events {
+            continue;
+        }
+
+        let Some((kind, value)) = line.split_once(':') else {
+            continue;
+        };
+        match kind.trim().to_lowercase().as_str() {
+            "format" => fields = value.split(',').map(|f| f.trim().to_lowercase()).collect(),
+            "dialogue" => {
+                // Text 是最后一个字段，本身可能包含逗号
+                let values: Vec<&str> = value.splitn(fields.len(), ',').collect();
+                let field = |name: &str| fields.iter().position(|f| f == name).and_then(|i| values.get(i).copied());
+
+                let (Some(start), Some(end)) = (field("start").and_then(parse_timestamp), field("end").and_then(parse_timestamp)) else {
+                    continue;
+                };
+                let text = clean_ass_text(field("text").unwrap_or(""));
+                // 纯绘图或特效行没有文本
+                if !text.is_empty() && end > start {
+                    cues.push(Cue { start, end, text });
+                }
+            }
+            _ => {}
+        }
+    }
+
+    cues.sort_by_key(|cue| (cue.start, cue.end));
+    write_vtt(&cues)
+}
+
+/// 将字幕文件内容转换为 WebVTT
+pub fn to_webvtt(format: SubtitleFormat, bytes: &[u8]) -> String {
+    let text = decode_subtitle(bytes);
+    match format {
+        SubtitleFormat::Srt => srt_to_vtt(&text),
+        SubtitleFormat::Ass | SubtitleFormat::Ssa => ass_to_vtt(&text),
+        SubtitleFormat::Vtt => {
+            let text = text.trim_start_matches('\u{FEFF}');
+            if text.starts_with("WEBVTT") {
+                text.to_string()
+            } else {
+                format!("WEBVTT\n\n{}", text)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_srt_to_vtt() {
+        let srt = "1\r\n00:00:01,500 --> 00:00:03,000 X1:10 X2:20\r\n<font color=\"#fff\">{\\an8}你好</font>\r\n<i>世界</i> & 朋友\r\n\r\n2\r\n00:01:02,000 --> 00:01:04,250\r\n第二句\r\n";
+        assert_eq!(
+            srt_to_vtt(srt),
+            "WEBVTT\n\n00:00:01.500 --> 00:00:03.000\n你好\n<i>世界</i> &amp; 朋友\n\n00:01:02.000 --> 00:01:04.250\n第二句\n\n"
+        );
+    }
+
+    #[test]
+    fn test_clean_srt_text_escapes_cue_text() {
+        assert_eq!(clean_srt_text("a < b & c"), "a &lt; b &amp; c");
+        assert_eq!(clean_srt_text("<b>A --> B</b>"), "<b>A --&gt; B</b>");
+        assert_eq!(clean_srt_text("&amp;amp; <I>x</I>"), "&amp;amp;amp; <I>x</I>");
+        assert_eq!(clean_srt_text("1 <2 {\\an8}<font size=\"20\">x</font>"), "1 &lt;2 x");
+    }
+
+    #[test]
+    fn test_ass_to_vtt() {
+        let ass = "[Script Info]\nTitle: test\n\n[Events]\nFormat: Layer, Start, End, Style, Name, MarginL, MarginR, MarginV, Effect, Text\nDialogue: 0,0:00:05.00,0:00:06.50,Default,,0,0,0,,第二句, 带逗号\nComment: 0,0:00:00.00,0:00:01.00,Default,,0,0,0,,注释\nDialogue: 0,0:00:01.20,0:00:02.00,Default,,0,0,0,,{\\pos(10,10)\\c&HFFFFFF&}第一句\\N<second>\nDialogue: 0,0:00:03.00,0:00:04.00,Default,,0,0,0,,{\\p1}m 0 0 l 100 0{\\p0}\n";
+        assert_eq!(
+            ass_to_vtt(ass),
+            "WEBVTT\n\n00:00:01.200 --> 00:00:02.000\n第一句\n&lt;second&gt;\n\n00:00:05.000 --> 00:00:06.500\n第二句, 带逗号\n\n"
+        );
+    }
+
+    #[test]
+    fn test_decode_gbk_and_utf16() {
+        let text = "1\n00:00:01,000 --> 00:00:02,000\n简体中文字幕\n";
+
+        let (gbk, _, _) = encoding_rs::GBK.encode(text);
+        assert_eq!(decode_subtitle(&gbk), text);
+
+        let utf16: Vec<u8> = text.encode_utf16().flat_map(|c| c.to_le_bytes()).collect();
+        assert_eq!(decode_subtitle(&utf16), text);
+
+        let mut utf16_bom = vec![0xFE, 0xFF];
+        utf16_bom.extend(text.encode_utf16().flat_map(|c| c.to_be_bytes()));
+        assert_eq!(decode_subtitle(&utf16_bom), text);
+    }
+}
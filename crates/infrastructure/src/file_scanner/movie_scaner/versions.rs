@@ -0,0 +1,208 @@
+//! 多版本和分段电影合并
+//!
+//! 同一目录下标题和年份相同的文件视为同一部电影的不同版本
+//! （`Movie (2010) - 1080p.mkv` / `Movie (2010) - 2160p.mkv`）；
+//! 文件名只差分段标记的文件视为同一版本的分段（`Movie CD1.avi` / `Movie CD2.avi`）。
+//!
+//! 每部电影只保留一个扫描结果（画质最高的版本的第一个分段），
+//! 所有文件记录到 [`VideoScanQueryResult::sources`]。
+
+use super::models::video::{MediaSource, VideoScanQueryResult};
+use super::parser::strip_stack_marker;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// 同一部电影的一个版本，分段文件按分段序号排列
+struct Version {
+    files: Vec<VideoScanQueryResult>,
+    stacked: bool,
+}
+
+impl Version {
+    fn height(&self) -> u32 {
+        self.files[0].height
+    }
+
+    fn byte_size(&self) -> u64 {
+        self.files.iter().map(|f| f.byte_size).sum()
+    }
+}
+
+/// 合并同一部电影的多个版本和分段文件
+///
+/// 返回的结果保持扫描顺序，每个结果的 `sources` 至少包含其自身；
+/// 已经填充了 `sources` 的结果（光盘结构）原样保留
+pub fn group_movie_files(videos: Vec<VideoScanQueryResult>) -> Vec<VideoScanQueryResult> {
+    let mut groups: Vec<Vec<VideoScanQueryResult>> = Vec::new();
+    let mut index: HashMap<(String, String, Option<u32>), usize> = HashMap::new();
+    for video in videos {
+        // 光盘结构在扫描时已经确定了正片的分段，不参与合并
+        if !video.sources.is_empty() {
+            groups.push(vec![video]);
+            continue;
+        }
+        let key = (
+            parent_dir(&video.path),
+            strip_stack_marker(&video.file_info.title).to_lowercase(),
+            video.file_info.year,
+        );
+        match index.get(&key) {
+            Some(&i) => groups[i].push(video),
+            None => {
+                index.insert(key, groups.len());
+                groups.push(vec![video]);
+            }
+        }
+    }
+    groups.into_iter().map(merge_versions).collect()
+}
+
+/// 把同一部电影的文件合并为一个扫描结果
+fn merge_versions(mut files: Vec<VideoScanQueryResult>) -> VideoScanQueryResult {
+    if files.len() == 1 && !files[0].sources.is_empty() {
+        return files.remove(0);
+    }
+    let mut versions = split_versions(files);
+    // 画质最高的版本作为默认版本，同画质时取文件较大的
+    versions.sort_by(|a, b| b.height().cmp(&a.height()).then(b.byte_size().cmp(&a.byte_size())));
+
+    let sources: Vec<MediaSource> = versions
+        .iter()
+        .enumerate()
+        .flat_map(|(i, version)| {
+            version.files.iter().map(move |file| {
+                let part = if version.stacked { file.file_info.part } else { None };
+                MediaSource::from_video(file, i as u32, part, version_label(file))
+            })
+        })
+        .collect();
+
+    let primary = versions.swap_remove(0);
+    let stacked = primary.stacked;
+    let duration = primary.files.iter().map(|f| f.duration).sum();
+    let byte_size = primary.byte_size();
+    let mut video = primary.files.into_iter().next().expect("version without files");
+
+    if stacked {
+        // 分段电影的时长和大小按所有分段计算，标题去掉分段标记
+        video.duration = duration;
+        video.byte_size = byte_size;
+        video.title = strip_stack_marker(&video.title);
+        video.file_info.title = strip_stack_marker(&video.file_info.title);
+        video.file_info.part = None;
+    }
+    if sources.len() > 1 {
+        println!("  🎞️  合并 {} 个文件: {}", sources.len(), video.title);
+    }
+    video.sources = sources;
+    video
+}
+
+/// 按去掉分段标记后的文件名划分版本
+///
+/// 文件名相同且分段序号各不相同的多个文件组成一个分段版本，其余文件各自是一个版本
+fn split_versions(files: Vec<VideoScanQueryResult>) -> Vec<Version> {
+    let mut by_name: Vec<(String, Vec<VideoScanQueryResult>)> = Vec::new();
+    for file in files {
+        let key = strip_stack_marker(&file_stem(&file.path)).to_lowercase();
+        match by_name.iter_mut().find(|(name, _)| *name == key) {
+            Some((_, group)) => group.push(file),
+            None => by_name.push((key, vec![file])),
+        }
+    }
+
+    let mut versions = Vec::new();
+    for (_, mut group) in by_name {
+        let mut parts: Vec<u32> = group.iter().filter_map(|f| f.file_info.part).collect();
+        parts.sort_unstable();
+        parts.dedup();
+        if group.len() > 1 && parts.len() == group.len() {
+            group.sort_by_key(|f| f.file_info.part);
+            versions.push(Version { files: group, stacked: true });
+        } else {
+            versions.extend(group.into_iter().map(|file| Version { files: vec![file], stacked: false }));
+        }
+    }
+    versions
+}
+
+/// 版本名称：文件名中的版本、分辨率和来源，都没有时使用视频高度或文件名
+fn version_label(video: &VideoScanQueryResult) -> String {
+    let info = &video.file_info;
+    let tags: Vec<&str> = [info.edition.as_deref(), info.resolution.as_deref(), info.source.as_deref()]
+        .into_iter()
+        .flatten()
+        .collect();
+    if !tags.is_empty() {
+        tags.join(" ")
+    } else if video.height > 0 {
+        format!("{}p", video.height)
+    } else {
+        strip_stack_marker(&file_stem(&video.path))
+    }
+}
+
+fn parent_dir(path: &str) -> String {
+    Path::new(path).parent().map(|p| p.to_string_lossy().to_string()).unwrap_or_default()
+}
+
+fn file_stem(path: &str) -> String {
+    Path::new(path).file_stem().map(|s| s.to_string_lossy().to_string()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn video(path: &str, height: u32, byte_size: u64) -> VideoScanQueryResult {
+        let file_name = Path::new(path).file_name().unwrap().to_string_lossy().to_string();
+        let mut video = VideoScanQueryResult::new(file_name, path.to_string());
+        video.title = video.file_info.title.clone();
+        video.height = height;
+        video.byte_size = byte_size;
+        video.duration = 3600;
+        video
+    }
+
+    #[test]
+    fn test_group_movie_files() {
+        let movies = group_movie_files(vec![
+            video("/movies/Inception (2010) - 1080p.mkv", 1080, 10),
+            video("/movies/Heat (1995) - CD2.avi", 480, 700),
+            video("/movies/Inception (2010) - 2160p.mkv", 2160, 40),
+            video("/movies/Heat (1995) - CD1.avi", 480, 700),
+            video("/movies/Heat (1995) - 1080p.mkv", 1080, 8),
+            video("/movies/Up (2009).mkv", 1080, 5),
+        ]);
+        assert_eq!(movies.len(), 3);
+
+        let inception = &movies[0];
+        assert_eq!(inception.path, "/movies/Inception (2010) - 2160p.mkv");
+        let labels: Vec<_> = inception.sources.iter().map(|s| (s.version, s.label.as_str())).collect();
+        assert_eq!(labels, [(0, "2160p"), (1, "1080p")]);
+
+        // 1080p 单文件版本优先于分段的 480p 版本
+        let heat = &movies[1];
+        assert_eq!((heat.title.as_str(), heat.height), ("Heat", 1080));
+        let parts: Vec<_> = heat.sources.iter().map(|s| (s.version, s.part)).collect();
+        assert_eq!(parts, [(0, None), (1, Some(1)), (1, Some(2))]);
+        assert_eq!(heat.sources[1].path, "/movies/Heat (1995) - CD1.avi");
+
+        let up = &movies[2];
+        assert_eq!(up.sources.len(), 1);
+        assert_eq!(up.sources[0].path, up.path);
+    }
+
+    #[test]
+    fn test_group_stacked_parts() {
+        let movies = group_movie_files(vec![
+            video("/movies/Heat/Heat.part2.mkv", 720, 700),
+            video("/movies/Heat/Heat.part1.mkv", 720, 700),
+        ]);
+        assert_eq!(movies.len(), 1);
+        let heat = &movies[0];
+        assert_eq!((heat.title.as_str(), heat.path.as_str()), ("Heat", "/movies/Heat/Heat.part1.mkv"));
+        assert_eq!((heat.duration, heat.byte_size), (7200, 1400));
+        assert_eq!(heat.sources.iter().map(|s| s.part).collect::<Vec<_>>(), [Some(1), Some(2)]);
+    }
+}
@@ -0,0 +1,127 @@
+use image::{ImageFormat, ImageReader};
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+
+/// 检查文件结尾时读取的最大字节数
+///
+/// 部分扫描图在结束标记之后还会附带少量填充字节，因此不要求结束标记恰好位于文件末尾
+const TRAILER_WINDOW: u64 = 1024;
+
+/// 检查单张漫画页面是否可读
+///
+/// 只读取文件头和文件尾，不做完整解码，扫描大量图片时开销很小：
+/// - 文件不能为空
+/// - 文件头能识别出图片格式并解析出有效的宽高
+/// - JPEG / PNG / GIF 必须包含结束标记（用于发现下载中断导致的截断文件）
+///
+/// # 返回
+/// - `Ok(())` - 页面可读
+/// - `Err(String)` - 不可读的原因
+pub fn check_page(path: &Path) -> Result<(), String> {
+    let len = std::fs::metadata(path)
+        .map_err(|e| format!("无法读取文件: {}", e))?
+        .len();
+    if len == 0 {
+        return Err("文件为空".to_string());
+    }
+
+    let reader = ImageReader::open(path)
+        .map_err(|e| format!("无法打开文件: {}", e))?
+        .with_guessed_format()
+        .map_err(|e| format!("无法识别图片格式: {}", e))?;
+    let format = reader.format();
+
+    let (width, height) = reader
+        .into_dimensions()
+        .map_err(|e| format!("无法解析图片头: {}", e))?;
+    if width == 0 || height == 0 {
+        return Err(format!("图片尺寸无效: {}x{}", width, height));
+    }
+
+    let Some(format) = format else {
+        return Ok(());
+    };
+
+    let marker: &[u8] = match format {
+        ImageFormat::Jpeg => &[0xFF, 0xD9],
+        ImageFormat::Png => b"IEND",
+        ImageFormat::Gif => &[0x3B],
+        _ => return Ok(()),
+    };
+
+    let mut file = std::fs::File::open(path).map_err(|e| format!("无法打开文件: {}", e))?;
+    let window = len.min(TRAILER_WINDOW);
+    let mut tail = Vec::with_capacity(window as usize);
+    file.seek(SeekFrom::End(-(window as i64)))
+        .and_then(|_| file.read_to_end(&mut tail))
+        .map_err(|e| format!("无法读取文件尾: {}", e))?;
+
+    if tail.windows(marker.len()).any(|w| w == marker) {
+        Ok(())
+    } else {
+        Err(format!("{:?} 文件缺少结束标记，可能已被截断", format))
+    }
+}
+
+/// 找出文件夹中不可读的页面
+///
+/// # 参数
+/// - `folder`: 图片所在文件夹
+/// - `image_names`: 图片文件名列表（与数据库中的 `ImagePaths` 一致）
+///
+/// # 返回
+/// - `Vec<String>` - 不可读页面的文件名，保持原有顺序
+pub fn find_unreadable_pages(folder: &str, image_names: &[String]) -> Vec<String> {
+    image_names
+        .iter()
+        .filter(|name| {
+            let path = Path::new(folder).join(name);
+            match check_page(&path) {
+                Ok(()) => false,
+                Err(reason) => {
+                    tracing::warn!("Unreadable manga page {}: {}", path.display(), reason);
+                    true
+                }
+            }
+        })
+        .cloned()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn encode(format: ImageFormat) -> Vec<u8> {
+        let img = image::RgbImage::from_fn(8, 8, |x, y| image::Rgb([(x * 30) as u8, (y * 30) as u8, 128]));
+        let mut buf = Cursor::new(Vec::new());
+        img.write_to(&mut buf, format).unwrap();
+        buf.into_inner()
+    }
+
+    #[test]
+    fn test_find_unreadable_pages() {
+        let dir = std::env::temp_dir().join(format!("modream_page_check_{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let jpeg = encode(ImageFormat::Jpeg);
+        let png = encode(ImageFormat::Png);
+        std::fs::write(dir.join("01.jpg"), &jpeg).unwrap();
+        std::fs::write(dir.join("02.png"), &png).unwrap();
+        std::fs::write(dir.join("03.jpg"), &jpeg[..jpeg.len() - 20]).unwrap();
+        std::fs::write(dir.join("04.png"), &png[..png.len() - 12]).unwrap();
+        std::fs::write(dir.join("05.jpg"), b"not an image at all").unwrap();
+        std::fs::write(dir.join("06.jpg"), b"").unwrap();
+
+        let names: Vec<String> = ["01.jpg", "02.png", "03.jpg", "04.png", "05.jpg", "06.jpg", "07.jpg"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+        let bad = find_unreadable_pages(dir.to_str().unwrap(), &names);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(bad, vec!["03.jpg", "04.png", "05.jpg", "06.jpg", "07.jpg"]);
+    }
+}
@@ -1,4 +1,5 @@
 use std::path::Path;
+use super::page_check::find_unreadable_pages;
 
 /// 章节信息
 #[derive(Debug, Clone)]
@@ -9,6 +10,8 @@ pub struct ChapterInfo {
     pub page_count: i32,
     /// 章节中的所有图片路径列表（已排序）
     pub image_paths: Vec<String>,
+    /// 无法读取的页面文件名（损坏或被截断）
+    pub bad_pages: Vec<String>,
 }
 
 /// 漫画扫描结果
@@ -20,6 +23,8 @@ pub enum MangaScanResult {
         page_count: i32,
         /// 漫画中的所有图片路径列表（已排序）
         image_paths: Vec<String>,
+        /// 无法读取的页面文件名（损坏或被截断）
+        bad_pages: Vec<String>,
     },
     /// 章节结构漫画（包含多个章节子目录）
     ChapterStructure {
@@ -145,10 +150,12 @@ pub fn scan_manga_folder(path: &str) -> anyhow::Result<Option<MangaScanResult>>
         let page_count = image_paths.len() as i32;
         if page_count >= 2 {
             tracing::debug!("Found single-folder manga: {} ({} pages)", path, page_count);
+            let bad_pages = find_unreadable_pages(path, &image_paths);
             return Ok(Some(MangaScanResult::SingleFolder {
                 path: path.to_string(),
                 page_count,
                 image_paths,
+                bad_pages,
             }));
         }
         return Ok(None);
@@ -165,12 +172,14 @@ pub fn scan_manga_folder(path: &str) -> anyhow::Result<Option<MangaScanResult>>
                 let page_count = image_paths.len() as i32;
                 if page_count >= 2 {
                     tracing::debug!("Found chapter: {} (number: {}, {} pages)", dir_name, chapter_num, page_count);
+                    let bad_pages = find_unreadable_pages(&dir_path.to_string_lossy(), &image_paths);
                     chapters.push(ChapterInfo {
                         path: dir_path.to_string_lossy().to_string(),
                         title: dir_name,
                         chapter_number: chapter_num,
                         page_count,
                         image_paths,
+                        bad_pages,
                     });
                 }
             }
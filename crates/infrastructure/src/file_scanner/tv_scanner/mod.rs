@@ -0,0 +1,30 @@
+//! 电视剧扫描库
+//!
+//! 按 `剧名/Season 1/剧名.S01E02.mkv` 等目录结构扫描剧集文件，
+//! 从文件名中解析季号和集号（`S01E02`、`1x02`、`第2集`），
+//! 并通过元数据提供者获取剧集、季和单集信息。
+
+pub mod models;
+pub mod parser;
+pub mod scanner;
+
+pub use models::{EpisodeScanResult, SeasonScanResult, SeriesScanResult};
+pub use scanner::TvScan;
+
+use super::movie_scaner::models::language::Language;
+
+/// 扫描指定目录中的电视剧并使用指定语言获取 TMDB 元数据
+///
+/// # 参数
+/// * `dir_path` - 要扫描的目录路径
+/// * `language` - 元数据的语言类型
+pub async fn tv_scan_with_language(dir_path: String, language: Language) -> Result<Vec<SeriesScanResult>, String> {
+    // 从配置中读取 TMDB API Key
+    let tmdb_api_key = shared::config::get().movie().tmdb().api_key().to_string();
+
+    TvScan::new()
+        .with_language(language)
+        .with_tmdb_provider(tmdb_api_key).await
+        .scan(dir_path)
+        .await
+}
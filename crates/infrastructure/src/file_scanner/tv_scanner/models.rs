@@ -0,0 +1,85 @@
+//! 电视剧扫描结果
+
+use super::super::movie_scaner::models::video::VideoScanQueryResult;
+
+/// 单集扫描结果
+#[derive(Debug, Clone)]
+pub struct EpisodeScanResult {
+    /// 季号
+    pub season_number: u32,
+    /// 集号
+    pub episode_number: u32,
+    /// 结束集号（一个文件包含多集时）
+    pub episode_number_end: Option<u32>,
+    /// 绝对集号（动漫按字幕组命名的连续集号）
+    pub absolute_number: Option<u32>,
+    /// 发布组（字幕组）
+    pub release_group: Option<String>,
+    /// 单集标题
+    pub title: Option<String>,
+    /// 简介
+    pub description: Option<String>,
+    /// 播出日期
+    pub air_date: Option<String>,
+    /// 剧照 URL
+    pub still_url: Option<String>,
+    /// 评分
+    pub rating: f32,
+    /// 视频文件信息（大小、时长、分辨率）
+    pub video: VideoScanQueryResult,
+}
+
+/// 季扫描结果
+#[derive(Debug, Clone)]
+pub struct SeasonScanResult {
+    /// 季号（0 为特别篇）
+    pub season_number: u32,
+    /// 季标题
+    pub title: Option<String>,
+    /// 简介
+    pub description: Option<String>,
+    /// 首播日期
+    pub air_date: Option<String>,
+    /// 海报 URL
+    pub poster_url: Option<String>,
+    /// 单集列表（按集号排序）
+    pub episodes: Vec<EpisodeScanResult>,
+}
+
+/// 电视剧扫描结果
+#[derive(Debug, Clone)]
+pub struct SeriesScanResult {
+    /// 剧名
+    pub title: String,
+    /// 原始剧名
+    pub original_title: Option<String>,
+    /// 文件夹名或文件名中的年份
+    pub year: Option<u32>,
+    /// 剧集文件夹路径
+    pub path: String,
+    /// 简介
+    pub description: Option<String>,
+    /// 首播日期
+    pub first_air_date: Option<String>,
+    /// 评分
+    pub rating: f32,
+    /// 评价人数
+    pub votes: u32,
+    /// 类型/流派列表
+    pub genres: Vec<String>,
+    /// 主演列表
+    pub actors: Vec<String>,
+    /// 海报 URL 列表
+    pub poster_urls: Vec<String>,
+    /// TMDB 剧集 ID
+    pub tmdb_id: Option<u64>,
+    /// 季列表（按季号排序）
+    pub seasons: Vec<SeasonScanResult>,
+}
+
+impl SeriesScanResult {
+    /// 本地集数
+    pub fn episode_count(&self) -> usize {
+        self.seasons.iter().map(|s| s.episodes.len()).sum()
+    }
+}
@@ -0,0 +1,262 @@
+//! 剧集文件名和季文件夹名解析
+
+use super::super::movie_scaner::models::scan_mode::VideoFilter;
+use regex::Regex;
+use std::sync::LazyLock;
+
+/// `S01E02`、`S01E02E03`、`S01E02-E03`、`S01E02-03`
+static SEASON_EPISODE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\bS(\d{1,2})[ ._-]?E(\d{1,4})(?:(?:[ ._-]?E|-)(\d{1,4})\b)?").expect("invalid season episode pattern")
+});
+
+/// `1x02`、`01x02-03`
+static CROSS_EPISODE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\b(\d{1,2})x(\d{2,3})(?:-(\d{2,3}))?\b").expect("invalid cross episode pattern"));
+
+/// 中文数字（阿拉伯数字或汉字）
+const CHINESE_NUMBER: &str = "[0-9零〇一二两三四五六七八九十百]+";
+
+/// `第2集`、`第二集`、`第12话`
+static CHINESE_EPISODE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(r"第\s*({})\s*[集话話]", CHINESE_NUMBER)).expect("invalid chinese episode pattern")
+});
+
+/// `第1季`、`第一季`
+static CHINESE_SEASON: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(&format!(r"第\s*({})\s*季", CHINESE_NUMBER)).expect("invalid chinese season pattern"));
+
+/// `EP01`、`E05`、`Ep.12`
+static EP_EPISODE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\bEP?[ ._-]?(\d{1,4})\b").expect("invalid ep episode pattern"));
+
+/// 字幕组常用的 `[05]`、`[05v2]` 和 ` - 05`
+static BRACKET_EPISODE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\[(\d{1,3})(?:v\d)?\]|\s-\s(\d{1,3})(?:v\d)?\b").expect("invalid bracket episode pattern"));
+
+/// 文件名开头的集号（`01.mkv`、`01 - Pilot.mkv`）
+static LEADING_EPISODE: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^(\d{1,3})(?:\D|$)").expect("invalid leading episode pattern"));
+
+/// `Season 1`、`Season.01`、`S01`
+static SEASON_FOLDER: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)^(?:season|series)?[ ._-]*?S?(\d{1,2})$").expect("invalid season folder pattern"));
+
+/// 年份（`2019`、`(2019)`）
+static YEAR: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b((?:19|20)\d{2})\b").expect("invalid year pattern"));
+
+/// 从文件名中解析出的集信息
+#[derive(Debug, Clone, PartialEq)]
+pub struct EpisodeInfo {
+    /// 季号（文件名中没有季号时为 None，由季文件夹决定）
+    pub season: Option<u32>,
+    /// 集号
+    pub episode: u32,
+    /// 结束集号（一个文件包含多集时）
+    pub episode_end: Option<u32>,
+    /// 集号之前的剧名（已清理）
+    pub series_title: Option<String>,
+}
+
+/// 解析中文数字（`12`、`十二`、`二十`、`一百零五`）
+pub fn parse_chinese_number(text: &str) -> Option<u32> {
+    if let Ok(number) = text.parse() {
+        return Some(number);
+    }
+
+    let mut total = 0;
+    let mut current = 0;
+    for c in text.chars() {
+        let digit = match c {
+            '零' | '〇' => 0,
+            '一' => 1,
+            '二' | '两' => 2,
+            '三' => 3,
+            '四' => 4,
+            '五' => 5,
+            '六' => 6,
+            '七' => 7,
+            '八' => 8,
+            '九' => 9,
+            '十' | '百' => {
+                let unit = if c == '十' { 10 } else { 100 };
+                // `十二` 中的 `十` 前面没有数字，表示 1
+                total += current.max(1) * unit;
+                current = 0;
+                continue;
+            }
+            _ => return None,
+        };
+        current = digit;
+    }
+    Some(total + current)
+}
+
+/// 清理剧名，返回 `(剧名, 年份)`
+///
+/// 复用电影标题的清理规则（去掉发布组、分辨率、编码等标记）
+pub fn clean_series_title(name: &str) -> (String, Option<u32>) {
+    let year = YEAR.captures(name).and_then(|c| c[1].parse().ok());
+    // extract_movie_title 会去掉最后一个 `.` 之后的扩展名
+    let title = VideoFilter::extract_movie_title(&format!("{}.", name));
+    let title = CHINESE_SEASON.replace_all(&title, " ");
+    let title = title.split_whitespace().collect::<Vec<_>>().join(" ");
+    (title, year)
+}
+
+/// 剧名前缀为空时返回 None
+fn prefix_title(stem: &str, end: usize) -> Option<String> {
+    let (title, _) = clean_series_title(&stem[..end]);
+    Some(title).filter(|t| !t.is_empty())
+}
+
+/// 只接受大于起始集号的结束集号
+fn episode_end(start: u32, end: Option<&str>) -> Option<u32> {
+    end.and_then(|e| e.parse().ok()).filter(|e| *e > start)
+}
+
+/// 从视频文件名（不含扩展名）中解析季号和集号
+///
+/// 按以下顺序尝试：
+/// 1. `S01E02`、`S01E02E03`、`S01E02-E03`
+/// 2. `1x02`
+/// 3. `第2集`、`第二集`（同时识别文件名中的 `第一季`）
+/// 4. `EP02`、`E02`
+/// 5. `[02]`、` - 02`（字幕组命名）
+/// 6. 文件名开头的数字（`02 - 标题`）
+pub fn parse_episode(stem: &str) -> Option<EpisodeInfo> {
+    if let Some(captures) = SEASON_EPISODE.captures(stem) {
+        let episode = captures[2].parse().ok()?;
+        return Some(EpisodeInfo {
+            season: captures[1].parse().ok(),
+            episode,
+            episode_end: episode_end(episode, captures.get(3).map(|m| m.as_str())),
+            series_title: prefix_title(stem, captures.get(0)?.start()),
+        });
+    }
+
+    if let Some(captures) = CROSS_EPISODE.captures(stem) {
+        let episode = captures[2].parse().ok()?;
+        return Some(EpisodeInfo {
+            season: captures[1].parse().ok(),
+            episode,
+            episode_end: episode_end(episode, captures.get(3).map(|m| m.as_str())),
+            series_title: prefix_title(stem, captures.get(0)?.start()),
+        });
+    }
+
+    if let Some(captures) = CHINESE_EPISODE.captures(stem) {
+        // 剧名在 `第X季` 和 `第X集` 中较早出现的那个之前
+        let season = CHINESE_SEASON.captures(stem);
+        let episode_start = captures.get(0)?.start();
+        let title_end = season
+            .as_ref()
+            .and_then(|s| s.get(0))
+            .map_or(episode_start, |s| s.start().min(episode_start));
+        return Some(EpisodeInfo {
+            season: season.and_then(|s| parse_chinese_number(&s[1])),
+            episode: parse_chinese_number(&captures[1])?,
+            episode_end: None,
+            series_title: prefix_title(stem, title_end),
+        });
+    }
+
+    for pattern in [&*EP_EPISODE, &*BRACKET_EPISODE] {
+        if let Some(captures) = pattern.captures(stem) {
+            let number = captures.iter().skip(1).flatten().next()?;
+            return Some(EpisodeInfo {
+                season: None,
+                episode: number.as_str().parse().ok()?,
+                episode_end: None,
+                series_title: prefix_title(stem, captures.get(0)?.start()),
+            });
+        }
+    }
+
+    let captures = LEADING_EPISODE.captures(stem)?;
+    Some(EpisodeInfo {
+        season: None,
+        episode: captures[1].parse().ok()?,
+        episode_end: None,
+        series_title: None,
+    })
+}
+
+/// 解析季文件夹名称，返回季号
+///
+/// 支持 `Season 1`、`Season 01`、`S01`、`第1季`、`第一季`，
+/// `Specials`、`SP`、`特别篇` 视为第 0 季
+pub fn parse_season_folder(name: &str) -> Option<u32> {
+    let name = name.trim();
+    let lower = name.to_lowercase();
+    if matches!(lower.as_str(), "specials" | "special" | "sp" | "sps" | "特别篇" | "特別篇" | "番外") {
+        return Some(0);
+    }
+
+    if let Some(captures) = CHINESE_SEASON.captures(name) {
+        return parse_chinese_number(&captures[1]);
+    }
+
+    // 纯数字不视为季文件夹（可能是年份或剧名）
+    if name.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    SEASON_FOLDER.captures(name).and_then(|c| c[1].parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn episode(stem: &str) -> (Option<u32>, u32, Option<u32>, Option<String>) {
+        let info = parse_episode(stem).unwrap();
+        (info.season, info.episode, info.episode_end, info.series_title)
+    }
+
+    #[test]
+    fn test_parse_episode() {
+        assert_eq!(
+            episode("Breaking.Bad.S01E02.1080p.BluRay.x264"),
+            (Some(1), 2, None, Some("Breaking Bad".to_string()))
+        );
+        assert_eq!(episode("Friends.S02E12E13.720p"), (Some(2), 12, Some(13), Some("Friends".to_string())));
+        assert_eq!(episode("Friends S02E12-E13"), (Some(2), 12, Some(13), Some("Friends".to_string())));
+        // 结束集号不能把分辨率误认为集号
+        assert_eq!(episode("Show.S01E05-1080p"), (Some(1), 5, None, Some("Show".to_string())));
+        assert_eq!(episode("The Office 3x07 - Branch Wars"), (Some(3), 7, None, Some("The Office".to_string())));
+        assert_eq!(episode("庆余年 第二季 第3集"), (Some(2), 3, None, Some("庆余年".to_string())));
+        assert_eq!(episode("甄嬛传第十二集"), (None, 12, None, Some("甄嬛传".to_string())));
+        assert_eq!(episode("Show EP05"), (None, 5, None, Some("Show".to_string())));
+        assert_eq!(
+            episode("[SubsPlease] Frieren - 05 (1080p) [ABCD1234]"),
+            (None, 5, None, Some("Frieren".to_string()))
+        );
+        assert_eq!(episode("[Group][Frieren][07][1080p]"), (None, 7, None, None));
+        assert_eq!(episode("01 - Pilot"), (None, 1, None, None));
+        // 分辨率不是集号
+        assert_eq!(parse_episode("Movie 1920x1080"), None);
+        assert_eq!(parse_episode("Interstellar.2014.1080p"), None);
+    }
+
+    #[test]
+    fn test_parse_season_folder() {
+        assert_eq!(parse_season_folder("Season 1"), Some(1));
+        assert_eq!(parse_season_folder("Season.02"), Some(2));
+        assert_eq!(parse_season_folder("S03"), Some(3));
+        assert_eq!(parse_season_folder("第1季"), Some(1));
+        assert_eq!(parse_season_folder("第十季"), Some(10));
+        assert_eq!(parse_season_folder("Specials"), Some(0));
+        assert_eq!(parse_season_folder("特别篇"), Some(0));
+        assert_eq!(parse_season_folder("Breaking Bad"), None);
+        assert_eq!(parse_season_folder("2019"), None);
+    }
+
+    #[test]
+    fn test_parse_chinese_number() {
+        assert_eq!(parse_chinese_number("12"), Some(12));
+        assert_eq!(parse_chinese_number("二"), Some(2));
+        assert_eq!(parse_chinese_number("十二"), Some(12));
+        assert_eq!(parse_chinese_number("二十"), Some(20));
+        assert_eq!(parse_chinese_number("一百零五"), Some(105));
+        assert_eq!(parse_chinese_number("第"), None);
+    }
+}
@@ -0,0 +1,369 @@
+//! 电视剧扫描器
+
+use super::models::{EpisodeScanResult, SeasonScanResult, SeriesScanResult};
+use super::parser::{clean_series_title, parse_episode, parse_season_folder};
+use super::super::movie_scaner::models::language::Language;
+use super::super::movie_scaner::models::video::VideoScanQueryResult;
+use super::super::movie_scaner::provider::{MetadataProvider, TvDetails};
+use super::super::movie_scaner::scanner::VIDEO_EXTENSIONS;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+/// 文件名包含这些关键词的视频不是正片
+const EXCLUDE_KEYWORDS: &[&str] = &["sample", "trailer", "预告", "花絮"];
+
+/// 电视剧扫描器
+///
+/// 目录结构：
+/// - `根目录/剧名/Season 1/剧名.S01E01.mkv`
+/// - `根目录/剧名/剧名.S01E01.mkv`（没有季文件夹时按文件名中的季号，默认第 1 季）
+/// - `根目录/剧名.S01E01.mkv`（直接放在根目录时按文件名中的剧名分组）
+///
+/// # 示例
+/// ```no_run
+/// use infrastructure::file_scanner::tv_scanner::TvScan;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let series = TvScan::new()
+///         .with_tmdb_provider("your_api_key".to_string()).await
+///         .scan("./tv".to_string())
+///         .await
+///         .unwrap();
+/// }
+/// ```
+pub struct TvScan {
+    /// 元数据语言
+    language: Language,
+    /// 元数据提供者列表（按优先级排序）
+    providers: Vec<Box<dyn MetadataProvider>>,
+}
+
+/// 扫描过程中的剧集分组
+struct SeriesGroup {
+    title: String,
+    year: Option<u32>,
+    seasons: BTreeMap<u32, Vec<EpisodeScanResult>>,
+}
+
+impl TvScan {
+    /// 创建新的电视剧扫描器实例
+    pub fn new() -> Self {
+        Self {
+            language: Language::default(),
+            providers: Vec::new(),
+        }
+    }
+
+    /// 设置元数据语言
+    pub fn with_language(mut self, language: Language) -> Self {
+        self.language = language;
+        self
+    }
+
+    /// 添加 TMDB 元数据提供者
+    pub async fn with_tmdb_provider(mut self, api_key: String) -> Self {
+        let provider = super::super::movie_scaner::provider::tmdb_provider::TMDBProvider::new(api_key);
+        self.providers.push(Box::new(super::super::movie_scaner::provider::cache::CachedProvider::new(provider)));
+        self
+    }
+
+    /// 添加自定义元数据提供者
+    pub fn with_provider(mut self, provider: Box<dyn MetadataProvider>) -> Self {
+        self.providers.push(provider);
+        self
+    }
+
+    /// 扫描目录中的电视剧并获取元数据
+    pub async fn scan(self, dir_path: String) -> Result<Vec<SeriesScanResult>, String> {
+        let mut series = self.scan_episode_files(&dir_path)?;
+
+        if !self.providers.is_empty() {
+            for item in series.iter_mut() {
+                self.enrich_series(item).await;
+            }
+        } else {
+            println!("⚠️  未配置元数据提供者，跳过元数据获取");
+        }
+
+        Ok(series)
+    }
+
+    /// 扫描目录中的剧集文件并按剧集、季分组
+    fn scan_episode_files(&self, dir_path: &str) -> Result<Vec<SeriesScanResult>, String> {
+        println!("📂 扫描电视剧目录: {}", dir_path);
+        let root = Path::new(dir_path);
+        let mut groups: BTreeMap<PathBuf, SeriesGroup> = BTreeMap::new();
+        let mut skipped = 0;
+
+        for result in ignore::Walk::new(dir_path) {
+            let entry = result.map_err(|e| format!("扫描错误: {}", e))?;
+            let path = entry.path();
+            if !entry.file_type().is_some_and(|t| t.is_file()) {
+                continue;
+            }
+            let Some(extension) = path.extension() else {
+                continue;
+            };
+            if !VIDEO_EXTENSIONS.contains(&extension.to_string_lossy().to_lowercase().as_str()) {
+                continue;
+            }
+
+            let (Some(file_name), Some(stem)) = (path.file_name(), path.file_stem()) else {
+                continue;
+            };
+            let file_name = file_name.to_string_lossy().to_string();
+            let stem = stem.to_string_lossy().to_string();
+            let lower = stem.to_lowercase();
+            if EXCLUDE_KEYWORDS.iter().any(|k| lower.contains(k)) {
+                skipped += 1;
+                continue;
+            }
+
+            let Some(info) = parse_episode(&stem) else {
+                println!("  ⏭️  跳过: {} (无法识别集号)", file_name);
+                skipped += 1;
+                continue;
+            };
+
+            let Some(parent) = path.parent() else {
+                continue;
+            };
+            let folder_season = parent.file_name().and_then(|n| parse_season_folder(&n.to_string_lossy()));
+
+            // 季文件夹的上一级是剧集文件夹；直接放在根目录的文件按文件名中的剧名分组
+            let series_dir = if folder_season.is_some() && parent != root {
+                parent.parent().unwrap_or(parent).to_path_buf()
+            } else if parent == root {
+                let Some(title) = info.series_title.clone() else {
+                    println!("  ⏭️  跳过: {} (无法识别剧名)", file_name);
+                    skipped += 1;
+                    continue;
+                };
+                root.join(title)
+            } else {
+                parent.to_path_buf()
+            };
+
+            let season_number = info.season.or(folder_season).unwrap_or(1);
+            let group = groups.entry(series_dir.clone()).or_insert_with(|| {
+                let folder_name = series_dir.file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default();
+                let (title, year) = clean_series_title(&folder_name);
+                let title = if title.is_empty() { info.series_title.clone().unwrap_or(folder_name) } else { title };
+                SeriesGroup {
+                    title,
+                    year,
+                    seasons: BTreeMap::new(),
+                }
+            });
+
+            let video = VideoScanQueryResult::new(file_name, path.to_string_lossy().to_string());
+            group.seasons.entry(season_number).or_default().push(EpisodeScanResult {
+                season_number,
+                episode_number: info.episode,
+                episode_number_end: info.episode_end,
+                absolute_number: None,
+                release_group: None,
+                title: None,
+                description: None,
+                air_date: None,
+                still_url: None,
+                rating: 0.0,
+                video,
+            });
+        }
+
+        let series: Vec<SeriesScanResult> = groups
+            .into_iter()
+            .map(|(path, group)| SeriesScanResult {
+                title: group.title,
+                original_title: None,
+                year: group.year,
+                path: path.to_string_lossy().to_string(),
+                description: None,
+                first_air_date: None,
+                rating: 0.0,
+                votes: 0,
+                genres: Vec::new(),
+                actors: Vec::new(),
+                poster_urls: Vec::new(),
+                tmdb_id: None,
+                seasons: into_seasons(group.seasons),
+            })
+            .collect();
+
+        if skipped > 0 {
+            println!("🔍 跳过 {} 个非剧集文件", skipped);
+        }
+        println!(
+            "✅ 找到 {} 部电视剧，共 {} 集",
+            series.len(),
+            series.iter().map(|s| s.episode_count()).sum::<usize>()
+        );
+        Ok(series)
+    }
+
+    /// 按优先级依次尝试提供者，补充剧集、季和单集的元数据
+    async fn enrich_series(&self, series: &mut SeriesScanResult) {
+        println!("\n🔍 搜索电视剧: {}", series.title);
+
+        for provider in &self.providers {
+            let results = match provider
+                .search_tv_with_language(&series.title, series.year.map(u64::from), self.language)
+                .await
+            {
+                Ok(results) => results,
+                Err(e) => {
+                    println!("  ⚠️  {} 搜索失败: {}", provider.name(), e);
+                    continue;
+                }
+            };
+            let Some(first) = results.first() else {
+                println!("  ⚠️  {} 未找到匹配结果", provider.name());
+                continue;
+            };
+
+            let details = match provider.get_tv_details_with_language(first.tmdb_id, self.language).await {
+                Ok(details) => details,
+                Err(e) => {
+                    println!("  ⚠️  获取详情失败: {}", e);
+                    continue;
+                }
+            };
+            println!("  ✅ 找到匹配: {} ({})", details.name, details.first_air_date);
+
+            let tmdb_id = details.tmdb_id;
+            apply_series_details(series, details);
+            enrich_seasons(provider.as_ref(), tmdb_id, self.language, &mut series.seasons).await;
+            return;
+        }
+
+        println!("  ❌ 所有数据源均未找到匹配结果");
+    }
+}
+
+/// 把按季号分组的单集转换为季列表（单集按集号排序，元数据待补充）
+pub(crate) fn into_seasons(seasons: BTreeMap<u32, Vec<EpisodeScanResult>>) -> Vec<SeasonScanResult> {
+    seasons
+        .into_iter()
+        .map(|(season_number, mut episodes)| {
+            episodes.sort_by_key(|e| e.episode_number);
+            SeasonScanResult {
+                season_number,
+                title: None,
+                description: None,
+                air_date: None,
+                poster_url: None,
+                episodes,
+            }
+        })
+        .collect()
+}
+
+/// 用提供者返回的剧集详情覆盖扫描结果中的剧集信息
+pub(crate) fn apply_series_details(series: &mut SeriesScanResult, details: TvDetails) {
+    series.tmdb_id = Some(details.tmdb_id);
+    series.title = details.name;
+    series.original_title = Some(details.original_name).filter(|t| !t.is_empty());
+    series.description = Some(details.overview).filter(|d| !d.is_empty());
+    series.first_air_date = Some(details.first_air_date).filter(|d| !d.is_empty());
+    series.rating = details.vote_average as f32;
+    series.votes = details.vote_count as u32;
+    series.genres = details.genres;
+    series.actors = details.cast;
+    series.poster_urls = details.poster_urls;
+}
+
+/// 逐季获取季详情，补充季和单集的元数据（按季号、集号匹配）
+pub(crate) async fn enrich_seasons(
+    provider: &dyn MetadataProvider,
+    tmdb_id: u64,
+    language: Language,
+    seasons: &mut [SeasonScanResult],
+) {
+    for season in seasons.iter_mut() {
+        let season_details = match provider.get_tv_season_with_language(tmdb_id, season.season_number, language).await {
+            Ok(season_details) => season_details,
+            Err(e) => {
+                println!("  ⚠️  获取第 {} 季详情失败: {}", season.season_number, e);
+                continue;
+            }
+        };
+
+        season.title = Some(season_details.name).filter(|t| !t.is_empty());
+        season.description = Some(season_details.overview).filter(|d| !d.is_empty());
+        season.air_date = Some(season_details.air_date).filter(|d| !d.is_empty());
+        season.poster_url = season_details.poster_url;
+
+        for episode in season.episodes.iter_mut() {
+            let Some(remote) = season_details
+                .episodes
+                .iter()
+                .find(|e| e.episode_number == episode.episode_number)
+            else {
+                continue;
+            };
+            episode.title = Some(remote.name.clone()).filter(|t| !t.is_empty());
+            episode.description = Some(remote.overview.clone()).filter(|d| !d.is_empty());
+            episode.air_date = Some(remote.air_date.clone()).filter(|d| !d.is_empty());
+            episode.still_url = remote.still_url.clone();
+            episode.rating = remote.vote_average as f32;
+        }
+    }
+}
+
+impl Default for TvScan {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_scan_episode_files() {
+        let dir = std::env::temp_dir().join(format!("modream_tv_{}", std::process::id()));
+        for name in [
+            "Breaking Bad (2008)/Season 1/Breaking.Bad.S01E02.mkv",
+            "Breaking Bad (2008)/Season 1/Breaking.Bad.S01E01.mkv",
+            "Breaking Bad (2008)/Specials/Breaking.Bad.S00E01.mkv",
+            "Breaking Bad (2008)/Season 1/Breaking.Bad.S01E01.sample.mkv",
+            "庆余年/第二季/第3集.mp4",
+            "Friends.S02E12E13.mkv",
+            "Friends.S02E14.mkv",
+        ] {
+            let path = dir.join(name);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, b"").unwrap();
+        }
+
+        let series = TvScan::new().scan_episode_files(&dir.to_string_lossy()).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        let summary: Vec<_> = series
+            .iter()
+            .map(|s| {
+                let seasons: Vec<_> = s
+                    .seasons
+                    .iter()
+                    .map(|season| {
+                        let episodes: Vec<_> = season.episodes.iter().map(|e| (e.episode_number, e.episode_number_end)).collect();
+                        (season.season_number, episodes)
+                    })
+                    .collect();
+                (s.title.as_str(), s.year, seasons)
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("Breaking Bad", Some(2008), vec![(0, vec![(1, None)]), (1, vec![(1, None), (2, None)])]),
+                ("Friends", None, vec![(2, vec![(12, Some(13)), (14, None)])]),
+                ("庆余年", None, vec![(2, vec![(3, None)])]),
+            ]
+        );
+    }
+}
@@ -3,3 +3,4 @@ pub mod password;
 pub mod jwt;
 pub mod repository;
 pub mod file_scanner;
+pub mod cbz;
@@ -64,6 +64,9 @@ impl MangaRepository for MangaRepositoryImpl {
             cover: Set(manga.cover),
             has_chapters: Set(manga.has_chapters),
             image_paths: Set(manga.image_paths),
+            bad_pages: Set(manga.bad_pages),
+            series_id: Set(manga.series_id),
+            volume_number: Set(manga.volume_number),
         };
 
         let created_manga = active_model.insert(&self.db).await?;
@@ -111,6 +114,9 @@ impl MangaRepository for MangaRepositoryImpl {
                     cover: Set(manga.cover.clone()),
                     has_chapters: Set(manga.has_chapters),
                     image_paths: Set(manga.image_paths.clone()),
+                    bad_pages: Set(manga.bad_pages.clone()),
+                    series_id: Set(manga.series_id),
+                    volume_number: Set(manga.volume_number),
                 })
                 .collect();
 
@@ -146,6 +152,9 @@ impl MangaRepository for MangaRepositoryImpl {
             cover: Set(manga.cover),
             has_chapters: Set(manga.has_chapters),
             image_paths: Set(manga.image_paths),
+            bad_pages: Set(manga.bad_pages),
+            series_id: Set(manga.series_id),
+            volume_number: Set(manga.volume_number),
         };
 
         let updated_manga = active_model.update(&self.db).await?;
@@ -180,6 +189,9 @@ impl MangaRepository for MangaRepositoryImpl {
                 cover: Set(manga.cover),
                 has_chapters: Set(manga.has_chapters),
                 image_paths: Set(manga.image_paths),
+                bad_pages: Set(manga.bad_pages),
+                series_id: Set(manga.series_id),
+                volume_number: Set(manga.volume_number),
             };
 
             let updated_manga = active_model.update(&txn).await?;
@@ -201,6 +213,22 @@ impl MangaRepository for MangaRepositoryImpl {
         Ok(mangas)
     }
 
+    async fn find_by_series_id(&self, series_id: i32) -> anyhow::Result<Vec<MangaModel>> {
+        let mangas = Manga::find()
+            .filter(domain::entity::manga::Column::SeriesId.eq(series_id))
+            .all(&self.db)
+            .await?;
+        Ok(mangas)
+    }
+
+    async fn find_with_bad_pages(&self) -> anyhow::Result<Vec<MangaModel>> {
+        let mangas = Manga::find()
+            .filter(domain::entity::manga::Column::BadPages.is_not_null())
+            .all(&self.db)
+            .await?;
+        Ok(mangas)
+    }
+
     async fn delete(&self, id: i32) -> anyhow::Result<()> {
         Manga::delete_by_id(id).exec(&self.db).await?;
         Ok(())
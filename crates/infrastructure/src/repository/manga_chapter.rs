@@ -29,6 +29,14 @@ impl MangaChapterRepository for MangaChapterRepositoryImpl {
         Ok(chapters)
     }
 
+    async fn find_with_bad_pages(&self) -> anyhow::Result<Vec<MangaChapterModel>> {
+        let chapters = MangaChapter::find()
+            .filter(Column::BadPages.is_not_null())
+            .all(&*self.db)
+            .await?;
+        Ok(chapters)
+    }
+
     async fn create(&self, chapter: MangaChapterModel) -> anyhow::Result<MangaChapterModel> {
         let active_model = ActiveModel {
             id: sea_orm::NotSet,
@@ -42,6 +50,7 @@ impl MangaChapterRepository for MangaChapterRepositoryImpl {
             byte_size: Set(chapter.byte_size),
             cover: Set(chapter.cover),
             image_paths: Set(chapter.image_paths),
+            bad_pages: Set(chapter.bad_pages),
         };
 
         let created_chapter = active_model.insert(&*self.db).await?;
@@ -86,6 +95,7 @@ impl MangaChapterRepository for MangaChapterRepositoryImpl {
                     byte_size: Set(chapter.byte_size),
                     cover: Set(chapter.cover.clone()),
                     image_paths: Set(chapter.image_paths.clone()),
+                    bad_pages: Set(chapter.bad_pages.clone()),
                 })
                 .collect();
 
@@ -117,6 +127,7 @@ impl MangaChapterRepository for MangaChapterRepositoryImpl {
             byte_size: Set(chapter.byte_size),
             cover: Set(chapter.cover),
             image_paths: Set(chapter.image_paths),
+            bad_pages: Set(chapter.bad_pages),
         };
 
         let updated_chapter = active_model.update(&*self.db).await?;
@@ -148,6 +159,7 @@ impl MangaChapterRepository for MangaChapterRepositoryImpl {
                 byte_size: Set(chapter.byte_size),
                 cover: Set(chapter.cover),
                 image_paths: Set(chapter.image_paths),
+                bad_pages: Set(chapter.bad_pages),
             };
 
             let updated_chapter = active_model.update(&txn).await?;
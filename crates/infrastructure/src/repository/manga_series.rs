@@ -0,0 +1,108 @@
+use async_trait::async_trait;
+use domain::entity::manga_series::{ActiveModel, Column, Entity as MangaSeries, Model as MangaSeriesModel};
+use domain::repository::MangaSeriesRepository;
+use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set};
+
+pub struct MangaSeriesRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl MangaSeriesRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl MangaSeriesRepository for MangaSeriesRepositoryImpl {
+    async fn find_by_id(&self, id: i32) -> anyhow::Result<Option<MangaSeriesModel>> {
+        let series = MangaSeries::find_by_id(id).one(&self.db).await?;
+        Ok(series)
+    }
+
+    async fn find_by_paged(
+        &self,
+        page_size: i32,
+        page_index: i32,
+    ) -> anyhow::Result<Option<Vec<MangaSeriesModel>>> {
+        // 验证参数
+        if page_size <= 0 || page_index <= 0 {
+            return Ok(None);
+        }
+
+        // 计算偏移量（page_index 从 1 开始）
+        let offset = ((page_index - 1) * page_size) as u64;
+        let limit = page_size as u64;
+
+        let series = MangaSeries::find()
+            .order_by_asc(Column::Title)
+            .offset(offset)
+            .limit(limit)
+            .all(&self.db)
+            .await?;
+
+        if series.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(series))
+        }
+    }
+
+    async fn find_by_media_library_id(&self, media_library_id: i32) -> anyhow::Result<Vec<MangaSeriesModel>> {
+        let series = MangaSeries::find()
+            .filter(Column::MediaLibraryId.eq(media_library_id))
+            .all(&self.db)
+            .await?;
+        Ok(series)
+    }
+
+    async fn create(&self, series: MangaSeriesModel) -> anyhow::Result<MangaSeriesModel> {
+        let active_model = ActiveModel {
+            id: sea_orm::NotSet,
+            create_time: Set(series.create_time),
+            update_time: Set(series.update_time),
+            title: Set(series.title),
+            normalized_title: Set(series.normalized_title),
+            description: Set(series.description),
+            cover_manga_id: Set(series.cover_manga_id),
+            media_library_id: Set(series.media_library_id),
+        };
+
+        let created_series = active_model.insert(&self.db).await?;
+        Ok(created_series)
+    }
+
+    async fn update(&self, series: MangaSeriesModel) -> anyhow::Result<MangaSeriesModel> {
+        let active_model = ActiveModel {
+            id: Set(series.id),
+            create_time: Set(series.create_time),
+            update_time: Set(series.update_time),
+            title: Set(series.title),
+            normalized_title: Set(series.normalized_title),
+            description: Set(series.description),
+            cover_manga_id: Set(series.cover_manga_id),
+            media_library_id: Set(series.media_library_id),
+        };
+
+        let updated_series = active_model.update(&self.db).await?;
+        Ok(updated_series)
+    }
+
+    async fn delete(&self, id: i32) -> anyhow::Result<()> {
+        MangaSeries::delete_by_id(id).exec(&self.db).await?;
+        Ok(())
+    }
+
+    async fn delete_by_media_library_id(&self, media_library_id: i32) -> anyhow::Result<()> {
+        MangaSeries::delete_many()
+            .filter(Column::MediaLibraryId.eq(media_library_id))
+            .exec(&self.db)
+            .await?;
+        Ok(())
+    }
+
+    async fn count_all(&self) -> anyhow::Result<i32> {
+        let count = MangaSeries::find().count(&self.db).await?;
+        Ok(count as i32)
+    }
+}
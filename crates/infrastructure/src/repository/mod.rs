@@ -4,18 +4,38 @@
 pub mod game;
 pub mod manga;
 pub mod manga_chapter;
+pub mod manga_series;
 pub mod media_library;
 pub mod movie;
+pub mod movie_collection;
+pub mod movie_credit;
+pub mod movie_extra;
+pub mod movie_play_state;
+pub mod movie_source;
+pub mod movie_subtitle;
+pub mod movie_track;
+pub mod person;
 pub mod photo;
 pub mod photo_album;
+pub mod tv_series;
 pub mod user;
 
 pub use game::GameRepositoryImpl;
 pub use manga::MangaRepositoryImpl;
 pub use manga_chapter::MangaChapterRepositoryImpl;
+pub use manga_series::MangaSeriesRepositoryImpl;
 pub use media_library::MediaLibraryRepositoryImpl;
 pub use movie::MovieRepositoryImpl;
+pub use movie_collection::MovieCollectionRepositoryImpl;
+pub use movie_credit::MovieCreditRepositoryImpl;
+pub use movie_extra::MovieExtraRepositoryImpl;
+pub use movie_play_state::MoviePlayStateRepositoryImpl;
+pub use movie_source::MovieSourceRepositoryImpl;
+pub use movie_subtitle::MovieSubtitleRepositoryImpl;
+pub use movie_track::MovieTrackRepositoryImpl;
+pub use person::PersonRepositoryImpl;
 pub use photo::{PhotoRepositoryImpl, PhotoExifRepositoryImpl};
 pub use photo_album::{PhotoAlbumRepositoryImpl, PhotoAlbumItemRepositoryImpl};
+pub use tv_series::{TvEpisodeRepositoryImpl, TvSeasonRepositoryImpl, TvSeriesRepositoryImpl};
 pub use user::UserRepositoryImpl;
 
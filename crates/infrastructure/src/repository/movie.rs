@@ -1,10 +1,15 @@
 use async_trait::async_trait;
 use domain::entity::movie::{ActiveModel, Column, Entity as Movie, Model as MovieModel};
+use domain::entity::movie_credit::{Column as MovieCreditColumn, Entity as MovieCredit};
+use domain::entity::person::{Column as PersonColumn, Entity as Person};
 use domain::repository::MovieRepository;
+use domain::value_object::{MovieFacets, MovieFilter, MovieSortKey, ResolutionClass};
+use sea_orm::sea_query::LikeExpr;
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
-    QuerySelect, Set, TransactionTrait,
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseConnection, EntityTrait, Order, PaginatorTrait, QueryFilter,
+    QueryOrder, QuerySelect, QueryTrait, Select, Set, TransactionTrait,
 };
+use std::collections::HashMap;
 
 pub struct MovieRepositoryImpl {
     db: DatabaseConnection,
@@ -14,6 +19,76 @@ impl MovieRepositoryImpl {
     pub fn new(db: DatabaseConnection) -> Self {
         Self { db }
     }
+
+    /// 把筛选条件应用到查询上
+    fn apply_filter(mut query: Select<Movie>, filter: &MovieFilter) -> Select<Movie> {
+        if let Some(genre) = &filter.genre {
+            // 类型以 JSON 数组保存，按带引号的 JSON 字符串匹配，避免 "剧情" 匹配到 "剧情片"
+            let quoted = serde_json::to_string(genre).unwrap_or_default();
+            query = query.filter(Column::Genres.like(Self::contains_pattern(&quoted)));
+        }
+        // 上映日期格式为 YYYY-MM-DD，按字符串比较年份范围
+        if let Some(year) = filter.year_from {
+            query = query.filter(Column::ReleaseDate.gte(format!("{:04}", year)));
+        }
+        if let Some(year) = filter.year_to {
+            query = query.filter(Column::ReleaseDate.lt(format!("{:04}", year.saturating_add(1))));
+        }
+        if let Some(rating) = filter.min_rating {
+            query = query.filter(Column::Rating.gte(rating));
+        }
+        if let Some(resolution) = filter.resolution {
+            query = query.filter(Self::resolution_condition(resolution));
+        }
+        if let Some(name) = &filter.person {
+            let credits = MovieCredit::find()
+                .select_only()
+                .column(MovieCreditColumn::MovieId)
+                .inner_join(Person)
+                .filter(MovieCreditColumn::Role.is_in(["actor", "director"]))
+                .filter(PersonColumn::Name.like(Self::contains_pattern(name)))
+                .into_query();
+            query = query.filter(Column::Id.in_subquery(credits));
+        }
+        if let Some(media_library_id) = filter.media_library_id {
+            query = query.filter(Column::MediaLibraryId.eq(media_library_id));
+        }
+        query
+    }
+
+    /// 按字面值包含匹配的 LIKE 模式，转义用户输入中的 `%` 和 `_`
+    fn contains_pattern(value: &str) -> LikeExpr {
+        let escaped = value.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_");
+        LikeExpr::new(format!("%{}%", escaped)).escape('\\')
+    }
+
+    /// 分辨率档位对应的宽高条件
+    fn resolution_condition(resolution: ResolutionClass) -> Condition {
+        let uhd = Condition::any()
+            .add(Column::Width.gte(ResolutionClass::UHD_MIN_WIDTH))
+            .add(Column::Height.gte(ResolutionClass::UHD_MIN_HEIGHT));
+        let hd = Condition::any()
+            .add(Column::Width.gte(ResolutionClass::HD_MIN_WIDTH))
+            .add(Column::Height.gte(ResolutionClass::HD_MIN_HEIGHT));
+        match resolution {
+            ResolutionClass::Uhd => uhd,
+            ResolutionClass::Hd => Condition::all().add(hd).add(uhd.not()),
+            // 宽度为 0 表示没有探测到分辨率，不算标清
+            ResolutionClass::Sd => Condition::all().add(Column::Width.gt(0)).add(hd.not()),
+        }
+    }
+
+    /// 排序字段对应的列
+    fn sort_column(sort: MovieSortKey) -> Column {
+        match sort {
+            MovieSortKey::Title => Column::Title,
+            MovieSortKey::ReleaseDate => Column::ReleaseDate,
+            MovieSortKey::Rating => Column::Rating,
+            MovieSortKey::DateAdded => Column::CreateTime,
+            MovieSortKey::FileSize => Column::ByteSize,
+            MovieSortKey::Duration => Column::Duration,
+        }
+    }
 }
 
 #[async_trait]
@@ -51,6 +126,60 @@ impl MovieRepository for MovieRepositoryImpl {
         }
     }
 
+    async fn find_by_filter(
+        &self,
+        filter: &MovieFilter,
+        page_size: i32,
+        page_index: i32,
+    ) -> anyhow::Result<(Vec<MovieModel>, i32)> {
+        if page_size <= 0 || page_index <= 0 {
+            return Ok((Vec::new(), 0));
+        }
+
+        let query = Self::apply_filter(Movie::find(), filter);
+        let total = query.clone().count(&self.db).await? as i32;
+
+        let order = if filter.descending { Order::Desc } else { Order::Asc };
+        let movies = query
+            .order_by(Self::sort_column(filter.sort), order.clone())
+            // 排序值相同时按 ID 保证翻页稳定
+            .order_by(Column::Id, order)
+            .offset(((page_index - 1) * page_size) as u64)
+            .limit(page_size as u64)
+            .all(&self.db)
+            .await?;
+
+        Ok((movies, total))
+    }
+
+    async fn find_facets(&self, media_library_id: Option<i32>) -> anyhow::Result<MovieFacets> {
+        let rows: Vec<(Option<String>, Option<String>)> = Movie::find()
+            .select_only()
+            .column(Column::Genres)
+            .column(Column::ReleaseDate)
+            .apply_if(media_library_id, |query, id| query.filter(Column::MediaLibraryId.eq(id)))
+            .into_tuple()
+            .all(&self.db)
+            .await?;
+
+        let mut genres = std::collections::BTreeSet::new();
+        let mut years = std::collections::BTreeSet::new();
+        for (genres_json, release_date) in rows {
+            let movie_genres: Vec<String> = genres_json
+                .and_then(|json| serde_json::from_str(&json).ok())
+                .unwrap_or_default();
+            genres.extend(movie_genres);
+            if let Some(year) = release_date.and_then(|date| date.get(..4)?.parse::<i32>().ok()) {
+                years.insert(year);
+            }
+        }
+
+        Ok(MovieFacets {
+            genres: genres.into_iter().collect(),
+            years: years.into_iter().rev().collect(),
+        })
+    }
+
     async fn create(&self, movie: MovieModel) -> anyhow::Result<MovieModel> {
         let active_model = ActiveModel {
             id: sea_orm::NotSet,
@@ -77,6 +206,14 @@ impl MovieRepository for MovieRepositoryImpl {
             tags: Set(movie.tags),
             poster_urls: Set(movie.poster_urls),
             cover: Set(movie.cover),
+            backdrop: Set(movie.backdrop),
+            tmdb_id: Set(movie.tmdb_id),
+            imdb_id: Set(movie.imdb_id),
+            source: Set(movie.source),
+            video_codec: Set(movie.video_codec),
+            edition: Set(movie.edition),
+            release_group: Set(movie.release_group),
+            collection_id: Set(movie.collection_id),
             media_library_id: Set(movie.media_library_id),
         };
 
@@ -133,6 +270,14 @@ impl MovieRepository for MovieRepositoryImpl {
                     tags: Set(movie.tags.clone()),
                     poster_urls: Set(movie.poster_urls.clone()),
                     cover: Set(movie.cover.clone()),
+                    backdrop: Set(movie.backdrop.clone()),
+                    tmdb_id: Set(movie.tmdb_id),
+                    imdb_id: Set(movie.imdb_id.clone()),
+                    source: Set(movie.source.clone()),
+                    video_codec: Set(movie.video_codec.clone()),
+                    edition: Set(movie.edition.clone()),
+                    release_group: Set(movie.release_group.clone()),
+                    collection_id: Set(movie.collection_id),
                     media_library_id: Set(movie.media_library_id),
                 };
 
@@ -174,6 +319,14 @@ impl MovieRepository for MovieRepositoryImpl {
             tags: Set(movie.tags),
             poster_urls: Set(movie.poster_urls),
             cover: Set(movie.cover),
+            backdrop: Set(movie.backdrop),
+            tmdb_id: Set(movie.tmdb_id),
+            imdb_id: Set(movie.imdb_id),
+            source: Set(movie.source),
+            video_codec: Set(movie.video_codec),
+            edition: Set(movie.edition),
+            release_group: Set(movie.release_group),
+            collection_id: Set(movie.collection_id),
             media_library_id: Set(movie.media_library_id),
         };
 
@@ -218,6 +371,14 @@ impl MovieRepository for MovieRepositoryImpl {
                 tags: Set(movie.tags.clone()),
                 poster_urls: Set(movie.poster_urls.clone()),
                 cover: Set(movie.cover.clone()),
+                backdrop: Set(movie.backdrop.clone()),
+                tmdb_id: Set(movie.tmdb_id),
+                imdb_id: Set(movie.imdb_id.clone()),
+                source: Set(movie.source.clone()),
+                video_codec: Set(movie.video_codec.clone()),
+                edition: Set(movie.edition.clone()),
+                release_group: Set(movie.release_group.clone()),
+                collection_id: Set(movie.collection_id),
                 media_library_id: Set(movie.media_library_id),
             };
 
@@ -232,6 +393,17 @@ impl MovieRepository for MovieRepositoryImpl {
         Ok(updated_movies)
     }
 
+    async fn find_by_ids(&self, ids: Vec<i32>) -> anyhow::Result<Vec<MovieModel>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let movies = Movie::find()
+            .filter(Column::Id.is_in(ids))
+            .all(&self.db)
+            .await?;
+        Ok(movies)
+    }
+
     async fn find_by_media_library_id(&self, media_library_id: i32) -> anyhow::Result<Vec<MovieModel>> {
         let movies = Movie::find()
             .filter(Column::MediaLibraryId.eq(media_library_id))
@@ -240,6 +412,15 @@ impl MovieRepository for MovieRepositoryImpl {
         Ok(movies)
     }
 
+    async fn find_by_collection_id(&self, collection_id: i32) -> anyhow::Result<Vec<MovieModel>> {
+        let movies = Movie::find()
+            .filter(Column::CollectionId.eq(collection_id))
+            .order_by_asc(Column::ReleaseDate)
+            .all(&self.db)
+            .await?;
+        Ok(movies)
+    }
+
     async fn delete(&self, id: i32) -> anyhow::Result<()> {
         Movie::delete_by_id(id).exec(&self.db).await?;
         Ok(())
@@ -257,5 +438,169 @@ impl MovieRepository for MovieRepositoryImpl {
             .await? as i32;
         Ok(count)
     }
+
+    async fn count_by_collection(&self) -> anyhow::Result<HashMap<i32, i32>> {
+        let rows: Vec<(i32, i64)> = Movie::find()
+            .select_only()
+            .column(Column::CollectionId)
+            .column_as(Column::Id.count(), "count")
+            .filter(Column::CollectionId.is_not_null())
+            .group_by(Column::CollectionId)
+            .into_tuple()
+            .all(&self.db)
+            .await?;
+        Ok(rows.into_iter().map(|(collection_id, count)| (collection_id, count as i32)).collect())
+    }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::{MovieCreditRepositoryImpl, PersonRepositoryImpl};
+    use domain::entity::{movie_credit, person};
+    use domain::repository::{MovieCreditRepository, PersonRepository};
+    use sea_orm::{ConnectionTrait, Database, Schema};
+
+    async fn setup() -> (DatabaseConnection, MovieRepositoryImpl) {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        // 只创建用到的表，关闭外键检查
+        db.execute_unprepared("PRAGMA foreign_keys = OFF").await.unwrap();
+        let backend = db.get_database_backend();
+        for stmt in [
+            Schema::new(backend).create_table_from_entity(Movie),
+            Schema::new(backend).create_table_from_entity(Person),
+            Schema::new(backend).create_table_from_entity(MovieCredit),
+        ] {
+            db.execute(&stmt).await.unwrap();
+        }
+        (db.clone(), MovieRepositoryImpl::new(db))
+    }
+
+    /// 插入一部电影，`genres` 按 JSON 数组保存
+    async fn insert(
+        repo: &MovieRepositoryImpl,
+        title: &str,
+        genres: &[&str],
+        release_date: &str,
+        size: (i32, i32),
+    ) -> MovieModel {
+        let mut movie = MovieModel::new(title.to_string(), format!("/movies/{}.mkv", title), 1);
+        movie.genres = Some(serde_json::to_string(genres).unwrap());
+        movie.release_date = Some(release_date.to_string());
+        (movie.width, movie.height) = size;
+        repo.create(movie).await.unwrap()
+    }
+
+    async fn titles(repo: &MovieRepositoryImpl, filter: MovieFilter) -> Vec<String> {
+        let (movies, _) = repo.find_by_filter(&filter, 100, 1).await.unwrap();
+        movies.into_iter().map(|movie| movie.title).collect()
+    }
+
+    #[tokio::test]
+    async fn test_filter_genre_and_year() {
+        let (_db, repo) = setup().await;
+        insert(&repo, "A", &["剧情"], "1999-12-31", (1920, 1080)).await;
+        insert(&repo, "B", &["剧情片"], "2000-01-01", (1920, 1080)).await;
+        insert(&repo, "C", &["100%_纪录"], "2001-06-15", (1920, 1080)).await;
+
+        // 按带引号的 JSON 字符串匹配，"剧情" 不匹配 "剧情片"
+        let filter = MovieFilter { genre: Some("剧情".to_string()), ..Default::default() };
+        assert_eq!(titles(&repo, filter).await, ["A"]);
+        // % 和 _ 按字面值匹配
+        let filter = MovieFilter { genre: Some("0%_纪".to_string()), ..Default::default() };
+        assert!(titles(&repo, filter).await.is_empty());
+        let filter = MovieFilter { genre: Some("100%_纪录".to_string()), ..Default::default() };
+        assert_eq!(titles(&repo, filter).await, ["C"]);
+
+        // 年份范围按字符串比较，上下限都包含
+        let filter = MovieFilter { year_from: Some(2000), year_to: Some(2000), ..Default::default() };
+        assert_eq!(titles(&repo, filter).await, ["B"]);
+        let filter = MovieFilter { year_to: Some(1999), ..Default::default() };
+        assert_eq!(titles(&repo, filter).await, ["A"]);
+        let filter = MovieFilter { year_from: Some(2001), ..Default::default() };
+        assert_eq!(titles(&repo, filter).await, ["C"]);
+        // 年份上限为 i32::MAX 时不溢出
+        let filter = MovieFilter { year_to: Some(i32::MAX), ..Default::default() };
+        assert_eq!(titles(&repo, filter).await, ["A", "B", "C"]);
+    }
+
+    #[tokio::test]
+    async fn test_filter_resolution() {
+        let (_db, repo) = setup().await;
+        insert(&repo, "4K", &[], "2020-01-01", (3840, 2160)).await;
+        insert(&repo, "1080p", &[], "2020-01-01", (1920, 1080)).await;
+        insert(&repo, "720p", &[], "2020-01-01", (1280, 720)).await;
+        insert(&repo, "480p", &[], "2020-01-01", (720, 480)).await;
+        insert(&repo, "未知", &[], "2020-01-01", (0, 0)).await;
+
+        let filter = |resolution| MovieFilter { resolution: Some(resolution), ..Default::default() };
+        assert_eq!(titles(&repo, filter(ResolutionClass::Uhd)).await, ["4K"]);
+        // 高清不包含超高清
+        assert_eq!(titles(&repo, filter(ResolutionClass::Hd)).await, ["1080p", "720p"]);
+        // 标清不包含没有探测到分辨率的电影
+        assert_eq!(titles(&repo, filter(ResolutionClass::Sd)).await, ["480p"]);
+    }
+
+    #[tokio::test]
+    async fn test_filter_person() {
+        let (db, repo) = setup().await;
+        let heat = insert(&repo, "Heat", &[], "1995-12-15", (1920, 1080)).await;
+        let ronin = insert(&repo, "Ronin", &[], "1998-09-25", (1920, 1080)).await;
+        insert(&repo, "Alien", &[], "1979-05-25", (1920, 1080)).await;
+
+        let persons = PersonRepositoryImpl::new(db.clone())
+            .upsert_batch(vec![
+                person::Model::new("Robert De Niro".to_string(), Some(380)),
+                person::Model::new("Michael Mann".to_string(), Some(638)),
+                person::Model::new("Robert_Writer".to_string(), None),
+            ])
+            .await
+            .unwrap();
+        let credit = |movie_id, person_id, role: &str| movie_credit::Model {
+            id: 0,
+            movie_id,
+            person_id,
+            role: role.to_string(),
+            character_name: None,
+            sort_order: 0,
+        };
+        MovieCreditRepositoryImpl::new(db)
+            .create_batch(vec![
+                credit(heat.id, persons[0].id, "actor"),
+                credit(ronin.id, persons[0].id, "actor"),
+                credit(heat.id, persons[1].id, "director"),
+                // 只匹配演员和导演
+                credit(ronin.id, persons[2].id, "writer"),
+            ])
+            .await
+            .unwrap();
+
+        let filter = |name: &str| MovieFilter { person: Some(name.to_string()), ..Default::default() };
+        assert_eq!(titles(&repo, filter("De Niro")).await, ["Heat", "Ronin"]);
+        assert_eq!(titles(&repo, filter("mann")).await, ["Heat"]);
+        assert!(titles(&repo, filter("Robert_W")).await.is_empty());
+        // _ 不匹配任意字符
+        assert!(titles(&repo, filter("Robert_De")).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_find_facets() {
+        let (_db, repo) = setup().await;
+        insert(&repo, "A", &["剧情", "犯罪"], "1995-12-15", (1920, 1080)).await;
+        insert(&repo, "B", &["犯罪", "动作"], "2010-07-16", (1920, 1080)).await;
+        let mut other = MovieModel::new("C".to_string(), "/other/C.mkv".to_string(), 2);
+        other.genres = Some(r#"["科幻"]"#.to_string());
+        other.release_date = Some("2014-11-07".to_string());
+        repo.create(other).await.unwrap();
+        // 没有类型和上映日期的电影不影响结果
+        repo.create(MovieModel::new("D".to_string(), "/movies/D.mkv".to_string(), 1)).await.unwrap();
+
+        let facets = repo.find_facets(Some(1)).await.unwrap();
+        assert_eq!(facets.genres, ["剧情", "动作", "犯罪"]);
+        assert_eq!(facets.years, [2010, 1995]);
+
+        let facets = repo.find_facets(None).await.unwrap();
+        assert_eq!(facets.genres, ["剧情", "动作", "犯罪", "科幻"]);
+        assert_eq!(facets.years, [2014, 2010, 1995]);
+    }
+}
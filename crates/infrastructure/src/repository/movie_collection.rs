@@ -0,0 +1,64 @@
+use async_trait::async_trait;
+use domain::entity::movie_collection::{ActiveModel, Column, Entity as MovieCollection, Model as MovieCollectionModel};
+use domain::repository::MovieCollectionRepository;
+use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set};
+
+pub struct MovieCollectionRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl MovieCollectionRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl MovieCollectionRepository for MovieCollectionRepositoryImpl {
+    async fn find_by_id(&self, id: i32) -> anyhow::Result<Option<MovieCollectionModel>> {
+        let collection = MovieCollection::find_by_id(id).one(&self.db).await?;
+        Ok(collection)
+    }
+
+    async fn find_by_tmdb_id(&self, tmdb_id: i64) -> anyhow::Result<Option<MovieCollectionModel>> {
+        let collection = MovieCollection::find()
+            .filter(Column::TmdbId.eq(tmdb_id))
+            .one(&self.db)
+            .await?;
+        Ok(collection)
+    }
+
+    async fn find_all(&self) -> anyhow::Result<Vec<MovieCollectionModel>> {
+        let collections = MovieCollection::find()
+            .order_by_asc(Column::Name)
+            .all(&self.db)
+            .await?;
+        Ok(collections)
+    }
+
+    async fn upsert(&self, collection: MovieCollectionModel) -> anyhow::Result<MovieCollectionModel> {
+        let existing = self.find_by_tmdb_id(collection.tmdb_id).await?;
+
+        let mut active_model = ActiveModel {
+            id: sea_orm::NotSet,
+            create_time: Set(collection.create_time),
+            update_time: Set(collection.update_time),
+            tmdb_id: Set(collection.tmdb_id),
+            name: Set(collection.name),
+            overview: Set(collection.overview),
+            poster_url: Set(collection.poster_url),
+            backdrop_url: Set(collection.backdrop_url),
+            parts: Set(collection.parts),
+        };
+
+        let saved = match existing {
+            Some(existing) => {
+                active_model.id = Set(existing.id);
+                active_model.create_time = Set(existing.create_time);
+                active_model.update(&self.db).await?
+            }
+            None => active_model.insert(&self.db).await?,
+        };
+        Ok(saved)
+    }
+}
@@ -0,0 +1,67 @@
+use async_trait::async_trait;
+use domain::entity::movie_credit::{ActiveModel, Column, Entity as MovieCredit, Model as MovieCreditModel};
+use domain::repository::MovieCreditRepository;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set, TransactionTrait,
+};
+
+pub struct MovieCreditRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl MovieCreditRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl MovieCreditRepository for MovieCreditRepositoryImpl {
+    async fn find_by_movie_id(&self, movie_id: i32) -> anyhow::Result<Vec<MovieCreditModel>> {
+        let credits = MovieCredit::find()
+            .filter(Column::MovieId.eq(movie_id))
+            .order_by_asc(Column::Role)
+            .order_by_asc(Column::SortOrder)
+            .all(&self.db)
+            .await?;
+        Ok(credits)
+    }
+
+    async fn find_by_person_id(&self, person_id: i32) -> anyhow::Result<Vec<MovieCreditModel>> {
+        let credits = MovieCredit::find()
+            .filter(Column::PersonId.eq(person_id))
+            .all(&self.db)
+            .await?;
+        Ok(credits)
+    }
+
+    async fn create_batch(&self, credits: Vec<MovieCreditModel>) -> anyhow::Result<Vec<MovieCreditModel>> {
+        let txn = self.db.begin().await?;
+        let mut results = Vec::new();
+
+        for credit in credits {
+            let active_model = ActiveModel {
+                id: sea_orm::NotSet,
+                movie_id: Set(credit.movie_id),
+                person_id: Set(credit.person_id),
+                role: Set(credit.role),
+                character_name: Set(credit.character_name),
+                sort_order: Set(credit.sort_order),
+            };
+
+            let result = active_model.insert(&txn).await?;
+            results.push(result);
+        }
+
+        txn.commit().await?;
+        Ok(results)
+    }
+
+    async fn delete_by_movie_id(&self, movie_id: i32) -> anyhow::Result<()> {
+        MovieCredit::delete_many()
+            .filter(Column::MovieId.eq(movie_id))
+            .exec(&self.db)
+            .await?;
+        Ok(())
+    }
+}
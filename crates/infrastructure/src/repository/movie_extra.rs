@@ -0,0 +1,66 @@
+use async_trait::async_trait;
+use domain::entity::movie_extra::{ActiveModel, Column, Entity as MovieExtra, Model as MovieExtraModel};
+use domain::repository::MovieExtraRepository;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set, TransactionTrait,
+};
+
+pub struct MovieExtraRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl MovieExtraRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl MovieExtraRepository for MovieExtraRepositoryImpl {
+    async fn find_by_id(&self, id: i32) -> anyhow::Result<Option<MovieExtraModel>> {
+        let extra = MovieExtra::find_by_id(id).one(&self.db).await?;
+        Ok(extra)
+    }
+
+    async fn find_by_movie_id(&self, movie_id: i32) -> anyhow::Result<Vec<MovieExtraModel>> {
+        let extras = MovieExtra::find()
+            .filter(Column::MovieId.eq(movie_id))
+            .order_by_asc(Column::ExtraType)
+            .order_by_asc(Column::Title)
+            .all(&self.db)
+            .await?;
+        Ok(extras)
+    }
+
+    async fn create_batch(&self, extras: Vec<MovieExtraModel>) -> anyhow::Result<Vec<MovieExtraModel>> {
+        let txn = self.db.begin().await?;
+        let mut results = Vec::new();
+
+        for extra in extras {
+            let active_model = ActiveModel {
+                id: sea_orm::NotSet,
+                movie_id: Set(extra.movie_id),
+                extra_type: Set(extra.extra_type),
+                title: Set(extra.title),
+                path: Set(extra.path),
+                byte_size: Set(extra.byte_size),
+                extension: Set(extra.extension),
+                duration: Set(extra.duration),
+            };
+
+            let result = active_model.insert(&txn).await?;
+            results.push(result);
+        }
+
+        txn.commit().await?;
+        Ok(results)
+    }
+
+    async fn delete_by_movie_id(&self, movie_id: i32) -> anyhow::Result<()> {
+        MovieExtra::delete_many()
+            .filter(Column::MovieId.eq(movie_id))
+            .exec(&self.db)
+            .await?;
+        Ok(())
+    }
+}
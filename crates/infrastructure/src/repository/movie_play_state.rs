@@ -0,0 +1,110 @@
+use async_trait::async_trait;
+use domain::entity::movie_play_state::{ActiveModel, Column, Entity as MoviePlayState, Model as MoviePlayStateModel};
+use domain::repository::MoviePlayStateRepository;
+use sea_orm::sea_query::OnConflict;
+use sea_orm::{ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set};
+
+pub struct MoviePlayStateRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl MoviePlayStateRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl MoviePlayStateRepository for MoviePlayStateRepositoryImpl {
+    async fn find_by_user_and_movie(&self, user_id: i32, movie_id: i32) -> anyhow::Result<Option<MoviePlayStateModel>> {
+        let state = MoviePlayState::find()
+            .filter(Column::UserId.eq(user_id))
+            .filter(Column::MovieId.eq(movie_id))
+            .one(&self.db)
+            .await?;
+        Ok(state)
+    }
+
+    async fn find_by_user_id(&self, user_id: i32) -> anyhow::Result<Vec<MoviePlayStateModel>> {
+        let states = MoviePlayState::find()
+            .filter(Column::UserId.eq(user_id))
+            .order_by_desc(Column::LastPlayedTime)
+            .all(&self.db)
+            .await?;
+        Ok(states)
+    }
+
+    async fn save(&self, state: MoviePlayStateModel) -> anyhow::Result<MoviePlayStateModel> {
+        let active_model = ActiveModel {
+            id: sea_orm::NotSet,
+            create_time: Set(state.create_time),
+            update_time: Set(state.update_time),
+            user_id: Set(state.user_id),
+            movie_id: Set(state.movie_id),
+            position: Set(state.position),
+            duration: Set(state.duration),
+            play_count: Set(state.play_count),
+            watched: Set(state.watched),
+            last_played_time: Set(state.last_played_time),
+        };
+
+        // 按 (UserId, MovieId) 唯一索引 upsert，同一电影的两次首次上报并发时不会因唯一约束失败
+        let saved = MoviePlayState::insert(active_model)
+            .on_conflict(
+                OnConflict::columns([Column::UserId, Column::MovieId])
+                    .update_columns([
+                        Column::UpdateTime,
+                        Column::Position,
+                        Column::Duration,
+                        Column::PlayCount,
+                        Column::Watched,
+                        Column::LastPlayedTime,
+                    ])
+                    .to_owned(),
+            )
+            .exec_with_returning(&self.db)
+            .await?;
+        Ok(saved)
+    }
+
+    async fn delete_by_movie_id(&self, movie_id: i32) -> anyhow::Result<()> {
+        MoviePlayState::delete_many()
+            .filter(Column::MovieId.eq(movie_id))
+            .exec(&self.db)
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sea_orm::{ConnectionTrait, Database, Schema};
+
+    #[tokio::test]
+    async fn test_save_upserts_by_user_and_movie() {
+        let db = Database::connect("sqlite::memory:").await.unwrap();
+        // 只创建播放状态表，关闭外键检查
+        db.execute_unprepared("PRAGMA foreign_keys = OFF").await.unwrap();
+        let stmt = Schema::new(db.get_database_backend()).create_table_from_entity(MoviePlayState);
+        db.execute(&stmt).await.unwrap();
+        db.execute_unprepared("CREATE UNIQUE INDEX idx_movie_play_state_user_movie ON MoviePlayState(UserId, MovieId)")
+            .await
+            .unwrap();
+        let repo = MoviePlayStateRepositoryImpl::new(db);
+
+        // 两次并发的首次上报都以新状态保存
+        let mut first = MoviePlayStateModel::new(1, 7);
+        first.record_progress(60, 6000);
+        let first = repo.save(first).await.unwrap();
+        let mut second = MoviePlayStateModel::new(1, 7);
+        second.record_progress(90, 6000);
+        let second = repo.save(second).await.unwrap();
+
+        assert_eq!(second.id, first.id);
+        assert_eq!(second.position, 90);
+        let states = repo.find_by_user_id(1).await.unwrap();
+        assert_eq!(states.len(), 1);
+        assert_eq!(states[0].position, 90);
+    }
+}
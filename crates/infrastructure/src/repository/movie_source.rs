@@ -0,0 +1,65 @@
+use async_trait::async_trait;
+use domain::entity::movie_source::{ActiveModel, Column, Entity as MovieSource, Model as MovieSourceModel};
+use domain::repository::MovieSourceRepository;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set, TransactionTrait,
+};
+
+pub struct MovieSourceRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl MovieSourceRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl MovieSourceRepository for MovieSourceRepositoryImpl {
+    async fn find_by_movie_id(&self, movie_id: i32) -> anyhow::Result<Vec<MovieSourceModel>> {
+        let sources = MovieSource::find()
+            .filter(Column::MovieId.eq(movie_id))
+            .order_by_asc(Column::VersionIndex)
+            .order_by_asc(Column::PartIndex)
+            .all(&self.db)
+            .await?;
+        Ok(sources)
+    }
+
+    async fn create_batch(&self, sources: Vec<MovieSourceModel>) -> anyhow::Result<Vec<MovieSourceModel>> {
+        let txn = self.db.begin().await?;
+        let mut results = Vec::new();
+
+        for source in sources {
+            let active_model = ActiveModel {
+                id: sea_orm::NotSet,
+                movie_id: Set(source.movie_id),
+                version_index: Set(source.version_index),
+                part_index: Set(source.part_index),
+                label: Set(source.label),
+                path: Set(source.path),
+                byte_size: Set(source.byte_size),
+                extension: Set(source.extension),
+                duration: Set(source.duration),
+                width: Set(source.width),
+                height: Set(source.height),
+                resolution: Set(source.resolution),
+            };
+
+            let result = active_model.insert(&txn).await?;
+            results.push(result);
+        }
+
+        txn.commit().await?;
+        Ok(results)
+    }
+
+    async fn delete_by_movie_id(&self, movie_id: i32) -> anyhow::Result<()> {
+        MovieSource::delete_many()
+            .filter(Column::MovieId.eq(movie_id))
+            .exec(&self.db)
+            .await?;
+        Ok(())
+    }
+}
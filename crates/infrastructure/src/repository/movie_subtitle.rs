@@ -0,0 +1,66 @@
+use async_trait::async_trait;
+use domain::entity::movie_subtitle::{ActiveModel, Column, Entity as MovieSubtitle, Model as MovieSubtitleModel};
+use domain::repository::MovieSubtitleRepository;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set, TransactionTrait,
+};
+
+pub struct MovieSubtitleRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl MovieSubtitleRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl MovieSubtitleRepository for MovieSubtitleRepositoryImpl {
+    async fn find_by_id(&self, id: i32) -> anyhow::Result<Option<MovieSubtitleModel>> {
+        let subtitle = MovieSubtitle::find_by_id(id).one(&self.db).await?;
+        Ok(subtitle)
+    }
+
+    async fn find_by_movie_id(&self, movie_id: i32) -> anyhow::Result<Vec<MovieSubtitleModel>> {
+        let subtitles = MovieSubtitle::find()
+            .filter(Column::MovieId.eq(movie_id))
+            .order_by_asc(Column::Id)
+            .all(&self.db)
+            .await?;
+        Ok(subtitles)
+    }
+
+    async fn create_batch(&self, subtitles: Vec<MovieSubtitleModel>) -> anyhow::Result<Vec<MovieSubtitleModel>> {
+        let txn = self.db.begin().await?;
+        let mut results = Vec::new();
+
+        for subtitle in subtitles {
+            let active_model = ActiveModel {
+                id: sea_orm::NotSet,
+                movie_id: Set(subtitle.movie_id),
+                path: Set(subtitle.path),
+                format: Set(subtitle.format),
+                language: Set(subtitle.language),
+                title: Set(subtitle.title),
+                is_default: Set(subtitle.is_default),
+                is_forced: Set(subtitle.is_forced),
+                is_sdh: Set(subtitle.is_sdh),
+            };
+
+            let result = active_model.insert(&txn).await?;
+            results.push(result);
+        }
+
+        txn.commit().await?;
+        Ok(results)
+    }
+
+    async fn delete_by_movie_id(&self, movie_id: i32) -> anyhow::Result<()> {
+        MovieSubtitle::delete_many()
+            .filter(Column::MovieId.eq(movie_id))
+            .exec(&self.db)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,69 @@
+use async_trait::async_trait;
+use domain::entity::movie_track::{ActiveModel, Column, Entity as MovieTrack, Model as MovieTrackModel};
+use domain::repository::MovieTrackRepository;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, QueryOrder, Set, TransactionTrait,
+};
+
+pub struct MovieTrackRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl MovieTrackRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl MovieTrackRepository for MovieTrackRepositoryImpl {
+    async fn find_by_movie_id(&self, movie_id: i32) -> anyhow::Result<Vec<MovieTrackModel>> {
+        let tracks = MovieTrack::find()
+            .filter(Column::MovieId.eq(movie_id))
+            .order_by_asc(Column::TrackType)
+            .order_by_asc(Column::TrackIndex)
+            .all(&self.db)
+            .await?;
+        Ok(tracks)
+    }
+
+    async fn create_batch(&self, tracks: Vec<MovieTrackModel>) -> anyhow::Result<Vec<MovieTrackModel>> {
+        let txn = self.db.begin().await?;
+        let mut results = Vec::new();
+
+        for track in tracks {
+            let active_model = ActiveModel {
+                id: sea_orm::NotSet,
+                movie_id: Set(track.movie_id),
+                track_type: Set(track.track_type),
+                track_index: Set(track.track_index),
+                codec: Set(track.codec),
+                language: Set(track.language),
+                title: Set(track.title),
+                is_default: Set(track.is_default),
+                is_forced: Set(track.is_forced),
+                width: Set(track.width),
+                height: Set(track.height),
+                frame_rate: Set(track.frame_rate),
+                is_hdr: Set(track.is_hdr),
+                bit_rate: Set(track.bit_rate),
+                channels: Set(track.channels),
+                sample_rate: Set(track.sample_rate),
+            };
+
+            let result = active_model.insert(&txn).await?;
+            results.push(result);
+        }
+
+        txn.commit().await?;
+        Ok(results)
+    }
+
+    async fn delete_by_movie_id(&self, movie_id: i32) -> anyhow::Result<()> {
+        MovieTrack::delete_many()
+            .filter(Column::MovieId.eq(movie_id))
+            .exec(&self.db)
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,86 @@
+use async_trait::async_trait;
+use domain::entity::person::{ActiveModel, Column, Entity as Person, Model as PersonModel};
+use domain::repository::PersonRepository;
+use sea_orm::{ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, QueryFilter, Set, TransactionTrait};
+
+pub struct PersonRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl PersonRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl PersonRepository for PersonRepositoryImpl {
+    async fn find_by_id(&self, id: i32) -> anyhow::Result<Option<PersonModel>> {
+        let person = Person::find_by_id(id).one(&self.db).await?;
+        Ok(person)
+    }
+
+    async fn find_by_ids(&self, ids: Vec<i32>) -> anyhow::Result<Vec<PersonModel>> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let persons = Person::find().filter(Column::Id.is_in(ids)).all(&self.db).await?;
+        Ok(persons)
+    }
+
+    async fn upsert_batch(&self, persons: Vec<PersonModel>) -> anyhow::Result<Vec<PersonModel>> {
+        let txn = self.db.begin().await?;
+        let mut results = Vec::new();
+
+        for person in persons {
+            let existing = match person.tmdb_id {
+                Some(tmdb_id) => match Person::find().filter(Column::TmdbId.eq(tmdb_id)).one(&txn).await? {
+                    Some(existing) => Some(existing),
+                    // 没有 TMDB ID 的同名人物（NFO 或旧数据迁移）获得 TMDB ID 后合并为同一人
+                    None => {
+                        Person::find()
+                            .filter(Column::Name.eq(person.name.as_str()))
+                            .filter(Column::TmdbId.is_null())
+                            .one(&txn)
+                            .await?
+                    }
+                },
+                // 只有姓名时沿用同名人物
+                None => Person::find().filter(Column::Name.eq(person.name.as_str())).one(&txn).await?,
+            };
+
+            let saved = match existing {
+                // 已有人物更新姓名（TMDB 按语言返回不同的译名）并补充 TMDB ID 和头像，没有变化时不写入
+                Some(existing) => {
+                    let tmdb_id = person.tmdb_id.or(existing.tmdb_id);
+                    let profile_url = person.profile_url.or(existing.profile_url.clone());
+                    if existing.name == person.name && existing.tmdb_id == tmdb_id && existing.profile_url == profile_url {
+                        existing
+                    } else {
+                        let mut active_model: ActiveModel = existing.into();
+                        active_model.name = Set(person.name);
+                        active_model.tmdb_id = Set(tmdb_id);
+                        active_model.profile_url = Set(profile_url);
+                        active_model.update_time = Set(person.update_time);
+                        active_model.update(&txn).await?
+                    }
+                }
+                None => {
+                    let active_model = ActiveModel {
+                        id: sea_orm::NotSet,
+                        create_time: Set(person.create_time),
+                        update_time: Set(person.update_time),
+                        name: Set(person.name),
+                        tmdb_id: Set(person.tmdb_id),
+                        profile_url: Set(person.profile_url),
+                    };
+                    active_model.insert(&txn).await?
+                }
+            };
+            results.push(saved);
+        }
+
+        txn.commit().await?;
+        Ok(results)
+    }
+}
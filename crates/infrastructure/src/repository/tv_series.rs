@@ -0,0 +1,261 @@
+use async_trait::async_trait;
+use domain::entity::tv_episode::{
+    ActiveModel as TvEpisodeActiveModel, Column as TvEpisodeColumn, Entity as TvEpisode, Model as TvEpisodeModel,
+};
+use domain::entity::tv_season::{
+    ActiveModel as TvSeasonActiveModel, Column as TvSeasonColumn, Entity as TvSeason, Model as TvSeasonModel,
+};
+use domain::entity::tv_series::{ActiveModel, Column, Entity as TvSeries, Model as TvSeriesModel};
+use domain::repository::{TvEpisodeRepository, TvSeasonRepository, TvSeriesRepository};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, DatabaseConnection, EntityTrait, PaginatorTrait, QueryFilter,
+    QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+
+pub struct TvSeriesRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl TvSeriesRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl TvSeriesRepository for TvSeriesRepositoryImpl {
+    async fn find_by_id(&self, id: i32) -> anyhow::Result<Option<TvSeriesModel>> {
+        let series = TvSeries::find_by_id(id).one(&self.db).await?;
+        Ok(series)
+    }
+
+    async fn find_by_paged(
+        &self,
+        page_size: i32,
+        page_index: i32,
+    ) -> anyhow::Result<Option<Vec<TvSeriesModel>>> {
+        // 验证参数
+        if page_size <= 0 || page_index <= 0 {
+            return Ok(None);
+        }
+
+        // 计算偏移量（page_index 从 1 开始）
+        let offset = ((page_index - 1) * page_size) as u64;
+        let limit = page_size as u64;
+
+        let series = TvSeries::find()
+            .order_by_asc(Column::Title)
+            .offset(offset)
+            .limit(limit)
+            .all(&self.db)
+            .await?;
+
+        if series.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(series))
+        }
+    }
+
+    async fn find_by_media_library_id(&self, media_library_id: i32) -> anyhow::Result<Vec<TvSeriesModel>> {
+        let series = TvSeries::find()
+            .filter(Column::MediaLibraryId.eq(media_library_id))
+            .order_by_asc(Column::Title)
+            .all(&self.db)
+            .await?;
+        Ok(series)
+    }
+
+    async fn create_batch(&self, series: Vec<TvSeriesModel>) -> anyhow::Result<Vec<TvSeriesModel>> {
+        if series.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        tracing::info!("Starting batch insert of {} tv series", series.len());
+
+        let txn = self.db.begin().await?;
+        let mut created_series = Vec::new();
+
+        for item in series {
+            let active_model = ActiveModel {
+                id: sea_orm::NotSet,
+                create_time: Set(item.create_time),
+                update_time: Set(item.update_time),
+                title: Set(item.title),
+                original_title: Set(item.original_title),
+                description: Set(item.description),
+                path: Set(item.path),
+                first_air_date: Set(item.first_air_date),
+                rating: Set(item.rating),
+                votes: Set(item.votes),
+                genres: Set(item.genres),
+                actors: Set(item.actors),
+                poster_urls: Set(item.poster_urls),
+                cover: Set(item.cover),
+                tmdb_id: Set(item.tmdb_id),
+                season_count: Set(item.season_count),
+                episode_count: Set(item.episode_count),
+                media_library_id: Set(item.media_library_id),
+            };
+
+            let created = active_model.insert(&txn).await?;
+            created_series.push(created);
+        }
+
+        txn.commit().await?;
+
+        tracing::info!("Successfully inserted {} tv series", created_series.len());
+        Ok(created_series)
+    }
+
+    async fn delete(&self, id: i32) -> anyhow::Result<()> {
+        TvSeries::delete_by_id(id).exec(&self.db).await?;
+        Ok(())
+    }
+
+    async fn count_all(&self) -> anyhow::Result<i32> {
+        let count = TvSeries::find().count(&self.db).await? as i32;
+        Ok(count)
+    }
+}
+
+// ==================== TvSeasonRepositoryImpl ====================
+
+pub struct TvSeasonRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl TvSeasonRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl TvSeasonRepository for TvSeasonRepositoryImpl {
+    async fn find_by_series_id(&self, series_id: i32) -> anyhow::Result<Vec<TvSeasonModel>> {
+        let seasons = TvSeason::find()
+            .filter(TvSeasonColumn::SeriesId.eq(series_id))
+            .order_by_asc(TvSeasonColumn::SeasonNumber)
+            .all(&self.db)
+            .await?;
+        Ok(seasons)
+    }
+
+    async fn create_batch(&self, seasons: Vec<TvSeasonModel>) -> anyhow::Result<Vec<TvSeasonModel>> {
+        let txn = self.db.begin().await?;
+        let mut results = Vec::new();
+
+        for season in seasons {
+            let active_model = TvSeasonActiveModel {
+                id: sea_orm::NotSet,
+                series_id: Set(season.series_id),
+                season_number: Set(season.season_number),
+                title: Set(season.title),
+                description: Set(season.description),
+                air_date: Set(season.air_date),
+                cover: Set(season.cover),
+                episode_count: Set(season.episode_count),
+            };
+
+            let result = active_model.insert(&txn).await?;
+            results.push(result);
+        }
+
+        txn.commit().await?;
+        Ok(results)
+    }
+
+    async fn delete_by_series_id(&self, series_id: i32) -> anyhow::Result<()> {
+        TvSeason::delete_many()
+            .filter(TvSeasonColumn::SeriesId.eq(series_id))
+            .exec(&self.db)
+            .await?;
+        Ok(())
+    }
+}
+
+// ==================== TvEpisodeRepositoryImpl ====================
+
+pub struct TvEpisodeRepositoryImpl {
+    db: DatabaseConnection,
+}
+
+impl TvEpisodeRepositoryImpl {
+    pub fn new(db: DatabaseConnection) -> Self {
+        Self { db }
+    }
+}
+
+#[async_trait]
+impl TvEpisodeRepository for TvEpisodeRepositoryImpl {
+    async fn find_by_id(&self, id: i32) -> anyhow::Result<Option<TvEpisodeModel>> {
+        let episode = TvEpisode::find_by_id(id).one(&self.db).await?;
+        Ok(episode)
+    }
+
+    async fn find_by_season_id(&self, season_id: i32) -> anyhow::Result<Vec<TvEpisodeModel>> {
+        let episodes = TvEpisode::find()
+            .filter(TvEpisodeColumn::SeasonId.eq(season_id))
+            .order_by_asc(TvEpisodeColumn::EpisodeNumber)
+            .all(&self.db)
+            .await?;
+        Ok(episodes)
+    }
+
+    async fn find_by_series_id(&self, series_id: i32) -> anyhow::Result<Vec<TvEpisodeModel>> {
+        let episodes = TvEpisode::find()
+            .filter(TvEpisodeColumn::SeriesId.eq(series_id))
+            .order_by_asc(TvEpisodeColumn::SeasonNumber)
+            .order_by_asc(TvEpisodeColumn::EpisodeNumber)
+            .all(&self.db)
+            .await?;
+        Ok(episodes)
+    }
+
+    async fn create_batch(&self, episodes: Vec<TvEpisodeModel>) -> anyhow::Result<Vec<TvEpisodeModel>> {
+        let txn = self.db.begin().await?;
+        let mut results = Vec::new();
+
+        for episode in episodes {
+            let active_model = TvEpisodeActiveModel {
+                id: sea_orm::NotSet,
+                create_time: Set(episode.create_time),
+                update_time: Set(episode.update_time),
+                series_id: Set(episode.series_id),
+                season_id: Set(episode.season_id),
+                season_number: Set(episode.season_number),
+                episode_number: Set(episode.episode_number),
+                episode_number_end: Set(episode.episode_number_end),
+                absolute_number: Set(episode.absolute_number),
+                release_group: Set(episode.release_group),
+                title: Set(episode.title),
+                description: Set(episode.description),
+                air_date: Set(episode.air_date),
+                path: Set(episode.path),
+                byte_size: Set(episode.byte_size),
+                extension: Set(episode.extension),
+                duration: Set(episode.duration),
+                width: Set(episode.width),
+                height: Set(episode.height),
+                resolution: Set(episode.resolution),
+                still: Set(episode.still),
+                rating: Set(episode.rating),
+            };
+
+            let result = active_model.insert(&txn).await?;
+            results.push(result);
+        }
+
+        txn.commit().await?;
+        Ok(results)
+    }
+
+    async fn delete_by_series_id(&self, series_id: i32) -> anyhow::Result<()> {
+        TvEpisode::delete_many()
+            .filter(TvEpisodeColumn::SeriesId.eq(series_id))
+            .exec(&self.db)
+            .await?;
+        Ok(())
+    }
+}
@@ -1,13 +1,16 @@
 use crate::app::AppState;
 use crate::error::{ApiResult, AppError};
 use crate::response::ApiResponse;
+use application::auth_service::AuthService;
 use application::user_service::UserService;
 use application::dto::{LoginRequest, LoginResponse, RegisterRequest, UserInfo};
-use axum::extract::State;
-use axum::http::StatusCode;
+use axum::extract::{FromRequestParts, State};
+use axum::http::request::Parts;
+use axum::http::{header, StatusCode};
 use axum::response::IntoResponse;
 use axum::routing;
 use axum::Router;
+use infrastructure::jwt::JwtService;
 
 pub fn create_router() -> Router<AppState> {
     Router::new()
@@ -15,6 +18,26 @@ pub fn create_router() -> Router<AppState> {
         .route("/register", routing::post(register))
 }
 
+/// 当前登录用户（从 `Authorization: Bearer <token>` 中解析用户 ID）
+///
+/// 作为处理函数的参数使用，没有或无效的 Token 返回 401
+pub struct CurrentUser(pub i32);
+
+impl<S: Send + Sync> FromRequestParts<S> for CurrentUser {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let auth_header = parts
+            .headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .ok_or(AppError::Unauthorized)?;
+        let token = JwtService::extract_token_from_header(auth_header).map_err(|_| AppError::Unauthorized)?;
+        let user_id = AuthService::verify_and_get_user_id(&token).map_err(|_| AppError::Unauthorized)?;
+        Ok(CurrentUser(user_id))
+    }
+}
+
 /// 用户登录
 ///
 /// 使用邮箱和密码进行登录，成功后返回 JWT Token
@@ -1,13 +1,17 @@
 use crate::app::AppState;
 use crate::error::{ApiResult, AppError};
 use crate::response::ApiResponse;
-use application::dto::{MangaInfo, PagedResponse, PaginationQuery, OptimizedImageListResponse, ThumbnailQuery};
+use application::image_service::CbzExport;
+use application::dto::{MangaHealthQuery, MangaHealthReport, MangaInfo, MangaMetadataInfo, MangaMetadataQuery, MangaMetadataSearchInfo, PagedResponse, PaginationQuery, OptimizedImageListResponse, ThumbnailQuery, UpdateMangaCoverRequest};
 use axum::body::Body;
 use axum::extract::{State, Path, Query};
 use axum::http::{HeaderMap, StatusCode, header, Response};
 use axum::response::IntoResponse;
 use axum::routing;
 use axum::Router;
+use tokio::sync::mpsc;
+use tokio_stream::StreamExt;
+use tokio_stream::wrappers::ReceiverStream;
 use tokio_util::io::ReaderStream;
 
 // region: 根据 ID 查询漫画
@@ -292,8 +296,280 @@ pub async fn get_manga_cover(
 }
 // endregion
 
+// region: 设置漫画的封面页
+
+/// 手动设置漫画的封面页
+///
+/// - 单文件夹漫画：只需传 `page_index`
+/// - 章节漫画：需同时传 `chapter_id` 和 `page_index`
+/// - 不传 `page_index` 则恢复自动选择封面
+#[utoipa::path(
+    put,
+    path = "/api/manga/{mangaId}/cover",
+    tag = "manga",
+    request_body = UpdateMangaCoverRequest,
+    responses(
+        (status = 200, description = "设置成功", body = ApiResponse<MangaInfo>),
+        (status = 404, description = "漫画不存在"),
+        (status = 500, description = "页索引超出范围或章节不属于该漫画"),
+    )
+)]
+pub async fn update_manga_cover(
+    State(AppState { image_service, .. }): State<AppState>,
+    Path(manga_id): Path<i32>,
+    axum::Json(req): axum::Json<UpdateMangaCoverRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let manga = image_service
+        .set_manga_cover_page(manga_id, req.chapter_id, req.page_index)
+        .await
+        .map_err(|e| AppError::Biz(e.to_string()))?;
+
+    let response = ApiResponse::ok(
+        Some("Update manga cover successful"),
+        Some(MangaInfo::from(manga)),
+        None,
+        None,
+    );
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+// endregion
+
+// region: 下载漫画（CBZ）
+
+/// 将漫画打包为 CBZ 下载
+///
+/// 压缩包边生成边传输，不会把整个文件加载到内存。
+/// 章节漫画的每个章节位于单独的子目录中，并附带 ComicInfo.xml 元数据
+#[utoipa::path(
+    get,
+    path = "/api/manga/{mangaId}/download",
+    tag = "manga",
+    responses(
+        (status = 200, description = "下载成功（application/vnd.comicbook+zip）"),
+        (status = 400, description = "压缩包超过 4GB 或 65535 个条目"),
+        (status = 404, description = "漫画不存在"),
+    )
+)]
+pub async fn download_manga(
+    State(AppState { image_service, .. }): State<AppState>,
+    Path(manga_id): Path<i32>,
+) -> Result<Response<Body>, AppError> {
+    let export = image_service
+        .prepare_manga_cbz(manga_id)
+        .await
+        .map_err(|e| AppError::Biz(e.to_string()))?;
+
+    Ok(stream_cbz(export))
+}
+// endregion
+
+// region: 损坏页面检查
+
+/// 获取存在损坏页面的漫画列表
+///
+/// 损坏页面在扫描时检测（文件为空、无法解析图片头或文件被截断），阅读时会自动跳过
+#[utoipa::path(
+    get,
+    path = "/api/manga/health",
+    tag = "manga",
+    params(
+        ("media_library_id" = Option<i32>, Query, description = "只查询指定媒体库，不传则查询全部"),
+    ),
+    responses(
+        (status = 200, description = "查询成功", body = ApiResponse<Vec<MangaHealthReport>>),
+    )
+)]
+pub async fn get_manga_health_report(
+    State(AppState { image_service, .. }): State<AppState>,
+    Query(params): Query<MangaHealthQuery>,
+) -> ApiResult<impl IntoResponse> {
+    let reports = image_service.get_health_report(params.media_library_id).await
+        .map_err(|e| AppError::Biz(e.to_string()))?;
+
+    let response = ApiResponse::ok(
+        Some("Get manga health report successful"),
+        Some(reports),
+        None,
+        None,
+    );
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+
+/// 获取单本漫画的损坏页面
+#[utoipa::path(
+    get,
+    path = "/api/manga/{mangaId}/health",
+    tag = "manga",
+    responses(
+        (status = 200, description = "查询成功", body = ApiResponse<MangaHealthReport>),
+        (status = 404, description = "漫画不存在"),
+    )
+)]
+pub async fn get_manga_health(
+    State(AppState { image_service, .. }): State<AppState>,
+    Path(manga_id): Path<i32>,
+) -> ApiResult<impl IntoResponse> {
+    let report = image_service.get_manga_health(manga_id).await
+        .map_err(|e| AppError::Biz(e.to_string()))?;
+
+    let response = ApiResponse::ok(
+        Some("Get manga health successful"),
+        Some(report),
+        None,
+        None,
+    );
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+
+/// 重新检查漫画的所有页面
+///
+/// 替换损坏的图片后调用，刷新损坏页面列表
+#[utoipa::path(
+    post,
+    path = "/api/manga/{mangaId}/health/check",
+    tag = "manga",
+    responses(
+        (status = 200, description = "检查完成", body = ApiResponse<MangaHealthReport>),
+        (status = 404, description = "漫画不存在"),
+    )
+)]
+pub async fn check_manga_health(
+    State(AppState { image_service, .. }): State<AppState>,
+    Path(manga_id): Path<i32>,
+) -> ApiResult<impl IntoResponse> {
+    let report = image_service.recheck_manga_pages(manga_id).await
+        .map_err(|e| AppError::Biz(e.to_string()))?;
+
+    let response = ApiResponse::ok(
+        Some("Check manga pages successful"),
+        Some(report),
+        None,
+        None,
+    );
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+// endregion
+
+// region: 在线元数据
+
+/// 为漫画搜索在线元数据候选
+///
+/// 默认使用去掉卷号后的漫画标题搜索，也可以通过 `title` 参数手动指定关键词
+#[utoipa::path(
+    get,
+    path = "/api/manga/{mangaId}/metadata",
+    tag = "manga",
+    params(
+        ("title" = Option<String>, Query, description = "搜索关键词，不传则使用漫画标题"),
+    ),
+    responses(
+        (status = 200, description = "搜索成功", body = ApiResponse<Vec<MangaMetadataSearchInfo>>),
+        (status = 404, description = "漫画不存在"),
+        (status = 500, description = "元数据提供者未启用或请求失败"),
+    )
+)]
+pub async fn search_manga_metadata(
+    State(AppState { manga_metadata_service, .. }): State<AppState>,
+    Path(manga_id): Path<i32>,
+    Query(params): Query<MangaMetadataQuery>,
+) -> ApiResult<impl IntoResponse> {
+    let results = match params.title {
+        Some(title) => manga_metadata_service.search(&title).await,
+        None => manga_metadata_service.search_for_manga(manga_id).await,
+    }
+    .map_err(|e| AppError::Biz(e.to_string()))?;
+
+    let response = ApiResponse::ok(
+        Some("Search manga metadata successful"),
+        Some(results),
+        None,
+        None,
+    );
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+
+/// 获取在线元数据详情（作者、类型、连载状态、简介和封面）
+#[utoipa::path(
+    get,
+    path = "/api/manga/metadata/{providerId}",
+    tag = "manga",
+    responses(
+        (status = 200, description = "获取成功", body = ApiResponse<MangaMetadataInfo>),
+        (status = 500, description = "元数据提供者未启用或请求失败"),
+    )
+)]
+pub async fn get_manga_metadata(
+    State(AppState { manga_metadata_service, .. }): State<AppState>,
+    Path(provider_id): Path<u64>,
+) -> ApiResult<impl IntoResponse> {
+    let details = manga_metadata_service.get_details(provider_id).await
+        .map_err(|e| AppError::Biz(e.to_string()))?;
+
+    let response = ApiResponse::ok(
+        Some("Get manga metadata successful"),
+        Some(details),
+        None,
+        None,
+    );
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+// endregion
+
 // region: 工具函数
 
+/// 在后台任务中生成 CBZ，并通过内存管道以流的方式返回给客户端
+///
+/// 管道缓冲区固定为 64KB，客户端读取慢时写入端会自动等待。
+/// 响应没有 Content-Length，生成失败时向响应体发送错误让连接中断，
+/// 否则客户端会把截断的压缩包当作下载成功
+pub(crate) fn stream_cbz(export: CbzExport) -> Response<Body> {
+    // 非 ASCII 文件名通过 filename* 传递，filename 作为旧客户端的兜底
+    let ascii_file_name: String = export
+        .file_name
+        .chars()
+        .map(|c| if c.is_ascii() && c != '"' { c } else { '_' })
+        .collect();
+    let content_disposition = format!(
+        "attachment; filename=\"{}\"; filename*=UTF-8''{}",
+        ascii_file_name,
+        urlencoding::encode(&export.file_name)
+    );
+
+    let (writer, reader) = tokio::io::duplex(64 * 1024);
+    let (sender, receiver) = mpsc::channel(4);
+    tokio::spawn(async move {
+        let file_name = export.file_name.clone();
+        let forward = async {
+            let mut chunks = ReaderStream::new(reader);
+            while let Some(chunk) = chunks.next().await {
+                if sender.send(chunk).await.is_err() {
+                    break; // 客户端已断开
+                }
+            }
+        };
+        let (result, ()) = tokio::join!(export.write_to(writer), forward);
+        if let Err(e) = result {
+            tracing::warn!("Failed to stream CBZ {}: {}", file_name, e);
+            let _ = sender.send(Err(std::io::Error::other(e))).await;
+        }
+    });
+
+    let body = Body::from_stream(ReceiverStream::new(receiver));
+
+    Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, "application/vnd.comicbook+zip")
+        .header(header::CONTENT_DISPOSITION, content_disposition)
+        .body(body)
+        .unwrap()
+}
+
 /// 解析 Range 请求头
 ///
 /// 支持格式：bytes=start-end
@@ -419,8 +695,14 @@ async fn serve_range(
 pub fn routes() -> Router<AppState> {
     Router::new()
         .route("/", routing::get(get_manga_paged))
+        .route("/health", routing::get(get_manga_health_report))
         .route("/{manga_id}", routing::get(get_manga))
-        .route("/{manga_id}/cover", routing::get(get_manga_cover))
+        .route("/{manga_id}/cover", routing::get(get_manga_cover).put(update_manga_cover))
+        .route("/{manga_id}/download", routing::get(download_manga))
+        .route("/{manga_id}/health", routing::get(get_manga_health))
+        .route("/{manga_id}/health/check", routing::post(check_manga_health))
+        .route("/{manga_id}/metadata", routing::get(search_manga_metadata))
+        .route("/metadata/{provider_id}", routing::get(get_manga_metadata))
         .route("/{manga_id}/images", routing::get(get_manga_images))
         .route("/{manga_id}/images/{image_index}", routing::get(get_manga_image))
 }
\ No newline at end of file
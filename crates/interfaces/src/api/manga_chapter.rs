@@ -1,7 +1,7 @@
 use crate::app::AppState;
 use crate::error::{ApiResult, AppError};
 use crate::response::ApiResponse;
-use application::dto::{MangaChapterInfo, OptimizedChapterImageListResponse, ThumbnailQuery};
+use application::dto::{MangaChapterInfo, OptimizedChapterImageListResponse, ThumbnailQuery, UpdateChapterCoverRequest};
 use axum::Router;
 use axum::body::Body;
 use axum::extract::{Path, Query, State};
@@ -227,11 +227,84 @@ pub async fn get_chapter_cover(
 }
 // endregion
 
+// region: 设置章节的封面页
+
+/// 手动设置章节的封面页
+///
+/// 不传 `page_index` 则恢复自动选择封面
+#[utoipa::path(
+    put,
+    path = "/api/manga_chapter/{mangaId}/{chapterId}/cover",
+    tag = "manga_chapter",
+    request_body = UpdateChapterCoverRequest,
+    responses(
+        (status = 200, description = "设置成功", body = ApiResponse<MangaChapterInfo>),
+        (status = 404, description = "章节不存在"),
+        (status = 500, description = "页索引超出范围或章节不属于该漫画"),
+    )
+)]
+pub async fn update_chapter_cover(
+    State(AppState { image_service, .. }): State<AppState>,
+    Path((manga_id, chapter_id)): Path<(i32, i32)>,
+    axum::Json(req): axum::Json<UpdateChapterCoverRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let chapter = image_service
+        .set_chapter_cover_page(manga_id, chapter_id, req.page_index)
+        .await
+        .map_err(|e| AppError::Biz(e.to_string()))?;
+
+    let response = ApiResponse::ok(
+        Some("Update chapter cover successful"),
+        Some(MangaChapterInfo::from(chapter)),
+        None,
+        None,
+    );
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+// endregion
+
+// region: 下载章节（CBZ）
+
+/// 将单个章节打包为 CBZ 下载
+///
+/// 压缩包边生成边传输，不会把整个文件加载到内存，并附带 ComicInfo.xml 元数据
+#[utoipa::path(
+    get,
+    path = "/api/manga_chapter/{mangaId}/{chapterId}/download",
+    tag = "manga_chapter",
+    responses(
+        (status = 200, description = "下载成功（application/vnd.comicbook+zip）"),
+        (status = 400, description = "压缩包超过 4GB 或 65535 个条目"),
+        (status = 404, description = "章节不存在"),
+    )
+)]
+pub async fn download_chapter(
+    State(AppState { image_service, .. }): State<AppState>,
+    Path((manga_id, chapter_id)): Path<(i32, i32)>,
+) -> Result<Response<Body>, AppError> {
+    let export = image_service
+        .prepare_chapter_cbz(manga_id, chapter_id)
+        .await
+        .map_err(|e| AppError::Biz(e.to_string()))?;
+
+    Ok(super::manga::stream_cbz(export))
+}
+// endregion
+
 /// 章节路由
 pub fn routes() -> Router<AppState> {
     Router::new()
         .route("/{manga_id}/chapters", routing::get(get_manga_chapters))
         .route("/{manga_id}/cover", routing::get(get_chapter_cover))  // 获取第一章的封面
+        .route(
+            "/{manga_id}/{chapter_id}/cover",
+            routing::put(update_chapter_cover),
+        )
+        .route(
+            "/{manga_id}/{chapter_id}/download",
+            routing::get(download_chapter),
+        )
         .route(
             "/{manga_id}/{chapter_id}/images",
             routing::get(get_chapter_images),
@@ -0,0 +1,273 @@
+use crate::app::AppState;
+use crate::error::{ApiResult, AppError};
+use crate::response::ApiResponse;
+use application::dto::{
+    MangaSeriesDetail, MangaSeriesInfo, MergeMangaSeriesRequest, PagedResponse, PaginationQuery,
+    SplitMangaSeriesRequest, ThumbnailQuery, UpdateMangaSeriesRequest,
+};
+use axum::Router;
+use axum::extract::{Path, Query, State};
+use axum::http::{HeaderMap, StatusCode, header};
+use axum::response::IntoResponse;
+use axum::routing;
+
+// region: 分页查询全部系列
+#[utoipa::path(
+    get,
+    path = "/api/manga_series",
+    tag = "manga_series",
+    params(
+        ("page_index" = i32, Query, description = "页码（从 1 开始），默认 1", example = 1),
+        ("page_size" = i32, Query, description = "每页数量，默认 10", example = 10),
+    ),
+    responses(
+        (status = 200, description = "查询成功", body = ApiResponse<PagedResponse<MangaSeriesInfo>>),
+    )
+)]
+pub async fn get_manga_series_paged(
+    State(AppState { manga_series_service, .. }): State<AppState>,
+    Query(params): Query<PaginationQuery>,
+) -> ApiResult<impl IntoResponse> {
+    // 验证参数
+    if params.page_index <= 0 || params.page_size <= 0 {
+        return Err(AppError::Biz("page_index and page_size must be greater than 0".to_string()));
+    }
+
+    if params.page_size > 100 {
+        return Err(AppError::Biz("page_size must be less than or equal to 100".to_string()));
+    }
+
+    let total = manga_series_service.count_all().await
+        .map_err(|e| AppError::Biz(e.to_string()))?;
+
+    let series = manga_series_service.find_paged(params.page_size, params.page_index).await
+        .map_err(|e| AppError::Biz(e.to_string()))?
+        .unwrap_or_default();
+
+    let series_infos: Vec<MangaSeriesInfo> = series
+        .into_iter()
+        .map(MangaSeriesInfo::from)
+        .collect();
+
+    let paged_response = PagedResponse::new(params.page_index, params.page_size, total, series_infos);
+
+    let response = ApiResponse::ok(
+        Some("Get manga series list successful"),
+        Some(paged_response),
+        None,
+        None,
+    );
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+// endregion
+
+// region: 查询系列详情（按卷号排序）
+#[utoipa::path(
+    get,
+    path = "/api/manga_series/{seriesId}",
+    tag = "manga_series",
+    responses(
+        (status = 200, description = "查询成功", body = ApiResponse<MangaSeriesDetail>),
+        (status = 404, description = "系列不存在"),
+    )
+)]
+pub async fn get_manga_series(
+    State(AppState { manga_series_service, .. }): State<AppState>,
+    Path(series_id): Path<i32>,
+) -> ApiResult<impl IntoResponse> {
+    let detail = manga_series_service.get_detail(series_id).await
+        .map_err(|e| AppError::Biz(e.to_string()))?
+        .ok_or_else(|| AppError::NotFound)?;
+
+    let response = ApiResponse::ok(
+        Some("Get manga series successful"),
+        Some(detail),
+        None,
+        None,
+    );
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+// endregion
+
+// region: 更新系列信息
+#[utoipa::path(
+    put,
+    path = "/api/manga_series/{seriesId}",
+    tag = "manga_series",
+    request_body = UpdateMangaSeriesRequest,
+    responses(
+        (status = 200, description = "更新成功", body = ApiResponse<MangaSeriesInfo>),
+        (status = 404, description = "系列不存在"),
+    )
+)]
+pub async fn update_manga_series(
+    State(AppState { manga_series_service, .. }): State<AppState>,
+    Path(series_id): Path<i32>,
+    axum::Json(req): axum::Json<UpdateMangaSeriesRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let series = manga_series_service.update(series_id, req).await
+        .map_err(|e| AppError::Biz(e.to_string()))?;
+
+    let response = ApiResponse::ok(
+        Some("Update manga series successful"),
+        Some(MangaSeriesInfo::from(series)),
+        None,
+        None,
+    );
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+// endregion
+
+// region: 合并系列
+#[utoipa::path(
+    post,
+    path = "/api/manga_series/{seriesId}/merge",
+    tag = "manga_series",
+    request_body = MergeMangaSeriesRequest,
+    responses(
+        (status = 200, description = "合并成功", body = ApiResponse<MangaSeriesInfo>),
+        (status = 404, description = "系列不存在"),
+    )
+)]
+pub async fn merge_manga_series(
+    State(AppState { manga_series_service, .. }): State<AppState>,
+    Path(series_id): Path<i32>,
+    axum::Json(req): axum::Json<MergeMangaSeriesRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let series = manga_series_service.merge(series_id, req.source_series_ids).await
+        .map_err(|e| AppError::Biz(e.to_string()))?;
+
+    let response = ApiResponse::ok(
+        Some("Merge manga series successful"),
+        Some(MangaSeriesInfo::from(series)),
+        None,
+        None,
+    );
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+// endregion
+
+// region: 拆分系列
+#[utoipa::path(
+    post,
+    path = "/api/manga_series/{seriesId}/split",
+    tag = "manga_series",
+    request_body = SplitMangaSeriesRequest,
+    responses(
+        (status = 200, description = "拆分成功，返回新系列", body = ApiResponse<MangaSeriesInfo>),
+        (status = 404, description = "系列不存在"),
+    )
+)]
+pub async fn split_manga_series(
+    State(AppState { manga_series_service, .. }): State<AppState>,
+    Path(series_id): Path<i32>,
+    axum::Json(req): axum::Json<SplitMangaSeriesRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let series = manga_series_service.split(series_id, req.manga_ids, req.title).await
+        .map_err(|e| AppError::Biz(e.to_string()))?;
+
+    let response = ApiResponse::ok(
+        Some("Split manga series successful"),
+        Some(MangaSeriesInfo::from(series)),
+        None,
+        None,
+    );
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+// endregion
+
+// region: 按标题自动分组媒体库中的漫画
+
+/// 将媒体库中尚未归入系列的漫画按规范化标题自动分组
+///
+/// 扫描媒体库时会自动执行，此接口用于处理升级前已存在的漫画
+#[utoipa::path(
+    post,
+    path = "/api/manga_series/group/{mediaLibraryId}",
+    tag = "manga_series",
+    responses(
+        (status = 200, description = "分组成功，返回本次归入系列的漫画数量", body = ApiResponse<usize>),
+    )
+)]
+pub async fn group_manga_series(
+    State(AppState { manga_series_service, .. }): State<AppState>,
+    Path(media_library_id): Path<i32>,
+) -> ApiResult<impl IntoResponse> {
+    let grouped = manga_series_service.group_media_library(media_library_id).await
+        .map_err(|e| AppError::Biz(e.to_string()))?;
+
+    let response = ApiResponse::ok(
+        Some("Group manga series successful"),
+        Some(grouped),
+        None,
+        None,
+    );
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+// endregion
+
+// region: 获取系列的封面缩略图
+
+/// 获取系列的封面缩略图
+///
+/// 使用手动指定的封面漫画，否则使用第一卷的封面
+#[utoipa::path(
+    get,
+    path = "/api/manga_series/{seriesId}/cover",
+    tag = "manga_series",
+    params(
+        ("width" = Option<u32>, Query, description = "缩略图宽度，默认 200"),
+        ("height" = Option<u32>, Query, description = "缩略图高度，默认 300"),
+        ("quality" = Option<u8>, Query, description = "图片质量 0-100，默认 85"),
+    ),
+    responses(
+        (status = 200, description = "获取成功"),
+        (status = 404, description = "封面不存在"),
+    )
+)]
+pub async fn get_manga_series_cover(
+    State(AppState { manga_series_service, image_service, .. }): State<AppState>,
+    Path(series_id): Path<i32>,
+    Query(params): Query<ThumbnailQuery>,
+) -> ApiResult<impl IntoResponse> {
+    let cover_manga_id = manga_series_service.resolve_cover_manga_id(series_id).await
+        .map_err(|e| AppError::Biz(e.to_string()))?;
+
+    let thumbnail_data = image_service
+        .get_manga_cover_thumbnail(cover_manga_id, params.width, params.height, params.quality)
+        .await
+        .map_err(|e| AppError::Biz(e.to_string()))?;
+
+    let mut headers = HeaderMap::new();
+    headers.insert(header::CONTENT_TYPE, "image/jpeg".parse().unwrap());
+    headers.insert(
+        header::CACHE_CONTROL,
+        shared::config::get()
+            .server()
+            .image()
+            .cache()
+            .image_cache_control()
+            .parse()
+            .unwrap_or_else(|_| "public, max-age=2592000".parse().unwrap()),
+    );
+
+    Ok((StatusCode::OK, headers, thumbnail_data))
+}
+// endregion
+
+/// 漫画系列路由
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/", routing::get(get_manga_series_paged))
+        .route("/group/{media_library_id}", routing::post(group_manga_series))
+        .route("/{series_id}", routing::get(get_manga_series).put(update_manga_series))
+        .route("/{series_id}/cover", routing::get(get_manga_series_cover))
+        .route("/{series_id}/merge", routing::post(merge_manga_series))
+        .route("/{series_id}/split", routing::post(split_manga_series))
+}
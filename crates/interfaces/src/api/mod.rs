@@ -17,8 +17,11 @@ pub mod user;
 pub mod media_library;
 pub mod manga;
 pub mod manga_chapter;
+pub mod manga_series;
 pub mod game;
 pub mod movie;
+pub mod person;
+pub mod tv_series;
 pub mod photo;
 pub mod config;
 
@@ -68,9 +71,12 @@ pub fn create_router() -> Router<AppState> {
                 .nest("/media_libraries", media_library::routes())
                 .nest("/manga", manga::routes())  // 漫画路由（包含图片相关接口）
                 .nest("/manga_chapter", manga_chapter::routes())  // 章节路由独立，保持清晰的分类
+                .nest("/manga_series", manga_series::routes())  // 漫画系列（多卷分组）
                 .nest("/config", config::routes())
                 .merge(game::routes())
                 .merge(movie::routes())
+                .merge(person::routes())
+                .merge(tv_series::routes())
                 .merge(photo::routes()),
         )
         .fallback(async || -> ApiResult<()> {
@@ -1,20 +1,29 @@
+use crate::api::auth::CurrentUser;
 use crate::app::AppState;
 use crate::error::{ApiResult, AppError};
 use crate::response::ApiResponse;
-use application::dto::{MovieInfo, PagedResponse, PaginationQuery};
+use application::dto::{
+    IdentifyMovieRequest, MovieCollectionDetail, MovieCollectionInfo, MovieDetailInfo, MovieExtraInfo,
+    MovieIdentifyCandidate, MovieIdentifyQuery, MovieInfo, MovieListQuery, MovieListResponse, MoviePlayItem,
+    MoviePlayListQuery, MoviePlayStateInfo, MovieSubtitleInfo, MovieVideoQuery, NfoExportQuery, NfoExportResult,
+    ReportMovieProgressRequest, ThumbnailQuery,
+};
+use application::movie_service::MovieArtwork;
 use axum::body::Body;
 use axum::extract::{Path, Query, State};
 use axum::http::{header, HeaderMap, Response, StatusCode};
-use axum::response::IntoResponse;
+use axum::response::{IntoResponse, Redirect};
 use axum::routing;
 use axum::Router;
+use infrastructure::file_scanner::movie_scaner::artwork::ArtworkKind;
 use tokio_util::io::ReaderStream;
 
 // region: 电影查询接口
 
 /// 获取单个电影详情
 ///
-/// 根据电影 ID 获取电影的详细信息
+/// 根据电影 ID 获取电影的详细信息，包括视频编码、HDR、帧率、码率、
+/// 音频轨道和内嵌字幕轨道，以及浏览器能否直接播放
 #[utoipa::path(
     get,
     path = "/api/movies/{movie_id}",
@@ -23,7 +32,7 @@ use tokio_util::io::ReaderStream;
         ("movie_id" = i32, Path, description = "电影 ID")
     ),
     responses(
-        (status = 200, description = "获取成功", body = ApiResponse<MovieInfo>),
+        (status = 200, description = "获取成功", body = ApiResponse<MovieDetailInfo>),
         (status = 404, description = "电影不存在"),
         (status = 500, description = "服务器错误"),
     )
@@ -32,17 +41,15 @@ pub async fn get_movie(
     State(state): State<AppState>,
     Path(movie_id): Path<i32>,
 ) -> ApiResult<impl IntoResponse> {
-    let movie = state
+    let movie_detail = state
         .movie_service
-        .get_by_id(movie_id)
+        .get_detail_by_id(movie_id)
         .await
         .map_err(|e| AppError::Biz(format!("Failed to get movie: {}", e)))?;
 
-    let movie_info: MovieInfo = movie.into();
-
     let response = ApiResponse::ok(
         Some("Get movie successful"),
-        Some(movie_info),
+        Some(movie_detail),
         None,
         None,
     );
@@ -52,7 +59,9 @@ pub async fn get_movie(
 
 /// 获取电影分页列表
 ///
-/// 支持分页查询所有电影
+/// 支持按类型、年份范围、最低评分、分辨率、演员或导演、媒体库筛选，
+/// 按标题、上映日期、评分、入库时间、文件大小或时长排序，
+/// 同时返回可选的类型和年份（只受媒体库条件影响）
 #[utoipa::path(
     get,
     path = "/api/movies",
@@ -60,15 +69,24 @@ pub async fn get_movie(
     params(
         ("page_index" = i32, Query, description = "页码（从 1 开始），默认 1", example = 1),
         ("page_size" = i32, Query, description = "每页数量，默认 10", example = 10),
+        ("genre" = Option<String>, Query, description = "类型", example = "犯罪"),
+        ("year_from" = Option<i32>, Query, description = "上映年份下限（包含）", example = 1990),
+        ("year_to" = Option<i32>, Query, description = "上映年份上限（包含）", example = 1999),
+        ("min_rating" = Option<f32>, Query, description = "最低评分", example = 7.5),
+        ("resolution" = Option<String>, Query, description = "分辨率档位：sd / hd / 4k", example = "4k"),
+        ("person" = Option<String>, Query, description = "演员或导演姓名（模糊匹配）", example = "Al Pacino"),
+        ("media_library_id" = Option<i32>, Query, description = "媒体库 ID"),
+        ("sort" = Option<String>, Query, description = "排序字段：title / release_date / rating / date_added / file_size / duration，默认 title", example = "rating"),
+        ("order" = Option<String>, Query, description = "排序方向：asc / desc，默认标题升序、其余降序", example = "desc"),
     ),
     responses(
-        (status = 200, description = "获取成功", body = ApiResponse<PagedResponse<MovieInfo>>),
+        (status = 200, description = "获取成功", body = ApiResponse<MovieListResponse>),
         (status = 500, description = "服务器错误"),
     )
 )]
 pub async fn get_movies_paged(
     State(state): State<AppState>,
-    Query(params): Query<PaginationQuery>,
+    Query(params): Query<MovieListQuery>,
 ) -> ApiResult<impl IntoResponse> {
     // 验证参数
     if params.page_index <= 0 || params.page_size <= 0 {
@@ -83,29 +101,15 @@ pub async fn get_movies_paged(
         ));
     }
 
-    // 获取总数
-    let total = state
+    let movie_list = state
         .movie_service
-        .count_all()
-        .await
-        .map_err(|e| AppError::Biz(e.to_string()))?;
-
-    // 分页查询
-    let (movies, _) = state
-        .movie_service
-        .get_paged(params.page_index, params.page_size)
+        .get_list(&params)
         .await
         .map_err(|e| AppError::Biz(format!("Failed to get movies: {}", e)))?;
 
-    // 转换为 DTO
-    let movie_infos: Vec<MovieInfo> = movies.into_iter().map(|m| m.into()).collect();
-
-    // 构建分页响应
-    let paged_response = PagedResponse::new(params.page_index, params.page_size, total, movie_infos);
-
     let response = ApiResponse::ok(
         Some("Get movies successful"),
-        Some(paged_response),
+        Some(movie_list),
         None,
         None,
     );
@@ -188,13 +192,16 @@ pub async fn delete_movie(
 
 /// 获取电影视频流
 ///
-/// 支持流式传输和 Range 请求（断点续传）
+/// 支持流式传输和 Range 请求（断点续传）。
+/// 多版本电影用 `source` 选择版本，分段电影用 `part` 选择分段，依次播放各分段
 #[utoipa::path(
     get,
     path = "/api/movies/{movie_id}/video",
     tag = "movie",
     params(
-        ("movie_id" = i32, Path, description = "电影 ID")
+        ("movie_id" = i32, Path, description = "电影 ID"),
+        ("source" = Option<i32>, Query, description = "版本序号（详情中的 versions[].index），默认 0"),
+        ("part" = Option<usize>, Query, description = "分段在版本中的位置（从 0 开始），默认 0"),
     ),
     responses(
         (status = 200, description = "返回完整视频"),
@@ -206,24 +213,706 @@ pub async fn delete_movie(
 pub async fn get_movie_video(
     State(state): State<AppState>,
     Path(movie_id): Path<i32>,
+    Query(query): Query<MovieVideoQuery>,
     headers: HeaderMap,
 ) -> Result<Response<Body>, AppError> {
     // 获取视频文件路径
     let video_path = state
         .movie_service
-        .get_movie_video_path(movie_id)
+        .get_movie_video_path(movie_id, query.source.unwrap_or(0), query.part.unwrap_or(0))
+        .await
+        .map_err(|e| AppError::Biz(e.to_string()))?;
+
+    serve_video_file(&video_path, &headers).await
+}
+
+// endregion
+
+// region: 电影附加视频接口
+
+/// 获取电影的预告片和花絮
+///
+/// 返回扫描时识别出的附加视频（`Trailers/`、`Extras/` 等子文件夹，
+/// 以及 `-trailer`、`.making.of` 等结尾的文件），`extra_type` 区分类型，
+/// 每项的 `url` 可直接用于播放
+#[utoipa::path(
+    get,
+    path = "/api/movies/{movie_id}/extras",
+    tag = "movie",
+    params(
+        ("movie_id" = i32, Path, description = "电影 ID")
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiResponse<Vec<MovieExtraInfo>>),
+        (status = 404, description = "电影不存在"),
+        (status = 500, description = "服务器错误"),
+    )
+)]
+pub async fn get_movie_extras(
+    State(state): State<AppState>,
+    Path(movie_id): Path<i32>,
+) -> ApiResult<impl IntoResponse> {
+    let extras = state
+        .movie_service
+        .get_extras(movie_id)
+        .await
+        .map_err(|e| AppError::Biz(format!("Failed to get extras: {}", e)))?;
+
+    let response = ApiResponse::ok(
+        Some("Get extras successful"),
+        Some(extras),
+        None,
+        None,
+    );
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+
+/// 流式传输附加视频
+///
+/// 支持流式传输和 Range 请求（断点续传）
+#[utoipa::path(
+    get,
+    path = "/api/movies/{movie_id}/extras/{extra_id}/video",
+    tag = "movie",
+    params(
+        ("movie_id" = i32, Path, description = "电影 ID"),
+        ("extra_id" = i32, Path, description = "附加视频 ID"),
+    ),
+    responses(
+        (status = 200, description = "返回完整视频"),
+        (status = 206, description = "返回部分视频内容（Range 请求）"),
+        (status = 404, description = "附加视频不存在"),
+        (status = 500, description = "服务器错误"),
+    )
+)]
+pub async fn get_movie_extra_video(
+    State(state): State<AppState>,
+    Path((movie_id, extra_id)): Path<(i32, i32)>,
+    headers: HeaderMap,
+) -> Result<Response<Body>, AppError> {
+    let video_path = state
+        .movie_service
+        .get_extra_video_path(movie_id, extra_id)
+        .await
+        .map_err(|e| AppError::Biz(e.to_string()))?;
+
+    serve_video_file(&video_path, &headers).await
+}
+
+// endregion
+
+// region: 电影字幕接口
+
+/// 获取电影的字幕列表
+///
+/// 返回扫描时在视频旁发现的字幕文件（`Movie.zh.srt`、`Subs/` 文件夹等）
+/// 和 MKV 内嵌的文本字幕轨道（`source` 区分来源），
+/// 每条字幕的 `url` 可直接用作 `<track>` 元素的 src
+#[utoipa::path(
+    get,
+    path = "/api/movies/{movie_id}/subtitles",
+    tag = "movie",
+    params(
+        ("movie_id" = i32, Path, description = "电影 ID")
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiResponse<Vec<MovieSubtitleInfo>>),
+        (status = 404, description = "电影不存在"),
+        (status = 500, description = "服务器错误"),
+    )
+)]
+pub async fn get_movie_subtitles(
+    State(state): State<AppState>,
+    Path(movie_id): Path<i32>,
+) -> ApiResult<impl IntoResponse> {
+    let subtitles = state
+        .movie_service
+        .get_subtitles(movie_id)
+        .await
+        .map_err(|e| AppError::Biz(format!("Failed to get subtitles: {}", e)))?;
+
+    let response = ApiResponse::ok(
+        Some("Get subtitles successful"),
+        Some(subtitles),
+        None,
+        None,
+    );
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+
+/// 获取 WebVTT 格式的字幕
+///
+/// SRT / ASS / SSA 字幕会转换为 WebVTT，GBK、Big5、UTF-16 编码会转换为 UTF-8
+#[utoipa::path(
+    get,
+    path = "/api/movies/{movie_id}/subtitles/{subtitle_id}",
+    tag = "movie",
+    params(
+        ("movie_id" = i32, Path, description = "电影 ID"),
+        ("subtitle_id" = i32, Path, description = "字幕 ID")
+    ),
+    responses(
+        (status = 200, description = "返回 WebVTT 字幕（text/vtt）"),
+        (status = 404, description = "字幕不存在"),
+        (status = 500, description = "服务器错误"),
+    )
+)]
+pub async fn get_movie_subtitle(
+    State(state): State<AppState>,
+    Path((movie_id, subtitle_id)): Path<(i32, i32)>,
+) -> Result<Response<Body>, AppError> {
+    let vtt = state
+        .movie_service
+        .get_subtitle_vtt(movie_id, subtitle_id)
         .await
         .map_err(|e| AppError::Biz(e.to_string()))?;
 
+    Ok(Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, "text/vtt; charset=utf-8")
+        .header(header::CACHE_CONTROL, "public, max-age=3600")
+        .body(Body::from(vtt))
+        .unwrap())
+}
+
+/// 获取 WebVTT 格式的内嵌字幕
+///
+/// 从 MKV 中提取文本字幕轨道（SRT / ASS / SSA / WebVTT）并转换为 WebVTT。
+/// 首次请求需要扫描整个视频文件，转换结果会缓存在 data 目录下
+#[utoipa::path(
+    get,
+    path = "/api/movies/{movie_id}/subtitles/embedded/{track_id}",
+    tag = "movie",
+    params(
+        ("movie_id" = i32, Path, description = "电影 ID"),
+        ("track_id" = i32, Path, description = "字幕轨道 ID")
+    ),
+    responses(
+        (status = 200, description = "返回 WebVTT 字幕（text/vtt）"),
+        (status = 404, description = "字幕轨道不存在"),
+        (status = 500, description = "服务器错误"),
+    )
+)]
+pub async fn get_movie_embedded_subtitle(
+    State(state): State<AppState>,
+    Path((movie_id, track_id)): Path<(i32, i32)>,
+) -> Result<Response<Body>, AppError> {
+    let vtt = state
+        .movie_service
+        .get_embedded_subtitle_vtt(movie_id, track_id)
+        .await
+        .map_err(|e| AppError::Biz(e.to_string()))?;
+
+    Ok(Response::builder()
+        .status(StatusCode::OK)
+        .header(header::CONTENT_TYPE, "text/vtt; charset=utf-8")
+        .header(header::CACHE_CONTROL, "public, max-age=3600")
+        .body(Body::from(vtt))
+        .unwrap())
+}
+
+/// 获取电影海报
+///
+/// 返回扫描时缓存在本地的海报，指定宽高时返回按比例缩放的版本；
+/// 本地缓存下载失败时重定向到 TMDB 原始地址
+#[utoipa::path(
+    get,
+    path = "/api/movies/{movie_id}/poster",
+    tag = "movie",
+    params(
+        ("movie_id" = i32, Path, description = "电影 ID"),
+        ("width" = Option<u32>, Query, description = "最大宽度（像素），不传时返回原图"),
+        ("height" = Option<u32>, Query, description = "最大高度（像素），不传时返回原图"),
+        ("quality" = Option<u8>, Query, description = "图片质量 0-100"),
+    ),
+    responses(
+        (status = 200, description = "返回 JPEG 图片"),
+        (status = 307, description = "本地缓存不可用，重定向到远程地址"),
+        (status = 404, description = "电影或海报不存在"),
+    )
+)]
+pub async fn get_movie_poster(
+    State(state): State<AppState>,
+    Path(movie_id): Path<i32>,
+    Query(params): Query<ThumbnailQuery>,
+) -> Result<Response<Body>, AppError> {
+    get_movie_artwork(state, movie_id, ArtworkKind::Poster, params).await
+}
+
+/// 获取电影背景图
+///
+/// 返回扫描时缓存在本地的背景图，指定宽高时返回按比例缩放的版本；
+/// 本地缓存下载失败时重定向到 TMDB 原始地址
+#[utoipa::path(
+    get,
+    path = "/api/movies/{movie_id}/backdrop",
+    tag = "movie",
+    params(
+        ("movie_id" = i32, Path, description = "电影 ID"),
+        ("width" = Option<u32>, Query, description = "最大宽度（像素），不传时返回原图"),
+        ("height" = Option<u32>, Query, description = "最大高度（像素），不传时返回原图"),
+        ("quality" = Option<u8>, Query, description = "图片质量 0-100"),
+    ),
+    responses(
+        (status = 200, description = "返回 JPEG 图片"),
+        (status = 307, description = "本地缓存不可用，重定向到远程地址"),
+        (status = 404, description = "电影或背景图不存在"),
+    )
+)]
+pub async fn get_movie_backdrop(
+    State(state): State<AppState>,
+    Path(movie_id): Path<i32>,
+    Query(params): Query<ThumbnailQuery>,
+) -> Result<Response<Body>, AppError> {
+    get_movie_artwork(state, movie_id, ArtworkKind::Backdrop, params).await
+}
+
+/// 搜索电影的候选匹配
+///
+/// 在配置的元数据提供者中搜索，用于修正扫描时自动匹配错误的电影
+#[utoipa::path(
+    get,
+    path = "/api/movies/{movie_id}/identify",
+    tag = "movie",
+    params(
+        ("movie_id" = i32, Path, description = "电影 ID"),
+        ("query" = Option<String>, Query, description = "搜索关键词，默认使用电影当前标题"),
+        ("year" = Option<u32>, Query, description = "上映年份"),
+    ),
+    responses(
+        (status = 200, description = "搜索成功", body = ApiResponse<Vec<MovieIdentifyCandidate>>),
+        (status = 404, description = "电影不存在"),
+        (status = 500, description = "服务器错误"),
+    )
+)]
+pub async fn get_movie_identify_candidates(
+    State(state): State<AppState>,
+    Path(movie_id): Path<i32>,
+    Query(query): Query<MovieIdentifyQuery>,
+) -> ApiResult<impl IntoResponse> {
+    let candidates = state
+        .movie_service
+        .identify_candidates(movie_id, query.query, query.year)
+        .await
+        .map_err(|e| AppError::Biz(format!("Failed to search candidates: {}", e)))?;
+
+    let response = ApiResponse::ok(Some("Search candidates successful"), Some(candidates), None, None);
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+
+/// 手动识别电影
+///
+/// 按选定的提供者 ID 重新获取详情并覆盖电影元数据，之后刷新时直接使用该 ID
+#[utoipa::path(
+    post,
+    path = "/api/movies/{movie_id}/identify",
+    tag = "movie",
+    params(
+        ("movie_id" = i32, Path, description = "电影 ID")
+    ),
+    request_body = IdentifyMovieRequest,
+    responses(
+        (status = 200, description = "识别成功", body = ApiResponse<MovieInfo>),
+        (status = 404, description = "电影不存在"),
+        (status = 500, description = "服务器错误"),
+    )
+)]
+pub async fn identify_movie(
+    State(state): State<AppState>,
+    Path(movie_id): Path<i32>,
+    axum::Json(req): axum::Json<IdentifyMovieRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let movie = state
+        .movie_service
+        .identify(movie_id, req)
+        .await
+        .map_err(|e| AppError::Biz(format!("Failed to identify movie: {}", e)))?;
+
+    let response = ApiResponse::ok(Some("Identify movie successful"), Some(movie), None, None);
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+
+/// 写入电影的 NFO 和图片
+///
+/// 在视频旁写入 Kodi/Jellyfin 兼容的 NFO、海报和背景图。
+/// 默认保留用户编写的 NFO 和已存在的图片，`force=true` 时覆盖
+#[utoipa::path(
+    post,
+    path = "/api/movies/{movie_id}/nfo",
+    tag = "movie",
+    params(
+        ("movie_id" = i32, Path, description = "电影 ID"),
+        ("force" = Option<bool>, Query, description = "是否覆盖已存在的 NFO 和图片，默认 false")
+    ),
+    responses(
+        (status = 200, description = "写入成功", body = ApiResponse<NfoExportResult>),
+        (status = 404, description = "电影不存在"),
+        (status = 500, description = "服务器错误"),
+    )
+)]
+pub async fn write_movie_nfo(
+    State(state): State<AppState>,
+    Path(movie_id): Path<i32>,
+    Query(query): Query<NfoExportQuery>,
+) -> ApiResult<impl IntoResponse> {
+    let result = state
+        .movie_service
+        .write_sidecars(movie_id, query.force)
+        .await
+        .map_err(|e| AppError::Biz(format!("Failed to write movie NFO: {}", e)))?;
+
+    let response = ApiResponse::ok(Some("Write movie NFO successful"), Some(result), None, None);
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+
+/// 导出媒体库的 NFO 和图片
+///
+/// 为媒体库中的所有电影写入 NFO、海报和背景图，单部电影失败不会中断导出
+#[utoipa::path(
+    post,
+    path = "/api/media-libraries/{media_library_id}/movies/nfo",
+    tag = "movie",
+    params(
+        ("media_library_id" = i32, Path, description = "媒体库 ID"),
+        ("force" = Option<bool>, Query, description = "是否覆盖已存在的 NFO 和图片，默认 false")
+    ),
+    responses(
+        (status = 200, description = "导出完成", body = ApiResponse<NfoExportResult>),
+        (status = 500, description = "服务器错误"),
+    )
+)]
+pub async fn export_media_library_nfo(
+    State(state): State<AppState>,
+    Path(media_library_id): Path<i32>,
+    Query(query): Query<NfoExportQuery>,
+) -> ApiResult<impl IntoResponse> {
+    let result = state
+        .movie_service
+        .export_library_sidecars(media_library_id, query.force)
+        .await
+        .map_err(|e| AppError::Biz(format!("Failed to export NFO: {}", e)))?;
+
+    let response = ApiResponse::ok(Some("Export NFO successful"), Some(result), None, None);
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+
+// endregion
+
+// region: 电影系列接口
+
+/// 获取电影系列列表
+///
+/// 只返回媒体库中至少有一部电影的系列，按名称排序
+#[utoipa::path(
+    get,
+    path = "/api/movie-collections",
+    tag = "movie",
+    responses(
+        (status = 200, description = "获取成功", body = ApiResponse<Vec<MovieCollectionInfo>>),
+        (status = 500, description = "服务器错误"),
+    )
+)]
+pub async fn get_movie_collections(State(state): State<AppState>) -> ApiResult<impl IntoResponse> {
+    let collections = state
+        .movie_service
+        .get_collections()
+        .await
+        .map_err(|e| AppError::Biz(format!("Failed to get movie collections: {}", e)))?;
+
+    let response = ApiResponse::ok(Some("Get movie collections successful"), Some(collections), None, None);
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+
+/// 获取电影系列详情
+///
+/// 返回媒体库中已有的电影和系列中缺少的电影，都按上映日期排序
+#[utoipa::path(
+    get,
+    path = "/api/movie-collections/{collection_id}",
+    tag = "movie",
+    params(
+        ("collection_id" = i32, Path, description = "电影系列 ID")
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiResponse<MovieCollectionDetail>),
+        (status = 404, description = "电影系列不存在"),
+        (status = 500, description = "服务器错误"),
+    )
+)]
+pub async fn get_movie_collection(
+    State(state): State<AppState>,
+    Path(collection_id): Path<i32>,
+) -> ApiResult<impl IntoResponse> {
+    let collection = state
+        .movie_service
+        .get_collection(collection_id)
+        .await
+        .map_err(|e| AppError::Biz(format!("Failed to get movie collection: {}", e)))?;
+
+    let response = ApiResponse::ok(Some("Get movie collection successful"), Some(collection), None, None);
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+
+// endregion
+
+// region: 播放进度接口
+
+/// 继续观看和最近看过列表的默认数量
+const DEFAULT_PLAY_LIST_LIMIT: usize = 20;
+
+/// 继续观看和最近看过列表的最大数量
+const MAX_PLAY_LIST_LIMIT: usize = 100;
+
+/// 获取当前用户对电影的播放状态
+///
+/// 播放器打开电影时调用，从 `resume_position_seconds` 续播（需要登录）
+#[utoipa::path(
+    get,
+    path = "/api/movies/{movie_id}/progress",
+    tag = "movie",
+    params(
+        ("movie_id" = i32, Path, description = "电影 ID")
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiResponse<MoviePlayStateInfo>),
+        (status = 401, description = "未登录"),
+        (status = 500, description = "服务器错误"),
+    )
+)]
+pub async fn get_movie_progress(
+    State(state): State<AppState>,
+    CurrentUser(user_id): CurrentUser,
+    Path(movie_id): Path<i32>,
+) -> ApiResult<impl IntoResponse> {
+    let play_state = state
+        .movie_service
+        .get_play_state(user_id, movie_id)
+        .await
+        .map_err(|e| AppError::Biz(format!("Failed to get movie progress: {}", e)))?;
+
+    let response = ApiResponse::ok(Some("Get movie progress successful"), Some(play_state), None, None);
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+
+/// 上报播放进度
+///
+/// 播放器定期调用（如每 10 秒和暂停、退出时），播放到时长的 90% 时自动标记为已看（需要登录）
+#[utoipa::path(
+    put,
+    path = "/api/movies/{movie_id}/progress",
+    tag = "movie",
+    params(
+        ("movie_id" = i32, Path, description = "电影 ID")
+    ),
+    request_body = ReportMovieProgressRequest,
+    responses(
+        (status = 200, description = "上报成功", body = ApiResponse<MoviePlayStateInfo>),
+        (status = 401, description = "未登录"),
+        (status = 500, description = "服务器错误"),
+    )
+)]
+pub async fn report_movie_progress(
+    State(state): State<AppState>,
+    CurrentUser(user_id): CurrentUser,
+    Path(movie_id): Path<i32>,
+    axum::Json(req): axum::Json<ReportMovieProgressRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let play_state = state
+        .movie_service
+        .report_progress(user_id, movie_id, req)
+        .await
+        .map_err(|e| AppError::Biz(format!("Failed to report movie progress: {}", e)))?;
+
+    let response = ApiResponse::ok(Some("Report movie progress successful"), Some(play_state), None, None);
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+
+/// 标记电影为已看
+///
+/// 增加一次播放次数，播放位置归零（需要登录）
+#[utoipa::path(
+    post,
+    path = "/api/movies/{movie_id}/watched",
+    tag = "movie",
+    params(
+        ("movie_id" = i32, Path, description = "电影 ID")
+    ),
+    responses(
+        (status = 200, description = "标记成功", body = ApiResponse<MoviePlayStateInfo>),
+        (status = 401, description = "未登录"),
+        (status = 500, description = "服务器错误"),
+    )
+)]
+pub async fn mark_movie_watched(
+    State(state): State<AppState>,
+    CurrentUser(user_id): CurrentUser,
+    Path(movie_id): Path<i32>,
+) -> ApiResult<impl IntoResponse> {
+    let play_state = state
+        .movie_service
+        .set_watched(user_id, movie_id, true)
+        .await
+        .map_err(|e| AppError::Biz(format!("Failed to mark movie as watched: {}", e)))?;
+
+    let response = ApiResponse::ok(Some("Mark movie as watched successful"), Some(play_state), None, None);
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+
+/// 标记电影为未看
+///
+/// 清除播放位置和播放次数（需要登录）
+#[utoipa::path(
+    delete,
+    path = "/api/movies/{movie_id}/watched",
+    tag = "movie",
+    params(
+        ("movie_id" = i32, Path, description = "电影 ID")
+    ),
+    responses(
+        (status = 200, description = "标记成功", body = ApiResponse<MoviePlayStateInfo>),
+        (status = 401, description = "未登录"),
+        (status = 500, description = "服务器错误"),
+    )
+)]
+pub async fn mark_movie_unwatched(
+    State(state): State<AppState>,
+    CurrentUser(user_id): CurrentUser,
+    Path(movie_id): Path<i32>,
+) -> ApiResult<impl IntoResponse> {
+    let play_state = state
+        .movie_service
+        .set_watched(user_id, movie_id, false)
+        .await
+        .map_err(|e| AppError::Biz(format!("Failed to mark movie as unwatched: {}", e)))?;
+
+    let response = ApiResponse::ok(Some("Mark movie as unwatched successful"), Some(play_state), None, None);
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+
+/// 获取继续观看列表
+///
+/// 看了一部分还没看完的电影，按最后播放时间从新到旧（需要登录）
+#[utoipa::path(
+    get,
+    path = "/api/movies/continue-watching",
+    tag = "movie",
+    params(
+        ("limit" = Option<usize>, Query, description = "返回数量，默认 20，最多 100", example = 20),
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiResponse<Vec<MoviePlayItem>>),
+        (status = 401, description = "未登录"),
+        (status = 500, description = "服务器错误"),
+    )
+)]
+pub async fn get_continue_watching(
+    State(state): State<AppState>,
+    CurrentUser(user_id): CurrentUser,
+    Query(params): Query<MoviePlayListQuery>,
+) -> ApiResult<impl IntoResponse> {
+    let limit = params.limit.unwrap_or(DEFAULT_PLAY_LIST_LIMIT).min(MAX_PLAY_LIST_LIMIT);
+    let items = state
+        .movie_service
+        .get_continue_watching(user_id, limit)
+        .await
+        .map_err(|e| AppError::Biz(format!("Failed to get continue watching: {}", e)))?;
+
+    let response = ApiResponse::ok(Some("Get continue watching successful"), Some(items), None, None);
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+
+/// 获取最近看过列表
+///
+/// 已看的电影，按最后播放时间从新到旧（需要登录）
+#[utoipa::path(
+    get,
+    path = "/api/movies/recently-watched",
+    tag = "movie",
+    params(
+        ("limit" = Option<usize>, Query, description = "返回数量，默认 20，最多 100", example = 20),
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiResponse<Vec<MoviePlayItem>>),
+        (status = 401, description = "未登录"),
+        (status = 500, description = "服务器错误"),
+    )
+)]
+pub async fn get_recently_watched(
+    State(state): State<AppState>,
+    CurrentUser(user_id): CurrentUser,
+    Query(params): Query<MoviePlayListQuery>,
+) -> ApiResult<impl IntoResponse> {
+    let limit = params.limit.unwrap_or(DEFAULT_PLAY_LIST_LIMIT).min(MAX_PLAY_LIST_LIMIT);
+    let items = state
+        .movie_service
+        .get_recently_watched(user_id, limit)
+        .await
+        .map_err(|e| AppError::Biz(format!("Failed to get recently watched: {}", e)))?;
+
+    let response = ApiResponse::ok(Some("Get recently watched successful"), Some(items), None, None);
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+
+// endregion
+
+// region: 辅助函数
+
+/// 返回电影海报或背景图，本地缓存不可用时重定向到远程地址
+async fn get_movie_artwork(
+    state: AppState,
+    movie_id: i32,
+    kind: ArtworkKind,
+    params: ThumbnailQuery,
+) -> Result<Response<Body>, AppError> {
+    let artwork = state
+        .movie_service
+        .get_artwork(movie_id, kind, params.width, params.height, params.quality)
+        .await
+        .map_err(|e| AppError::Biz(e.to_string()))?;
+
+    match artwork {
+        MovieArtwork::Local(data) => Ok(Response::builder()
+            .status(StatusCode::OK)
+            .header(header::CONTENT_TYPE, "image/jpeg")
+            .header(header::CONTENT_LENGTH, data.len().to_string())
+            .header(
+                header::CACHE_CONTROL,
+                shared::config::get().server().image().cache().image_cache_control(),
+            )
+            .body(Body::from(data))
+            .unwrap()),
+        MovieArtwork::Remote(url) => Ok(Redirect::temporary(&url).into_response()),
+    }
+}
+
+/// 流式返回视频文件
+///
+/// 有 Range 请求头时返回 206 部分内容，否则流式返回完整文件，
+/// 电影和电视剧单集的视频接口共用
+pub(crate) async fn serve_video_file(video_path: &str, headers: &HeaderMap) -> Result<Response<Body>, AppError> {
     // 获取文件元数据
-    let metadata = tokio::fs::metadata(&video_path)
+    let metadata = tokio::fs::metadata(video_path)
         .await
         .map_err(|e| AppError::Biz(format!("Failed to get file metadata: {}", e)))?;
 
     let file_size = metadata.len();
 
     // 获取文件扩展名用于确定 MIME 类型
-    let extension = std::path::Path::new(&video_path)
+    let extension = std::path::Path::new(video_path)
         .extension()
         .and_then(|ext| ext.to_str())
         .unwrap_or("mp4");
@@ -238,13 +927,13 @@ pub async fn get_movie_video(
         // 处理 Range 请求
         if let Ok(range_str) = range_value.to_str() {
             if let Some(range) = parse_range_header(range_str, file_size) {
-                return serve_video_range(&video_path, range, file_size, &mime_type).await;
+                return serve_video_range(video_path, range, file_size, mime_type).await;
             }
         }
     }
 
     // 没有 Range 请求，返回完整文件（流式传输）
-    let file = tokio::fs::File::open(&video_path)
+    let file = tokio::fs::File::open(video_path)
         .await
         .map_err(|e| AppError::Biz(format!("Failed to open video: {}", e)))?;
 
@@ -266,10 +955,6 @@ pub async fn get_movie_video(
         .unwrap())
 }
 
-// endregion
-
-// region: 辅助函数
-
 /// 根据文件扩展名获取视频 MIME 类型
 fn get_video_mime_type(extension: &str) -> &'static str {
     match extension.to_lowercase().as_str() {
@@ -282,7 +967,8 @@ fn get_video_mime_type(extension: &str) -> &'static str {
         "flv" => "video/x-flv",
         "m4v" => "video/x-m4v",
         "3gp" => "video/3gpp",
-        "ts" => "video/mp2t",
+        "ts" | "m2ts" | "mts" => "video/mp2t",
+        "vob" | "mpg" | "mpeg" => "video/mpeg",
         _ => "application/octet-stream",
     }
 }
@@ -403,13 +1089,33 @@ async fn serve_video_range(
 pub fn routes() -> Router<AppState> {
     Router::new()
         .route("/movies", routing::get(get_movies_paged))
+        .route("/movies/continue-watching", routing::get(get_continue_watching))
+        .route("/movies/recently-watched", routing::get(get_recently_watched))
         .route("/movies/{movie_id}", routing::get(get_movie))
         .route("/movies/{movie_id}", routing::delete(delete_movie))
         .route("/movies/{movie_id}/video", routing::get(get_movie_video))
+        .route("/movies/{movie_id}/extras", routing::get(get_movie_extras))
+        .route("/movies/{movie_id}/extras/{extra_id}/video", routing::get(get_movie_extra_video))
+        .route("/movies/{movie_id}/subtitles", routing::get(get_movie_subtitles))
+        .route("/movies/{movie_id}/subtitles/{subtitle_id}", routing::get(get_movie_subtitle))
+        .route("/movies/{movie_id}/subtitles/embedded/{track_id}", routing::get(get_movie_embedded_subtitle))
+        .route("/movies/{movie_id}/poster", routing::get(get_movie_poster))
+        .route("/movies/{movie_id}/backdrop", routing::get(get_movie_backdrop))
+        .route("/movies/{movie_id}/identify", routing::get(get_movie_identify_candidates))
+        .route("/movies/{movie_id}/identify", routing::post(identify_movie))
+        .route("/movies/{movie_id}/nfo", routing::post(write_movie_nfo))
+        .route("/movies/{movie_id}/progress", routing::get(get_movie_progress).put(report_movie_progress))
+        .route("/movies/{movie_id}/watched", routing::post(mark_movie_watched).delete(mark_movie_unwatched))
+        .route("/movie-collections", routing::get(get_movie_collections))
+        .route("/movie-collections/{collection_id}", routing::get(get_movie_collection))
         .route(
             "/media-libraries/{media_library_id}/movies",
             routing::get(get_movies_by_media_library),
         )
+        .route(
+            "/media-libraries/{media_library_id}/movies/nfo",
+            routing::post(export_media_library_nfo),
+        )
 }
 
 // endregion
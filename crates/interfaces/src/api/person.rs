@@ -0,0 +1,54 @@
+use crate::app::AppState;
+use crate::error::{ApiResult, AppError};
+use crate::response::ApiResponse;
+use application::dto::PersonDetail;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use axum::routing;
+use axum::Router;
+
+// region: 人物查询接口
+
+/// 获取人物详情
+///
+/// 包含人物信息和在所有媒体库中参与的电影（按上映日期从新到旧）
+#[utoipa::path(
+    get,
+    path = "/api/people/{person_id}",
+    tag = "person",
+    params(
+        ("person_id" = i32, Path, description = "人物 ID")
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiResponse<PersonDetail>),
+        (status = 404, description = "人物不存在"),
+        (status = 500, description = "服务器错误"),
+    )
+)]
+pub async fn get_person(
+    State(state): State<AppState>,
+    Path(person_id): Path<i32>,
+) -> ApiResult<impl IntoResponse> {
+    let person_detail = state
+        .person_service
+        .get_detail(person_id)
+        .await
+        .map_err(|e| AppError::Biz(format!("Failed to get person: {}", e)))?;
+
+    let response = ApiResponse::ok(
+        Some("Get person successful"),
+        Some(person_detail),
+        None,
+        None,
+    );
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/people/{person_id}", routing::get(get_person))
+}
+
+// endregion
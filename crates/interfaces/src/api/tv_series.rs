@@ -0,0 +1,260 @@
+use crate::api::movie::serve_video_file;
+use crate::app::AppState;
+use crate::error::{ApiResult, AppError};
+use crate::response::ApiResponse;
+use application::dto::{PagedResponse, PaginationQuery, TvSeasonDetailInfo, TvSeriesDetailInfo, TvSeriesInfo};
+use axum::body::Body;
+use axum::extract::{Path, Query, State};
+use axum::http::{HeaderMap, Response, StatusCode};
+use axum::response::IntoResponse;
+use axum::routing;
+use axum::Router;
+
+// region: 电视剧查询接口
+
+/// 获取电视剧分页列表
+///
+/// 支持分页查询所有电视剧（按剧名排序）
+#[utoipa::path(
+    get,
+    path = "/api/tv-series",
+    tag = "tv_series",
+    params(
+        ("page_index" = i32, Query, description = "页码（从 1 开始），默认 1", example = 1),
+        ("page_size" = i32, Query, description = "每页数量，默认 10", example = 10),
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiResponse<PagedResponse<TvSeriesInfo>>),
+        (status = 500, description = "服务器错误"),
+    )
+)]
+pub async fn get_tv_series_paged(
+    State(state): State<AppState>,
+    Query(params): Query<PaginationQuery>,
+) -> ApiResult<impl IntoResponse> {
+    // 验证参数
+    if params.page_index <= 0 || params.page_size <= 0 {
+        return Err(AppError::Biz(
+            "page_index and page_size must be greater than 0".to_string(),
+        ));
+    }
+
+    if params.page_size > 100 {
+        return Err(AppError::Biz(
+            "page_size must be less than or equal to 100".to_string(),
+        ));
+    }
+
+    let (series, total) = state
+        .tv_series_service
+        .get_paged(params.page_index, params.page_size)
+        .await
+        .map_err(|e| AppError::Biz(format!("Failed to get tv series: {}", e)))?;
+
+    let series_infos: Vec<TvSeriesInfo> = series.into_iter().map(|s| s.into()).collect();
+    let paged_response = PagedResponse::new(params.page_index, params.page_size, total, series_infos);
+
+    let response = ApiResponse::ok(
+        Some("Get tv series successful"),
+        Some(paged_response),
+        None,
+        None,
+    );
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+
+/// 获取单部电视剧详情
+///
+/// 包含电视剧信息和季列表
+#[utoipa::path(
+    get,
+    path = "/api/tv-series/{series_id}",
+    tag = "tv_series",
+    params(
+        ("series_id" = i32, Path, description = "电视剧 ID")
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiResponse<TvSeriesDetailInfo>),
+        (status = 404, description = "电视剧不存在"),
+        (status = 500, description = "服务器错误"),
+    )
+)]
+pub async fn get_tv_series(
+    State(state): State<AppState>,
+    Path(series_id): Path<i32>,
+) -> ApiResult<impl IntoResponse> {
+    let series_detail = state
+        .tv_series_service
+        .get_detail_by_id(series_id)
+        .await
+        .map_err(|e| AppError::Biz(format!("Failed to get tv series: {}", e)))?;
+
+    let response = ApiResponse::ok(
+        Some("Get tv series successful"),
+        Some(series_detail),
+        None,
+        None,
+    );
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+
+/// 获取电视剧某一季的详情
+///
+/// 包含季信息和单集列表，季号 0 为特别篇
+#[utoipa::path(
+    get,
+    path = "/api/tv-series/{series_id}/seasons/{season_number}",
+    tag = "tv_series",
+    params(
+        ("series_id" = i32, Path, description = "电视剧 ID"),
+        ("season_number" = i32, Path, description = "季号（0 为特别篇）"),
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiResponse<TvSeasonDetailInfo>),
+        (status = 404, description = "季不存在"),
+        (status = 500, description = "服务器错误"),
+    )
+)]
+pub async fn get_tv_season(
+    State(state): State<AppState>,
+    Path((series_id, season_number)): Path<(i32, i32)>,
+) -> ApiResult<impl IntoResponse> {
+    let season_detail = state
+        .tv_series_service
+        .get_season_detail(series_id, season_number)
+        .await
+        .map_err(|e| AppError::Biz(format!("Failed to get tv season: {}", e)))?;
+
+    let response = ApiResponse::ok(
+        Some("Get tv season successful"),
+        Some(season_detail),
+        None,
+        None,
+    );
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+
+/// 根据媒体库 ID 获取电视剧列表
+#[utoipa::path(
+    get,
+    path = "/api/media-libraries/{media_library_id}/tv-series",
+    tag = "tv_series",
+    params(
+        ("media_library_id" = i32, Path, description = "媒体库 ID")
+    ),
+    responses(
+        (status = 200, description = "获取成功", body = ApiResponse<Vec<TvSeriesInfo>>),
+        (status = 500, description = "服务器错误"),
+    )
+)]
+pub async fn get_tv_series_by_media_library(
+    State(state): State<AppState>,
+    Path(media_library_id): Path<i32>,
+) -> ApiResult<impl IntoResponse> {
+    let series = state
+        .tv_series_service
+        .get_by_media_library_id(media_library_id)
+        .await
+        .map_err(|e| AppError::Biz(format!("Failed to get tv series: {}", e)))?;
+
+    let series_infos: Vec<TvSeriesInfo> = series.into_iter().map(|s| s.into()).collect();
+
+    let response = ApiResponse::ok(
+        Some("Get tv series by media library successful"),
+        Some(series_infos),
+        None,
+        None,
+    );
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+
+/// 删除电视剧
+///
+/// 同时删除电视剧的所有季和单集记录（不会删除视频文件）
+#[utoipa::path(
+    delete,
+    path = "/api/tv-series/{series_id}",
+    tag = "tv_series",
+    params(
+        ("series_id" = i32, Path, description = "电视剧 ID")
+    ),
+    responses(
+        (status = 200, description = "删除成功"),
+        (status = 404, description = "电视剧不存在"),
+        (status = 500, description = "服务器错误"),
+    )
+)]
+pub async fn delete_tv_series(
+    State(state): State<AppState>,
+    Path(series_id): Path<i32>,
+) -> ApiResult<impl IntoResponse> {
+    state
+        .tv_series_service
+        .delete(series_id)
+        .await
+        .map_err(|e| AppError::Biz(format!("Failed to delete tv series: {}", e)))?;
+
+    let response: ApiResponse<()> = ApiResponse::ok(
+        Some("Delete tv series successful"),
+        None,
+        None,
+        None,
+    );
+
+    Ok((StatusCode::OK, axum::Json(response)))
+}
+
+/// 获取单集视频流
+///
+/// 支持流式传输和 Range 请求（断点续传），与电影视频接口相同
+#[utoipa::path(
+    get,
+    path = "/api/tv-episodes/{episode_id}/video",
+    tag = "tv_series",
+    params(
+        ("episode_id" = i32, Path, description = "单集 ID")
+    ),
+    responses(
+        (status = 200, description = "返回完整视频"),
+        (status = 206, description = "返回部分视频内容（Range 请求）"),
+        (status = 404, description = "单集不存在"),
+        (status = 500, description = "服务器错误"),
+    )
+)]
+pub async fn get_tv_episode_video(
+    State(state): State<AppState>,
+    Path(episode_id): Path<i32>,
+    headers: HeaderMap,
+) -> Result<Response<Body>, AppError> {
+    let video_path = state
+        .tv_series_service
+        .get_episode_video_path(episode_id)
+        .await
+        .map_err(|e| AppError::Biz(e.to_string()))?;
+
+    serve_video_file(&video_path, &headers).await
+}
+
+// endregion
+
+// region: 路由配置
+
+/// 电视剧路由
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/tv-series", routing::get(get_tv_series_paged))
+        .route("/tv-series/{series_id}", routing::get(get_tv_series))
+        .route("/tv-series/{series_id}", routing::delete(delete_tv_series))
+        .route("/tv-series/{series_id}/seasons/{season_number}", routing::get(get_tv_season))
+        .route("/tv-episodes/{episode_id}/video", routing::get(get_tv_episode_video))
+        .route(
+            "/media-libraries/{media_library_id}/tv-series",
+            routing::get(get_tv_series_by_media_library),
+        )
+}
+
+// endregion
@@ -2,13 +2,17 @@ use crate::server;
 use axum::Router;
 use sea_orm::DatabaseConnection;
 use std::sync::Arc;
-use infrastructure::repository::{UserRepositoryImpl, MediaLibraryRepositoryImpl, MangaRepositoryImpl, MangaChapterRepositoryImpl, GameRepositoryImpl, MovieRepositoryImpl, PhotoRepositoryImpl, PhotoExifRepositoryImpl, PhotoAlbumRepositoryImpl, PhotoAlbumItemRepositoryImpl};
+use infrastructure::repository::{UserRepositoryImpl, MediaLibraryRepositoryImpl, MangaRepositoryImpl, MangaChapterRepositoryImpl, MangaSeriesRepositoryImpl, GameRepositoryImpl, MovieCollectionRepositoryImpl, MovieCreditRepositoryImpl, MovieExtraRepositoryImpl, MoviePlayStateRepositoryImpl, MovieRepositoryImpl, MovieSourceRepositoryImpl, MovieSubtitleRepositoryImpl, MovieTrackRepositoryImpl, PersonRepositoryImpl, TvSeriesRepositoryImpl, TvSeasonRepositoryImpl, TvEpisodeRepositoryImpl, PhotoRepositoryImpl, PhotoExifRepositoryImpl, PhotoAlbumRepositoryImpl, PhotoAlbumItemRepositoryImpl};
 use application::user_service::UserService;
 use application::auth_service::AuthService;
 use application::media_library_service::MediaLibraryService;
 use application::manga_service::MangaService;
+use application::manga_series_service::MangaSeriesService;
+use application::manga_metadata_service::MangaMetadataService;
 use application::game_service::GameService;
-use application::movie_service::MovieService;
+use application::movie_service::{MovieRepositories, MovieService};
+use application::person_service::PersonService;
+use application::tv_series_service::TvSeriesService;
 use application::photo_service::PhotoService;
 use application::image_service::ImageService;
 
@@ -19,8 +23,12 @@ pub struct AppState {
     pub media_library_service: Arc<MediaLibraryService>,
     pub manga_service: Arc<MangaService>,
     pub manga_chapter_service: Arc<application::manga_chapter_service::MangaChapterService>,
+    pub manga_series_service: Arc<MangaSeriesService>,
+    pub manga_metadata_service: Arc<MangaMetadataService>,
     pub game_service: Arc<GameService>,
     pub movie_service: Arc<MovieService>,
+    pub person_service: Arc<PersonService>,
+    pub tv_series_service: Arc<TvSeriesService>,
     pub photo_service: Arc<PhotoService>,
     pub image_service: Arc<ImageService>,
 }
@@ -32,8 +40,20 @@ impl AppState {
         let media_library_repo = Arc::new(MediaLibraryRepositoryImpl::new(db.clone()));
         let manga_repo = Arc::new(MangaRepositoryImpl::new(db.clone()));
         let manga_chapter_repo = Arc::new(MangaChapterRepositoryImpl::new(Arc::new(db.clone())));
+        let manga_series_repo = Arc::new(MangaSeriesRepositoryImpl::new(db.clone()));
         let game_repo = Arc::new(GameRepositoryImpl::new(db.clone()));
         let movie_repo = Arc::new(MovieRepositoryImpl::new(db.clone()));
+        let movie_track_repo = Arc::new(MovieTrackRepositoryImpl::new(db.clone()));
+        let movie_subtitle_repo = Arc::new(MovieSubtitleRepositoryImpl::new(db.clone()));
+        let movie_source_repo = Arc::new(MovieSourceRepositoryImpl::new(db.clone()));
+        let movie_extra_repo = Arc::new(MovieExtraRepositoryImpl::new(db.clone()));
+        let movie_credit_repo = Arc::new(MovieCreditRepositoryImpl::new(db.clone()));
+        let person_repo = Arc::new(PersonRepositoryImpl::new(db.clone()));
+        let movie_play_state_repo = Arc::new(MoviePlayStateRepositoryImpl::new(db.clone()));
+        let movie_collection_repo = Arc::new(MovieCollectionRepositoryImpl::new(db.clone()));
+        let tv_series_repo = Arc::new(TvSeriesRepositoryImpl::new(db.clone()));
+        let tv_season_repo = Arc::new(TvSeasonRepositoryImpl::new(db.clone()));
+        let tv_episode_repo = Arc::new(TvEpisodeRepositoryImpl::new(db.clone()));
         let photo_repo = Arc::new(PhotoRepositoryImpl::new(db.clone()));
         let photo_exif_repo = Arc::new(PhotoExifRepositoryImpl::new(db.clone()));
         let photo_album_repo = Arc::new(PhotoAlbumRepositoryImpl::new(db.clone()));
@@ -46,21 +66,40 @@ impl AppState {
         let user_service = Arc::new(UserService::new(user_repo));
         let auth_service = Arc::new(AuthService::new(user_service.clone()));
         let image_service = Arc::new(ImageService::new(manga_repo.clone(), manga_chapter_repo.clone()));
+        let manga_series_service = Arc::new(MangaSeriesService::new(manga_series_repo, manga_repo.clone()));
+        let movie_repos = MovieRepositories {
+            movie: movie_repo.clone(),
+            track: movie_track_repo,
+            subtitle: movie_subtitle_repo,
+            source: movie_source_repo,
+            extra: movie_extra_repo,
+            credit: movie_credit_repo.clone(),
+            person: person_repo.clone(),
+            play_state: movie_play_state_repo,
+            collection: movie_collection_repo,
+        };
         let media_library_service = Arc::new(MediaLibraryService::new(
-            media_library_repo,
+            media_library_repo.clone(),
             manga_repo.clone(),
             manga_chapter_repo.clone(),
             game_repo.clone(),
-            movie_repo.clone(),
+            movie_repos.clone(),
+            tv_series_repo.clone(),
+            tv_season_repo.clone(),
+            tv_episode_repo.clone(),
             photo_repo.clone(),
             photo_exif_repo.clone(),
             image_service.clone(),
+            manga_series_service.clone(),
             scan_task_manager,
         ));
         let manga_service = Arc::new(MangaService::new(manga_repo.clone()));
+        let manga_metadata_service = Arc::new(MangaMetadataService::new(manga_repo.clone()));
         let manga_chapter_service = Arc::new(application::manga_chapter_service::MangaChapterService::new(manga_chapter_repo.clone()));
         let game_service = Arc::new(GameService::new(game_repo));
-        let movie_service = Arc::new(MovieService::new(movie_repo));
+        let person_service = Arc::new(PersonService::new(person_repo, movie_credit_repo, movie_repo));
+        let movie_service = Arc::new(MovieService::new(movie_repos, media_library_repo));
+        let tv_series_service = Arc::new(TvSeriesService::new(tv_series_repo, tv_season_repo, tv_episode_repo));
         let photo_service = Arc::new(PhotoService::new(photo_repo, photo_exif_repo, photo_album_repo, photo_album_item_repo));
 
         AppState {
@@ -69,8 +108,12 @@ impl AppState {
             media_library_service,
             manga_service,
             manga_chapter_service,
+            manga_series_service,
+            manga_metadata_service,
             game_service,
             movie_service,
+            person_service,
+            tv_series_service,
             photo_service,
             image_service,
         }
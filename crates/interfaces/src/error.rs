@@ -11,6 +11,8 @@ pub enum AppError {
     NotFound,
     #[error("Method not allowed")]
     MethodNotAllowed,
+    #[error("Unauthorized")]
+    Unauthorized,
     #[error("{0}")]
     Biz(String),
     #[error("Error: {0}")]
@@ -22,6 +24,7 @@ impl AppError {
         match self {
             AppError::NotFound => StatusCode::NOT_FOUND,
             AppError::MethodNotAllowed => StatusCode::METHOD_NOT_ALLOWED,
+            AppError::Unauthorized => StatusCode::UNAUTHORIZED,
             AppError::Biz(_) => StatusCode::BAD_REQUEST,
             AppError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
         }
@@ -54,6 +57,13 @@ impl IntoResponse for AppError {
                     "❌ Method Not Allowed Error (405)"
                 );
             }
+            AppError::Unauthorized => {
+                tracing::warn!(
+                    status_code = response_code,
+                    error = %error_message,
+                    "🔒 Unauthorized Error (401)"
+                );
+            }
             AppError::Biz(msg) => {
                 tracing::warn!(
                     status_code = response_code,
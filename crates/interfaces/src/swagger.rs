@@ -1,10 +1,15 @@
 use utoipa::OpenApi;
 use application::dto::{
     LoginRequest, LoginResponse, RegisterRequest, UserInfo,
-    MediaLibraryInfo, MangaInfo, MangaChapterInfo, GameInfo, MovieInfo,
+    MediaLibraryInfo, MangaInfo, MangaChapterInfo, GameInfo, MovieInfo, MovieDetailInfo, MovieVersionInfo, MoviePartInfo, MovieTrackInfo, MovieSubtitleInfo, MovieExtraInfo, NfoExportResult,
+    MovieIdentifyCandidate, IdentifyMovieRequest, MovieCollectionInfo, MovieCollectionPartInfo, MovieCollectionDetail,
+    MovieCreditInfo, MovieFacetsInfo, MovieListResponse, MoviePlayItem, MoviePlayStateInfo, ReportMovieProgressRequest, PersonDetail, PersonMovieInfo,
+    TvSeriesInfo, TvSeriesDetailInfo, TvSeasonInfo, TvSeasonDetailInfo, TvEpisodeInfo,
     PagedResponse, CreateMediaLibraryRequest, PaginationQuery,
     FixPasswordsResponse, ImageInfo, OptimizedImageListResponse,
-    OptimizedChapterImageListResponse,
+    OptimizedChapterImageListResponse, UpdateMangaCoverRequest, UpdateChapterCoverRequest,
+    MangaSeriesInfo, MangaSeriesDetail, UpdateMangaSeriesRequest, MergeMangaSeriesRequest, SplitMangaSeriesRequest,
+    MangaMetadataSearchInfo, MangaMetadataInfo, MangaHealthReport, ChapterHealthReport,
     ScanGamesRequest, LaunchGameRequest, UpdateDefaultStartPathRequest,
     PhotoInfo, PhotoDetailInfo, PhotoExifInfo, PhotoAlbumInfo,
 };
@@ -19,7 +24,7 @@ use domain::entity::user::Model as UserModel;
 use crate::api::{
     auth, 
     user, 
-    media_library, manga, manga_chapter, game, movie, config, photo};
+    media_library, manga, manga_chapter, manga_series, game, movie, person, tv_series, config, photo};
 
 /// API 文档
 #[derive(OpenApi)]
@@ -37,10 +42,26 @@ use crate::api::{
         manga::get_manga_images,
         manga::get_manga_image,
         manga::get_manga_cover,
+        manga::update_manga_cover,
+        manga::download_manga,
+        manga::get_manga_health_report,
+        manga::get_manga_health,
+        manga::check_manga_health,
+        manga::search_manga_metadata,
+        manga::get_manga_metadata,
         manga_chapter::get_manga_chapters,
         manga_chapter::get_chapter_images,
         manga_chapter::get_chapter_image,
         manga_chapter::get_chapter_cover,
+        manga_chapter::update_chapter_cover,
+        manga_chapter::download_chapter,
+        manga_series::get_manga_series_paged,
+        manga_series::get_manga_series,
+        manga_series::update_manga_series,
+        manga_series::merge_manga_series,
+        manga_series::split_manga_series,
+        manga_series::group_manga_series,
+        manga_series::get_manga_series_cover,
         game::get_game,
         game::get_games_paged,
         game::scan_games,
@@ -53,6 +74,32 @@ use crate::api::{
         movie::get_movies_by_media_library,
         movie::delete_movie,
         movie::get_movie_video,
+        movie::get_movie_extras,
+        movie::get_movie_extra_video,
+        movie::get_movie_subtitles,
+        movie::get_movie_subtitle,
+        movie::get_movie_embedded_subtitle,
+        movie::get_movie_poster,
+        movie::get_movie_backdrop,
+        movie::get_movie_identify_candidates,
+        movie::identify_movie,
+        movie::write_movie_nfo,
+        movie::export_media_library_nfo,
+        movie::get_movie_collections,
+        movie::get_movie_collection,
+        movie::get_movie_progress,
+        movie::report_movie_progress,
+        movie::mark_movie_watched,
+        movie::mark_movie_unwatched,
+        movie::get_continue_watching,
+        movie::get_recently_watched,
+        person::get_person,
+        tv_series::get_tv_series_paged,
+        tv_series::get_tv_series,
+        tv_series::get_tv_season,
+        tv_series::get_tv_series_by_media_library,
+        tv_series::delete_tv_series,
+        tv_series::get_tv_episode_video,
         photo::get_photo,
         photo::get_photos_paged,
         photo::get_photos_by_media_library,
@@ -88,15 +135,53 @@ use crate::api::{
             MangaChapterInfo,
             OptimizedImageListResponse,
             OptimizedChapterImageListResponse,
+            UpdateMangaCoverRequest,
+            UpdateChapterCoverRequest,
+            MangaMetadataSearchInfo,
+            MangaMetadataInfo,
+            MangaHealthReport,
+            ChapterHealthReport,
+            MangaSeriesInfo,
+            MangaSeriesDetail,
+            UpdateMangaSeriesRequest,
+            MergeMangaSeriesRequest,
+            SplitMangaSeriesRequest,
             GameInfo,
             MovieInfo,
+            MovieListResponse,
+            MovieFacetsInfo,
+            MoviePlayStateInfo,
+            MoviePlayItem,
+            ReportMovieProgressRequest,
+            MovieDetailInfo,
+            MovieVersionInfo,
+            MoviePartInfo,
+            MovieTrackInfo,
+            MovieSubtitleInfo,
+            MovieExtraInfo,
+            MovieCreditInfo,
+            NfoExportResult,
+            MovieIdentifyCandidate,
+            MovieCollectionInfo,
+            MovieCollectionPartInfo,
+            MovieCollectionDetail,
+            IdentifyMovieRequest,
+            PersonDetail,
+            PersonMovieInfo,
+            TvSeriesInfo,
+            TvSeriesDetailInfo,
+            TvSeasonInfo,
+            TvSeasonDetailInfo,
+            TvEpisodeInfo,
             PhotoInfo,
             PhotoDetailInfo,
             PhotoExifInfo,
             PhotoAlbumInfo,
             PagedResponse<MangaInfo>,
+            PagedResponse<MangaSeriesInfo>,
             PagedResponse<GameInfo>,
             PagedResponse<MovieInfo>,
+            PagedResponse<TvSeriesInfo>,
             PagedResponse<PhotoInfo>,
             PaginationQuery,
             ImageInfo,
@@ -139,8 +224,11 @@ use crate::api::{
         (name = "media_library", description = "媒体库相关接口"),
         (name = "manga", description = "漫画相关接口（包括图片）"),
         (name = "manga_chapter", description = "漫画章节相关接口（包括图片）"),
+        (name = "manga_series", description = "漫画系列相关接口（多卷分组、合并、拆分）"),
         (name = "game", description = "游戏相关接口"),
         (name = "movie", description = "电影相关接口"),
+        (name = "person", description = "人物相关接口（演职人员和作品）"),
+        (name = "tv_series", description = "电视剧相关接口（季、单集和视频流）"),
         (name = "photo", description = "照片相关接口（包括相册）"),
         (name = "config", description = "配置相关接口"),
     )
@@ -0,0 +1,56 @@
+use serde::Deserialize;
+
+/// Manga 配置
+#[derive(Debug, Deserialize, Clone, Default)]
+pub struct MangaConfig {
+    /// AniList 配置
+    #[serde(default)]
+    pub anilist: AnilistConfig,
+}
+
+impl MangaConfig {
+    pub fn anilist(&self) -> &AnilistConfig {
+        &self.anilist
+    }
+}
+
+/// AniList 配置
+///
+/// AniList 的 GraphQL 接口无需 API Key
+#[derive(Debug, Deserialize, Clone)]
+pub struct AnilistConfig {
+    /// GraphQL 接口地址
+    #[serde(default = "default_api_url")]
+    pub api_url: String,
+
+    /// 是否启用 AniList
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+impl Default for AnilistConfig {
+    fn default() -> Self {
+        Self {
+            api_url: default_api_url(),
+            enabled: default_enabled(),
+        }
+    }
+}
+
+impl AnilistConfig {
+    pub fn api_url(&self) -> &str {
+        &self.api_url
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.enabled
+    }
+}
+
+fn default_api_url() -> String {
+    "https://graphql.anilist.co".to_string()
+}
+
+fn default_enabled() -> bool {
+    true
+}
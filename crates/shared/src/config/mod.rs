@@ -7,6 +7,7 @@ pub use server::{ServerConfig, ServerMode}; // 导出服务器配置结构体和
 pub use database::DatabaseConfig;
 pub use gamebox::GameboxConfig;
 pub use movie::MovieConfig;
+pub use manga::MangaConfig;
 // 导出数据库配置机构提
 
 
@@ -15,6 +16,7 @@ mod database;
 pub mod server;
 pub mod gamebox;
 pub mod movie;
+pub mod manga;
 // 服务器配置模块，包含ServerConfig定义
 
 static CONFIG: LazyLock<AppConfig> =
@@ -29,6 +31,8 @@ pub struct AppConfig {
     gamebox: GameboxConfig,
     #[serde(default)]
     movie: MovieConfig,
+    #[serde(default)]
+    manga: MangaConfig,
 }
 
 impl AppConfig {
@@ -67,6 +71,9 @@ impl AppConfig {
     pub fn movie(&self) -> &MovieConfig {
         &self.movie
     }
+    pub fn manga(&self) -> &MangaConfig {
+        &self.manga
+    }
 }
 
 // 获取全局配置的引用，线程安全且高效
@@ -0,0 +1,3 @@
+-- 记录扫描时发现的无法读取的页面（JSON 文件名数组，无损坏页面时为 NULL）
+ALTER TABLE Manga ADD COLUMN BadPages TEXT NULL;
+ALTER TABLE MangaChapter ADD COLUMN BadPages TEXT NULL;
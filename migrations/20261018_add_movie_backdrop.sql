@@ -0,0 +1,2 @@
+-- 电影背景图 URL（写入 fanart.jpg 和背景图缓存使用）
+ALTER TABLE Movie ADD COLUMN Backdrop TEXT NULL;
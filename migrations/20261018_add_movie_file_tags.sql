@@ -0,0 +1,5 @@
+-- 从文件名解析出的技术标记（来源、视频编码、版本、发布组）
+ALTER TABLE Movie ADD COLUMN Source TEXT NULL;
+ALTER TABLE Movie ADD COLUMN VideoCodec TEXT NULL;
+ALTER TABLE Movie ADD COLUMN Edition TEXT NULL;
+ALTER TABLE Movie ADD COLUMN ReleaseGroup TEXT NULL;
@@ -0,0 +1,2 @@
+-- 电影的 TMDB ID（手动识别后固定匹配结果，之后刷新元数据时不再重新搜索）
+ALTER TABLE Movie ADD COLUMN TmdbId INTEGER NULL;
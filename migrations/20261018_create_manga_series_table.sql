@@ -0,0 +1,22 @@
+-- 创建漫画系列表
+CREATE TABLE IF NOT EXISTS MangaSeries (
+    Id INTEGER PRIMARY KEY AUTOINCREMENT,
+    CreateTime DATETIME NOT NULL DEFAULT (datetime('now', 'localtime')),
+    UpdateTime DATETIME NOT NULL DEFAULT (datetime('now', 'localtime')),
+    Title TEXT NOT NULL,
+    NormalizedTitle TEXT NOT NULL,
+    Description TEXT,
+    CoverMangaId INTEGER,
+    MediaLibraryId INTEGER NOT NULL,
+    FOREIGN KEY (MediaLibraryId) REFERENCES MediaLibrary(Id) ON DELETE CASCADE,
+    FOREIGN KEY (CoverMangaId) REFERENCES Manga(Id) ON DELETE SET NULL
+);
+
+-- 漫画所属系列和卷号
+ALTER TABLE Manga ADD COLUMN SeriesId INTEGER REFERENCES MangaSeries(Id) ON DELETE SET NULL;
+ALTER TABLE Manga ADD COLUMN VolumeNumber REAL;
+
+-- 创建索引
+CREATE INDEX IF NOT EXISTS idx_manga_series_media_library_id ON MangaSeries(MediaLibraryId);
+CREATE INDEX IF NOT EXISTS idx_manga_series_normalized_title ON MangaSeries(NormalizedTitle);
+CREATE INDEX IF NOT EXISTS idx_manga_series_id ON Manga(SeriesId);
@@ -0,0 +1,19 @@
+-- 创建电影系列（合集）表
+CREATE TABLE IF NOT EXISTS MovieCollection (
+    Id INTEGER PRIMARY KEY AUTOINCREMENT,
+    CreateTime DATETIME NOT NULL DEFAULT (datetime('now', 'localtime')),
+    UpdateTime DATETIME NOT NULL DEFAULT (datetime('now', 'localtime')),
+    TmdbId INTEGER NOT NULL,
+    Name TEXT NOT NULL,
+    Overview TEXT,
+    PosterUrl TEXT,
+    BackdropUrl TEXT,
+    Parts TEXT
+);
+
+-- 电影所属系列
+ALTER TABLE Movie ADD COLUMN CollectionId INTEGER REFERENCES MovieCollection(Id) ON DELETE SET NULL;
+
+-- 创建索引
+CREATE UNIQUE INDEX IF NOT EXISTS idx_movie_collection_tmdb_id ON MovieCollection(TmdbId);
+CREATE INDEX IF NOT EXISTS idx_movie_collection_id ON Movie(CollectionId);
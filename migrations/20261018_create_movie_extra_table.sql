@@ -0,0 +1,15 @@
+-- 创建电影附加视频表（预告片、花絮、访谈、删减片段等）
+CREATE TABLE IF NOT EXISTS MovieExtra (
+    Id INTEGER PRIMARY KEY AUTOINCREMENT,
+    MovieId INTEGER NOT NULL,
+    ExtraType TEXT NOT NULL,
+    Title TEXT NOT NULL,
+    Path TEXT NOT NULL,
+    ByteSize INTEGER NOT NULL DEFAULT 0,
+    Extension TEXT,
+    Duration INTEGER NOT NULL DEFAULT 0,
+    FOREIGN KEY (MovieId) REFERENCES Movie(Id) ON DELETE CASCADE
+);
+
+-- 创建索引
+CREATE INDEX IF NOT EXISTS idx_movie_extra_movie_id ON MovieExtra(MovieId);
@@ -0,0 +1,20 @@
+-- 创建电影播放状态表（每个用户每部电影一条，用于跨设备续播和已看标记）
+CREATE TABLE IF NOT EXISTS MoviePlayState (
+    Id INTEGER PRIMARY KEY AUTOINCREMENT,
+    CreateTime DATETIME NOT NULL,
+    UpdateTime DATETIME NOT NULL,
+    UserId INTEGER NOT NULL,
+    MovieId INTEGER NOT NULL,
+    Position INTEGER NOT NULL DEFAULT 0,
+    Duration INTEGER NOT NULL DEFAULT 0,
+    PlayCount INTEGER NOT NULL DEFAULT 0,
+    Watched BOOLEAN NOT NULL DEFAULT 0,
+    LastPlayedTime DATETIME NOT NULL,
+    FOREIGN KEY (UserId) REFERENCES User(Id) ON DELETE CASCADE,
+    FOREIGN KEY (MovieId) REFERENCES Movie(Id) ON DELETE CASCADE
+);
+
+-- 创建索引
+CREATE UNIQUE INDEX IF NOT EXISTS idx_movie_play_state_user_movie ON MoviePlayState(UserId, MovieId);
+CREATE INDEX IF NOT EXISTS idx_movie_play_state_movie_id ON MoviePlayState(MovieId);
+CREATE INDEX IF NOT EXISTS idx_movie_play_state_last_played ON MoviePlayState(UserId, LastPlayedTime);
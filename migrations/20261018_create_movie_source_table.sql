@@ -0,0 +1,19 @@
+-- 创建电影媒体源表（同一电影的多个版本 / 分段文件）
+CREATE TABLE IF NOT EXISTS MovieSource (
+    Id INTEGER PRIMARY KEY AUTOINCREMENT,
+    MovieId INTEGER NOT NULL,
+    VersionIndex INTEGER NOT NULL DEFAULT 0,
+    PartIndex INTEGER,
+    Label TEXT NOT NULL,
+    Path TEXT NOT NULL,
+    ByteSize INTEGER NOT NULL DEFAULT 0,
+    Extension TEXT,
+    Duration INTEGER NOT NULL DEFAULT 0,
+    Width INTEGER NOT NULL DEFAULT 0,
+    Height INTEGER NOT NULL DEFAULT 0,
+    Resolution TEXT,
+    FOREIGN KEY (MovieId) REFERENCES Movie(Id) ON DELETE CASCADE
+);
+
+-- 创建索引
+CREATE INDEX IF NOT EXISTS idx_movie_source_movie_id ON MovieSource(MovieId);
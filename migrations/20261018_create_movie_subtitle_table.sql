@@ -0,0 +1,16 @@
+-- 创建电影外挂字幕表
+CREATE TABLE IF NOT EXISTS MovieSubtitle (
+    Id INTEGER PRIMARY KEY AUTOINCREMENT,
+    MovieId INTEGER NOT NULL,
+    Path TEXT NOT NULL,
+    Format TEXT NOT NULL,
+    Language TEXT,
+    Title TEXT,
+    IsDefault BOOLEAN NOT NULL DEFAULT 0,
+    IsForced BOOLEAN NOT NULL DEFAULT 0,
+    IsSdh BOOLEAN NOT NULL DEFAULT 0,
+    FOREIGN KEY (MovieId) REFERENCES Movie(Id) ON DELETE CASCADE
+);
+
+-- 创建索引
+CREATE INDEX IF NOT EXISTS idx_movie_subtitle_movie_id ON MovieSubtitle(MovieId);
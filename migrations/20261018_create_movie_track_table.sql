@@ -0,0 +1,23 @@
+-- 创建电影轨道表（视频 / 音频 / 字幕）
+CREATE TABLE IF NOT EXISTS MovieTrack (
+    Id INTEGER PRIMARY KEY AUTOINCREMENT,
+    MovieId INTEGER NOT NULL,
+    TrackType TEXT NOT NULL,
+    TrackIndex INTEGER NOT NULL,
+    Codec TEXT NOT NULL,
+    Language TEXT,
+    Title TEXT,
+    IsDefault BOOLEAN NOT NULL DEFAULT 0,
+    IsForced BOOLEAN NOT NULL DEFAULT 0,
+    Width INTEGER,
+    Height INTEGER,
+    FrameRate REAL,
+    IsHdr BOOLEAN NOT NULL DEFAULT 0,
+    BitRate INTEGER,
+    Channels INTEGER,
+    SampleRate INTEGER,
+    FOREIGN KEY (MovieId) REFERENCES Movie(Id) ON DELETE CASCADE
+);
+
+-- 创建索引
+CREATE INDEX IF NOT EXISTS idx_movie_track_movie_id ON MovieTrack(MovieId);
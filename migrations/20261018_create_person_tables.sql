@@ -0,0 +1,52 @@
+-- 创建人物表（演员、导演、编剧、制片人）
+CREATE TABLE IF NOT EXISTS Person (
+    Id INTEGER PRIMARY KEY AUTOINCREMENT,
+    CreateTime DATETIME NOT NULL DEFAULT (datetime('now', 'localtime')),
+    UpdateTime DATETIME NOT NULL DEFAULT (datetime('now', 'localtime')),
+    Name TEXT NOT NULL,
+    TmdbId INTEGER,
+    ProfileUrl TEXT
+);
+
+-- 创建电影演职人员表
+CREATE TABLE IF NOT EXISTS MovieCredit (
+    Id INTEGER PRIMARY KEY AUTOINCREMENT,
+    MovieId INTEGER NOT NULL,
+    PersonId INTEGER NOT NULL,
+    Role TEXT NOT NULL,
+    CharacterName TEXT,
+    SortOrder INTEGER NOT NULL DEFAULT 0,
+    FOREIGN KEY (MovieId) REFERENCES Movie(Id) ON DELETE CASCADE,
+    FOREIGN KEY (PersonId) REFERENCES Person(Id) ON DELETE CASCADE
+);
+
+-- 创建索引（有 TMDB ID 的人物按 ID 唯一，没有的按姓名唯一）
+CREATE UNIQUE INDEX IF NOT EXISTS idx_person_tmdb_id ON Person(TmdbId) WHERE TmdbId IS NOT NULL;
+CREATE UNIQUE INDEX IF NOT EXISTS idx_person_name ON Person(Name) WHERE TmdbId IS NULL;
+CREATE INDEX IF NOT EXISTS idx_movie_credit_movie_id ON MovieCredit(MovieId);
+CREATE INDEX IF NOT EXISTS idx_movie_credit_person_id ON MovieCredit(PersonId);
+
+-- 迁移 Movie 中已有的演职人员姓名（JSON 数组）
+CREATE TEMP TABLE MovieCreditImport AS
+SELECT m.Id AS MovieId, 'actor' AS Role, CAST(j.key AS INTEGER) AS SortOrder, TRIM(j.value) AS Name
+FROM Movie m, json_each(CASE WHEN json_valid(m.Actors) THEN m.Actors ELSE '[]' END) j
+UNION ALL
+SELECT m.Id, 'director', CAST(j.key AS INTEGER), TRIM(j.value)
+FROM Movie m, json_each(CASE WHEN json_valid(m.Directors) THEN m.Directors ELSE '[]' END) j
+UNION ALL
+SELECT m.Id, 'writer', CAST(j.key AS INTEGER), TRIM(j.value)
+FROM Movie m, json_each(CASE WHEN json_valid(m.Writers) THEN m.Writers ELSE '[]' END) j
+UNION ALL
+SELECT m.Id, 'producer', CAST(j.key AS INTEGER), TRIM(j.value)
+FROM Movie m, json_each(CASE WHEN json_valid(m.Producers) THEN m.Producers ELSE '[]' END) j;
+
+INSERT OR IGNORE INTO Person (Name)
+SELECT DISTINCT Name FROM MovieCreditImport WHERE Name <> '';
+
+INSERT INTO MovieCredit (MovieId, PersonId, Role, SortOrder)
+SELECT i.MovieId, p.Id, i.Role, i.SortOrder
+FROM MovieCreditImport i
+JOIN Person p ON p.Name = i.Name AND p.TmdbId IS NULL
+WHERE NOT EXISTS (SELECT 1 FROM MovieCredit c WHERE c.MovieId = i.MovieId);
+
+DROP TABLE MovieCreditImport;
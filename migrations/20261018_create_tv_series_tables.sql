@@ -0,0 +1,67 @@
+-- 创建电视剧表
+CREATE TABLE IF NOT EXISTS TvSeries (
+    Id INTEGER PRIMARY KEY AUTOINCREMENT,
+    CreateTime DATETIME NOT NULL,
+    UpdateTime DATETIME NOT NULL,
+    Title TEXT NOT NULL,
+    OriginalTitle TEXT,
+    Description TEXT,
+    Path TEXT NOT NULL,
+    FirstAirDate TEXT,
+    Rating REAL NOT NULL DEFAULT 0,
+    Votes INTEGER NOT NULL DEFAULT 0,
+    Genres TEXT,
+    Actors TEXT,
+    PosterUrls TEXT,
+    Cover TEXT,
+    TmdbId INTEGER,
+    SeasonCount INTEGER NOT NULL DEFAULT 0,
+    EpisodeCount INTEGER NOT NULL DEFAULT 0,
+    MediaLibraryId INTEGER NOT NULL,
+    FOREIGN KEY (MediaLibraryId) REFERENCES MediaLibrary(Id)
+);
+
+-- 创建电视剧季表
+CREATE TABLE IF NOT EXISTS TvSeason (
+    Id INTEGER PRIMARY KEY AUTOINCREMENT,
+    SeriesId INTEGER NOT NULL,
+    SeasonNumber INTEGER NOT NULL,
+    Title TEXT,
+    Description TEXT,
+    AirDate TEXT,
+    Cover TEXT,
+    EpisodeCount INTEGER NOT NULL DEFAULT 0,
+    FOREIGN KEY (SeriesId) REFERENCES TvSeries(Id) ON DELETE CASCADE
+);
+
+-- 创建电视剧单集表
+CREATE TABLE IF NOT EXISTS TvEpisode (
+    Id INTEGER PRIMARY KEY AUTOINCREMENT,
+    CreateTime DATETIME NOT NULL,
+    UpdateTime DATETIME NOT NULL,
+    SeriesId INTEGER NOT NULL,
+    SeasonId INTEGER NOT NULL,
+    SeasonNumber INTEGER NOT NULL,
+    EpisodeNumber INTEGER NOT NULL,
+    EpisodeNumberEnd INTEGER,
+    Title TEXT,
+    Description TEXT,
+    AirDate TEXT,
+    Path TEXT NOT NULL,
+    ByteSize INTEGER NOT NULL DEFAULT 0,
+    Extension TEXT,
+    Duration INTEGER NOT NULL DEFAULT 0,
+    Width INTEGER NOT NULL DEFAULT 0,
+    Height INTEGER NOT NULL DEFAULT 0,
+    Resolution TEXT,
+    Still TEXT,
+    Rating REAL NOT NULL DEFAULT 0,
+    FOREIGN KEY (SeriesId) REFERENCES TvSeries(Id) ON DELETE CASCADE,
+    FOREIGN KEY (SeasonId) REFERENCES TvSeason(Id) ON DELETE CASCADE
+);
+
+-- 创建索引
+CREATE INDEX IF NOT EXISTS idx_tv_series_media_library_id ON TvSeries(MediaLibraryId);
+CREATE UNIQUE INDEX IF NOT EXISTS idx_tv_season_series_number ON TvSeason(SeriesId, SeasonNumber);
+CREATE INDEX IF NOT EXISTS idx_tv_episode_season_id ON TvEpisode(SeasonId);
+CREATE INDEX IF NOT EXISTS idx_tv_episode_series_id ON TvEpisode(SeriesId);
@@ -0,0 +1,2 @@
+-- 电影的 IMDb ID（来自 NFO、文件名或提供者，写入 NFO 时保留）
+ALTER TABLE Movie ADD COLUMN ImdbId TEXT NULL;
@@ -0,0 +1,3 @@
+-- 动漫单集的绝对集号和发布组（字幕组），电视剧单集为 NULL
+ALTER TABLE TvEpisode ADD COLUMN AbsoluteNumber INTEGER NULL;
+ALTER TABLE TvEpisode ADD COLUMN ReleaseGroup TEXT NULL;
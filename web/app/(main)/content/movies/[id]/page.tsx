@@ -10,9 +10,20 @@ import { Chip } from "@heroui/chip";
 import { Button } from "@heroui/button";
 import { Spinner } from "@heroui/spinner";
 import { moviesApi } from "@/lib/api";
-import type { Movie } from "@/types/movie";
+import type { Movie, MovieCredit, MovieExtra, MoviePlayState } from "@/types/movie";
 import { getPlaceholderImage } from "@/lib/placeholder-images";
 
+// 附加视频类型的显示名称
+const EXTRA_TYPE_LABELS: Record<string, string> = {
+    trailer: "预告片",
+    sample: "样片",
+    behind_the_scenes: "幕后花絮",
+    interview: "访谈",
+    deleted_scene: "删减片段",
+    featurette: "特辑",
+    extra: "附加内容",
+};
+
 interface MovieDetailProps {
     params: Promise<{
         id: string;
@@ -30,6 +41,9 @@ export default function MovieDetail({ params }: MovieDetailProps) {
     const [loading, setLoading] = useState(true);
     const [error, setError] = useState<string | null>(null);
     const [activeTab, setActiveTab] = useState("overview");
+    const [extras, setExtras] = useState<MovieExtra[]>([]);
+    const [playingExtra, setPlayingExtra] = useState<MovieExtra | null>(null);
+    const [playState, setPlayState] = useState<MoviePlayState | null>(null);
 
     // 主题样式
     const themeStyles = {
@@ -69,6 +83,39 @@ export default function MovieDetail({ params }: MovieDetailProps) {
         loadMovie();
     }, [resolvedParams]);
 
+    // 加载预告片和花絮（失败时不影响详情展示）
+    useEffect(() => {
+        if (!resolvedParams) return;
+
+        moviesApi
+            .getExtras(parseInt(resolvedParams.id))
+            .then(setExtras)
+            .catch((err) => console.error("Failed to load extras:", err));
+    }, [resolvedParams]);
+
+    // 加载播放进度（失败时不影响详情展示）
+    useEffect(() => {
+        if (!resolvedParams) return;
+
+        moviesApi
+            .getProgress(parseInt(resolvedParams.id))
+            .then(setPlayState)
+            .catch((err) => console.error("Failed to load play state:", err));
+    }, [resolvedParams]);
+
+    // 切换已看 / 未看
+    const toggleWatched = async () => {
+        if (!movie) return;
+        try {
+            const state = playState?.watched
+                ? await moviesApi.markUnwatched(movie.id)
+                : await moviesApi.markWatched(movie.id);
+            setPlayState(state);
+        } catch (err) {
+            console.error("Failed to update watched state:", err);
+        }
+    };
+
     if (loading) {
         return (
             <div className="flex justify-center items-center min-h-screen">
@@ -91,8 +138,13 @@ export default function MovieDetail({ params }: MovieDetailProps) {
     // 提取数据
     const year = movie.release_date?.substring(0, 4);
     const director = movie.directors?.[0];
-    const poster = movie.cover || movie.poster_urls?.[0] || getPlaceholderImage('movies', movie.id);
-    const banner = movie.poster_urls?.[1] || poster; // 使用第二张海报作为横幅，或使用第一张
+    const hasPoster = Boolean(movie.cover || movie.poster_urls?.length);
+    const poster = hasPoster ? moviesApi.getPosterUrl(movie.id, 500) : getPlaceholderImage('movies', movie.id);
+    const banner = movie.backdrop ? moviesApi.getBackdropUrl(movie.id, 1920) : poster; // 优先使用背景图作为横幅，没有时使用海报
+
+    // 按职责和姓名查找演职人员记录（有记录时可以跳转到人物页）
+    const findCredit = (role: string, name: string): MovieCredit | undefined =>
+        movie.credits?.find((c) => c.role === role && c.name === name);
 
     // 判断画质
     let quality: "4K" | "1080P" | "HDR" | "IMAX" | undefined;
@@ -264,7 +316,29 @@ export default function MovieDetail({ params }: MovieDetailProps) {
                                 </svg>
                             }
                         >
-                            <span className="pointer-events-none">立即播放</span>
+                            <span className="pointer-events-none">
+                                {playState?.resume_position_seconds ? "继续播放" : "立即播放"}
+                            </span>
+                        </Button>
+
+                        {/* 已看 / 未看切换按钮 */}
+                        <Button
+                            size="lg"
+                            variant="bordered"
+                            onPress={toggleWatched}
+                            className={clsx(
+                                "border-2 backdrop-blur-sm px-6 py-6",
+                                isDark
+                                    ? "border-white/30 text-white hover:bg-white/10"
+                                    : "border-gray-400 text-gray-900 hover:bg-gray-100"
+                            )}
+                            startContent={
+                                <svg className="w-5 h-5" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                                    <path strokeLinecap="round" strokeLinejoin="round" strokeWidth={2} d="M5 13l4 4L19 7" />
+                                </svg>
+                            }
+                        >
+                            {playState?.watched ? "已看" : "标记为已看"}
                         </Button>
 
                         {/* 收藏按钮 */}
@@ -340,7 +414,8 @@ export default function MovieDetail({ params }: MovieDetailProps) {
                         {[
                             { id: "overview", name: "剧情简介", icon: "📖" },
                             { id: "cast", name: "演职员", icon: "🎭" },
-                            { id: "media", name: "海报剧照", icon: "🎬" }
+                            { id: "media", name: "海报剧照", icon: "🎬" },
+                            ...(extras.length > 0 ? [{ id: "extras", name: "预告花絮", icon: "🎞️" }] : [])
                         ].map((tab) => (
                             <button
                                 key={tab.id}
@@ -493,24 +568,38 @@ export default function MovieDetail({ params }: MovieDetailProps) {
                                         导演
                                     </h3>
                                     <div className="grid grid-cols-2 sm:grid-cols-3 md:grid-cols-4 lg:grid-cols-5 gap-4">
-                                        {movie.directors.map((dir, index) => (
+                                        {movie.directors.map((dir, index) => {
+                                            const credit = findCredit("director", dir);
+                                            return (
                                             <div
                                                 key={index}
+                                                onClick={() => credit && router.push(`/content/people/${credit.person_id}`)}
                                                 className={clsx(
                                                     "backdrop-blur-sm rounded-xl p-4 border transition-all hover:scale-105",
+                                                    credit && "cursor-pointer",
                                                     isDark
                                                         ? "bg-gradient-to-br from-red-900/20 to-red-800/20 border-red-500/20 hover:border-red-500/40"
                                                         : "bg-gradient-to-br from-red-50 to-red-100 border-red-300 hover:border-red-400"
                                                 )}
                                             >
                                                 <div className="text-center">
-                                                    <div className="w-16 h-16 mx-auto mb-3 bg-gradient-to-br from-red-600 to-red-700 rounded-full flex items-center justify-center text-2xl">
-                                                        🎭
-                                                    </div>
+                                                    {credit?.profile_url ? (
+                                                        <Image
+                                                            src={credit.profile_url}
+                                                            alt={dir}
+                                                            className="w-16 h-16 mx-auto mb-3 rounded-full object-cover"
+                                                            removeWrapper
+                                                        />
+                                                    ) : (
+                                                        <div className="w-16 h-16 mx-auto mb-3 bg-gradient-to-br from-red-600 to-red-700 rounded-full flex items-center justify-center text-2xl">
+                                                            🎭
+                                                        </div>
+                                                    )}
                                                     <p className={clsx("font-medium", themeStyles.textPrimary)}>{dir}</p>
                                                 </div>
                                             </div>
-                                        ))}
+                                            );
+                                        })}
                                     </div>
                                 </div>
                             )}
@@ -528,24 +617,41 @@ export default function MovieDetail({ params }: MovieDetailProps) {
                                         主要演员
                                     </h3>
                                     <div className="grid grid-cols-2 sm:grid-cols-3 md:grid-cols-4 lg:grid-cols-6 gap-4">
-                                        {movie.actors.map((actor, index) => (
+                                        {movie.actors.map((actor, index) => {
+                                            const credit = findCredit("actor", actor);
+                                            return (
                                             <div
                                                 key={index}
+                                                onClick={() => credit && router.push(`/content/people/${credit.person_id}`)}
                                                 className={clsx(
                                                     "backdrop-blur-sm rounded-xl p-4 border transition-all hover:scale-105",
+                                                    credit && "cursor-pointer",
                                                     isDark
                                                         ? "bg-gradient-to-br from-blue-900/20 to-blue-800/20 border-blue-500/20 hover:border-blue-500/40"
                                                         : "bg-gradient-to-br from-blue-50 to-blue-100 border-blue-300 hover:border-blue-400"
                                                 )}
                                             >
                                                 <div className="text-center">
-                                                    <div className="w-16 h-16 mx-auto mb-3 bg-gradient-to-br from-blue-600 to-blue-700 rounded-full flex items-center justify-center text-2xl">
-                                                        👤
-                                                    </div>
+                                                    {credit?.profile_url ? (
+                                                        <Image
+                                                            src={credit.profile_url}
+                                                            alt={actor}
+                                                            className="w-16 h-16 mx-auto mb-3 rounded-full object-cover"
+                                                            removeWrapper
+                                                        />
+                                                    ) : (
+                                                        <div className="w-16 h-16 mx-auto mb-3 bg-gradient-to-br from-blue-600 to-blue-700 rounded-full flex items-center justify-center text-2xl">
+                                                            👤
+                                                        </div>
+                                                    )}
                                                     <p className={clsx("font-medium text-sm", themeStyles.textPrimary)}>{actor}</p>
+                                                    {credit?.character && (
+                                                        <p className={clsx("text-xs mt-1", themeStyles.textTertiary)}>饰 {credit.character}</p>
+                                                    )}
                                                 </div>
                                             </div>
-                                        ))}
+                                            );
+                                        })}
                                     </div>
                                 </div>
                             )}
@@ -616,6 +722,53 @@ export default function MovieDetail({ params }: MovieDetailProps) {
                             )}
                         </div>
                     )}
+
+                    {activeTab === "extras" && (
+                        <div className={clsx(
+                            "backdrop-blur-sm rounded-2xl p-8 border",
+                            isDark
+                                ? "bg-gradient-to-br from-gray-900/50 to-gray-800/50 border-white/10"
+                                : "bg-white/80 border-gray-200"
+                        )}>
+                            <h3 className={clsx("text-2xl font-bold mb-6 flex items-center gap-2", themeStyles.textPrimary)}>
+                                <span className="text-3xl">🎞️</span>
+                                预告花絮
+                                <span className={clsx("text-sm font-normal ml-2", themeStyles.textTertiary)}>({extras.length})</span>
+                            </h3>
+                            {playingExtra && (
+                                <video
+                                    key={playingExtra.id}
+                                    src={moviesApi.getExtraVideoUrl(movie.id, playingExtra.id)}
+                                    className="w-full max-h-[70vh] rounded-xl bg-black mb-6"
+                                    controls
+                                    autoPlay
+                                />
+                            )}
+                            <div className="grid grid-cols-1 md:grid-cols-2 lg:grid-cols-3 gap-4">
+                                {extras.map((extra) => (
+                                    <button
+                                        key={extra.id}
+                                        onClick={() => setPlayingExtra(extra)}
+                                        className={clsx(
+                                            "text-left rounded-xl p-4 border transition-all",
+                                            playingExtra?.id === extra.id
+                                                ? "border-red-600"
+                                                : isDark ? "border-white/10 hover:border-white/30" : "border-gray-200 hover:border-gray-400"
+                                        )}
+                                    >
+                                        <div className="flex items-center gap-2 mb-2">
+                                            <Chip size="sm" variant="flat">{EXTRA_TYPE_LABELS[extra.extra_type] ?? extra.extra_type}</Chip>
+                                            {extra.duration > 0 && (
+                                                <span className={clsx("text-sm", themeStyles.textTertiary)}>{extra.formatted_duration}</span>
+                                            )}
+                                        </div>
+                                        <p className={clsx("font-medium truncate", themeStyles.textPrimary)}>{extra.title}</p>
+                                        <p className={clsx("text-sm", themeStyles.textTertiary)}>{extra.formatted_size}</p>
+                                    </button>
+                                ))}
+                            </div>
+                        </div>
+                    )}
                 </div>
                 </div>
             </div>
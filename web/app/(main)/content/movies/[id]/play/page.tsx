@@ -1,6 +1,6 @@
 "use client";
 
-import { useState, useEffect, use } from "react";
+import { useState, useEffect, useRef, use } from "react";
 import { useTheme } from "next-themes";
 import { useIsSSR } from "@react-aria/ssr";
 import clsx from "clsx";
@@ -16,6 +16,9 @@ import { moviesApi } from "@/lib/api";
 import type { Movie } from "@/types/movie";
 import { getPlaceholderImage } from "@/lib/placeholder-images";
 
+// 播放进度上报间隔（毫秒），暂停和播放结束时会立即上报
+const PROGRESS_REPORT_INTERVAL = 10 * 1000;
+
 interface PageProps {
     params: Promise<{ id: string }>;
 }
@@ -29,6 +32,12 @@ export default function MoviePlayPage({ params }: PageProps) {
     const [movie, setMovie] = useState<Movie | null>(null);
     const [loading, setLoading] = useState(true);
     const [error, setError] = useState<string | null>(null);
+    // 当前播放的版本和分段（分段电影按顺序播放）
+    const [sourceIndex, setSourceIndex] = useState(0);
+    const [partIndex, setPartIndex] = useState(0);
+    // 当前分段加载后跳转到的位置（秒），用于续播
+    const [startTime, setStartTime] = useState(0);
+    const lastReportRef = useRef(Date.now());
 
     // 主题样式
     const themeStyles = {
@@ -50,6 +59,21 @@ export default function MoviePlayPage({ params }: PageProps) {
                 setError(null);
                 const data = await moviesApi.getById(parseInt(resolvedParams.id));
                 setMovie(data);
+
+                // 从上次的播放位置续播，分段电影需要先定位到所在分段
+                const progress = await moviesApi.getProgress(data.id).catch(() => null);
+                const resume = progress?.resume_position_seconds;
+                if (resume) {
+                    const parts = data.versions?.find((v) => v.index === 0)?.parts ?? [];
+                    let offset = resume;
+                    let index = 0;
+                    while (index + 1 < parts.length && parts[index].duration > 0 && offset >= parts[index].duration) {
+                        offset -= parts[index].duration;
+                        index++;
+                    }
+                    setPartIndex(index);
+                    setStartTime(offset);
+                }
             } catch (err) {
                 console.error("Failed to load movie:", err);
                 setError("加载电影详情失败");
@@ -81,8 +105,48 @@ export default function MoviePlayPage({ params }: PageProps) {
     }
 
     // 获取视频 URL
-    const videoUrl = moviesApi.getVideoUrl(movie.id);
-    const poster = movie.cover || movie.poster_urls?.[0] || getPlaceholderImage('movies', movie.id);
+    const versions = movie.versions ?? [];
+    const currentVersion = versions.find((v) => v.index === sourceIndex);
+    const partCount = currentVersion?.parts.length ?? 1;
+    const videoUrl = moviesApi.getVideoUrl(movie.id, sourceIndex, partIndex);
+
+    // 当前分段播放结束后自动播放下一段
+    const handleEnded = () => {
+        if (partIndex + 1 < partCount) {
+            selectPart(partIndex + 1);
+        }
+    };
+
+    // 切换版本时从第一段开始
+    const selectVersion = (index: number) => {
+        setSourceIndex(index);
+        selectPart(0);
+    };
+
+    // 手动切换分段时从头播放
+    const selectPart = (index: number) => {
+        setPartIndex(index);
+        setStartTime(0);
+    };
+
+    // 上报播放进度，分段电影的位置和时长按整部电影计算
+    const handleProgress = (currentTime: number, duration: number, paused: boolean) => {
+        const now = Date.now();
+        if (!paused && now - lastReportRef.current < PROGRESS_REPORT_INTERVAL) return;
+        lastReportRef.current = now;
+
+        const parts = currentVersion?.parts ?? [];
+        const offset = parts.slice(0, partIndex).reduce((sum, part) => sum + part.duration, 0);
+        const total = partCount > 1 ? currentVersion?.duration : duration;
+        moviesApi
+            .reportProgress(movie.id, offset + currentTime, total && Number.isFinite(total) ? total : undefined)
+            .catch((err) => console.error("Failed to report progress:", err));
+    };
+    const poster = movie.backdrop
+        ? moviesApi.getBackdropUrl(movie.id, 1280)
+        : movie.cover || movie.poster_urls?.length
+            ? moviesApi.getPosterUrl(movie.id, 500)
+            : getPlaceholderImage('movies', movie.id);
 
     // 判断画质
     let quality: "4K" | "1080P" | "HDR" | "IMAX" | undefined;
@@ -122,11 +186,45 @@ export default function MoviePlayPage({ params }: PageProps) {
                 <div className="px-4 md:px-8 lg:px-16 pb-8">
                     <div className="relative aspect-video bg-black overflow-hidden">
                         <VideoPlayer
+                            key={videoUrl}
                             src={videoUrl}
                             poster={poster}
                             title={movie.title}
+                            autoPlay={partIndex > 0}
+                            onEnded={handleEnded}
+                            startTime={startTime}
+                            onProgress={handleProgress}
                         />
                     </div>
+
+                    {/* 版本和分段选择 */}
+                    {(versions.length > 1 || partCount > 1) && (
+                        <div className="flex flex-wrap items-center gap-2 mt-4">
+                            {versions.length > 1 && versions.map((version) => (
+                                <Button
+                                    key={version.index}
+                                    size="sm"
+                                    color={version.index === sourceIndex ? "primary" : "default"}
+                                    variant={version.index === sourceIndex ? "solid" : "bordered"}
+                                    onPress={() => selectVersion(version.index)}
+                                >
+                                    {version.label}
+                                    <span className="opacity-70">{version.formatted_size}</span>
+                                </Button>
+                            ))}
+                            {partCount > 1 && Array.from({ length: partCount }, (_, index) => (
+                                <Button
+                                    key={`part-${index}`}
+                                    size="sm"
+                                    color={index === partIndex ? "primary" : "default"}
+                                    variant={index === partIndex ? "flat" : "light"}
+                                    onPress={() => selectPart(index)}
+                                >
+                                    第 {index + 1} 段
+                                </Button>
+                            ))}
+                        </div>
+                    )}
                 </div>
 
                 {/* 电影信息和详情区域 */}
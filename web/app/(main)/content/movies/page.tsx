@@ -11,25 +11,28 @@ import MovieCard from "@/components/cards/movie-card";
 import { SearchIcon } from "@/components/icons";
 import { getPlaceholderImage } from "@/lib/placeholder-images";
 import { moviesApi } from "@/lib/api";
-import type { Movie } from "@/types/movie";
+import type { Movie, MovieFacets, MoviePaginationRequest, MovieSortKey } from "@/types/movie";
 
-// 排序选项
-const sortOptions = [
-    { id: "hot", name: "热门" },
+// 排序选项（对应后端的排序字段）
+const sortOptions: { id: MovieSortKey; name: string }[] = [
+    { id: "date_added", name: "最近添加" },
+    { id: "release_date", name: "最新上映" },
     { id: "rating", name: "评分" },
-    { id: "latest", name: "最新" },
-    { id: "views", name: "播放量" },
-    { id: "name", name: "名称" }
+    { id: "title", name: "名称" },
+    { id: "duration", name: "时长" },
+    { id: "file_size", name: "文件大小" }
 ];
 
-// 年份分类
-const yearCategories = [
-    { id: "2024", name: "2024" },
-    { id: "2023", name: "2023" },
-    { id: "2022", name: "2022" },
-    { id: "2021", name: "2021" },
-    { id: "older", name: "更早" }
-];
+// 年份筛选直接列出的年份数量，更早的合并为"更早"
+const RECENT_YEAR_COUNT = 4;
+
+// 画质筛选
+const resolutionOptions = [
+    { id: "all", name: "全部" },
+    { id: "4k", name: "4K" },
+    { id: "hd", name: "高清" },
+    { id: "sd", name: "标清" }
+] as const;
 
 // 地区分类
 const regionCategories = [
@@ -43,8 +46,9 @@ const regionCategories = [
 
 export default function Movies() {
     const [selectedCategory, setSelectedCategory] = useState("all");
-    const [selectedSort, setSelectedSort] = useState("hot");
+    const [selectedSort, setSelectedSort] = useState<MovieSortKey>("date_added");
     const [selectedYear, setSelectedYear] = useState("all");
+    const [selectedResolution, setSelectedResolution] = useState<(typeof resolutionOptions)[number]["id"]>("all");
     const [selectedRegion, setSelectedRegion] = useState("all");
     const [searchQuery, setSearchQuery] = useState("");
     const [movies, setMovies] = useState<Movie[]>([]);
@@ -52,22 +56,45 @@ export default function Movies() {
     const [currentPage, setCurrentPage] = useState(1);
     const [totalPages, setTotalPages] = useState(1);
     const [total, setTotal] = useState(0);
+    const [facets, setFacets] = useState<MovieFacets>({ genres: [], years: [] });
 
-    // 加载电影数据
+    // 直接列出的年份，更早的年份合并为"更早"
+    const recentYears = facets.years.slice(0, RECENT_YEAR_COUNT);
+    const hasOlderYears = facets.years.length > RECENT_YEAR_COUNT;
+
+    // 加载电影数据（筛选和排序由后端完成）
     useEffect(() => {
         loadMovies();
-    }, [currentPage]);
+    }, [currentPage, selectedCategory, selectedSort, selectedYear, selectedResolution]);
 
     const loadMovies = async () => {
+        const params: MoviePaginationRequest = {
+            page_index: currentPage,
+            page_size: 50,
+            sort: selectedSort,
+        };
+        if (selectedCategory !== "all" && selectedCategory !== "collected") {
+            params.genre = selectedCategory;
+        }
+        if (selectedYear === "older") {
+            params.year_to = recentYears[recentYears.length - 1] - 1;
+        } else if (selectedYear !== "all") {
+            params.year_from = parseInt(selectedYear);
+            params.year_to = parseInt(selectedYear);
+        }
+        if (selectedResolution !== "all") {
+            params.resolution = selectedResolution;
+        }
+
         try {
             setLoading(true);
-            const response = await moviesApi.getPaginated({
-                page_index: currentPage,
-                page_size: 50,
-            });
+            const response = await moviesApi.getPaginated(params);
             setMovies(response.items);
             setTotalPages(response.total_pages);
             setTotal(response.total);
+            if (response.facets) {
+                setFacets(response.facets);
+            }
         } catch (error) {
             console.error("Failed to load movies:", error);
         } finally {
@@ -75,23 +102,14 @@ export default function Movies() {
         }
     };
 
-    // 动态计算分类
-    const allGenres = new Set<string>();
-    movies.forEach(movie => {
-        movie.genres?.forEach(genre => allGenres.add(genre));
-    });
-
+    // 分类（类型来自后端返回的可选筛选值）
     const categories = [
-        { id: "all", name: "全部", count: movies.length },
-        { id: "collected", name: "我的收藏", count: 0 }, // TODO: 实现收藏功能
-        ...Array.from(allGenres).map(genre => ({
-            id: genre,
-            name: genre,
-            count: movies.filter(m => m.genres?.includes(genre)).length
-        }))
+        { id: "all", name: "全部" },
+        { id: "collected", name: "我的收藏" }, // TODO: 实现收藏功能
+        ...facets.genres.map(genre => ({ id: genre, name: genre }))
     ];
 
-    // 筛选数据
+    // 当前页内按标题搜索
     const filteredMovies = movies.filter(movie => {
         // 搜索过滤
         if (searchQuery) {
@@ -101,25 +119,9 @@ export default function Movies() {
             if (!titleMatch && !originalTitleMatch) return false;
         }
 
-        // 分类过滤
-        if (selectedCategory !== "all") {
-            if (selectedCategory === "collected") {
-                // TODO: 实现收藏功能
-                return false;
-            }
-            if (!movie.genres?.some(g => g.includes(selectedCategory))) {
-                return false;
-            }
-        }
-
-        // 年份过滤
-        if (selectedYear !== "all") {
-            const releaseYear = movie.release_date?.substring(0, 4);
-            if (selectedYear === "older") {
-                if (!releaseYear || parseInt(releaseYear) >= 2021) return false;
-            } else {
-                if (releaseYear !== selectedYear) return false;
-            }
+        // TODO: 实现收藏功能
+        if (selectedCategory === "collected") {
+            return false;
         }
 
         return true;
@@ -192,7 +194,7 @@ export default function Movies() {
                             onPress={() => setSelectedCategory(category.id)}
                             className="min-w-fit"
                         >
-                            {category.name} ({category.count})
+                            {category.id === "all" && selectedCategory === "all" ? `${category.name} (${total})` : category.name}
                         </Button>
                     ))}
                 </div>
@@ -228,15 +230,42 @@ export default function Movies() {
                                 >
                                     全部
                                 </Button>
-                                {yearCategories.map((year) => (
+                                {recentYears.map((year) => (
+                                    <Button
+                                        key={year}
+                                        variant={selectedYear === String(year) ? "solid" : "ghost"}
+                                        color={selectedYear === String(year) ? "primary" : "default"}
+                                        size="sm"
+                                        onPress={() => setSelectedYear(String(year))}
+                                    >
+                                        {year}
+                                    </Button>
+                                ))}
+                                {hasOlderYears && (
+                                    <Button
+                                        variant={selectedYear === "older" ? "solid" : "ghost"}
+                                        color={selectedYear === "older" ? "primary" : "default"}
+                                        size="sm"
+                                        onPress={() => setSelectedYear("older")}
+                                    >
+                                        更早
+                                    </Button>
+                                )}
+                            </div>
+                        </div>
+
+                        <div className="flex items-center gap-2">
+                            <span className="text-sm text-gray-600 dark:text-gray-400">画质:</span>
+                            <div className="flex gap-1">
+                                {resolutionOptions.map((option) => (
                                     <Button
-                                        key={year.id}
-                                        variant={selectedYear === year.id ? "solid" : "ghost"}
-                                        color={selectedYear === year.id ? "primary" : "default"}
+                                        key={option.id}
+                                        variant={selectedResolution === option.id ? "solid" : "ghost"}
+                                        color={selectedResolution === option.id ? "primary" : "default"}
                                         size="sm"
-                                        onPress={() => setSelectedYear(year.id)}
+                                        onPress={() => setSelectedResolution(option.id)}
                                     >
-                                        {year.name}
+                                        {option.name}
                                     </Button>
                                 ))}
                             </div>
@@ -290,8 +319,10 @@ export default function Movies() {
                             }
                         }
 
-                        // 获取海报（优先使用 cover，否则使用第一个 poster_url）
-                        const poster = movie.cover || movie.poster_urls?.[0] || getPlaceholderImage('movies', movie.id);
+                        // 获取海报（使用服务端缓存的缩略图，没有海报时使用占位图）
+                        const poster = movie.cover || movie.poster_urls?.length
+                            ? moviesApi.getPosterUrl(movie.id, 300)
+                            : getPlaceholderImage('movies', movie.id);
 
                         return (
                             <MovieCard
@@ -0,0 +1,142 @@
+"use client";
+
+import { useState, useEffect } from "react";
+import { useRouter } from "next/navigation";
+import { Image } from "@heroui/image";
+import { Button } from "@heroui/button";
+import { Spinner } from "@heroui/spinner";
+import MovieCard from "@/components/cards/movie-card";
+import { getPlaceholderImage } from "@/lib/placeholder-images";
+import { moviesApi, peopleApi } from "@/lib/api";
+import type { PersonDetail } from "@/types/person";
+
+// 演职人员职责的显示名称
+const ROLE_LABELS: Record<string, string> = {
+    actor: "演员",
+    director: "导演",
+    writer: "编剧",
+    producer: "制片人",
+};
+
+interface PersonDetailProps {
+    params: Promise<{
+        id: string;
+    }>;
+}
+
+export default function PersonDetailPage({ params }: PersonDetailProps) {
+    const router = useRouter();
+
+    const [resolvedParams, setResolvedParams] = useState<{ id: string } | null>(null);
+    const [person, setPerson] = useState<PersonDetail | null>(null);
+    const [loading, setLoading] = useState(true);
+    const [error, setError] = useState<string | null>(null);
+
+    // 解析 params Promise
+    useEffect(() => {
+        params.then(setResolvedParams);
+    }, [params]);
+
+    // 加载人物详情
+    useEffect(() => {
+        if (!resolvedParams) return;
+
+        const loadPerson = async () => {
+            try {
+                setLoading(true);
+                setError(null);
+                const data = await peopleApi.getById(parseInt(resolvedParams.id));
+                setPerson(data);
+            } catch (err) {
+                console.error("Failed to load person:", err);
+                setError("加载人物详情失败");
+            } finally {
+                setLoading(false);
+            }
+        };
+
+        loadPerson();
+    }, [resolvedParams]);
+
+    if (loading) {
+        return (
+            <div className="flex justify-center items-center min-h-screen">
+                <Spinner size="lg" label="加载中..." />
+            </div>
+        );
+    }
+
+    if (error || !person) {
+        return (
+            <div className="flex flex-col items-center justify-center min-h-screen gap-4">
+                <p className="text-lg text-gray-600 dark:text-gray-400">{error || "人物不存在"}</p>
+                <Button color="primary" onPress={() => router.push("/content/movies")}>
+                    返回电影列表
+                </Button>
+            </div>
+        );
+    }
+
+    // 汇总所有作品中的职责，如 "导演 · 编剧"
+    const roles = Array.from(new Set(person.filmography.flatMap((item) => item.roles)))
+        .map((role) => ROLE_LABELS[role] || role)
+        .join(" · ");
+
+    return (
+        <div className="w-full px-8 md:px-12 lg:px-16 py-8">
+            <div className="max-w-[1600px] mx-auto space-y-8">
+                {/* 人物信息 */}
+                <div className="flex items-center gap-6">
+                    {person.profile_url ? (
+                        <Image
+                            alt={person.name}
+                            src={person.profile_url}
+                            className="w-32 h-32 rounded-full object-cover shadow-xl"
+                            removeWrapper
+                        />
+                    ) : (
+                        <div className="w-32 h-32 rounded-full bg-gradient-to-br from-blue-600 to-blue-700 flex items-center justify-center text-5xl">
+                            👤
+                        </div>
+                    )}
+                    <div>
+                        <h1 className="text-4xl font-bold">{person.name}</h1>
+                        {roles && <p className="text-lg text-gray-500 mt-2">{roles}</p>}
+                        <p className="text-sm text-gray-500 mt-1">
+                            媒体库中共 {person.filmography.length} 部作品
+                        </p>
+                    </div>
+                </div>
+
+                {/* 作品列表 */}
+                <div className="grid grid-cols-2 sm:grid-cols-3 md:grid-cols-4 lg:grid-cols-6 gap-6">
+                    {person.filmography.map(({ movie, roles, character }) => {
+                        const poster = movie.cover || movie.poster_urls?.length
+                            ? moviesApi.getPosterUrl(movie.id, 300)
+                            : getPlaceholderImage('movies', movie.id);
+
+                        return (
+                            <div key={movie.id} className="space-y-2">
+                                <MovieCard
+                                    id={movie.id.toString()}
+                                    title={movie.title}
+                                    originalTitle={movie.original_title || undefined}
+                                    year={movie.release_date?.substring(0, 4)}
+                                    duration={movie.formatted_duration}
+                                    rating={movie.rating > 0 ? movie.rating : undefined}
+                                    genre={movie.genres || []}
+                                    poster={poster}
+                                    type="电影"
+                                    releaseDate={movie.release_date || undefined}
+                                />
+                                <p className="text-sm text-gray-500 text-center">
+                                    {character ? `饰 ${character}` : roles.map((role) => ROLE_LABELS[role] || role).join(" · ")}
+                                </p>
+                            </div>
+                        );
+                    })}
+                </div>
+            </div>
+        </div>
+    );
+}
@@ -33,6 +33,7 @@ export interface LocalLibraryData {
     movieMetadataDownloaders?: string; // 影片元数据下载器（逗号分隔字符串，如 "theMovieDb,theTVDB"）
     movieLanguage?: string; // 电影元数据语言（如 "zh-CN", "en-US"）
     movieMinFileSize?: number; // 电影最小文件大小（MB）
+    movieWriteNfo?: boolean; // 是否在视频旁写入 NFO 和图片
     photoThumbnailMaxWidth?: number; // 照片缩略图最大宽度（像素）
     photoThumbnailMaxHeight?: number; // 照片缩略图最大高度（像素）
     photoThumbnailResizeFilter?: 'triangle' | 'catmullrom' | 'lanczos3'; // 缩略图缩放算法
@@ -73,6 +74,7 @@ export const LocalLibraryForm = ({
     const [internalMovieDownloaders, setInternalMovieDownloaders] = useState<string[]>(["theMovieDb", "theTVDB"]);
     const [internalMovieLanguage, setInternalMovieLanguage] = useState<string>("zh-CN");
     const [internalMovieMinFileSize, setInternalMovieMinFileSize] = useState<number>(300);
+    const [internalMovieWriteNfo, setInternalMovieWriteNfo] = useState<boolean>(false);
     const [internalPhotoThumbnailWidth, setInternalPhotoThumbnailWidth] = useState<number>(300);
     const [internalPhotoThumbnailHeight, setInternalPhotoThumbnailHeight] = useState<number>(300);
     const [internalPhotoResizeFilter, setInternalPhotoResizeFilter] = useState<'triangle' | 'catmullrom' | 'lanczos3'>('triangle');
@@ -139,6 +141,7 @@ export const LocalLibraryForm = ({
                     : undefined,
                 movieLanguage: internalMovieLanguage,
                 movieMinFileSize: internalMovieMinFileSize,
+                movieWriteNfo: internalMovieWriteNfo,
                 photoThumbnailMaxWidth: internalPhotoThumbnailWidth,
                 photoThumbnailMaxHeight: internalPhotoThumbnailHeight,
                 photoThumbnailResizeFilter: internalPhotoResizeFilter,
@@ -156,6 +159,7 @@ export const LocalLibraryForm = ({
             setInternalMovieDownloaders(["theMovieDb", "theTVDB"]);
             setInternalMovieLanguage("zh-CN");
             setInternalMovieMinFileSize(300);
+            setInternalMovieWriteNfo(false);
             setInternalPhotoThumbnailWidth(300);
             setInternalPhotoThumbnailHeight(300);
             setInternalPhotoExtractExif(true);
@@ -172,6 +176,7 @@ export const LocalLibraryForm = ({
         setInternalMovieDownloaders(["theMovieDb", "theTVDB"]);
         setInternalMovieLanguage("zh-CN");
         setInternalMovieMinFileSize(300);
+        setInternalMovieWriteNfo(false);
         setErrors({});
         onClose();
     };
@@ -240,6 +245,8 @@ export const LocalLibraryForm = ({
                                             onMovieLanguageChange={setInternalMovieLanguage}
                                             movieMinFileSize={internalMovieMinFileSize}
                                             onMovieMinFileSizeChange={setInternalMovieMinFileSize}
+                                            movieWriteNfo={internalMovieWriteNfo}
+                                            onMovieWriteNfoChange={setInternalMovieWriteNfo}
                                         />
                                     </>
                                 )}
@@ -14,6 +14,8 @@ interface MovieConfigFormProps {
     onMovieLanguageChange?: (language: string) => void;
     movieMinFileSize?: number;
     onMovieMinFileSizeChange?: (minFileSize: number) => void;
+    movieWriteNfo?: boolean;
+    onMovieWriteNfoChange?: (writeNfo: boolean) => void;
 }
 
 export function MovieConfigForm({
@@ -23,10 +25,13 @@ export function MovieConfigForm({
     onMovieLanguageChange,
     movieMinFileSize = 300,
     onMovieMinFileSizeChange,
+    movieWriteNfo = false,
+    onMovieWriteNfoChange,
 }: MovieConfigFormProps) {
     const [internalDownloaders, setInternalDownloaders] = useState<string[]>(movieMetadataDownloaders);
     const [internalLanguage, setInternalLanguage] = useState<string>(movieLanguage);
     const [internalMinFileSize, setInternalMinFileSize] = useState<number>(movieMinFileSize);
+    const [internalWriteNfo, setInternalWriteNfo] = useState<boolean>(movieWriteNfo);
 
     const handleDownloaderChange = (downloader: string, checked: boolean) => {
         let updated: string[];
@@ -51,6 +56,11 @@ export function MovieConfigForm({
         onMovieMinFileSizeChange?.(value);
     };
 
+    const handleWriteNfoChange = (checked: boolean) => {
+        setInternalWriteNfo(checked);
+        onMovieWriteNfoChange?.(checked);
+    };
+
     const isDownloaderSelected = (downloader: string) => {
         return internalDownloaders.includes(downloader);
     };
@@ -199,6 +209,28 @@ export function MovieConfigForm({
                         </CardBody>
                     </Card>
                 </Tab>
+                <Tab key="nfo-export" title="NFO 导出">
+                    <Card>
+                        <CardBody className="gap-4">
+                            <div className="flex items-center gap-3 p-3 rounded-lg hover:bg-gray-100 dark:hover:bg-gray-800 transition-colors">
+                                <Checkbox
+                                    isSelected={internalWriteNfo}
+                                    onChange={(e) => handleWriteNfoChange(e.target.checked)}
+                                    color="primary"
+                                />
+                                <div className="flex-1">
+                                    <p className="font-semibold text-sm">写入 NFO 和图片</p>
+                                    <p className="text-xs text-gray-500 dark:text-gray-400">
+                                        扫描完成后在视频旁写入 movie.nfo、poster.jpg 和 fanart.jpg，供 Kodi / Jellyfin / Emby 读取
+                                    </p>
+                                </div>
+                            </div>
+                            <p className="text-xs text-gray-500 dark:text-gray-400">
+                                ⚠️ 已存在的用户 NFO 和图片不会被覆盖，需要媒体库目录有写入权限
+                            </p>
+                        </CardBody>
+                    </Card>
+                </Tab>
             </Tabs>
         </div>
     );
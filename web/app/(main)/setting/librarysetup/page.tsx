@@ -134,6 +134,9 @@ export default function LibrarySetup() {
                     if (data.movieMinFileSize !== undefined) {
                         config.movieMinFileSize = data.movieMinFileSize;
                     }
+                    if (data.movieWriteNfo !== undefined) {
+                        config.movieWriteNfo = data.movieWriteNfo;
+                    }
                     const languageDisplay = data.movieLanguage === "zh-CN" ? "简体中文" :
                         data.movieLanguage === "zh-TW" ? "繁體中文" :
                         data.movieLanguage === "en-US" ? "English" :
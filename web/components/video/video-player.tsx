@@ -9,9 +9,16 @@ interface VideoPlayerProps {
     src: string;
     poster?: string;
     title?: string;
+    // 加载后自动播放（分段电影切换到下一段时使用）
+    autoPlay?: boolean;
+    onEnded?: () => void;
+    // 加载后跳转到的位置（秒），用于续播
+    startTime?: number;
+    // 播放位置变化和暂停时回调，用于上报播放进度
+    onProgress?: (currentTime: number, duration: number, paused: boolean) => void;
 }
 
-export default function VideoPlayer({ src, poster, title }: VideoPlayerProps) {
+export default function VideoPlayer({ src, poster, title, autoPlay, onEnded, startTime, onProgress }: VideoPlayerProps) {
     const videoRef = useRef<HTMLVideoElement>(null);
     const [isPlaying, setIsPlaying] = useState(false);
     const [currentTime, setCurrentTime] = useState(0);
@@ -184,6 +191,15 @@ export default function VideoPlayer({ src, poster, title }: VideoPlayerProps) {
                 onClick={togglePlay}
                 preload="metadata"
                 controls={true}
+                autoPlay={autoPlay}
+                onEnded={onEnded}
+                onLoadedMetadata={(e) => {
+                    if (startTime && startTime > 0) {
+                        e.currentTarget.currentTime = startTime;
+                    }
+                }}
+                onTimeUpdate={(e) => onProgress?.(e.currentTarget.currentTime, e.currentTarget.duration, false)}
+                onPause={(e) => onProgress?.(e.currentTarget.currentTime, e.currentTarget.duration, true)}
                 onError={(e) => {
                     console.error('Video error:', e);
                     console.log('Video src:', src);
@@ -25,6 +25,7 @@ import { mangasApi } from './mangas';
 import { mangaChaptersApi } from './manga-chapters';
 import { gamesApi } from './games';
 import { moviesApi } from './movies';
+import { peopleApi } from './people';
 import { photosApi } from './photos';
 
 // 导出各个模块
@@ -38,6 +39,7 @@ export { mangasApi } from './mangas';
 export { mangaChaptersApi } from './manga-chapters';
 export { gamesApi } from './games';
 export { moviesApi } from './movies';
+export { peopleApi } from './people';
 export { photosApi } from './photos';
 
 // 创建统一的 API 对象
@@ -52,6 +54,7 @@ export const api = {
   mangaChapters: mangaChaptersApi,
   games: gamesApi,
   movies: moviesApi,
+  people: peopleApi,
   photos: photosApi,
 };
 
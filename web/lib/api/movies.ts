@@ -3,7 +3,7 @@
  */
 
 import { http } from '@/lib/http';
-import type { Movie, MoviePaginationResponse, MoviePaginationRequest } from '@/types/movie';
+import type { Movie, MovieExtra, MoviePaginationResponse, MoviePaginationRequest, MoviePlayItem, MoviePlayState } from '@/types/movie';
 
 // 电影数据缓存
 interface CacheEntry<T> {
@@ -61,24 +61,29 @@ export function clearMoviesCache(): void {
 export const moviesApi = {
   /**
    * 获取电影分页列表
-   * @param params 分页参数
-   * @returns 电影分页响应
+   * @param params 分页、筛选和排序参数
+   * @returns 电影分页响应（附带可选的类型和年份）
    */
   async getPaginated(params: MoviePaginationRequest = {}): Promise<MoviePaginationResponse> {
-    const pageIndex = params.page_index || 1;
-    const pageSize = params.page_size || 20;
+    const query = new URLSearchParams({
+      page_index: String(params.page_index || 1),
+      page_size: String(params.page_size || 20),
+    });
+    Object.entries(params).forEach(([key, value]) => {
+      if (key !== 'page_index' && key !== 'page_size' && value !== undefined && value !== '') {
+        query.set(key, String(value));
+      }
+    });
 
     // 检查缓存
-    const cacheKey = getCacheKey('/movies', { pageIndex, pageSize });
+    const cacheKey = getCacheKey('/movies', query.toString());
     const cached = getFromCache<MoviePaginationResponse>(cacheKey);
     if (cached) {
       return cached;
     }
 
     // 请求数据
-    const data = await http.get<MoviePaginationResponse>(
-      `/movies?page_index=${pageIndex}&page_size=${pageSize}`
-    );
+    const data = await http.get<MoviePaginationResponse>(`/movies?${query.toString()}`);
 
     // 存入缓存
     saveToCache(cacheKey, data);
@@ -116,11 +121,111 @@ export const moviesApi = {
   /**
    * 获取电影视频流 URL
    * @param id 电影 ID
+   * @param source 版本序号，默认版本时不传
+   * @param part 分段位置（从 0 开始），默认第一段
    * @returns 视频流 URL
    */
-  getVideoUrl(id: number): string {
+  getVideoUrl(id: number, source = 0, part = 0): string {
     const apiUrl = process.env.NEXT_PUBLIC_API_URL || 'http://localhost:8080/api';
-    return `${apiUrl}/movies/${id}/video`;
+    const query = source > 0 || part > 0 ? `?source=${source}&part=${part}` : '';
+    return `${apiUrl}/movies/${id}/video${query}`;
+  },
+
+  /**
+   * 获取电影的预告片和花絮
+   * @param id 电影 ID
+   * @returns 附加视频列表
+   */
+  async getExtras(id: number): Promise<MovieExtra[]> {
+    return await http.get<MovieExtra[]>(`/movies/${id}/extras`);
+  },
+
+  /**
+   * 获取附加视频流 URL
+   * @param id 电影 ID
+   * @param extraId 附加视频 ID
+   * @returns 视频流 URL
+   */
+  getExtraVideoUrl(id: number, extraId: number): string {
+    const apiUrl = process.env.NEXT_PUBLIC_API_URL || 'http://localhost:8080/api';
+    return `${apiUrl}/movies/${id}/extras/${extraId}/video`;
+  },
+
+  /**
+   * 获取当前用户的播放进度
+   * @param id 电影 ID
+   * @returns 播放状态
+   */
+  async getProgress(id: number): Promise<MoviePlayState> {
+    return await http.get<MoviePlayState>(`/movies/${id}/progress`);
+  },
+
+  /**
+   * 上报播放进度（播放到 90% 时自动标记为已看）
+   * @param id 电影 ID
+   * @param positionSeconds 当前播放位置（秒）
+   * @param durationSeconds 视频总时长（秒），不传时使用扫描到的时长
+   * @returns 更新后的播放状态
+   */
+  async reportProgress(id: number, positionSeconds: number, durationSeconds?: number): Promise<MoviePlayState> {
+    return await http.put<MoviePlayState>(`/movies/${id}/progress`, {
+      position_seconds: positionSeconds,
+      duration_seconds: durationSeconds,
+    });
+  },
+
+  /**
+   * 标记为已看
+   * @param id 电影 ID
+   */
+  async markWatched(id: number): Promise<MoviePlayState> {
+    return await http.post<MoviePlayState>(`/movies/${id}/watched`);
+  },
+
+  /**
+   * 标记为未看（清除播放进度）
+   * @param id 电影 ID
+   */
+  async markUnwatched(id: number): Promise<MoviePlayState> {
+    return await http.delete<MoviePlayState>(`/movies/${id}/watched`);
+  },
+
+  /**
+   * 获取继续观看列表
+   * @param limit 返回数量，默认 20
+   */
+  async getContinueWatching(limit = 20): Promise<MoviePlayItem[]> {
+    return await http.get<MoviePlayItem[]>(`/movies/continue-watching?limit=${limit}`);
+  },
+
+  /**
+   * 获取最近看过列表
+   * @param limit 返回数量，默认 20
+   */
+  async getRecentlyWatched(limit = 20): Promise<MoviePlayItem[]> {
+    return await http.get<MoviePlayItem[]>(`/movies/recently-watched?limit=${limit}`);
+  },
+
+  /**
+   * 获取电影海报 URL（服务端本地缓存，支持缩放）
+   * @param id 电影 ID
+   * @param width 最大宽度（像素），不传时返回原图
+   * @returns 海报 URL
+   */
+  getPosterUrl(id: number, width?: number): string {
+    const apiUrl = process.env.NEXT_PUBLIC_API_URL || 'http://localhost:8080/api';
+    return width ? `${apiUrl}/movies/${id}/poster?width=${width}` : `${apiUrl}/movies/${id}/poster`;
+  },
+
+  /**
+   * 获取电影背景图 URL（服务端本地缓存，支持缩放）
+   * @param id 电影 ID
+   * @param width 最大宽度（像素），不传时返回原图
+   * @returns 背景图 URL
+   */
+  getBackdropUrl(id: number, width?: number): string {
+    const apiUrl = process.env.NEXT_PUBLIC_API_URL || 'http://localhost:8080/api';
+    return width ? `${apiUrl}/movies/${id}/backdrop?width=${width}` : `${apiUrl}/movies/${id}/backdrop`;
   },
 };
 
@@ -0,0 +1,17 @@
+/**
+ * 人物 API 服务
+ */
+
+import { http } from '@/lib/http';
+import type { PersonDetail } from '@/types/person';
+
+export const peopleApi = {
+  /**
+   * 获取人物详情（包含所有媒体库中的作品）
+   * @param id 人物 ID
+   * @returns 人物详情
+   */
+  async getById(id: number): Promise<PersonDetail> {
+    return await http.get<PersonDetail>(`/people/${id}`);
+  },
+};
@@ -11,7 +11,7 @@ import { z } from 'zod';
  * 媒体库类型枚举
  */
 export const LibraryTypeEnum = z.enum([
-  '电影', '视频', '音乐', '电视节目', '有声读物', '书籍', '游戏', '漫画', '音乐视频', '照片', '混合内容'
+  '电影', '电视剧', '动漫', '视频', '音乐', '电视节目', '有声读物', '书籍', '游戏', '漫画', '音乐视频', '照片', '混合内容'
 ]);
 
 export type LibraryType = z.infer<typeof LibraryTypeEnum>;
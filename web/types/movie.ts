@@ -30,7 +30,76 @@ export interface Movie {
   tags: string[] | null;
   poster_urls: string[] | null;
   cover: string | null;
+  backdrop: string | null;
   media_library_id: number;
+  // 详情接口返回的所有版本（第一个为默认版本）
+  versions?: MovieVersion[];
+  // 详情接口返回的演职人员
+  credits?: MovieCredit[];
+}
+
+// 电影演职人员
+export interface MovieCredit {
+  person_id: number;
+  name: string;
+  // actor / director / writer / producer
+  role: string;
+  character: string | null;
+  profile_url: string | null;
+}
+
+// 电影版本（同一电影的不同文件，如 1080p / 2160p）
+export interface MovieVersion {
+  index: number;
+  label: string;
+  resolution: string | null;
+  byte_size: number;
+  formatted_size: string;
+  duration: number;
+  formatted_duration: string;
+  // 分段文件，按播放顺序排列
+  parts: MoviePart[];
+}
+
+// 电影分段文件（CD1 / CD2）
+export interface MoviePart {
+  part_index: number | null;
+  path: string;
+  extension: string | null;
+  byte_size: number;
+  duration: number;
+}
+
+// 电影附加视频（预告片、花絮等）
+export interface MovieExtra {
+  id: number;
+  // trailer / sample / behind_the_scenes / interview / deleted_scene / featurette / extra
+  extra_type: string;
+  title: string;
+  extension: string | null;
+  byte_size: number;
+  formatted_size: string;
+  duration: number;
+  formatted_duration: string;
+  url: string;
+}
+
+// 当前用户对电影的播放状态
+export interface MoviePlayState {
+  movie_id: number;
+  position_seconds: number;
+  duration_seconds: number;
+  // 续播位置，没有可续播的进度时为 null
+  resume_position_seconds: number | null;
+  play_count: number;
+  watched: boolean;
+  last_played: string | null;
+}
+
+// 继续观看 / 最近看过列表项
+export interface MoviePlayItem {
+  movie: Movie;
+  play_state: MoviePlayState;
 }
 
 // 电影分页响应
@@ -40,11 +109,33 @@ export interface MoviePaginationResponse {
   total: number;
   total_pages: number;
   items: Movie[];
+  // 可选的筛选值（只受媒体库条件影响）
+  facets?: MovieFacets;
 }
 
-// 电影分页请求参数
+// 电影列表可选的筛选值
+export interface MovieFacets {
+  genres: string[];
+  // 从新到旧
+  years: number[];
+}
+
+// 电影列表排序字段
+export type MovieSortKey = 'title' | 'release_date' | 'rating' | 'date_added' | 'file_size' | 'duration';
+
+// 电影分页请求参数（筛选条件不传时不参与筛选）
 export interface MoviePaginationRequest {
   page_index?: number;
   page_size?: number;
+  genre?: string;
+  year_from?: number;
+  year_to?: number;
+  min_rating?: number;
+  resolution?: 'sd' | 'hd' | '4k';
+  // 演员或导演姓名
+  person?: string;
+  media_library_id?: number;
+  sort?: MovieSortKey;
+  order?: 'asc' | 'desc';
 }
 
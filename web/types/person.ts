@@ -0,0 +1,23 @@
+/**
+ * 人物相关类型定义
+ */
+
+import type { Movie } from './movie';
+
+// 人物参与的一部电影
+export interface PersonMovie {
+  // actor / director / writer / producer，可能有多个
+  roles: string[];
+  character: string | null;
+  movie: Movie;
+}
+
+// 人物详情（包含所有媒体库中的作品）
+export interface PersonDetail {
+  id: number;
+  name: string;
+  tmdb_id: number | null;
+  profile_url: string | null;
+  // 按上映日期从新到旧
+  filmography: PersonMovie[];
+}